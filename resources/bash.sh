@@ -1,6 +1,8 @@
 # dr function to handle task execution
 dr() {
-    local cmd=$(command dela get-command -- "$@")
+    # Declared apart from the assignment, which would otherwise take the status of `local`
+    local cmd
+    cmd=$(command dela get-command -- "$@") || return $?
     eval "$cmd"
 }
 
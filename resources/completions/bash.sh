@@ -0,0 +1,24 @@
+# Task name completion for dela
+_dela_task_names() {
+    command dela complete-tasks 2>/dev/null
+}
+
+# Complete the task name given to dr
+_dela_complete_dr() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        COMPREPLY=($(compgen -W "$(_dela_task_names)" -- "$cur"))
+    fi
+}
+complete -o default -F _dela_complete_dr dr
+
+# Complete bare task names alongside commands at the start of a line (bash 5 and later)
+_dela_complete_command() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    if [ "$COMP_CWORD" -eq 0 ]; then
+        COMPREPLY=($(compgen -c -- "$cur") $(compgen -W "$(_dela_task_names)" -- "$cur"))
+    fi
+}
+if [ "${BASH_VERSINFO[0]}" -ge 5 ]; then
+    complete -I -F _dela_complete_command
+fi
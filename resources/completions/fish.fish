@@ -0,0 +1,3 @@
+# Task name completion for dela
+# fish has no hook for the command position, so only dr completes task names
+complete -c dr -f -n 'test (count (commandline -opc)) -eq 1' -a '(command dela complete-tasks 2>/dev/null)' -d 'dela task'
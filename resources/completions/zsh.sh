@@ -0,0 +1,21 @@
+# Task name completion for dela
+_dela_tasks() {
+    local -a tasks
+    tasks=(${(f)"$(command dela complete-tasks 2>/dev/null)"})
+    compadd -a tasks
+}
+
+# Complete the task name given to dr
+_dela_dr() {
+    if (( CURRENT == 2 )); then
+        _dela_tasks
+    fi
+}
+compdef _dela_dr dr
+
+# Complete bare task names alongside commands at the start of a line
+_dela_command() {
+    _command_names -e
+    _dela_tasks
+}
+compdef _dela_command -command-
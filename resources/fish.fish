@@ -1,12 +1,16 @@
 # dr function to handle task execution
 function dr
     set -l cmd (command dela get-command -- $argv)
-    if test $status -eq 0
-        set -x DELA_TASK_RUNNING 1
-        eval $cmd
-        set -e DELA_TASK_RUNNING
-        return $status
+    set -l get_status $status
+    if test $get_status -ne 0
+        return $get_status
     end
+    set -x DELA_TASK_RUNNING 1
+    eval $cmd
+    # Keep the task's exit status, which `set -e` would otherwise replace
+    set -l task_status $status
+    set -e DELA_TASK_RUNNING
+    return $task_status
 end
 
 # Command not found handler to delegate unknown commands to dela
@@ -27,9 +31,10 @@ function fish_command_not_found
         # Execute the task
         set -x DELA_TASK_RUNNING 1
         eval $cmd
+        set -l task_status $status
         set -e DELA_TASK_RUNNING
-        return $status
+        return $task_status
     end
     echo "fish: Unknown command: $argv[1]" >&2
     return 127
-end 
\ No newline at end of file
+end
@@ -0,0 +1,50 @@
+# dr function to handle task execution
+function dr {
+    $cmd = & dela get-command -- @args
+    if ($LASTEXITCODE -ne 0) {
+        return
+    }
+    $env:DELA_TASK_RUNNING = 1
+    try {
+        Invoke-Expression ($cmd -join "`n")
+    } finally {
+        Remove-Item Env:\DELA_TASK_RUNNING -ErrorAction SilentlyContinue
+    }
+}
+
+# Command not found handler to delegate unknown commands to dela
+$ExecutionContext.InvokeCommand.CommandNotFoundAction = {
+    param([string]$commandName, [System.Management.Automation.CommandLookupEventArgs]$lookup)
+
+    # Only handle commands typed at the prompt, and skip if we're already running a task
+    if ($env:DELA_TASK_RUNNING -or $lookup.CommandOrigin -ne 'Runspace') {
+        return
+    }
+
+    # Check if this is a dela task
+    & dela get-command -- $commandName *> $null
+    if ($LASTEXITCODE -ne 0) {
+        return
+    }
+
+    # Check if task is allowed - only passing the task name, not the arguments
+    & dela allow-command $commandName
+    if ($LASTEXITCODE -ne 0) {
+        return
+    }
+
+    # Run the task with whatever arguments were given to the unknown command
+    $lookup.CommandScriptBlock = {
+        $cmd = & dela get-command -- $commandName @args
+        if ($LASTEXITCODE -ne 0) {
+            return
+        }
+        $env:DELA_TASK_RUNNING = 1
+        try {
+            Invoke-Expression ($cmd -join "`n")
+        } finally {
+            Remove-Item Env:\DELA_TASK_RUNNING -ErrorAction SilentlyContinue
+        }
+    }.GetNewClosure()
+    $lookup.StopSearch = $true
+}
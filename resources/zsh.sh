@@ -1,6 +1,7 @@
 # dr function to handle task execution
 dr() {
-    cmd=$(command dela get-command -- "$@")
+    local cmd
+    cmd=$(command dela get-command -- "$@") || return $?
     eval "$cmd"
 }
 
@@ -1,8 +1,9 @@
 use crate::config::{active_allowlist_path, active_dela_config_dir, preferred_allowlist_path};
+use crate::glob::glob_match;
 use crate::prompt::{self, AllowDecision};
 use crate::types::{AllowScope, Allowlist, AllowlistEntry, Task};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// Returns the path to the active allowlist.toml.
 fn allowlist_path() -> anyhow::Result<PathBuf> {
@@ -62,6 +63,30 @@ fn path_matches(task_path: &Path, allowlist_path: &Path, allow_subdirs: bool) ->
     }
 }
 
+/// `path` with symlinks and `..` resolved, so a prefix check cannot be escaped. Paths
+/// that do not exist are only cleaned up lexically.
+fn resolved_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = fs::canonicalize(path) {
+        return canonical;
+    }
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+    resolved
+}
+
+/// Whether a task file is inside `dir` or any of its subdirectories.
+fn directory_tree_matches(task_path: &Path, dir: &Path) -> bool {
+    resolved_path(task_path).starts_with(resolved_path(dir))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AllowlistMatch {
     Allowed,
@@ -75,10 +100,19 @@ pub enum AllowlistMatch {
 pub fn evaluate_task_against_allowlist(task: &Task, allowlist: &Allowlist) -> AllowlistMatch {
     let task_path = task.allowlist_path();
 
-    // First pass: Check for deny entries (highest precedence)
+    let (pattern_entries, entries): (Vec<&AllowlistEntry>, Vec<&AllowlistEntry>) = allowlist
+        .entries
+        .iter()
+        .partition(|entry| entry.pattern.is_some());
+
+    // First pass: Check for deny entries, patterns included (highest precedence)
     for entry in &allowlist.entries {
         if let AllowScope::Deny = entry.scope {
-            if let Some(ref tasks) = entry.tasks {
+            if let Some(pattern) = &entry.pattern {
+                if pattern_matches(task, pattern, &entry.path) {
+                    return AllowlistMatch::Denied;
+                }
+            } else if let Some(ref tasks) = entry.tasks {
                 if path_matches(task_path, &entry.path, false) && tasks.contains(&task.name) {
                     return AllowlistMatch::Denied;
                 }
@@ -89,13 +123,18 @@ pub fn evaluate_task_against_allowlist(task: &Task, allowlist: &Allowlist) -> Al
     }
 
     // Second pass: Check for allow entries
-    for entry in &allowlist.entries {
+    for entry in &entries {
         match entry.scope {
             AllowScope::Directory => {
                 if path_matches(task_path, &entry.path, true) {
                     return AllowlistMatch::Allowed;
                 }
             }
+            AllowScope::DirectoryRecursive => {
+                if directory_tree_matches(task_path, &entry.path) {
+                    return AllowlistMatch::Allowed;
+                }
+            }
             AllowScope::File => {
                 if path_matches(task_path, &entry.path, false) {
                     return AllowlistMatch::Allowed;
@@ -113,9 +152,41 @@ pub fn evaluate_task_against_allowlist(task: &Task, allowlist: &Allowlist) -> Al
         }
     }
 
+    // Last pass: any allow pattern that matches allows the task
+    for entry in pattern_entries {
+        if let Some(pattern) = &entry.pattern
+            && !matches!(entry.scope, AllowScope::Deny | AllowScope::Once)
+            && pattern_matches(task, pattern, &entry.path)
+        {
+            return AllowlistMatch::Allowed;
+        }
+    }
+
     AllowlistMatch::NotFound
 }
 
+/// Match a pattern entry against `task`. A pattern containing a `/` is a glob over the
+/// definition file path, relative to `base` when the entry has a path; any other
+/// pattern is a glob over the task name. A non-empty `base` also limits the entry to
+/// files below it.
+fn pattern_matches(task: &Task, pattern: &str, base: &Path) -> bool {
+    let task_path = task.allowlist_path();
+    let relative = if base.as_os_str().is_empty() {
+        task_path
+    } else {
+        match task_path.strip_prefix(base) {
+            Ok(relative) => relative,
+            Err(_) => return false,
+        }
+    };
+
+    if pattern.contains('/') {
+        glob_match(pattern, &relative.to_string_lossy())
+    } else {
+        glob_match(pattern, &task.name)
+    }
+}
+
 pub fn allowlist_entry_for_task(task: &Task, scope: AllowScope) -> AllowlistEntry {
     let tasks = if scope == AllowScope::Task || scope == AllowScope::Deny {
         Some(vec![task.name.clone()])
@@ -123,10 +194,22 @@ pub fn allowlist_entry_for_task(task: &Task, scope: AllowScope) -> AllowlistEntr
         None
     };
 
+    let task_path = task.allowlist_path();
+    let path = match scope {
+        AllowScope::DirectoryRecursive => resolved_path(
+            task_path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .unwrap_or(Path::new(".")),
+        ),
+        _ => task_path.to_path_buf(),
+    };
+
     AllowlistEntry {
-        path: task.allowlist_path().to_path_buf(),
+        path,
         scope,
         tasks,
+        pattern: None,
     }
 }
 
@@ -143,6 +226,28 @@ pub fn is_task_allowed(task: &Task) -> anyhow::Result<(bool, bool)> {
     })
 }
 
+/// Whether a deny entry blocks `task`. Before `dela init` there is no allowlist, so
+/// nothing is denied.
+pub fn is_task_denied(task: &Task) -> anyhow::Result<bool> {
+    if !active_dela_config_dir()?.exists() {
+        return Ok(false);
+    }
+    Ok(evaluate_task_against_allowlist(task, &load_allowlist()?) == AllowlistMatch::Denied)
+}
+
+/// The error for running a task the allowlist denies. Deny entries are never
+/// overridden by a prompt or an allow choice.
+pub fn denied_error(task: &Task) -> anyhow::Error {
+    match preferred_allowlist_path() {
+        Ok(path) => anyhow::anyhow!(
+            "Dela task '{}' was denied by the {}",
+            task.name,
+            path.display()
+        ),
+        Err(_) => anyhow::anyhow!("Dela task '{}' was denied by the allowlist", task.name),
+    }
+}
+
 /// Check if a given task is allowed, based on the loaded allowlist
 /// If the task is not in the allowlist, prompt the user for a decision
 pub fn check_task_allowed(task: &Task) -> anyhow::Result<bool> {
@@ -223,6 +328,17 @@ mod tests {
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         }
     }
 
@@ -261,6 +377,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         };
 
         allowlist.entries.push(entry);
@@ -299,6 +416,7 @@ mod tests {
                 path: PathBuf::from("Makefile"),
                 scope: AllowScope::File,
                 tasks: None,
+                pattern: None,
             }],
         };
         let toml = toml::to_string_pretty(&legacy_allowlist).unwrap();
@@ -350,6 +468,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         save_allowlist(&allowlist).unwrap();
@@ -372,6 +491,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::Task,
             tasks: Some(vec!["test-task".to_string()]),
+            pattern: None,
         };
         allowlist.entries.push(entry);
         save_allowlist(&allowlist).unwrap();
@@ -398,6 +518,7 @@ mod tests {
             path: PathBuf::from("/project"),
             scope: AllowScope::Directory,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         save_allowlist(&allowlist).unwrap();
@@ -414,6 +535,125 @@ mod tests {
         reset_to_real_environment();
     }
 
+    #[test]
+    #[serial]
+    fn test_is_task_allowed_directory_recursive_scope() {
+        let (temp_dir, _task) = setup_test_env();
+        let project = temp_dir.path().join("project");
+        fs::create_dir_all(project.join("api/worker")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("project-other")).unwrap();
+        let root_task = create_test_task("build", project.join("Makefile"));
+
+        let entry = allowlist_entry_for_task(&root_task, AllowScope::DirectoryRecursive);
+        assert_eq!(entry.path, fs::canonicalize(&project).unwrap());
+        let allowlist = Allowlist {
+            entries: vec![entry],
+        };
+        save_allowlist(&allowlist).unwrap();
+
+        for allowed in [
+            project.join("Makefile"),
+            project.join("api/worker/Makefile"),
+        ] {
+            let task = create_test_task("build", allowed.clone());
+            assert_eq!(
+                is_task_allowed(&task).unwrap(),
+                (true, false),
+                "{:?}",
+                allowed
+            );
+        }
+        for outside in [
+            temp_dir.path().join("Makefile"),
+            temp_dir.path().join("project-other/Makefile"),
+            project.join("api/../../project-other/Makefile"),
+        ] {
+            let task = create_test_task("build", outside.clone());
+            assert_eq!(
+                is_task_allowed(&task).unwrap(),
+                (false, false),
+                "{:?}",
+                outside
+            );
+        }
+
+        drop(temp_dir);
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_pattern_entries() {
+        let allowlist: Allowlist = toml::from_str(
+            r#"
+[[entries]]
+scope = "Task"
+pattern = "build*"
+
+[[entries]]
+scope = "File"
+pattern = "**/package.json"
+
+[[entries]]
+path = "/project"
+scope = "Deny"
+pattern = "deploy*"
+
+[[entries]]
+path = "/project/ops/Makefile"
+scope = "Task"
+tasks = ["deploy-staging"]
+"#,
+        )
+        .unwrap();
+        let evaluate = |name: &str, path: &str| {
+            evaluate_task_against_allowlist(
+                &create_test_task(name, PathBuf::from(path)),
+                &allowlist,
+            )
+        };
+
+        // Task-name globs
+        assert_eq!(
+            evaluate("build", "/anywhere/Makefile"),
+            AllowlistMatch::Allowed
+        );
+        assert_eq!(
+            evaluate("build-release", "/x/Makefile"),
+            AllowlistMatch::Allowed
+        );
+        assert_eq!(evaluate("test", "/x/Makefile"), AllowlistMatch::NotFound);
+
+        // Path globs
+        assert_eq!(
+            evaluate("lint", "/web/app/package.json"),
+            AllowlistMatch::Allowed
+        );
+        assert_eq!(
+            evaluate("lint", "/web/app/package.json5"),
+            AllowlistMatch::NotFound
+        );
+
+        // A pattern with a path only applies below it
+        assert_eq!(
+            evaluate("deploy", "/project/Makefile"),
+            AllowlistMatch::Denied
+        );
+        assert_eq!(
+            evaluate("deploy", "/other/Makefile"),
+            AllowlistMatch::NotFound
+        );
+
+        // Deny patterns win over explicit allows
+        assert_eq!(
+            evaluate("deploy-staging", "/project/ops/Makefile"),
+            AllowlistMatch::Denied
+        );
+
+        // Only entries that use patterns write the field
+        let serialized = toml::to_string(&allowlist).unwrap();
+        assert_eq!(serialized.matches("pattern").count(), 3);
+    }
+
     #[test]
     #[serial]
     fn test_is_task_allowed_deny_scope() {
@@ -425,6 +665,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         save_allowlist(&allowlist).unwrap();
@@ -447,6 +688,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: Some(vec!["test-task".to_string()]),
+            pattern: None,
         };
         allowlist.entries.push(entry);
         save_allowlist(&allowlist).unwrap();
@@ -478,6 +720,7 @@ mod tests {
             path: PathBuf::from("/project/.github/workflows/test.yml"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         });
         save_allowlist(&allowlist).unwrap();
 
@@ -501,6 +744,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(allow_entry);
 
@@ -509,6 +753,7 @@ mod tests {
             path: PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(deny_entry);
 
@@ -537,4 +782,35 @@ mod tests {
         drop(temp_dir);
         reset_to_real_environment();
     }
+
+    #[test]
+    fn test_deny_pattern_beats_directory_allow() {
+        let allowlist = Allowlist {
+            entries: vec![
+                AllowlistEntry {
+                    path: PathBuf::from("/project/Makefile"),
+                    scope: AllowScope::Directory,
+                    tasks: None,
+                    pattern: None,
+                },
+                AllowlistEntry {
+                    path: PathBuf::new(),
+                    scope: AllowScope::Deny,
+                    tasks: None,
+                    pattern: Some("deploy*".to_string()),
+                },
+            ],
+        };
+
+        let deploy = create_test_task("deploy", PathBuf::from("/project/Makefile"));
+        let build = create_test_task("build", PathBuf::from("/project/Makefile"));
+        assert_eq!(
+            evaluate_task_against_allowlist(&deploy, &allowlist),
+            AllowlistMatch::Denied
+        );
+        assert_eq!(
+            evaluate_task_against_allowlist(&build, &allowlist),
+            AllowlistMatch::Allowed
+        );
+    }
 }
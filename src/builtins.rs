@@ -1,255 +1,354 @@
-use crate::environment::ENVIRONMENT;
+use crate::config::load_config;
+use crate::environment::{
+    ENVIRONMENT, Environment, RealEnvironment, current_environment, shell_name,
+};
 use crate::types::ShadowType;
-use std::path::Path;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// The builtins of each supported shell, hashed once so a check is a single lookup.
+/// Only the lists are cached: the shell is looked up on every check.
+static BUILTINS_BY_SHELL: Lazy<HashMap<&'static str, HashSet<&'static str>>> = Lazy::new(|| {
+    [
+        ("zsh", ZSH_BUILTINS),
+        ("bash", BASH_BUILTINS),
+        ("fish", FISH_BUILTINS),
+        ("pwsh", PWSH_BUILTINS),
+    ]
+    .into_iter()
+    .map(|(shell, builtins)| (shell, builtins.iter().copied().collect()))
+    .collect()
+});
 
 /// Check if a name is a shell builtin
 pub fn check_shell_builtin(name: &str) -> Option<ShadowType> {
     // Get current shell
     let shell = ENVIRONMENT.lock().unwrap().get_shell()?;
-    let shell_path = Path::new(&shell);
-    let shell_name = shell_path.file_name()?.to_str()?;
 
-    match shell_name {
+    match shell_name(&shell)?.as_str() {
         "zsh" => check_zsh_builtin(name),
         "bash" => check_bash_builtin(name),
         "fish" => check_fish_builtin(name),
-        "pwsh" => check_pwsh_builtin(name),
+        "pwsh" | "powershell" => check_pwsh_builtin(name),
         _ => None,
     }
 }
 
 /// Check if a name is a zsh builtin
 fn check_zsh_builtin(name: &str) -> Option<ShadowType> {
-    const ZSH_BUILTINS: &[&str] = &[
-        "cd",
-        "echo",
-        "pwd",
-        "export",
-        "alias",
-        "bg",
-        "bindkey",
-        "builtin",
-        "command",
-        "declare",
-        "dirs",
-        "disable",
-        "disown",
-        "enable",
-        "eval",
-        "exec",
-        "exit",
-        "fg",
-        "getopts",
-        "hash",
-        "jobs",
-        "kill",
-        "let",
-        "local",
-        "popd",
-        "print",
-        "pushd",
-        "read",
-        "readonly",
-        "return",
-        "set",
-        "setopt",
-        "shift",
-        "source",
-        "suspend",
-        "test",
-        "times",
-        "trap",
-        "type",
-        "typeset",
-        "ulimit",
-        "umask",
-        "unalias",
-        "unfunction",
-        "unhash",
-        "unset",
-        "unsetopt",
-        "wait",
-        "whence",
-        "where",
-        "which",
-        ".",
-        ":",
-        "[",
-        "ls",
-        "test",
-    ];
-
-    if ZSH_BUILTINS.contains(&name) {
-        Some(ShadowType::ShellBuiltin("zsh".to_string()))
-    } else {
-        None
-    }
+    check_builtin("zsh", name)
 }
 
 /// Check if a name is a bash builtin
 fn check_bash_builtin(name: &str) -> Option<ShadowType> {
-    const BASH_BUILTINS: &[&str] = &[
-        "cd",
-        "echo",
-        "pwd",
-        "export",
-        "alias",
-        "bg",
-        "bind",
-        "break",
-        "builtin",
-        "caller",
-        "command",
-        "compgen",
-        "complete",
-        "continue",
-        "declare",
-        "dirs",
-        "disown",
-        "enable",
-        "eval",
-        "exec",
-        "exit",
-        "fc",
-        "fg",
-        "getopts",
-        "hash",
-        "help",
-        "history",
-        "jobs",
-        "kill",
-        "let",
-        "local",
-        "logout",
-        "mapfile",
-        "popd",
-        "printf",
-        "pushd",
-        "pwd",
-        "read",
-        "readarray",
-        "readonly",
-        "return",
-        "set",
-        "shift",
-        "shopt",
-        "source",
-        "suspend",
-        "test",
-        "times",
-        "trap",
-        "type",
-        "typeset",
-        "ulimit",
-        "umask",
-        "unalias",
-        "unset",
-        "wait",
-        ".",
-        ":",
-        "[",
-        "ls",
-        "test",
-    ];
-
-    if BASH_BUILTINS.contains(&name) {
-        Some(ShadowType::ShellBuiltin("bash".to_string()))
-    } else {
-        None
-    }
+    check_builtin("bash", name)
 }
 
 /// Check if a name is a fish builtin
 fn check_fish_builtin(name: &str) -> Option<ShadowType> {
-    const FISH_BUILTINS: &[&str] = &[
-        "cd",
-        "echo",
-        "pwd",
-        "export",
-        "alias",
-        "bg",
-        "bind",
-        "block",
-        "breakpoint",
-        "builtin",
-        "case",
-        "command",
-        "commandline",
-        "complete",
-        "contains",
-        "count",
-        "dirh",
-        "dirs",
-        "disown",
-        "emit",
-        "eval",
-        "exec",
-        "exit",
-        "fg",
-        "fish_config",
-        "fish_update_completions",
-        "funced",
-        "funcsave",
-        "functions",
-        "help",
-        "history",
-        "isatty",
-        "jobs",
-        "math",
-        "nextd",
-        "open",
-        "popd",
-        "prevd",
-        "printf",
-        "pushd",
-        "pwd",
-        "random",
-        "read",
-        "realpath",
-        "set",
-        "set_color",
-        "source",
-        "status",
-        "string",
-        "test",
-        "time",
-        "trap",
-        "type",
-        "ulimit",
-        "umask",
-        "vared",
-        ".",
-        ":",
-        "[",
-        "ls",
-        "test",
-    ];
-
-    if FISH_BUILTINS.contains(&name) {
-        Some(ShadowType::ShellBuiltin("fish".to_string()))
-    } else {
-        None
-    }
+    check_builtin("fish", name)
 }
 
 /// Check if a name is a PowerShell builtin
 fn check_pwsh_builtin(name: &str) -> Option<ShadowType> {
-    #[rustfmt::skip]
-    const PWSH_BUILTINS: &[&str] = &[
-        "cd", "echo", "pwd", "export", "alias", "clear", "copy", "del",
-        "dir", "exit", "get", "help", "history", "kill", "mkdir", "move",
-        "popd", "pushd", "pwd", "read", "remove", "rename", "set", "start",
-        "test", "type", "wait", "where", "write", "ls", "rm", "cp", "mv",
-        "cat", "clear", "sleep", "sort", "tee", "write",
-    ];
-
-    if PWSH_BUILTINS.contains(&name) {
-        Some(ShadowType::ShellBuiltin("pwsh".to_string()))
+    check_builtin("pwsh", name)
+}
+
+fn check_builtin(shell: &'static str, name: &str) -> Option<ShadowType> {
+    BUILTINS_BY_SHELL
+        .get(shell)
+        .is_some_and(|builtins| builtins.contains(name))
+        .then(|| ShadowType::ShellBuiltin(shell.to_string()))
+}
+
+/// How long the shell may take to start and list its aliases before it is given up on.
+const ALIAS_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Aliases of the real shell, read once per process and only when `shell_aliases` is
+/// set under `[discovery]` in config.toml.
+static REAL_SHELL_ALIASES: Lazy<HashSet<String>> = Lazy::new(|| {
+    if load_config().is_ok_and(|config| config.discovery.shell_aliases) {
+        RealEnvironment.shell_aliases()
+    } else {
+        HashSet::new()
+    }
+});
+
+/// Check if a name is an alias in the user's shell
+pub fn check_shell_alias(name: &str) -> Option<ShadowType> {
+    let env = current_environment();
+    let shell = shell_name(&env.get_shell()?)?;
+    let is_alias = if env.is_real() {
+        REAL_SHELL_ALIASES.contains(name)
     } else {
-        None
+        env.shell_aliases().contains(name)
+    };
+    is_alias.then_some(ShadowType::ShellAlias(shell))
+}
+
+/// The alias names `shell` defines once its startup files have run, found by starting it
+/// interactively to run `alias`. Shells other than zsh, bash and fish, and shells that
+/// fail or take longer than [`ALIAS_TIMEOUT`], give no aliases.
+pub(crate) fn aliases_from_shell(shell: &str) -> HashSet<String> {
+    if !matches!(shell_name(shell).as_deref(), Some("zsh" | "bash" | "fish")) {
+        return HashSet::new();
     }
+    let Ok(mut child) = Command::new(shell)
+        .args(["-i", "-c", "alias"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return HashSet::new();
+    };
+
+    // Read on another thread so a shell printing a lot cannot block on a full pipe
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = std::thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+    let deadline = Instant::now() + ALIAS_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(20)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return HashSet::new();
+            }
+        }
+    }
+    reader
+        .join()
+        .map(|output| parse_alias_names(&output))
+        .unwrap_or_default()
 }
 
+/// Alias names from the output of `alias`: `name='value'` from zsh, `alias name='value'`
+/// from bash and `alias name 'value'` from fish. Other lines, such as a greeting printed
+/// by a startup file, are skipped.
+fn parse_alias_names(output: &str) -> HashSet<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let (rest, prefixed) = match line.strip_prefix("alias ") {
+                Some(rest) => (rest.trim_start(), true),
+                None => (line, false),
+            };
+            let end = rest.find(['=', ' ']).unwrap_or(rest.len());
+            if !prefixed && !rest[end..].starts_with('=') {
+                return None;
+            }
+            let name = rest[..end].trim_matches(['\'', '"']);
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+const ZSH_BUILTINS: &[&str] = &[
+    "cd",
+    "echo",
+    "pwd",
+    "export",
+    "alias",
+    "bg",
+    "bindkey",
+    "builtin",
+    "command",
+    "declare",
+    "dirs",
+    "disable",
+    "disown",
+    "enable",
+    "eval",
+    "exec",
+    "exit",
+    "fg",
+    "getopts",
+    "hash",
+    "jobs",
+    "kill",
+    "let",
+    "local",
+    "popd",
+    "print",
+    "pushd",
+    "read",
+    "readonly",
+    "return",
+    "set",
+    "setopt",
+    "shift",
+    "source",
+    "suspend",
+    "test",
+    "times",
+    "trap",
+    "type",
+    "typeset",
+    "ulimit",
+    "umask",
+    "unalias",
+    "unfunction",
+    "unhash",
+    "unset",
+    "unsetopt",
+    "wait",
+    "whence",
+    "where",
+    "which",
+    ".",
+    ":",
+    "[",
+    "ls",
+    "test",
+];
+
+const BASH_BUILTINS: &[&str] = &[
+    "cd",
+    "echo",
+    "pwd",
+    "export",
+    "alias",
+    "bg",
+    "bind",
+    "break",
+    "builtin",
+    "caller",
+    "command",
+    "compgen",
+    "complete",
+    "continue",
+    "declare",
+    "dirs",
+    "disown",
+    "enable",
+    "eval",
+    "exec",
+    "exit",
+    "fc",
+    "fg",
+    "getopts",
+    "hash",
+    "help",
+    "history",
+    "jobs",
+    "kill",
+    "let",
+    "local",
+    "logout",
+    "mapfile",
+    "popd",
+    "printf",
+    "pushd",
+    "pwd",
+    "read",
+    "readarray",
+    "readonly",
+    "return",
+    "set",
+    "shift",
+    "shopt",
+    "source",
+    "suspend",
+    "test",
+    "times",
+    "trap",
+    "type",
+    "typeset",
+    "ulimit",
+    "umask",
+    "unalias",
+    "unset",
+    "wait",
+    ".",
+    ":",
+    "[",
+    "ls",
+    "test",
+];
+
+const FISH_BUILTINS: &[&str] = &[
+    "cd",
+    "echo",
+    "pwd",
+    "export",
+    "alias",
+    "bg",
+    "bind",
+    "block",
+    "breakpoint",
+    "builtin",
+    "case",
+    "command",
+    "commandline",
+    "complete",
+    "contains",
+    "count",
+    "dirh",
+    "dirs",
+    "disown",
+    "emit",
+    "eval",
+    "exec",
+    "exit",
+    "fg",
+    "fish_config",
+    "fish_update_completions",
+    "funced",
+    "funcsave",
+    "functions",
+    "help",
+    "history",
+    "isatty",
+    "jobs",
+    "math",
+    "nextd",
+    "open",
+    "popd",
+    "prevd",
+    "printf",
+    "pushd",
+    "pwd",
+    "random",
+    "read",
+    "realpath",
+    "set",
+    "set_color",
+    "source",
+    "status",
+    "string",
+    "test",
+    "time",
+    "trap",
+    "type",
+    "ulimit",
+    "umask",
+    "vared",
+    ".",
+    ":",
+    "[",
+    "ls",
+    "test",
+];
+
+#[rustfmt::skip]
+const PWSH_BUILTINS: &[&str] = &[
+    "cd", "echo", "pwd", "export", "alias", "clear", "copy", "del",
+    "dir", "exit", "get", "help", "history", "kill", "mkdir", "move",
+    "popd", "pushd", "pwd", "read", "remove", "rename", "set", "start",
+    "test", "type", "wait", "where", "write", "ls", "rm", "cp", "mv",
+    "cat", "clear", "sleep", "sort", "tee", "write",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,4 +488,78 @@ mod tests {
         assert!(check_shell_builtin("cd").is_none());
         reset_to_real_environment();
     }
+
+    #[test]
+    #[serial]
+    fn test_check_shell_builtin_many_names() {
+        set_test_environment(TestEnvironment::new().with_shell("/bin/zsh"));
+        let names: Vec<String> = (0..20_000).map(|i| format!("task-{}", i)).collect();
+        let shadowed = names
+            .iter()
+            .chain(std::iter::repeat_n(&"cd".to_string(), 1000))
+            .filter(|name| check_shell_builtin(name).is_some())
+            .count();
+        reset_to_real_environment();
+
+        assert_eq!(shadowed, 1000);
+    }
+
+    #[test]
+    fn test_parse_alias_names() {
+        let names = parse_alias_names(
+            "Welcome back!\n\
+             ll='ls -l'\n\
+             gs=git status\n\
+             alias deploy='make deploy'\n\
+             alias serve 'npm start'\n\
+             alias\n",
+        );
+        let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, vec!["deploy", "gs", "ll", "serve"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_shell_alias() {
+        set_test_environment(
+            TestEnvironment::new()
+                .with_shell("/bin/bash")
+                .with_alias("deploy"),
+        );
+        assert_eq!(
+            check_shell_alias("deploy"),
+            Some(ShadowType::ShellAlias("bash".to_string()))
+        );
+        assert!(check_shell_alias("build").is_none());
+        reset_to_real_environment();
+
+        // Without a shell there is nothing to expand aliases
+        set_test_environment(TestEnvironment::new().with_alias("deploy"));
+        assert!(check_shell_alias("deploy").is_none());
+        reset_to_real_environment();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_aliases_from_shell() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let shell = temp_dir.path().join("bash");
+        std::fs::write(
+            &shell,
+            "#!/bin/sh\necho \"alias ll='ls -l'\"\necho \"alias deploy='make deploy'\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&shell, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let aliases = aliases_from_shell(&shell.to_string_lossy());
+        assert_eq!(aliases.len(), 2);
+        assert!(aliases.contains("deploy"));
+
+        // A shell that fails gives no aliases, as does one dela cannot read aliases from
+        std::fs::write(&shell, "#!/bin/sh\necho \"alias ll='ls -l'\"\nexit 1\n").unwrap();
+        assert!(aliases_from_shell(&shell.to_string_lossy()).is_empty());
+        assert!(aliases_from_shell("/usr/bin/pwsh").is_empty());
+    }
 }
@@ -24,6 +24,13 @@ fn execute_inner(task_name: &str) -> anyhow::Result<()> {
         )),
         1 => {
             let task = matching_tasks[0];
+            // An allow entry would have no effect, since deny entries take precedence
+            if allowlist::is_task_allowed(task)?.1 {
+                return Err(anyhow::anyhow!(
+                    "Task '{}' is denied in the allowlist; remove its deny entry to allow it",
+                    task.name
+                ));
+            }
             allowlist::check_task_allowed_with_scope(task, AllowScope::Task)?;
             println!(
                 "Added task '{}' ({}) to allowlist.",
@@ -226,4 +233,23 @@ test: ## Running tests
         assert!(allowlist_content.contains("test")); // Note: allowlist uses original task name
         assert!(allowlist_content.contains("scope = \"Task\""));
     }
+
+    #[test]
+    #[serial]
+    fn test_execute_allow_denied_task() {
+        let guard = TestEnvGuard::new();
+        env::set_current_dir(&guard.project_dir).expect("Failed to change directory");
+
+        let discovered = task_discovery::discover_tasks(&env::current_dir().unwrap());
+        let task = discovered.tasks.iter().find(|t| t.name == "test").unwrap();
+        allowlist::check_task_allowed_with_scope(task, AllowScope::Deny).unwrap();
+
+        let result = execute_inner("test");
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("remove its deny entry")
+        );
+    }
 }
@@ -1,5 +1,4 @@
 use crate::allowlist;
-use crate::config::preferred_allowlist_path;
 use crate::task_discovery;
 use crate::types::AllowScope;
 use anyhow::Context;
@@ -28,45 +27,41 @@ pub fn execute(task_with_args: &str, allow: Option<u8>) -> anyhow::Result<()> {
             let task = matching_tasks[0];
 
             // If allow option is provided, use it directly
-            if let Some(choice) = allow {
-                match choice {
-                    2 => {
-                        allowlist::check_task_allowed_with_scope(task, AllowScope::Task)?;
-                        Ok(())
-                    }
-                    3 => {
-                        allowlist::check_task_allowed_with_scope(task, AllowScope::File)?;
-                        Ok(())
-                    }
-                    4 => {
-                        allowlist::check_task_allowed_with_scope(task, AllowScope::Directory)?;
-                        Ok(())
-                    }
-                    5 => {
-                        eprintln!("Task '{}' was denied by the allowlist.", task.name);
-                        Err(anyhow::anyhow!(
-                            "Dela task '{}' was denied by the {}",
-                            task.name,
-                            preferred_allowlist_path()?.display()
-                        ))
-                    }
-                    _ => Err(anyhow::anyhow!(
-                        "Invalid allow choice {}. Please use a number between 2 and 5.",
-                        choice
-                    )),
-                }
-            } else {
-                // Otherwise, use the interactive prompt
-                if !allowlist::check_task_allowed(task)? {
-                    eprintln!("Task '{}' was denied by the allowlist.", task.name);
+            let scope = match allow {
+                None => None,
+                Some(2) => Some(AllowScope::Task),
+                Some(3) => Some(AllowScope::File),
+                Some(4) => Some(AllowScope::Directory),
+                Some(5) => Some(AllowScope::Deny),
+                Some(6) => Some(AllowScope::DirectoryRecursive),
+                Some(choice) => {
                     return Err(anyhow::anyhow!(
-                        "Dela task '{}' was denied by the {}",
-                        task.name,
-                        preferred_allowlist_path()?.display()
+                        "Invalid allow choice {}. Please use a number between 2 and 6.",
+                        choice
                     ));
                 }
-                Ok(())
+            };
+
+            // A deny entry wins over any choice, so it is checked before prompting
+            if allowlist::is_task_allowed(task)?.1 {
+                eprintln!("Task '{}' was denied by the allowlist.", task.name);
+                return Err(allowlist::denied_error(task));
+            }
+
+            let allowed = match scope {
+                Some(scope) => {
+                    // A deny is remembered the same way as one answered at the prompt
+                    allowlist::check_task_allowed_with_scope(task, scope.clone())?;
+                    scope != AllowScope::Deny
+                }
+                // Otherwise, use the interactive prompt
+                None => allowlist::check_task_allowed(task)?,
+            };
+            if !allowed {
+                eprintln!("Task '{}' was denied by the allowlist.", task.name);
+                return Err(allowlist::denied_error(task));
             }
+            Ok(())
         }
         _ => {
             // Multiple tasks found, print error and list them
@@ -244,6 +239,10 @@ test: ## Running tests
             execute("test", Some(4)).is_ok(),
             "Should succeed with allow=4"
         );
+        assert!(
+            execute("test", Some(6)).is_ok(),
+            "Should succeed with allow=6"
+        );
 
         // Test with deny option
         let result = execute("test", Some(5));
@@ -261,15 +260,15 @@ test: ## Running tests
         assert!(result.is_err(), "Should fail with allow=1");
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Invalid allow choice 1. Please use a number between 2 and 5."
+            "Invalid allow choice 1. Please use a number between 2 and 6."
         );
 
         // Test with out of range allow option
-        let result = execute("test", Some(6));
-        assert!(result.is_err(), "Should fail with allow=6");
+        let result = execute("test", Some(7));
+        assert!(result.is_err(), "Should fail with allow=7");
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Invalid allow choice 6. Please use a number between 2 and 5."
+            "Invalid allow choice 7. Please use a number between 2 and 6."
         );
 
         reset_to_real_environment();
@@ -459,4 +458,29 @@ build:
 
         reset_to_real_environment();
     }
+
+    #[test]
+    #[serial]
+    fn test_allow_command_deny_entry_cannot_be_overridden() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+
+        assert!(execute("test", Some(5)).is_err());
+
+        // Neither an allow choice nor the prompt runs a denied task
+        for allow in [Some(2), Some(6), None] {
+            let result = execute("test", allow);
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "Dela task 'test' was denied by the {}",
+                    preferred_allowlist_path_for(home_dir.path()).display()
+                ),
+                "allow choice {:?}",
+                allow
+            );
+        }
+
+        reset_to_real_environment();
+    }
 }
@@ -0,0 +1,11 @@
+use crate::task_discovery;
+
+pub fn execute_clear() -> anyhow::Result<()> {
+    let removed = task_discovery::clear_discovery_cache()?;
+    match removed {
+        0 => println!("The task cache is already empty."),
+        1 => println!("Cleared the cached tasks of 1 directory."),
+        _ => println!("Cleared the cached tasks of {} directories.", removed),
+    }
+    Ok(())
+}
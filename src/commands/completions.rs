@@ -0,0 +1,112 @@
+use crate::task_discovery::{self, DiscoveredTasks};
+use crate::task_shadowing;
+use std::env;
+use std::io::Write;
+
+const BASH_COMPLETIONS: &str = include_str!("../../resources/completions/bash.sh");
+const ZSH_COMPLETIONS: &str = include_str!("../../resources/completions/zsh.sh");
+const FISH_COMPLETIONS: &str = include_str!("../../resources/completions/fish.fish");
+
+/// Shells `dela completions` can print a script for.
+pub const COMPLETION_SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+/// Executes `dela completions <shell>`, printing a script that completes task names by
+/// calling `dela complete-tasks`.
+pub fn execute(shell: &str) -> anyhow::Result<()> {
+    let script = match shell {
+        "bash" => BASH_COMPLETIONS,
+        "zsh" => ZSH_COMPLETIONS,
+        "fish" => FISH_COMPLETIONS,
+        other => {
+            return Err(anyhow::anyhow!(
+                "Unsupported shell for completions: {}. Supported shells: {}",
+                other,
+                COMPLETION_SHELLS.join(", ")
+            ));
+        }
+    };
+    print!("{}", script);
+    Ok(())
+}
+
+/// Executes the hidden `dela complete-tasks`, printing the task names of the current
+/// directory one per line.
+pub fn execute_complete_tasks() -> anyhow::Result<()> {
+    let current_dir = env::current_dir()
+        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
+    // Completion runs on every keypress, so builtins and PATH are not probed
+    task_shadowing::disable_shadowing_checks();
+    let discovered = task_discovery::discover_tasks(&current_dir);
+
+    let mut stdout = std::io::stdout();
+    for name in task_names(&discovered) {
+        writeln!(stdout, "{}", name)?;
+    }
+    Ok(())
+}
+
+/// Every name a task can be run by: its own name and, if it has one, its
+/// disambiguated name. Names are sorted and listed once.
+fn task_names(discovered: &DiscoveredTasks) -> Vec<&str> {
+    let mut names: Vec<&str> = discovered
+        .tasks
+        .iter()
+        .flat_map(|task| {
+            std::iter::once(task.name.as_str()).chain(task.disambiguated_name.as_deref())
+        })
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::task_shadowing::{enable_mock, reset_mock};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn test_task_names_include_disambiguated_names() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "build:\n\techo build\n\ntest:\n\techo test\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"scripts": {"test": "jest", "lint": "eslint ."}}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("package-lock.json"), "{}").unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_executable("make")
+                .with_executable("npm"),
+        );
+
+        let discovered = task_discovery::discover_tasks(temp_dir.path());
+        assert_eq!(
+            task_names(&discovered),
+            vec!["build", "lint", "test", "test-m", "test-n"]
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_completion_scripts_call_complete_tasks() {
+        for script in [BASH_COMPLETIONS, ZSH_COMPLETIONS, FISH_COMPLETIONS] {
+            assert!(script.contains("dela complete-tasks"));
+        }
+        assert!(execute("tcsh").is_err());
+    }
+}
@@ -1,10 +1,10 @@
-use crate::environment::get_current_shell;
+use crate::environment::{get_current_shell, shell_name};
 use anyhow::Context;
 
 const ZSH_CONFIG: &str = include_str!("../../resources/zsh.sh");
 const BASH_CONFIG: &str = include_str!("../../resources/bash.sh");
-const FISH_CONFIG: &str = include_str!("../../resources/fish.sh");
-const PWSH_CONFIG: &str = include_str!("../../resources/pwsh.ps1");
+const FISH_CONFIG: &str = include_str!("../../resources/fish.fish");
+const POWERSHELL_CONFIG: &str = include_str!("../../resources/powershell.ps1");
 
 #[derive(Debug, PartialEq)]
 enum Shell {
@@ -17,17 +17,13 @@ enum Shell {
 
 impl Shell {
     fn from_path(path: &str) -> anyhow::Result<Shell> {
-        let shell_path = std::path::PathBuf::from(path);
-        let shell_name = shell_path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .context("Invalid shell path")?;
+        let shell_name = shell_name(path).context("Invalid shell path")?;
 
-        match shell_name {
+        match shell_name.as_str() {
             "zsh" => Ok(Shell::Zsh),
             "bash" => Ok(Shell::Bash),
             "fish" => Ok(Shell::Fish),
-            "pwsh" => Ok(Shell::Pwsh),
+            "pwsh" | "powershell" => Ok(Shell::Pwsh),
             name => Ok(Shell::Unknown(name.to_string())),
         }
     }
@@ -55,7 +51,7 @@ pub fn execute() -> anyhow::Result<()> {
             Ok(())
         }
         Shell::Pwsh => {
-            print!("{}", PWSH_CONFIG);
+            print!("{}", POWERSHELL_CONFIG);
             Ok(())
         }
         Shell::Unknown(name) => Err(anyhow::anyhow!("Unsupported shell: {}", name)),
@@ -109,6 +105,19 @@ mod tests {
         reset_to_real_environment();
     }
 
+    #[test]
+    fn test_windows_powershell_paths() {
+        for path in [
+            r"C:\Program Files\PowerShell\7\pwsh.exe",
+            r"C:\Windows\System32\WindowsPowerShell\v1.0\powershell.exe",
+            "/usr/local/bin/pwsh",
+        ] {
+            assert_eq!(Shell::from_path(path).unwrap(), Shell::Pwsh, "{}", path);
+        }
+        assert!(POWERSHELL_CONFIG.contains("CommandNotFoundAction"));
+        assert!(POWERSHELL_CONFIG.contains("function dr"));
+    }
+
     #[test]
     #[serial]
     fn test_unknown_shell() {
@@ -146,4 +155,17 @@ mod tests {
         );
         reset_to_real_environment();
     }
+
+    #[test]
+    fn test_fish_config_keeps_task_exit_status() {
+        // `set -e` succeeds and replaces $status, so the task's status is saved first
+        let runs: Vec<&str> = FISH_CONFIG.split("eval $cmd\n").skip(1).collect();
+        assert_eq!(runs.len(), 2);
+        for rest in runs {
+            let saved = rest.find("set -l task_status $status").unwrap();
+            let unset = rest.find("set -e DELA_TASK_RUNNING").unwrap();
+            assert!(saved < unset);
+            assert!(rest[unset..].contains("return $task_status"));
+        }
+    }
 }
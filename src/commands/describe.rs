@@ -0,0 +1,171 @@
+use crate::config;
+use crate::task_discovery;
+use crate::types::Task;
+use std::env;
+use std::io::Write;
+
+/// Executes `dela describe <task>`, printing the task's definition as written in its file.
+///
+/// The name resolves like `dela run` resolves it: project aliases, `runner:task` names and
+/// `runner_priority` apply, and a name still matching several tasks is an error.
+pub fn execute(task_name: &str) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()
+        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
+    let discovered = task_discovery::discover_tasks(&current_dir);
+    let task_name = config::load_project_config(&current_dir)?
+        .resolve_alias(task_name)
+        .to_string();
+
+    let mut matching_tasks =
+        task_discovery::get_matching_tasks_qualified(&discovered, task_name.as_str());
+    if matching_tasks.is_empty() {
+        return Err(anyhow::anyhow!(
+            task_discovery::format_task_not_found_error(&discovered, task_name.as_str())
+        ));
+    }
+    task_discovery::apply_runner_priority(&mut matching_tasks);
+    if matching_tasks.len() > 1 {
+        let error_msg =
+            task_discovery::format_ambiguous_task_error(task_name.as_str(), &matching_tasks);
+        println!("{}", error_msg);
+        return Err(anyhow::anyhow!("Ambiguous task name: '{}'", task_name));
+    }
+
+    write_definition(matching_tasks[0], &mut std::io::stdout())
+}
+
+/// Write a header naming the task and where it is defined, then its body verbatim. Tasks
+/// whose parser keeps no body show their description, or else the command dela runs.
+fn write_definition(task: &Task, out: &mut dyn Write) -> anyhow::Result<()> {
+    let location = match task.line {
+        Some(line) => format!("{}:{}", task.definition_path().display(), line),
+        None => task.definition_path().display().to_string(),
+    };
+    writeln!(
+        out,
+        "{} ({}) from {}",
+        task.name,
+        task.runner.short_name(),
+        location
+    )?;
+    match (&task.body, &task.description) {
+        (Some(body), _) => writeln!(out, "{}", body)?,
+        (None, Some(description)) => writeln!(out, "{}", description)?,
+        (None, None) => writeln!(out, "{}", task.runner.get_command(task))?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::task_shadowing::{enable_mock, reset_mock};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    fn describe(task: &Task) -> String {
+        let mut out = Vec::new();
+        write_definition(task, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[serial]
+    fn test_write_definition_shows_bodies() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "CC := cc\n\nbuild: main.c\n\t$(CC) -o app main.c\n\tstrip app\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("justfile"),
+            "# Run the test suite\ntest filter=\"\":\n    cargo test {{filter}}\n\n    echo done\n\nlint:\n    cargo clippy\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("Taskfile.yml"),
+            "version: '3'\ntasks:\n  release:\n    cmds:\n      - task: build\n      - gh release create\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_executable("make")
+                .with_executable("just")
+                .with_executable("task"),
+        );
+        let discovered = task_discovery::discover_tasks(temp_dir.path());
+        reset_mock();
+        reset_to_real_environment();
+        let find = |name: &str| discovered.tasks.iter().find(|t| t.name == name).unwrap();
+
+        assert_eq!(
+            describe(find("build")),
+            format!(
+                "build (make) from {}:3\n$(CC) -o app main.c\nstrip app\n",
+                temp_dir.path().join("Makefile").display()
+            )
+        );
+        assert_eq!(
+            describe(find("test")),
+            format!(
+                "test (just) from {}:2\n    cargo test {{{{filter}}}}\n\n    echo done\n",
+                temp_dir.path().join("justfile").display()
+            )
+        );
+        assert_eq!(
+            describe(find("release")),
+            format!(
+                "release (task) from {}\n- task: build\n- gh release create\n",
+                temp_dir.path().join("Taskfile.yml").display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_definition_falls_back_to_description() {
+        let mut task = Task {
+            name: "lint".to_string(),
+            file_path: "Makefile".into(),
+            definition_path: None,
+            definition_type: crate::types::TaskDefinitionType::Makefile,
+            runner: crate::types::TaskRunner::Make,
+            source_name: "lint".to_string(),
+            description: Some("Check the code".to_string()),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        assert_eq!(
+            describe(&task),
+            "lint (make) from Makefile\nCheck the code\n"
+        );
+
+        task.description = None;
+        assert_eq!(describe(&task), "lint (make) from Makefile\nmake lint\n");
+    }
+
+    #[test]
+    #[serial]
+    fn test_describe_missing_task() {
+        let temp_dir = TempDir::new().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let error = execute("missing").unwrap_err().to_string();
+        assert!(error.contains("missing"), "{}", error);
+    }
+}
@@ -0,0 +1,731 @@
+use crate::allowlist::{load_allowlist, save_allowlist};
+use crate::commands::init::{
+    INTEGRATION_SENTINEL, add_shell_integration, get_current_shell, get_shell_config_path,
+    integration_line,
+};
+use crate::config::{active_allowlist_path, active_dela_config_dir};
+use crate::prompt;
+use crate::runner::is_runner_available;
+use crate::task_discovery;
+use crate::types::{Allowlist, AllowlistEntry, TaskRunner};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when a check finds something `dela doctor --fix` could repair.
+static REPAIRABLE_FOUND: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, PartialEq)]
+enum IntegrationState {
+    Current,
+    Missing,
+    /// The sentinel comment is present but the line after it is no longer what `dela init` writes.
+    Outdated,
+}
+
+/// Executes `dela doctor`, optionally applying fixes for the issues it finds.
+///
+/// Fails when a check marked ✗ is still failing afterwards, so CI can run it.
+pub fn execute(fix: bool) -> anyhow::Result<()> {
+    println!("Checking dela setup...");
+    REPAIRABLE_FOUND.store(false, Ordering::Relaxed);
+
+    let mut failures = 0;
+    let config_ready = check_config_dir(fix)?;
+    if !config_ready || !check_allowlist(fix)? {
+        failures += 1;
+    }
+    if !check_shell_integration(fix)? {
+        failures += 1;
+    }
+    check_runners();
+    if !check_tasks() {
+        failures += 1;
+    }
+    if !check_discovery_cache(fix)? {
+        failures += 1;
+    }
+
+    if !fix && REPAIRABLE_FOUND.load(Ordering::Relaxed) {
+        println!("\nRun 'dela doctor --fix' to repair the issues above.");
+    }
+    if failures > 0 {
+        return Err(anyhow::anyhow!("{} doctor check(s) failed", failures));
+    }
+    Ok(())
+}
+
+fn report_ok(message: &str) {
+    println!("  {} {}", "✓".green(), message);
+}
+
+fn report_warning(message: &str) {
+    println!("  {} {}", "!".yellow(), message);
+}
+
+fn report_failure(message: &str) {
+    println!("  {} {}", "✗".red(), message);
+}
+
+/// Note that the issue just reported is one `--fix` can repair.
+fn mark_repairable() {
+    REPAIRABLE_FOUND.store(true, Ordering::Relaxed);
+}
+
+fn report_fixed(message: &str) {
+    println!("    {} {}", "→".cyan(), message);
+}
+
+fn check_config_dir(fix: bool) -> anyhow::Result<bool> {
+    let dela_dir = active_dela_config_dir()?;
+    if dela_dir.exists() {
+        report_ok(&format!("Config directory {}", dela_dir.display()));
+        return Ok(true);
+    }
+
+    report_failure(&format!(
+        "Config directory {} does not exist",
+        dela_dir.display()
+    ));
+    mark_repairable();
+    if !fix {
+        return Ok(false);
+    }
+
+    fs::create_dir_all(&dela_dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create dela config directory: {}", e))?;
+    save_allowlist(&Allowlist::default())?;
+    report_fixed(&format!(
+        "Created {} with an empty allowlist",
+        dela_dir.display()
+    ));
+    Ok(true)
+}
+
+/// Returns false if the allowlist is missing or unreadable and was not repaired.
+fn check_allowlist(fix: bool) -> anyhow::Result<bool> {
+    let allowlist_path = active_allowlist_path()?;
+    if !allowlist_path.exists() {
+        report_failure(&format!(
+            "Allowlist {} does not exist",
+            allowlist_path.display()
+        ));
+        mark_repairable();
+        if !fix {
+            return Ok(false);
+        }
+        save_allowlist(&Allowlist::default())?;
+        report_fixed(&format!(
+            "Created {} with an empty allowlist",
+            allowlist_path.display()
+        ));
+        return Ok(true);
+    }
+
+    let mut allowlist = match load_allowlist() {
+        Ok(allowlist) => allowlist,
+        Err(error) => {
+            report_failure(&format!(
+                "Allowlist {}: {}",
+                allowlist_path.display(),
+                error
+            ));
+            mark_repairable();
+            if fix
+                && allowlist_path.exists()
+                && prompt::confirm("Replace the unreadable allowlist with an empty one?")?
+            {
+                let backup = backup_file(&allowlist_path)?;
+                save_allowlist(&Allowlist::default())?;
+                report_fixed(&format!(
+                    "Reset allowlist, previous contents saved to {}",
+                    backup.display()
+                ));
+                return Ok(true);
+            }
+            return Ok(false);
+        }
+    };
+
+    let dead_entries = find_dead_allowlist_entries(&allowlist);
+    if dead_entries.is_empty() {
+        report_ok(&format!(
+            "Allowlist {} ({} entries)",
+            allowlist_path.display(),
+            allowlist.entries.len()
+        ));
+        return Ok(true);
+    }
+
+    report_warning(&format!(
+        "Allowlist has {} entries pointing at paths that no longer exist",
+        dead_entries.len()
+    ));
+    mark_repairable();
+    for entry in &dead_entries {
+        println!("      {}", entry.path.display().to_string().dimmed());
+    }
+
+    if fix && prompt::confirm("Remove these allowlist entries?")? {
+        let backup = backup_file(&allowlist_path)?;
+        let removed = prune_dead_allowlist_entries(&mut allowlist);
+        save_allowlist(&allowlist)?;
+        report_fixed(&format!(
+            "Removed {} entries, previous allowlist saved to {}",
+            removed.len(),
+            backup.display()
+        ));
+    }
+
+    Ok(true)
+}
+
+/// Returns false if the shell integration is missing and was not added.
+fn check_shell_integration(fix: bool) -> anyhow::Result<bool> {
+    let (shell, config_path) = match (get_current_shell(), get_shell_config_path()) {
+        (Ok(shell), Ok(config_path)) => (shell, config_path),
+        (Err(error), _) | (_, Err(error)) => {
+            report_warning(&format!("Could not determine shell: {}", error));
+            return Ok(true);
+        }
+    };
+
+    let content = match fs::read_to_string(&config_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(anyhow::anyhow!("Failed to read shell config: {}", e)),
+    };
+
+    match shell_integration_state(&content, &shell) {
+        IntegrationState::Current => {
+            report_ok(&format!("Shell integration in {}", config_path.display()));
+        }
+        IntegrationState::Missing => {
+            report_failure(&format!(
+                "Shell integration missing from {}",
+                config_path.display()
+            ));
+            mark_repairable();
+            if !fix
+                || !prompt::confirm(&format!(
+                    "Add shell integration to {}?",
+                    config_path.display()
+                ))?
+            {
+                return Ok(false);
+            }
+            if config_path.exists() {
+                backup_file(&config_path)?;
+            }
+            add_shell_integration(&config_path)?;
+            report_fixed(&format!(
+                "Added shell integration to {}",
+                config_path.display()
+            ));
+        }
+        IntegrationState::Outdated => {
+            report_warning(&format!(
+                "Shell integration in {} is outdated",
+                config_path.display()
+            ));
+            mark_repairable();
+            if fix
+                && prompt::confirm(&format!(
+                    "Replace the outdated shell integration in {}?",
+                    config_path.display()
+                ))?
+            {
+                let backup = backup_file(&config_path)?;
+                fs::write(&config_path, refresh_shell_integration(&content, &shell))
+                    .map_err(|e| anyhow::anyhow!("Failed to write shell config: {}", e))?;
+                report_fixed(&format!(
+                    "Refreshed shell integration, previous config saved to {}",
+                    backup.display()
+                ));
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+fn check_runners() {
+    let (available, missing): (Vec<&TaskRunner>, Vec<&TaskRunner>) = TaskRunner::ALL
+        .iter()
+        .partition(|runner| is_runner_available(runner));
+    report_ok(&format!("Runners available: {}", runner_names(&available)));
+    if !missing.is_empty() {
+        report_warning(&format!(
+            "Runners not installed: {}",
+            runner_names(&missing)
+        ));
+    }
+}
+
+fn runner_names(runners: &[&TaskRunner]) -> String {
+    let mut names: Vec<&str> = runners.iter().map(|runner| runner.short_name()).collect();
+    names.dedup();
+    names.join(", ")
+}
+
+/// Returns false if tasks in the current directory depend on each other in a cycle.
+fn check_tasks() -> bool {
+    let Ok(current_dir) = std::env::current_dir() else {
+        report_warning("Skipped task checks: current directory is unavailable");
+        return true;
+    };
+    let discovered = task_discovery::discover_tasks(&current_dir);
+    if discovered.tasks.is_empty() {
+        report_warning("No tasks found in the current directory");
+    } else {
+        report_ok(&format!(
+            "{} tasks found in the current directory",
+            discovered.tasks.len()
+        ));
+    }
+
+    let cycles = task_discovery::find_dependency_cycles(&discovered.tasks);
+    if cycles.is_empty() {
+        report_ok("No dependency cycles between tasks in the current directory");
+        return true;
+    }
+    for cycle in cycles {
+        report_failure(&format!("Dependency cycle: {}", cycle));
+    }
+    false
+}
+
+/// Returns false if the task cache holds entries that cannot be read and was not cleared.
+///
+/// Only unreadable entries are reported, as the cache is opt-in. Everything in it can be
+/// discovered again, so it is cleared without asking and without a backup.
+fn check_discovery_cache(fix: bool) -> anyhow::Result<bool> {
+    let unreadable = task_discovery::unreadable_discovery_cache_files()?;
+    if unreadable.is_empty() {
+        return Ok(true);
+    }
+
+    report_failure(&format!(
+        "Task cache has {} unreadable entries",
+        unreadable.len()
+    ));
+    mark_repairable();
+    for path in &unreadable {
+        println!("      {}", path.display().to_string().dimmed());
+    }
+    if !fix {
+        return Ok(false);
+    }
+
+    let removed = task_discovery::clear_discovery_cache()?;
+    report_fixed(&format!(
+        "Cleared the task cache of {} directories",
+        removed
+    ));
+    Ok(true)
+}
+
+fn shell_integration_state(content: &str, shell: &str) -> IntegrationState {
+    if content.contains(integration_line(shell)) {
+        IntegrationState::Current
+    } else if content
+        .lines()
+        .any(|line| line.trim() == INTEGRATION_SENTINEL)
+    {
+        IntegrationState::Outdated
+    } else {
+        IntegrationState::Missing
+    }
+}
+
+/// Replace the line following the integration sentinel with the current integration line.
+fn refresh_shell_integration(content: &str, shell: &str) -> String {
+    let mut refreshed = Vec::new();
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        refreshed.push(line.to_string());
+        if line.trim() == INTEGRATION_SENTINEL {
+            lines.next();
+            refreshed.push(integration_line(shell).to_string());
+        }
+    }
+
+    let mut refreshed = refreshed.join("\n");
+    if content.ends_with('\n') {
+        refreshed.push('\n');
+    }
+    refreshed
+}
+
+/// Whether an entry names a file or directory that no longer exists. Pattern entries
+/// without a path match anywhere, so they are never dead.
+fn is_dead_entry(entry: &AllowlistEntry) -> bool {
+    !entry.path.as_os_str().is_empty() && !entry.path.exists()
+}
+
+fn find_dead_allowlist_entries(allowlist: &Allowlist) -> Vec<AllowlistEntry> {
+    allowlist
+        .entries
+        .iter()
+        .filter(|entry| is_dead_entry(entry))
+        .cloned()
+        .collect()
+}
+
+fn prune_dead_allowlist_entries(allowlist: &mut Allowlist) -> Vec<AllowlistEntry> {
+    let (dead, alive) = allowlist.entries.drain(..).partition(is_dead_entry);
+    allowlist.entries = alive;
+    dead
+}
+
+/// Copy a file next to itself with a `.bak` suffix so a fix can be undone by hand.
+///
+/// An existing backup is never overwritten: the copy goes to the first free name of
+/// `.bak`, `.bak.1`, `.bak.2` and so on.
+fn backup_file(path: &Path) -> anyhow::Result<PathBuf> {
+    let error = |e: std::io::Error| anyhow::anyhow!("Failed to back up {}: {}", path.display(), e);
+    let mut original = fs::File::open(path).map_err(error)?;
+    for attempt in 0.. {
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        if attempt > 0 {
+            backup.push(format!(".{}", attempt));
+        }
+        let backup = PathBuf::from(backup);
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&backup)
+        {
+            Ok(mut file) => {
+                std::io::copy(&mut original, &mut file).map_err(error)?;
+                return Ok(backup);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(error(e)),
+        }
+    }
+    unreachable!("every backup name is taken")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{preferred_allowlist_path_for, preferred_config_dir_path_for};
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::types::AllowScope;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    // Test helper to simulate user input (copied from prompt.rs)
+    fn with_stdin<F>(input: &str, test: F)
+    where
+        F: FnOnce(),
+    {
+        use std::fs::File;
+        use std::io::Write;
+        use std::os::unix::io::FromRawFd;
+
+        unsafe {
+            let mut pipe = [0; 2];
+            libc::pipe(&mut pipe[0]);
+
+            let mut writer = File::from_raw_fd(pipe[1]);
+            writer.write_all(input.as_bytes()).unwrap();
+            drop(writer);
+
+            let old_stdin = libc::dup(0);
+            libc::dup2(pipe[0], 0);
+
+            test();
+
+            libc::dup2(old_stdin, 0);
+            libc::close(old_stdin);
+            libc::close(pipe[0]);
+        }
+    }
+
+    fn setup_home(shell: &str) -> TempDir {
+        let home_dir = TempDir::new().unwrap();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_shell(shell)
+                .with_home(home_dir.path().to_string_lossy()),
+        );
+        fs::create_dir_all(preferred_config_dir_path_for(home_dir.path())).unwrap();
+        home_dir
+    }
+
+    fn entry(path: PathBuf) -> AllowlistEntry {
+        AllowlistEntry {
+            path,
+            scope: AllowScope::File,
+            tasks: None,
+            pattern: None,
+        }
+    }
+
+    #[test]
+    fn test_find_dead_allowlist_entries() {
+        let project_dir = TempDir::new().unwrap();
+        let makefile = project_dir.path().join("Makefile");
+        fs::write(&makefile, "build:\n").unwrap();
+        let missing = project_dir.path().join("gone").join("Makefile");
+
+        let allowlist = Allowlist {
+            entries: vec![entry(makefile), entry(missing.clone())],
+        };
+
+        let dead = find_dead_allowlist_entries(&allowlist);
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].path, missing);
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_prunes_dead_allowlist_entries() {
+        let home_dir = setup_home("/bin/zsh");
+        fs::write(
+            home_dir.path().join(".zshrc"),
+            format!("{}\n{}\n", INTEGRATION_SENTINEL, integration_line("zsh")),
+        )
+        .unwrap();
+
+        let project_dir = TempDir::new().unwrap();
+        let makefile = project_dir.path().join("Makefile");
+        fs::write(&makefile, "build:\n").unwrap();
+        let missing = project_dir.path().join("removed").join("Makefile");
+        save_allowlist(&Allowlist {
+            entries: vec![entry(makefile.clone()), entry(missing)],
+        })
+        .unwrap();
+
+        with_stdin("y\n", || {
+            execute(true).unwrap();
+        });
+
+        let allowlist = load_allowlist().unwrap();
+        assert_eq!(allowlist.entries.len(), 1);
+        assert_eq!(allowlist.entries[0].path, makefile);
+
+        let mut backup = preferred_allowlist_path_for(home_dir.path()).into_os_string();
+        backup.push(".bak");
+        let backup: Allowlist = toml::from_str(&fs::read_to_string(backup).unwrap()).unwrap();
+        assert_eq!(backup.entries.len(), 2);
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_keeps_allowlist_when_not_confirmed() {
+        let home_dir = setup_home("/bin/zsh");
+        fs::write(
+            home_dir.path().join(".zshrc"),
+            format!("{}\n{}\n", INTEGRATION_SENTINEL, integration_line("zsh")),
+        )
+        .unwrap();
+        let missing = PathBuf::from("/nonexistent/dela/Makefile");
+        save_allowlist(&Allowlist {
+            entries: vec![entry(missing)],
+        })
+        .unwrap();
+
+        with_stdin("n\n", || {
+            execute(true).unwrap();
+        });
+
+        assert_eq!(load_allowlist().unwrap().entries.len(), 1);
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_shell_integration_state() {
+        let current = format!("{}\n{}\n", INTEGRATION_SENTINEL, integration_line("zsh"));
+        assert_eq!(
+            shell_integration_state(&current, "zsh"),
+            IntegrationState::Current
+        );
+
+        let outdated = format!("{}\nsource ~/.dela/zsh.sh\n", INTEGRATION_SENTINEL);
+        assert_eq!(
+            shell_integration_state(&outdated, "zsh"),
+            IntegrationState::Outdated
+        );
+
+        assert_eq!(
+            shell_integration_state("# nothing here\n", "zsh"),
+            IntegrationState::Missing
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_refreshes_outdated_shell_integration() {
+        let home_dir = setup_home("/bin/zsh");
+        let zshrc = home_dir.path().join(".zshrc");
+        fs::write(
+            &zshrc,
+            format!(
+                "export PATH=$HOME/bin:$PATH\n{}\nsource ~/.dela/zsh.sh\nalias ll='ls -l'\n",
+                INTEGRATION_SENTINEL
+            ),
+        )
+        .unwrap();
+
+        with_stdin("y\n", || {
+            execute(true).unwrap();
+        });
+
+        let content = fs::read_to_string(&zshrc).unwrap();
+        assert_eq!(
+            content,
+            format!(
+                "export PATH=$HOME/bin:$PATH\n{}\n{}\nalias ll='ls -l'\n",
+                INTEGRATION_SENTINEL,
+                integration_line("zsh")
+            )
+        );
+        let backup = fs::read_to_string(home_dir.path().join(".zshrc.bak")).unwrap();
+        assert!(backup.contains("source ~/.dela/zsh.sh"));
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_installs_missing_shell_integration() {
+        let home_dir = setup_home("/bin/bash");
+        let bashrc = home_dir.path().join(".bashrc");
+        fs::write(&bashrc, "# existing bash config\n").unwrap();
+
+        with_stdin("y\n", || {
+            execute(true).unwrap();
+        });
+
+        let content = fs::read_to_string(&bashrc).unwrap();
+        assert!(content.contains(INTEGRATION_SENTINEL));
+        assert_eq!(content.matches(integration_line("bash")).count(), 1);
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_without_fix_changes_nothing() {
+        let home_dir = setup_home("/bin/bash");
+        let bashrc = home_dir.path().join(".bashrc");
+        fs::write(&bashrc, "# existing bash config\n").unwrap();
+
+        assert!(
+            execute(false).is_err(),
+            "Missing integration is a failed check"
+        );
+
+        assert_eq!(
+            fs::read_to_string(&bashrc).unwrap(),
+            "# existing bash config\n"
+        );
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_passes_on_a_complete_setup() {
+        let home_dir = setup_home("/bin/bash");
+        save_allowlist(&Allowlist::default()).unwrap();
+        fs::write(
+            home_dir.path().join(".bashrc"),
+            format!("{}\n{}\n", INTEGRATION_SENTINEL, integration_line("bash")),
+        )
+        .unwrap();
+
+        assert!(execute(false).is_ok());
+        assert!(!REPAIRABLE_FOUND.load(Ordering::Relaxed));
+
+        fs::remove_file(preferred_allowlist_path_for(home_dir.path())).unwrap();
+        assert!(
+            execute(false).is_err(),
+            "A missing allowlist is a failed check"
+        );
+        assert!(REPAIRABLE_FOUND.load(Ordering::Relaxed));
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_clears_unreadable_task_cache() {
+        let home_dir = setup_home("/bin/bash");
+        save_allowlist(&Allowlist::default()).unwrap();
+        fs::write(
+            home_dir.path().join(".bashrc"),
+            format!("{}\n{}\n", INTEGRATION_SENTINEL, integration_line("bash")),
+        )
+        .unwrap();
+        let cache_dir = preferred_config_dir_path_for(home_dir.path()).join("cache");
+        fs::create_dir_all(&cache_dir).unwrap();
+        let corrupt = cache_dir.join("0123456789abcdef.json");
+        fs::write(&corrupt, "{\"version\":").unwrap();
+
+        assert!(
+            execute(false).is_err(),
+            "An unreadable task cache is a failed check"
+        );
+        assert!(corrupt.exists());
+
+        execute(true).unwrap();
+        assert!(!corrupt.exists());
+        assert!(execute(false).is_ok());
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_doctor_fix_keeps_shell_config_when_not_confirmed() {
+        let home_dir = setup_home("/bin/zsh");
+        let zshrc = home_dir.path().join(".zshrc");
+        let outdated = format!("{}\nsource ~/.dela/zsh.sh\n", INTEGRATION_SENTINEL);
+        fs::write(&zshrc, &outdated).unwrap();
+
+        with_stdin("n\n", || {
+            execute(true).unwrap();
+        });
+
+        assert_eq!(fs::read_to_string(&zshrc).unwrap(), outdated);
+        assert!(!home_dir.path().join(".zshrc.bak").exists());
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_backup_file_keeps_earlier_backups() {
+        let temp_dir = TempDir::new().unwrap();
+        let rc = temp_dir.path().join(".bashrc");
+
+        fs::write(&rc, "original\n").unwrap();
+        assert_eq!(
+            backup_file(&rc).unwrap(),
+            temp_dir.path().join(".bashrc.bak")
+        );
+        fs::write(&rc, "edited\n").unwrap();
+        assert_eq!(
+            backup_file(&rc).unwrap(),
+            temp_dir.path().join(".bashrc.bak.1")
+        );
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join(".bashrc.bak")).unwrap(),
+            "original\n"
+        );
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join(".bashrc.bak.1")).unwrap(),
+            "edited\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::environment::{get_current_shell, shell_name};
+use crate::task_discovery::{self, DiscoveredTasks};
+use crate::types::{ShadowType, Task};
+use std::env;
+use std::io::Write;
+
+/// Shells `dela export-aliases` can write aliases for.
+pub const ALIAS_SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+/// Executes `dela export-aliases`, printing a `dela run` alias for every task in the
+/// current directory in the syntax of `shell`, or of the current shell when not given.
+pub fn execute(shell: Option<&str>) -> anyhow::Result<()> {
+    let shell = match shell {
+        Some(shell) => shell.to_string(),
+        None => get_current_shell()
+            .and_then(|shell| shell_name(&shell))
+            .ok_or_else(|| {
+                anyhow::anyhow!("SHELL environment variable not set; pass --shell instead")
+            })?,
+    };
+    if !ALIAS_SHELLS.contains(&shell.as_str()) {
+        return Err(anyhow::anyhow!(
+            "Unsupported shell for aliases: {}. Supported shells: {}",
+            shell,
+            ALIAS_SHELLS.join(", ")
+        ));
+    }
+
+    let current_dir = env::current_dir()
+        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
+    let discovered = task_discovery::discover_tasks(&current_dir);
+    write_aliases(&discovered, &shell, &mut std::io::stdout())
+}
+
+/// Whether bash, zsh and fish all accept `name` as an alias name.
+fn is_alias_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.:+@%,".contains(c))
+}
+
+/// Write one alias per task, named by its disambiguated name when it has one so tasks
+/// sharing a name get distinct aliases. A task shadowed by a shell builtin or an existing
+/// alias gets no alias, since that would have to be given up for it, and is noted in a
+/// comment instead.
+fn write_aliases(
+    discovered: &DiscoveredTasks,
+    shell: &str,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let mut tasks: Vec<&Task> = discovered.tasks.iter().collect();
+    tasks.sort_by_key(|task| unique_name(task));
+    tasks.dedup_by_key(|task| unique_name(task));
+
+    writeln!(
+        out,
+        "# Aliases for dela tasks, generated by 'dela export-aliases'"
+    )?;
+    for task in tasks {
+        let name = unique_name(task);
+        if let Some(ShadowType::ShellBuiltin(builtin_shell)) = &task.shadowed_by {
+            writeln!(
+                out,
+                "# {} is a {} builtin, run the task with 'dela run {}'",
+                task.name, builtin_shell, name
+            )?;
+        } else if let Some(ShadowType::ShellAlias(_)) = &task.shadowed_by {
+            writeln!(
+                out,
+                "# {} is already an alias, run the task with 'dela run {}'",
+                task.name, name
+            )?;
+        } else if !is_alias_name(name) {
+            writeln!(
+                out,
+                "# {} cannot be an alias name, run the task with 'dela run {}'",
+                name,
+                shell_words::quote(name)
+            )?;
+        } else if shell == "fish" {
+            writeln!(out, "alias {} 'dela run {}'", name, name)?;
+        } else {
+            writeln!(out, "alias {}='dela run {}'", name, name)?;
+        }
+    }
+    Ok(())
+}
+
+fn unique_name(task: &Task) -> &str {
+    task.disambiguated_name.as_deref().unwrap_or(&task.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TaskDefinitionType, TaskRunner};
+
+    fn task(name: &str, disambiguated_name: Option<&str>, shadowed_by: Option<ShadowType>) -> Task {
+        Task {
+            name: name.to_string(),
+            file_path: "Makefile".into(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by,
+            disambiguated_name: disambiguated_name.map(str::to_string),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        }
+    }
+
+    fn aliases(shell: &str) -> String {
+        let discovered = DiscoveredTasks {
+            tasks: vec![
+                task("test", Some("test-m"), None),
+                task("build", None, None),
+                task(
+                    "cd",
+                    Some("cd-m"),
+                    Some(ShadowType::ShellBuiltin("zsh".to_string())),
+                ),
+                task(
+                    "install",
+                    Some("install-m"),
+                    Some(ShadowType::PathExecutable("/usr/bin/install".to_string())),
+                ),
+                task("docs/build", None, None),
+                task(
+                    "deploy",
+                    Some("deploy-m"),
+                    Some(ShadowType::ShellAlias("zsh".to_string())),
+                ),
+            ],
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        write_aliases(&discovered, shell, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_aliases() {
+        assert_eq!(
+            aliases("zsh"),
+            "# Aliases for dela tasks, generated by 'dela export-aliases'\n\
+             alias build='dela run build'\n\
+             # cd is a zsh builtin, run the task with 'dela run cd-m'\n\
+             # deploy is already an alias, run the task with 'dela run deploy-m'\n\
+             # docs/build cannot be an alias name, run the task with 'dela run docs/build'\n\
+             alias install-m='dela run install-m'\n\
+             alias test-m='dela run test-m'\n"
+        );
+        assert!(aliases("fish").contains("\nalias build 'dela run build'\n"));
+    }
+
+    #[test]
+    fn test_is_alias_name() {
+        assert!(is_alias_name("build"));
+        assert!(is_alias_name("docker::image"));
+        assert!(is_alias_name("test-m"));
+        assert!(!is_alias_name(""));
+        assert!(!is_alias_name("-v"));
+        assert!(!is_alias_name("a b"));
+        assert!(!is_alias_name("it's"));
+    }
+
+    #[test]
+    fn test_execute_rejects_unsupported_shell() {
+        let error = execute(Some("pwsh")).unwrap_err().to_string();
+        assert!(
+            error.contains("Supported shells: bash, zsh, fish"),
+            "{}",
+            error
+        );
+    }
+}
@@ -1,28 +1,94 @@
+use crate::commands::run_command::{not_runnable_warning, sudo_warning};
+use crate::config;
+use crate::environment::{get_current_shell, shell_name};
 use crate::runner::is_runner_available;
 use crate::task_discovery;
 use anyhow::Context;
 use std::env;
+use std::path::Path;
+
+/// Wrap `command` so the shell integration runs it from `dir` without changing the
+/// directory of the calling shell.
+fn command_in_dir(dir: &Path, command: &str) -> String {
+    let dir = dir.to_string_lossy();
+    let shell = get_current_shell().unwrap_or_default();
+    match shell_name(&shell).unwrap_or_default().as_str() {
+        "fish" => {
+            let quoted = format!("'{}'", dir.replace('\\', "\\\\").replace('\'', "\\'"));
+            format!(
+                "begin; pushd {}; and {}; set -l dela_status $status; popd; test $dela_status -eq 0; end",
+                quoted, command
+            )
+        }
+        "pwsh" | "powershell" => format!(
+            "Push-Location '{}'; try {{ {} }} finally {{ Pop-Location }}",
+            dir.replace('\'', "''"),
+            command
+        ),
+        _ => format!("(cd {} && {})", shell_words::quote(&dir), command),
+    }
+}
 
-pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
-    let mut parts = task_with_args.split_whitespace();
-    let task_name = parts.next().context("No task name provided")?;
-    let args: Vec<&str> = parts.collect();
+pub fn execute(task_with_args: &str, no_builtins: bool, walk_up: bool) -> anyhow::Result<()> {
+    // `dr --dry-run <task>` passes the flag through ahead of the task name
+    let (dry_run, task_with_args) = match task_with_args.strip_prefix("--dry-run") {
+        Some(rest) if rest.is_empty() || rest.starts_with(' ') => (true, rest.trim_start()),
+        _ => (false, task_with_args),
+    };
+    let command = resolve_command(task_with_args, no_builtins, walk_up)?;
+    if dry_run {
+        // Nothing on stdout, so the shell integration has nothing to eval
+        eprintln!("{}", command);
+    } else {
+        println!("{}", command);
+    }
+    Ok(())
+}
+
+/// The shell command for a task name followed by its arguments.
+fn resolve_command(
+    task_with_args: &str,
+    no_builtins: bool,
+    walk_up: bool,
+) -> anyhow::Result<String> {
+    let parts = shell_words::split(task_with_args)
+        .map_err(|e| anyhow::anyhow!("Failed to parse args: {}", e))?;
+    let (task_name, args) = parts.split_first().context("No task name provided")?;
 
     let current_dir = env::current_dir()
         .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
-    let discovered = task_discovery::discover_tasks(&current_dir);
+    // A project alias stands for the task it names
+    let project_config = config::load_project_config(&current_dir)?;
+    let task_name = project_config.resolve_alias(task_name);
+    let (task_dir, mut discovered) = if walk_up {
+        task_discovery::discover_tasks_walking_up(&current_dir)
+    } else {
+        (
+            current_dir.clone(),
+            task_discovery::discover_tasks(&current_dir),
+        )
+    };
+    if no_builtins {
+        task_discovery::ignore_shadowing(&mut discovered);
+    }
 
     // Find all tasks with the given name (both original and disambiguated)
-    let matching_tasks = task_discovery::get_matching_tasks(&discovered, task_name);
+    let mut matching_tasks = task_discovery::get_matching_tasks_qualified(&discovered, task_name);
+    task_discovery::apply_runner_priority(&mut matching_tasks);
 
     match matching_tasks.len() {
         0 => Err(anyhow::anyhow!(
-            "dela: command or task not found: {}",
-            task_name
+            task_discovery::format_task_not_found_error(&discovered, task_name)
         )),
         1 => {
             // Single task found, check if runner is available
             let task = matching_tasks[0];
+            for warning in [not_runnable_warning(task), sudo_warning(task)]
+                .into_iter()
+                .flatten()
+            {
+                eprintln!("{}", warning);
+            }
             if !is_runner_available(&task.runner) {
                 if task.runner == crate::types::TaskRunner::TravisCi {
                     return Err(anyhow::anyhow!(
@@ -34,13 +100,12 @@ pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
                     task.runner.short_name()
                 ));
             }
-            let mut command = task.runner.get_command(task);
-            if !args.is_empty() {
-                command.push(' ');
-                command.push_str(&args.join(" "));
+            let mut command = task.runner.get_command_with_args(task, args);
+            let run_dir = project_config.run_dir(task_dir)?;
+            if run_dir != current_dir {
+                command = command_in_dir(&run_dir, &command);
             }
-            println!("{}", command);
-            Ok(())
+            Ok(command)
         }
         _ => {
             // Multiple matches (should not happen with get_matching_tasks, but handle for safety)
@@ -106,7 +171,7 @@ test: ## Running tests
         let env = TestEnvironment::new().with_executable("make");
         set_test_environment(env);
 
-        let result = execute("test");
+        let result = execute("test", false, false);
         assert!(result.is_ok(), "Should succeed for a single task");
 
         reset_mock();
@@ -128,7 +193,7 @@ test: ## Running tests
         set_test_environment(env);
 
         // Test with the execute function
-        let result = execute("test --verbose --coverage");
+        let result = execute("test --verbose --coverage", false, false);
 
         // Verify the command was executed successfully
         assert!(result.is_ok(), "Should succeed for task with arguments");
@@ -145,7 +210,7 @@ test: ## Running tests
         let (project_dir, home_dir) = setup_test_env();
         env::set_current_dir(&project_dir).expect("Failed to change directory");
 
-        let result = execute("nonexistent");
+        let result = execute("nonexistent", false, false);
         assert!(result.is_err(), "Should fail when no task found");
         assert_eq!(
             result.unwrap_err().to_string(),
@@ -168,7 +233,7 @@ test: ## Running tests
         let env = TestEnvironment::new();
         set_test_environment(env);
 
-        let result = execute("test");
+        let result = execute("test", false, false);
         assert!(result.is_err(), "Should fail when runner is missing");
         assert_eq!(result.unwrap_err().to_string(), "Runner 'make' not found");
 
@@ -212,7 +277,7 @@ test: ## Running tests
         set_test_environment(env);
 
         // First verify that ambiguous task gives error
-        let result = execute("test");
+        let result = execute("test", false, false);
         assert!(result.is_err(), "Should fail with ambiguous task name");
         assert!(
             result
@@ -223,21 +288,21 @@ test: ## Running tests
         );
 
         // Verify task lookup for make variant works
-        let result = execute("test-m");
+        let result = execute("test-m", false, false);
         assert!(
             result.is_ok(),
             "Should succeed with disambiguated task name (make)"
         );
 
         // Verify task lookup for npm variant works
-        let result = execute("test-n");
+        let result = execute("test-n", false, false);
         assert!(
             result.is_ok(),
             "Should succeed with disambiguated task name (npm)"
         );
 
         // Verify arguments are correctly passed with disambiguated names
-        let result = execute("test-m --verbose");
+        let result = execute("test-m --verbose", false, false);
         assert!(
             result.is_ok(),
             "Should succeed with disambiguated task name and args"
@@ -248,4 +313,178 @@ test: ## Running tests
         drop(project_dir);
         drop(home_dir);
     }
+
+    #[test]
+    #[serial]
+    fn test_get_command_no_builtins_uses_plain_name() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        fs::write(
+            project_dir.path().join("Makefile"),
+            "cd:\n\t@echo Changing...\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        let env = TestEnvironment::new()
+            .with_shell("/bin/zsh")
+            .with_executable("make");
+        set_test_environment(env);
+
+        let discovered = task_discovery::discover_tasks(project_dir.path());
+        let cd_task = discovered.tasks.iter().find(|t| t.name == "cd").unwrap();
+        assert_eq!(cd_task.disambiguated_name.as_deref(), Some("cd-m"));
+
+        assert!(execute("cd", true, false).is_ok());
+        assert!(
+            execute("cd-m", true, false).is_err(),
+            "Shadow suffix should not resolve under --no-builtins"
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_command_walks_up_to_parent_makefile() {
+        let (project_dir, home_dir) = setup_test_env();
+        let nested = project_dir.path().join("src");
+        fs::create_dir_all(&nested).unwrap();
+        env::set_current_dir(&nested).expect("Failed to change directory");
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+
+        assert!(execute("build", false, false).is_err());
+        assert!(execute("build", false, true).is_ok());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_command_in_dir_per_shell() {
+        let dir = Path::new("/work/my project");
+
+        set_test_environment(TestEnvironment::new().with_shell("/bin/zsh"));
+        assert_eq!(
+            command_in_dir(dir, "make build"),
+            "(cd '/work/my project' && make build)"
+        );
+
+        set_test_environment(TestEnvironment::new().with_shell("/usr/bin/fish"));
+        assert_eq!(
+            command_in_dir(dir, "make build"),
+            "begin; pushd '/work/my project'; and make build; set -l dela_status $status; popd; test $dela_status -eq 0; end"
+        );
+
+        set_test_environment(TestEnvironment::new().with_shell("pwsh"));
+        assert_eq!(
+            command_in_dir(Path::new("/work/it's"), "make build"),
+            "Push-Location '/work/it''s'; try { make build } finally { Pop-Location }"
+        );
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_command_dry_run() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+
+        assert_eq!(
+            resolve_command("test --coverage", false, false).unwrap(),
+            "make test --coverage"
+        );
+        assert!(execute("--dry-run test --coverage", false, false).is_ok());
+        assert!(execute("--dry-run missing", false, false).is_err());
+        assert!(execute("--dry-run", false, false).is_err());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_command_expands_project_alias() {
+        let (project_dir, home_dir) = setup_test_env();
+        fs::write(
+            project_dir.path().join(".dela.toml"),
+            "[aliases]\nci = \"test\"\ncheck = \"make:test\"\nverify = \"check\"\nnpm-test = \"npm:test\"\n",
+        )
+        .unwrap();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+
+        assert_eq!(
+            resolve_command("ci --coverage", false, false).unwrap(),
+            "make test --coverage"
+        );
+        // A target can name the runner, and aliases can point at other aliases
+        assert_eq!(
+            resolve_command("verify", false, false).unwrap(),
+            "make test"
+        );
+        assert!(resolve_command("npm-test", false, false).is_err());
+        // The task itself is still found by its own name
+        assert_eq!(resolve_command("test", false, false).unwrap(), "make test");
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_command_runs_from_project_working_dir() {
+        let (project_dir, home_dir) = setup_test_env();
+        let project = project_dir.path().canonicalize().unwrap();
+        fs::create_dir_all(project.join("app")).unwrap();
+        fs::write(
+            project.join(config::PROJECT_CONFIG_FILE),
+            "working_dir = \"app\"\n",
+        )
+        .unwrap();
+        env::set_current_dir(&project).expect("Failed to change directory");
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_shell("/bin/zsh")
+                .with_executable("make"),
+        );
+
+        assert_eq!(
+            resolve_command("build", false, false).unwrap(),
+            format!(
+                "(cd {} && make build)",
+                shell_words::quote(&project.join("app").to_string_lossy())
+            )
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
 }
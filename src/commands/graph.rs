@@ -0,0 +1,56 @@
+use crate::task_discovery;
+use colored::Colorize;
+use std::env;
+use std::io::Write;
+
+/// Executes `dela graph [task]`, printing what a task depends on, or the dependency trees
+/// of every task in the current directory when no task is given.
+pub fn execute(task_name: Option<&str>) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()
+        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
+    let discovered = task_discovery::discover_tasks(&current_dir);
+    let tasks = &discovered.tasks;
+
+    let roots = match task_name {
+        None => task_discovery::dependency_roots(tasks),
+        Some(task_name) => {
+            let matching_tasks = task_discovery::get_matching_tasks(&discovered, task_name);
+            match matching_tasks.as_slice() {
+                [] => {
+                    return Err(anyhow::anyhow!(
+                        "dela: command or task not found: {}",
+                        task_name
+                    ));
+                }
+                [task] => vec![
+                    tasks
+                        .iter()
+                        .position(|candidate| std::ptr::eq(candidate, *task))
+                        .expect("matching tasks come from the discovered tasks"),
+                ],
+                _ => {
+                    println!(
+                        "{}",
+                        task_discovery::format_ambiguous_task_error(task_name, &matching_tasks)
+                    );
+                    return Err(anyhow::anyhow!(
+                        "Multiple tasks named '{}' found",
+                        task_name
+                    ));
+                }
+            }
+        }
+    };
+
+    let mut stdout = std::io::stdout();
+    for root in roots {
+        for line in task_discovery::dependency_tree(tasks, root) {
+            writeln!(stdout, "{}", line)?;
+        }
+    }
+
+    for cycle in task_discovery::find_dependency_cycles(tasks) {
+        eprintln!("{} dependency cycle: {}", "Warning:".yellow(), cycle);
+    }
+    Ok(())
+}
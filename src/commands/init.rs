@@ -1,5 +1,7 @@
 use crate::config::{legacy_dela_config_dir, preferred_allowlist_path, preferred_config_dir_path};
-use crate::environment::{get_current_home, get_current_shell as env_get_current_shell};
+use crate::environment::{
+    get_current_home, get_current_shell as env_get_current_shell, shell_name,
+};
 use crate::types::Allowlist;
 use anyhow::Context;
 use std::env;
@@ -7,8 +9,11 @@ use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
 
+/// Marker comment written above the integration line so it can be located later.
+pub(crate) const INTEGRATION_SENTINEL: &str = "# dela shell integration";
+
 /// Get the current shell name by checking the parent process
-fn get_current_shell() -> anyhow::Result<String> {
+pub(crate) fn get_current_shell() -> anyhow::Result<String> {
     // Try to get shell from BASH_VERSION or ZSH_VERSION first
     if env::var("BASH_VERSION").is_ok() {
         return Ok("bash".to_string());
@@ -20,16 +25,11 @@ fn get_current_shell() -> anyhow::Result<String> {
     // Fallback to $SHELL if version variables aren't set
     let shell = env_get_current_shell().context("SHELL environment variable not set")?;
 
-    let shell_path = std::path::PathBuf::from(&shell);
-    shell_path
-        .file_name()
-        .and_then(|name| name.to_str())
-        .map(|s| s.to_string())
-        .context("Invalid shell path")
+    shell_name(&shell).context("Invalid shell path")
 }
 
 /// Get the appropriate shell config path based on current shell
-fn get_shell_config_path() -> anyhow::Result<PathBuf> {
+pub(crate) fn get_shell_config_path() -> anyhow::Result<PathBuf> {
     let shell_name = get_current_shell()?;
     let home = get_current_home().context("HOME environment variable not set")?;
     let home_path = PathBuf::from(&home);
@@ -38,7 +38,16 @@ fn get_shell_config_path() -> anyhow::Result<PathBuf> {
         "zsh" => Ok(home_path.join(".zshrc")),
         "bash" => Ok(home_path.join(".bashrc")),
         "fish" => Ok(home_path.join(".config").join("fish").join("config.fish")),
-        "pwsh" => Ok(home_path
+        // $PROFILE for the current user and host
+        "pwsh" if cfg!(windows) => Ok(home_path
+            .join("Documents")
+            .join("PowerShell")
+            .join("Microsoft.PowerShell_profile.ps1")),
+        "powershell" if cfg!(windows) => Ok(home_path
+            .join("Documents")
+            .join("WindowsPowerShell")
+            .join("Microsoft.PowerShell_profile.ps1")),
+        "pwsh" | "powershell" => Ok(home_path
             .join(".config")
             .join("powershell")
             .join("Microsoft.PowerShell_profile.ps1")),
@@ -46,8 +55,17 @@ fn get_shell_config_path() -> anyhow::Result<PathBuf> {
     }
 }
 
+/// The line that evaluates `dela configure-shell` for the given shell.
+pub(crate) fn integration_line(shell: &str) -> &'static str {
+    match shell {
+        "fish" => "eval (dela configure-shell | string collect)",
+        "pwsh" | "powershell" => "Invoke-Expression (dela configure-shell | Out-String)",
+        _ => "eval \"$(dela configure-shell)\"",
+    }
+}
+
 /// Add dela shell integration to the shell config file
-fn add_shell_integration(config_path: &PathBuf) -> anyhow::Result<()> {
+pub(crate) fn add_shell_integration(config_path: &PathBuf) -> anyhow::Result<()> {
     // Read the current content
     let content = match fs::read_to_string(config_path) {
         Ok(c) => c,
@@ -58,11 +76,7 @@ fn add_shell_integration(config_path: &PathBuf) -> anyhow::Result<()> {
     let shell = get_current_shell()?;
 
     // Check if dela integration is already present, with shell-specific patterns
-    let integration_pattern = match shell.as_str() {
-        "fish" => "eval (dela configure-shell | string collect)",
-        "pwsh" => "Invoke-Expression (dela configure-shell | Out-String)",
-        _ => "eval \"$(dela configure-shell)\"",
-    };
+    let integration_pattern = integration_line(&shell);
 
     if content.contains(integration_pattern) {
         println!(
@@ -89,7 +103,7 @@ fn add_shell_integration(config_path: &PathBuf) -> anyhow::Result<()> {
 
     // Add dela integration with shell-specific syntax
     writeln!(file).map_err(|e| anyhow::anyhow!("Failed to write to shell config: {}", e))?;
-    writeln!(file, "# dela shell integration")
+    writeln!(file, "{}", INTEGRATION_SENTINEL)
         .map_err(|e| anyhow::anyhow!("Failed to write to shell config: {}", e))?;
     writeln!(file, "{}", integration_pattern)
         .map_err(|e| anyhow::anyhow!("Failed to write to shell config: {}", e))?;
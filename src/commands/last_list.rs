@@ -0,0 +1,149 @@
+use crate::config::active_dela_config_dir;
+use crate::types::Task;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// One task as the last `dela list` showed it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListedTask {
+    /// The name the task was listed under, disambiguated if it had to be
+    pub name: String,
+    pub definition_path: PathBuf,
+}
+
+/// The tasks of the last `dela list`, in the order they were shown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LastList {
+    dir: PathBuf,
+    tasks: Vec<ListedTask>,
+}
+
+fn last_list_path() -> anyhow::Result<PathBuf> {
+    Ok(active_dela_config_dir()?.join("last-list.json"))
+}
+
+/// Remember `tasks` as listed in `dir`, so `dela run @N` can refer to them.
+///
+/// Nothing is saved before `dela init` has created the config directory, and a failure to
+/// save never fails the listing.
+pub fn save(dir: &Path, tasks: &[&Task]) {
+    let Ok(path) = last_list_path() else {
+        return;
+    };
+    if path.parent().is_some_and(Path::exists) {
+        let _ = save_to(&path, dir, tasks);
+    }
+}
+
+fn save_to(path: &Path, dir: &Path, tasks: &[&Task]) -> anyhow::Result<()> {
+    let last_list = LastList {
+        dir: dir.to_path_buf(),
+        tasks: tasks
+            .iter()
+            .map(|task| ListedTask {
+                name: task
+                    .disambiguated_name
+                    .as_ref()
+                    .unwrap_or(&task.name)
+                    .clone(),
+                definition_path: task.definition_path().to_path_buf(),
+            })
+            .collect(),
+    };
+    std::fs::write(path, serde_json::to_string(&last_list)?)
+        .map_err(|e| anyhow::anyhow!("Failed to save the last list: {}", e))
+}
+
+/// The 1-based position in `@N`, if `arg` has that form.
+pub fn parse_index(arg: &str) -> Option<usize> {
+    arg.strip_prefix('@')?.parse().ok()
+}
+
+/// The task shown at `index` by the last `dela list` in `dir`.
+pub fn listed_task(index: usize, dir: &Path) -> anyhow::Result<ListedTask> {
+    listed_task_from(&last_list_path()?, index, dir)
+}
+
+fn listed_task_from(path: &Path, index: usize, dir: &Path) -> anyhow::Result<ListedTask> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|_| anyhow::anyhow!("No saved task list; run 'dela list' first"))?;
+    let last_list: LastList = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Failed to read the last list: {}", e))?;
+    if last_list.dir != dir {
+        return Err(anyhow::anyhow!(
+            "The last 'dela list' was run in {}; run 'dela list' here first",
+            last_list.dir.display()
+        ));
+    }
+    index
+        .checked_sub(1)
+        .and_then(|position| last_list.tasks.get(position))
+        .cloned()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No task @{}: the last 'dela list' showed {} tasks",
+                index,
+                last_list.tasks.len()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TaskDefinitionType, TaskRunner};
+    use tempfile::TempDir;
+
+    fn task(name: &str, file: &Path) -> Task {
+        Task {
+            name: name.to_string(),
+            file_path: file.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        }
+    }
+
+    #[test]
+    fn test_index_resolves_against_saved_list() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("last-list.json");
+        let project = temp_dir.path().join("project");
+        let makefile = project.join("Makefile");
+        let mut test = task("test", &makefile);
+        test.disambiguated_name = Some("test-m".to_string());
+        let build = task("build", &makefile);
+        save_to(&path, &project, &[&build, &test]).unwrap();
+
+        assert_eq!(parse_index("@2"), Some(2));
+        assert_eq!(parse_index("build"), None);
+        assert_eq!(
+            listed_task_from(&path, 2, &project).unwrap(),
+            ListedTask {
+                name: "test-m".to_string(),
+                definition_path: makefile,
+            }
+        );
+
+        let error = listed_task_from(&path, 3, &project).unwrap_err();
+        assert!(error.to_string().contains("showed 2 tasks"));
+        assert!(listed_task_from(&path, 0, &project).is_err());
+        let error = listed_task_from(&path, 1, temp_dir.path()).unwrap_err();
+        assert!(error.to_string().contains("run 'dela list' here first"));
+    }
+}
@@ -1,12 +1,16 @@
+use crate::commands::last_list;
+use crate::config::{self, ListConfig, PROJECT_CONFIG_FILE, load_config};
+use crate::mcp::allowlist::McpAllowlistEvaluator;
+use crate::mcp::dto::TaskDto;
 use crate::runner::is_runner_available;
-use crate::task_discovery;
+use crate::task_discovery::{self, DiscoveredTasks};
+use crate::task_shadowing;
 use crate::types::ShadowType;
-use crate::types::{Task, TaskFileStatus};
+use crate::types::{Task, TaskFileStatus, TaskRunner};
 use colored::Colorize;
-use std::collections::{HashMap, HashSet};
-use std::env;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[cfg(test)]
 macro_rules! test_println {
@@ -19,51 +23,249 @@ macro_rules! test_println {
     ($($arg:tt)*) => { println!($($arg)*) };
 }
 
-pub fn execute(verbose: bool, color: &str) -> anyhow::Result<()> {
-    match color {
+/// Output formats accepted by `dela list --format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Pretty,
+    Json,
+    Flat,
+    Tree,
+}
+
+impl ListFormat {
+    const NAMES: [&'static str; 4] = ["pretty", "json", "flat", "tree"];
+
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "pretty" => Ok(ListFormat::Pretty),
+            "json" => Ok(ListFormat::Json),
+            "flat" => Ok(ListFormat::Flat),
+            "tree" => Ok(ListFormat::Tree),
+            other => Err(anyhow::anyhow!(
+                "Unknown list format '{}'. Valid formats: {}",
+                other,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+
+    fn formatter(
+        self,
+        verbose: bool,
+        layout: &ListConfig,
+        group: ListGroup,
+        sort: ListSort,
+    ) -> Box<dyn ListFormatter> {
+        match self {
+            ListFormat::Pretty if group != ListGroup::Runner => Box::new(GroupedFormatter {
+                group,
+                verbose,
+                layout: layout.clone(),
+                sort,
+            }),
+            ListFormat::Pretty => Box::new(PrettyFormatter {
+                verbose,
+                layout: layout.clone(),
+                sort,
+            }),
+            ListFormat::Json => Box::new(JsonFormatter),
+            ListFormat::Flat => Box::new(FlatFormatter),
+            ListFormat::Tree => Box::new(TreeFormatter),
+        }
+    }
+}
+
+/// How `dela list --group` splits the pretty output into sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListGroup {
+    Runner,
+    Prefix,
+    File,
+    Tag,
+    Dir,
+}
+
+impl ListGroup {
+    const NAMES: [&'static str; 5] = ["runner", "prefix", "file", "tag", "dir"];
+
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "runner" => Ok(ListGroup::Runner),
+            "prefix" => Ok(ListGroup::Prefix),
+            "file" => Ok(ListGroup::File),
+            "tag" => Ok(ListGroup::Tag),
+            "dir" => Ok(ListGroup::Dir),
+            other => Err(anyhow::anyhow!(
+                "Unknown list grouping '{}'. Valid groupings: {}",
+                other,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+/// How `dela list --sort` orders the tasks within each section of the pretty output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListSort {
+    /// Alphabetically by displayed name
+    Name,
+    /// By definition file, then in the order the file defines them
+    Source,
+    /// In the order discovery found them
+    None,
+}
+
+impl ListSort {
+    const NAMES: [&'static str; 3] = ["name", "source", "none"];
+
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "name" => Ok(ListSort::Name),
+            "source" => Ok(ListSort::Source),
+            "none" => Ok(ListSort::None),
+            other => Err(anyhow::anyhow!(
+                "Unknown list sort '{}'. Valid sorts: {}",
+                other,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+
+    /// Put `tasks`, which are in discovery order, in this order.
+    fn apply(self, tasks: &mut [&Task]) {
+        match self {
+            ListSort::Name => tasks.sort_by(|a, b| {
+                display_name(a)
+                    .cmp(display_name(b))
+                    .then_with(|| a.runner.short_name().cmp(b.runner.short_name()))
+            }),
+            ListSort::Source => tasks.sort_by(|a, b| {
+                a.definition_path().cmp(b.definition_path()).then_with(|| {
+                    a.line
+                        .unwrap_or(usize::MAX)
+                        .cmp(&b.line.unwrap_or(usize::MAX))
+                })
+            }),
+            ListSort::None => {}
+        }
+    }
+}
+
+/// Renders discovered tasks for `dela list`.
+pub trait ListFormatter {
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()>;
+
+    /// The tasks in the order they are written, which `dela run @N` counts in.
+    fn display_order<'a>(
+        &self,
+        discovered: &'a DiscoveredTasks,
+        _current_dir: &Path,
+    ) -> Vec<&'a Task> {
+        tasks_sorted_by_name(discovered)
+    }
+}
+
+/// How many levels of subdirectories `dela list --recursive` searches.
+pub const DEFAULT_RECURSIVE_DEPTH: usize = 3;
+
+/// Flags accepted by `dela list`.
+#[derive(Debug, Clone)]
+pub struct ListOptions {
+    pub verbose: bool,
+    /// always, auto or never
+    pub color: String,
+    /// pretty, json, flat or tree
+    pub format: String,
+    /// runner, prefix, file, tag or dir; only the pretty format has sections
+    pub group: String,
+    /// name, source or none; the order of tasks within each pretty section
+    pub sort: String,
+    /// Hide tasks that would do nothing when run locally
+    pub runnable_only: bool,
+    /// Report dependency cycles between tasks and fail if there are any
+    pub check_deps: bool,
+    /// List tasks under their plain names even when a builtin or PATH command shadows them
+    pub no_builtins: bool,
+    /// Also list tasks from subdirectories up to this many levels deep
+    pub depth: Option<usize>,
+    /// Look in parent directories when the current one defines no tasks
+    pub walk_up: bool,
+    /// Only list tasks whose runner matches one of these, see [`TaskRunner::matches_filter`]
+    pub runners: Vec<String>,
+    /// Print only the number of tasks, per runner when verbose
+    pub count: bool,
+    /// Discover the tasks again even when `[discovery] cache` is enabled
+    pub no_cache: bool,
+    /// List the tasks of this directory instead of the current one
+    pub cwd: Option<PathBuf>,
+}
+
+/// Reject `--runner` values that can never match, listing the names that can.
+fn validate_runner_filters(runners: &[String]) -> anyhow::Result<()> {
+    let unknown = runners.iter().find(|runner| {
+        !TaskRunner::ALL
+            .iter()
+            .any(|known| known.matches_filter(runner))
+    });
+    match unknown {
+        Some(runner) => Err(anyhow::anyhow!(
+            "Unknown runner '{}'. Valid runners: {}",
+            runner,
+            TaskRunner::filter_names().join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn execute(options: &ListOptions) -> anyhow::Result<()> {
+    let verbose = options.verbose;
+    match options.color.as_str() {
         "always" => colored::control::set_override(true),
         "never" => colored::control::set_override(false),
-        _ => colored::control::unset_override(),
-    }
-
-    let current_dir = env::current_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
-    let discovered = task_discovery::discover_tasks(&current_dir);
-
-    // Only show task definition files status in verbose mode
-    if verbose {
-        test_println!("Task definition files:");
-        for (_def_type, files) in discovered.definitions.iter() {
-            for file in files {
-                let file_name = file
-                    .path
-                    .strip_prefix(&current_dir)
-                    .map(|p| p.to_string_lossy().to_string())
-                    .unwrap_or_else(|_| file.path.to_string_lossy().to_string());
-                match &file.status {
-                    TaskFileStatus::Parsed => {
-                        test_println!("  {} {}: Found and parsed", "✓".green(), file_name);
-                    }
-                    TaskFileStatus::NotImplemented => {
-                        test_println!(
-                            "  {} {}: Found but parsing not yet implemented",
-                            "!".yellow(),
-                            file_name
-                        );
-                    }
-                    TaskFileStatus::ParseError(e) => {
-                        test_println!("  {} {}: Error parsing: {}", "✗".red(), file_name, e);
-                    }
-                    TaskFileStatus::NotReadable(e) => {
-                        test_println!("  {} {}: Not readable: {}", "✗".red(), file_name, e);
-                    }
-                    TaskFileStatus::NotFound => {
-                        test_println!("  {} {}: Not found", "-".dimmed(), file_name);
-                    }
-                }
-            }
+        // Leave the decision made for --no-color, NO_COLOR and whether stdout is a terminal
+        _ => {}
+    }
+    let format = ListFormat::parse(&options.format)?;
+    let group = ListGroup::parse(&options.group)?;
+    let sort = ListSort::parse(&options.sort)?;
+    validate_runner_filters(&options.runners)?;
+    // A broken config.toml should not stop tasks from being listed.
+    let (layout, use_cache) = match load_config() {
+        Ok(config) => (config.list, config.discovery.cache && !options.no_cache),
+        Err(error) => {
+            eprintln!("Warning: {}", error);
+            (ListConfig::default(), false)
         }
-        test_println!("");
+    };
+
+    let current_dir = super::working_dir(options.cwd.as_deref())?;
+    // Counts are meant for prompts, and shadowing only changes names, not how many tasks there are
+    let _shadowing_pause = options.count.then(task_shadowing::pause_shadowing_checks);
+    let mut discovered = match options.depth {
+        Some(depth) => task_discovery::discover_tasks_recursive(&current_dir, depth),
+        None if options.walk_up => task_discovery::discover_tasks_walking_up(&current_dir).1,
+        None if use_cache => task_discovery::discover_tasks_cached(&current_dir),
+        None => task_discovery::discover_tasks(&current_dir),
+    };
+    if options.no_builtins {
+        task_discovery::ignore_shadowing(&mut discovered);
+    }
+    if options.runnable_only {
+        discovered.tasks.retain(|task| task.runnable);
+    }
+    discovered.tasks.retain(|task| !task.hidden);
+    if !options.runners.is_empty() {
+        discovered.tasks.retain(|task| {
+            options
+                .runners
+                .iter()
+                .any(|runner| task.runner.matches_filter(runner))
+        });
     }
 
     // Create writer for output
@@ -73,163 +275,658 @@ pub fn execute(verbose: bool, color: &str) -> anyhow::Result<()> {
         Box::new(std::io::stdout())
     };
 
-    let mut write_line = |line: &str| -> anyhow::Result<()> {
-        writeln!(writer, "{}", line).map_err(|e| anyhow::anyhow!("Failed to write output: {}", e))
-    };
+    if options.count {
+        for line in count_lines(&discovered, verbose) {
+            writeln!(writer, "{}", line)?;
+        }
+        return Ok(());
+    }
 
-    // Group tasks by runner for the new format
-    let mut tasks_by_runner: HashMap<String, Vec<&Task>> = HashMap::new();
-    for task in &discovered.tasks {
-        let runner_name = task.runner.short_name().to_string();
-        tasks_by_runner.entry(runner_name).or_default().push(task);
+    // Only show task definition files status in verbose mode, and never mix it into JSON
+    if verbose && format != ListFormat::Json {
+        print_definition_files(&discovered, &current_dir);
+    }
+
+    let formatter = format.formatter(verbose, &layout, group, sort);
+    formatter.write(&discovered, &current_dir, writer.as_mut())?;
+    if matches!(format, ListFormat::Pretty | ListFormat::Tree) {
+        match config::load_project_config(&current_dir) {
+            Ok(project) => write_aliases(&project.resolved_aliases(), &layout, writer.as_mut())?,
+            Err(error) => eprintln!("Warning: {}", error),
+        }
+    }
+    last_list::save(
+        &current_dir,
+        &formatter.display_order(&discovered, &current_dir),
+    );
+
+    if options.check_deps {
+        let cycles = task_discovery::find_dependency_cycles(&discovered.tasks);
+        if !cycles.is_empty() {
+            eprintln!("\n{}", "Dependency cycles:".red().bold());
+            for cycle in &cycles {
+                eprintln!("  {} {}", "•".red(), cycle);
+            }
+            return Err(anyhow::anyhow!(
+                "Found {} dependency cycle(s)",
+                cycles.len()
+            ));
+        }
     }
+    Ok(())
+}
 
-    // Track footnotes used
-    let mut used_footnotes: HashMap<char, bool> = HashMap::new();
-    used_footnotes.insert('*', false); // tool not installed
-    used_footnotes.insert('†', false); // shadowed by shell builtin
-    used_footnotes.insert('‡', false); // shadowed by command on path
-    used_footnotes.insert('‖', false); // conflicts with task from another tool
-    used_footnotes.insert('§', false); // no tool exists for ci execution
+/// The total number of tasks, followed by one `runner count` line per runner when
+/// `per_runner` is set.
+fn count_lines(discovered: &DiscoveredTasks, per_runner: bool) -> Vec<String> {
+    let mut lines = vec![discovered.tasks.len().to_string()];
+    if per_runner {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for task in &discovered.tasks {
+            *counts.entry(task.runner.short_name()).or_default() += 1;
+        }
+        lines.extend(
+            counts
+                .into_iter()
+                .map(|(runner, count)| format!("{} {}", runner, count)),
+        );
+    }
+    lines
+}
 
-    if tasks_by_runner.is_empty() {
-        write_line(&format!(
-            "{}",
-            "No tasks found in the current directory.".yellow()
-        ))?;
-    } else {
-        // Calculate max task name width across all runners
-        let max_task_name_width = discovered
-            .tasks
-            .iter()
-            .map(|t| t.disambiguated_name.as_ref().unwrap_or(&t.name).len())
-            .max()
-            .unwrap_or(0)
-            .max(18); // Minimum 18 characters
+fn print_definition_files(discovered: &DiscoveredTasks, current_dir: &Path) {
+    test_println!("Task definition files:");
+    for (_def_type, files) in discovered.definitions.iter() {
+        for file in files {
+            let file_name = file
+                .path
+                .strip_prefix(current_dir)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| file.path.to_string_lossy().to_string());
+            match &file.status {
+                TaskFileStatus::Parsed => {
+                    test_println!("  {} {}: Found and parsed", "✓".green(), file_name);
+                }
+                TaskFileStatus::NotImplemented => {
+                    test_println!(
+                        "  {} {}: Found but parsing not yet implemented",
+                        "!".yellow(),
+                        file_name
+                    );
+                }
+                TaskFileStatus::ParseError(e) => {
+                    test_println!("  {} {}: Error parsing: {}", "✗".red(), file_name, e);
+                }
+                TaskFileStatus::NotReadable(e) => {
+                    test_println!("  {} {}: Not readable: {}", "✗".red(), file_name, e);
+                }
+                TaskFileStatus::NotFound => {
+                    test_println!("  {} {}: Not found", "-".dimmed(), file_name);
+                }
+            }
+        }
+    }
+    test_println!("");
+}
 
-        // Ensure all task names will be padded to this width
-        // Round up to nearest multiple of 5 for better alignment
-        let display_width = max_task_name_width.div_ceil(5) * 5;
+/// The `aliases` section, with each `.dela.toml` alias and the task it runs.
+fn write_aliases(
+    aliases: &[(&str, &str)],
+    layout: &ListConfig,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let Some(longest) = aliases.iter().map(|(alias, _)| alias.len()).max() else {
+        return Ok(());
+    };
+    let width = layout.name_column_width(longest);
+    write_output_line(
+        out,
+        &format!("\n{} — {}", "aliases".cyan(), PROJECT_CONFIG_FILE.dimmed()),
+    )?;
+    for (alias, task) in aliases {
+        write_output_line(
+            out,
+            &format!("  {:<width$} → {}", alias, task, width = width),
+        )?;
+    }
+    Ok(())
+}
 
-        // Get a sorted list of runners for deterministic output
-        let mut runners: Vec<String> = tasks_by_runner.keys().cloned().collect();
-        runners.sort();
+fn write_output_line(out: &mut dyn Write, line: &str) -> anyhow::Result<()> {
+    writeln!(out, "{}", line).map_err(|e| anyhow::anyhow!("Failed to write output: {}", e))
+}
 
-        // Process each runner section
-        for runner in runners {
-            let tasks = tasks_by_runner.get(&runner).unwrap();
+fn display_name(task: &Task) -> &str {
+    task.disambiguated_name.as_ref().unwrap_or(&task.name)
+}
 
-            // Sort tasks by name for deterministic output
-            let mut sorted_tasks = tasks.to_vec();
-            sorted_tasks.sort_by(|a, b| {
-                let a_name = a.disambiguated_name.as_ref().unwrap_or(&a.name);
-                let b_name = b.disambiguated_name.as_ref().unwrap_or(&b.name);
-                a_name.cmp(b_name)
-            });
+/// Tasks ordered by their displayed name, which is how every non-pretty format lists them.
+fn tasks_sorted_by_name(discovered: &DiscoveredTasks) -> Vec<&Task> {
+    tasks_sorted(discovered, ListSort::Name)
+}
 
-            // Add missing runner indicator if needed
-            let tool_not_installed = !is_runner_available(&sorted_tasks[0].runner);
-            let runner_name = runner.clone();
-            let runner_footnote = if sorted_tasks[0].runner == crate::types::TaskRunner::TravisCi {
-                used_footnotes.insert('§', true);
-                Some("§".yellow())
-            } else if tool_not_installed {
-                used_footnotes.insert('*', true);
-                Some("*".yellow())
-            } else {
-                None
-            };
-
-            let runner_paths: HashSet<_> =
-                sorted_tasks.iter().map(|task| &task.file_path).collect();
-            let section_runner_path =
-                (runner_paths.len() == 1).then_some(sorted_tasks[0].file_path.as_path());
-            let display_path = if let Some(runner_path) = section_runner_path {
-                format_runner_path_for_display(&runner, runner_path, &current_dir)
-            } else {
-                "multiple files".to_string()
-            };
-
-            // Write section header
-            let colored_runner = if tool_not_installed {
-                runner_name.dimmed().red()
-            } else {
-                runner_name.cyan()
-            };
-            let runner_header = if let Some(footnote) = runner_footnote {
-                format!("{} {}", colored_runner, footnote)
-            } else {
-                format!("{}", colored_runner)
-            };
-            write_line(&format!("\n{} — {}", runner_header, display_path.dimmed()))?;
-
-            // Process each task in the section
-            for task in sorted_tasks {
-                // Check for conflicts and update footnotes tracker
-                let is_ambiguous = task_discovery::is_task_ambiguous(&discovered, &task.name);
-                if is_ambiguous {
-                    used_footnotes.insert('‖', true);
-                }
+fn tasks_sorted(discovered: &DiscoveredTasks, sort: ListSort) -> Vec<&Task> {
+    let mut tasks: Vec<&Task> = discovered.tasks.iter().collect();
+    sort.apply(&mut tasks);
+    tasks
+}
 
-                if let Some(shadowed_by) = &task.shadowed_by {
-                    match shadowed_by {
-                        ShadowType::ShellBuiltin(_) => {
-                            used_footnotes.insert('†', true);
-                        }
-                        ShadowType::PathExecutable(_) => {
-                            used_footnotes.insert('‡', true);
+/// Tasks in sections by runner, with runners ordered by name and the tasks in each by `sort`.
+fn tasks_by_runner(discovered: &DiscoveredTasks, sort: ListSort) -> Vec<(String, Vec<&Task>)> {
+    let mut sections: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+    for task in tasks_sorted(discovered, sort) {
+        let runner_name = task.runner.short_name().to_string();
+        sections.entry(runner_name).or_default().push(task);
+    }
+    sections.into_iter().collect()
+}
+
+/// The default colored output grouped by runner, with a footnote legend.
+pub struct PrettyFormatter {
+    /// Also show the parameters each task accepts
+    pub verbose: bool,
+    /// Width and alignment of the task name column
+    pub layout: ListConfig,
+    /// Order of the tasks within each runner
+    pub sort: ListSort,
+}
+
+impl ListFormatter for PrettyFormatter {
+    fn display_order<'a>(
+        &self,
+        discovered: &'a DiscoveredTasks,
+        _current_dir: &Path,
+    ) -> Vec<&'a Task> {
+        tasks_by_runner(discovered, self.sort)
+            .into_iter()
+            .flat_map(|(_, tasks)| tasks)
+            .collect()
+    }
+
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        let mut write_line = |line: &str| write_output_line(out, line);
+        let tasks_by_runner = tasks_by_runner(discovered, self.sort);
+
+        // Track footnotes used
+        let mut used_footnotes: HashMap<char, bool> = HashMap::new();
+        used_footnotes.insert('*', false); // tool not installed
+        used_footnotes.insert('†', false); // shadowed by shell builtin
+        used_footnotes.insert('‡', false); // shadowed by command on path
+        used_footnotes.insert('¶', false); // shadowed by shell alias
+        used_footnotes.insert('‖', false); // conflicts with task from another tool
+        used_footnotes.insert('§', false); // no tool exists for ci execution
+
+        if tasks_by_runner.is_empty() {
+            write_line(&format!(
+                "{}",
+                "No tasks found in the current directory.".yellow()
+            ))?;
+        } else {
+            // Calculate max task name width across all runners
+            let max_task_name_width = discovered
+                .tasks
+                .iter()
+                .map(|t| t.disambiguated_name.as_ref().unwrap_or(&t.name).len())
+                .max()
+                .unwrap_or(0);
+
+            // Ensure all task names will be padded to this width, rounded up for alignment
+            let display_width = self.layout.name_column_width(max_task_name_width);
+
+            // Process each runner section
+            for (runner, sorted_tasks) in tasks_by_runner {
+                // Add missing runner indicator if needed
+                let tool_not_installed = !is_runner_available(&sorted_tasks[0].runner);
+                let runner_name = runner.clone();
+                // GitLab CI jobs can run locally, but only through gitlab-ci-local.
+                let no_ci_tool = match sorted_tasks[0].runner {
+                    crate::types::TaskRunner::TravisCi => true,
+                    crate::types::TaskRunner::GitlabCi => tool_not_installed,
+                    _ => false,
+                };
+                let runner_footnote = if no_ci_tool {
+                    used_footnotes.insert('§', true);
+                    Some("§".yellow())
+                } else if tool_not_installed {
+                    used_footnotes.insert('*', true);
+                    Some("*".yellow())
+                } else {
+                    None
+                };
+
+                let runner_paths: HashSet<_> =
+                    sorted_tasks.iter().map(|task| &task.file_path).collect();
+                let section_runner_path =
+                    (runner_paths.len() == 1).then_some(sorted_tasks[0].file_path.as_path());
+                let display_path = if let Some(runner_path) = section_runner_path {
+                    format_runner_path_for_display(&runner, runner_path, current_dir)
+                } else {
+                    "multiple files".to_string()
+                };
+
+                // Write section header
+                let colored_runner = if tool_not_installed {
+                    runner_name.dimmed().red()
+                } else {
+                    runner_name.cyan()
+                };
+                let runner_header = if let Some(footnote) = runner_footnote {
+                    format!("{} {}", colored_runner, footnote)
+                } else {
+                    format!("{}", colored_runner)
+                };
+                write_line(&format!("\n{} — {}", runner_header, display_path.dimmed()))?;
+
+                // Process each task in the section
+                for task in sorted_tasks {
+                    // Check for conflicts and update footnotes tracker
+                    let is_ambiguous = task_discovery::is_task_ambiguous(discovered, &task.name);
+                    if is_ambiguous {
+                        used_footnotes.insert('‖', true);
+                    }
+
+                    if let Some(shadowed_by) = &task.shadowed_by {
+                        match shadowed_by {
+                            ShadowType::ShellBuiltin(_) => {
+                                used_footnotes.insert('†', true);
+                            }
+                            ShadowType::PathExecutable(_) => {
+                                used_footnotes.insert('‡', true);
+                            }
+                            ShadowType::ShellAlias(_) => {
+                                used_footnotes.insert('¶', true);
+                            }
                         }
                     }
+
+                    // Format the task entry
+                    let formatted_task = format_task_entry(task, is_ambiguous, display_width);
+                    let source_label = task_source_label(task, section_runner_path, current_dir);
+                    let formatted_task =
+                        format_task_entry_with_source(formatted_task, source_label.as_deref());
+                    let formatted_task = if self.verbose {
+                        let formatted_task = format_task_entry_with_details(
+                            formatted_task,
+                            "params",
+                            &task.parameters,
+                        );
+                        let formatted_task = format_task_entry_with_details(
+                            formatted_task,
+                            "depends",
+                            &task.dependencies,
+                        );
+                        format_task_entry_with_location(formatted_task, task, current_dir)
+                    } else {
+                        formatted_task
+                    };
+                    write_line(&format!("  {}", formatted_task))?;
                 }
+            }
 
-                // Format the task entry
-                let formatted_task = format_task_entry(task, is_ambiguous, display_width);
-                let source_label = task_source_label(task, section_runner_path, &current_dir);
-                let formatted_task =
-                    format_task_entry_with_source(formatted_task, source_label.as_deref());
-                write_line(&format!("  {}", formatted_task))?;
+            // Add footnotes legend
+            let mut footnotes: Vec<(char, &str)> = Vec::new();
+            if *used_footnotes.get(&'*').unwrap_or(&false) {
+                footnotes.push(('*', "tool not installed"));
+            }
+            if *used_footnotes.get(&'†').unwrap_or(&false) {
+                footnotes.push(('†', "shadowed by a shell builtin"));
+            }
+            if *used_footnotes.get(&'‡').unwrap_or(&false) {
+                footnotes.push(('‡', "shadowed by a command on the path"));
+            }
+            if *used_footnotes.get(&'¶').unwrap_or(&false) {
+                footnotes.push(('¶', "shadowed by a shell alias"));
+            }
+            if *used_footnotes.get(&'‖').unwrap_or(&false) {
+                footnotes.push(('‖', "conflicts with task from another tool"));
+            }
+            if *used_footnotes.get(&'§').unwrap_or(&false) {
+                footnotes.push(('§', "no tool exists for ci execution"));
+            }
+
+            if !footnotes.is_empty() {
+                write_line(&format!("\n{}", "footnotes legend:".dimmed()))?;
+                for (symbol, description) in footnotes {
+                    write_line(&format!(
+                        "{} {}",
+                        symbol.to_string().yellow(),
+                        description.dimmed()
+                    ))?;
+                }
             }
         }
 
-        // Add footnotes legend
-        let mut footnotes: Vec<(char, &str)> = Vec::new();
-        if *used_footnotes.get(&'*').unwrap_or(&false) {
-            footnotes.push(('*', "tool not installed"));
+        // Show any errors encountered during discovery
+        if !discovered.errors.is_empty() {
+            write_line(&format!("\n{}", "Errors encountered:".red().bold()))?;
+            for error in &discovered.errors {
+                write_line(&format!("  {} {}", "•".red(), error.red()))?;
+            }
         }
-        if *used_footnotes.get(&'†').unwrap_or(&false) {
-            footnotes.push(('†', "shadowed by a shell builtin"));
+
+        Ok(())
+    }
+}
+
+/// Separators that end the prefix used by `--group prefix`, as in `docker:build`.
+const PREFIX_SEPARATORS: [char; 5] = [':', '-', '_', '.', '/'];
+
+/// Pretty output split into sections by something other than the runner.
+///
+/// Each task is labeled with its runner, and with its file unless sections are files.
+/// A task with several tags is listed under each of them.
+pub struct GroupedFormatter {
+    pub group: ListGroup,
+    /// Also show the parameters and dependencies of each task
+    pub verbose: bool,
+    /// Width and alignment of the task name column
+    pub layout: ListConfig,
+    /// Order of the tasks within each section
+    pub sort: ListSort,
+}
+
+impl GroupedFormatter {
+    fn section_names(
+        &self,
+        task: &Task,
+        shared_prefixes: &HashSet<&str>,
+        current_dir: &Path,
+    ) -> Vec<String> {
+        match self.group {
+            ListGroup::Runner => vec![task.runner.short_name().to_string()],
+            ListGroup::Prefix => match task_prefix(task) {
+                Some(prefix) if shared_prefixes.contains(prefix) => vec![prefix.to_string()],
+                _ => vec!["other".to_string()],
+            },
+            ListGroup::File => vec![format_definition_path_for_display(
+                task.definition_path(),
+                current_dir,
+            )],
+            ListGroup::Tag if task.tags.is_empty() => vec!["untagged".to_string()],
+            ListGroup::Tag => task.tags.clone(),
+            ListGroup::Dir => vec![
+                task.workspace_dir
+                    .as_ref()
+                    .map(|dir| dir.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| ".".to_string()),
+            ],
         }
-        if *used_footnotes.get(&'‡').unwrap_or(&false) {
-            footnotes.push(('‡', "shadowed by a command on the path"));
+    }
+}
+
+impl GroupedFormatter {
+    fn sections<'a>(
+        &self,
+        discovered: &'a DiscoveredTasks,
+        current_dir: &Path,
+    ) -> BTreeMap<String, Vec<&'a Task>> {
+        // A prefix only makes a section when more than one task shares it
+        let mut prefix_counts: HashMap<&str, usize> = HashMap::new();
+        for task in &discovered.tasks {
+            if let Some(prefix) = task_prefix(task) {
+                *prefix_counts.entry(prefix).or_default() += 1;
+            }
+        }
+        let shared_prefixes: HashSet<&str> = prefix_counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(prefix, _)| prefix)
+            .collect();
+
+        let mut sections: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+        for task in tasks_sorted(discovered, self.sort) {
+            for section in self.section_names(task, &shared_prefixes, current_dir) {
+                sections.entry(section).or_default().push(task);
+            }
         }
-        if *used_footnotes.get(&'‖').unwrap_or(&false) {
-            footnotes.push(('‖', "conflicts with task from another tool"));
+        sections
+    }
+}
+
+/// The part of a task name before its first separator, if it has one.
+fn task_prefix(task: &Task) -> Option<&str> {
+    task.name
+        .split_once(PREFIX_SEPARATORS)
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| !prefix.is_empty())
+}
+
+impl ListFormatter for GroupedFormatter {
+    fn display_order<'a>(
+        &self,
+        discovered: &'a DiscoveredTasks,
+        current_dir: &Path,
+    ) -> Vec<&'a Task> {
+        self.sections(discovered, current_dir)
+            .into_values()
+            .flatten()
+            .collect()
+    }
+
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        if discovered.tasks.is_empty() {
+            return write_output_line(
+                out,
+                &format!("{}", "No tasks found in the current directory.".yellow()),
+            );
         }
-        if *used_footnotes.get(&'§').unwrap_or(&false) {
-            footnotes.push(('§', "no tool exists for ci execution"));
+
+        let sections = self.sections(discovered, current_dir);
+
+        let longest_name = discovered
+            .tasks
+            .iter()
+            .map(|task| display_name(task).len())
+            .max()
+            .unwrap_or(0);
+        let display_width = self.layout.name_column_width(longest_name);
+
+        for (section, tasks) in sections {
+            write_output_line(out, &format!("\n{}", section.cyan()))?;
+            for task in tasks {
+                let is_ambiguous = task_discovery::is_task_ambiguous(discovered, &task.name);
+                let formatted_task = format_task_entry(task, is_ambiguous, display_width);
+                let source_label = if self.group == ListGroup::File {
+                    task.runner.short_name().to_string()
+                } else {
+                    format!(
+                        "{}, {}",
+                        task.runner.short_name(),
+                        format_definition_path_for_display(task.definition_path(), current_dir)
+                    )
+                };
+                let mut formatted_task =
+                    format_task_entry_with_source(formatted_task, Some(&source_label));
+                if self.verbose {
+                    formatted_task =
+                        format_task_entry_with_details(formatted_task, "params", &task.parameters);
+                    formatted_task = format_task_entry_with_details(
+                        formatted_task,
+                        "depends",
+                        &task.dependencies,
+                    );
+                    formatted_task =
+                        format_task_entry_with_location(formatted_task, task, current_dir);
+                }
+                write_output_line(out, &format!("  {}", formatted_task))?;
+            }
         }
 
-        if !footnotes.is_empty() {
-            write_line(&format!("\n{}", "footnotes legend:".dimmed()))?;
-            for (symbol, description) in footnotes {
-                write_line(&format!(
-                    "{} {}",
-                    symbol.to_string().yellow(),
-                    description.dimmed()
-                ))?;
+        if !discovered.errors.is_empty() {
+            write_output_line(out, &format!("\n{}", "Errors encountered:".red().bold()))?;
+            for error in &discovered.errors {
+                write_output_line(out, &format!("  {} {}", "•".red(), error.red()))?;
             }
         }
+        Ok(())
+    }
+}
+
+/// A JSON array of tasks with no color codes, for scripts and editor integrations.
+///
+/// Each entry has the fields of the MCP `list_tasks` tool plus the CLI-only `runnable`,
+/// `tags`, `deprecated` and `workspace_dir`.
+pub struct JsonFormatter;
+
+impl ListFormatter for JsonFormatter {
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        _current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        // A missing allowlist, e.g. before `dela init`, means nothing is allowlisted yet.
+        let allowlist = McpAllowlistEvaluator::new().ok();
+        let tasks: Vec<serde_json::Value> = tasks_sorted_by_name(discovered)
+            .into_iter()
+            .map(|task| {
+                let mut dto = TaskDto::from_task(task);
+                dto.allowlisted = allowlist
+                    .as_ref()
+                    .is_some_and(|allowlist| allowlist.is_task_allowed(task).unwrap_or(false));
+                let mut entry = serde_json::to_value(dto)?;
+                entry
+                    .as_object_mut()
+                    .expect("TaskDto is an object")
+                    .extend([
+                        ("runnable".to_string(), task.runnable.into()),
+                        ("tags".to_string(), task.tags.clone().into()),
+                        ("deprecated".to_string(), task.deprecated.into()),
+                        (
+                            "workspace_dir".to_string(),
+                            task.workspace_dir
+                                .as_ref()
+                                .map(|dir| dir.to_string_lossy())
+                                .into(),
+                        ),
+                    ]);
+                Ok(entry)
+            })
+            .collect::<Result<_, serde_json::Error>>()
+            .map_err(|e| anyhow::anyhow!("Failed to serialize tasks: {}", e))?;
+
+        let json = serde_json::to_string_pretty(&tasks)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize tasks: {}", e))?;
+        for error in &discovered.errors {
+            eprintln!("{}", error);
+        }
+        write_output_line(out, &json)
     }
+}
 
-    // Show any errors encountered during discovery
-    if !discovered.errors.is_empty() {
-        write_line(&format!("\n{}", "Errors encountered:".red().bold()))?;
-        for error in discovered.errors {
-            write_line(&format!("  {} {}", "•".red(), error.red()))?;
+/// One `name<TAB>runner<TAB>description` line per task, for piping into grep or fzf.
+pub struct FlatFormatter;
+
+impl ListFormatter for FlatFormatter {
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        _current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        for task in tasks_sorted_by_name(discovered) {
+            write_output_line(
+                out,
+                &format!(
+                    "{}\t{}\t{}",
+                    display_name(task),
+                    task.runner.short_name(),
+                    // Keep the description from breaking the fields or the line
+                    task.description
+                        .as_deref()
+                        .unwrap_or("")
+                        .replace(['\t', '\n', '\r'], " ")
+                ),
+            )?;
         }
+        Ok(())
     }
+}
 
-    Ok(())
+/// Tasks nested under the definition file they come from.
+pub struct TreeFormatter;
+
+fn tasks_by_file<'a>(
+    discovered: &'a DiscoveredTasks,
+    current_dir: &Path,
+) -> BTreeMap<String, Vec<&'a Task>> {
+    let mut tasks_by_file: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+    for task in tasks_sorted_by_name(discovered) {
+        tasks_by_file
+            .entry(format_definition_path_for_display(
+                task.definition_path(),
+                current_dir,
+            ))
+            .or_default()
+            .push(task);
+    }
+    tasks_by_file
+}
+
+impl ListFormatter for TreeFormatter {
+    fn display_order<'a>(
+        &self,
+        discovered: &'a DiscoveredTasks,
+        current_dir: &Path,
+    ) -> Vec<&'a Task> {
+        tasks_by_file(discovered, current_dir)
+            .into_values()
+            .flatten()
+            .collect()
+    }
+
+    fn write(
+        &self,
+        discovered: &DiscoveredTasks,
+        current_dir: &Path,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        for (file, tasks) in tasks_by_file(discovered, current_dir) {
+            write_output_line(out, &file.cyan().to_string())?;
+            for (index, task) in tasks.iter().enumerate() {
+                let branch = if index + 1 == tasks.len() {
+                    "└──"
+                } else {
+                    "├──"
+                };
+                let description = task
+                    .description
+                    .as_ref()
+                    .map(|desc| format!(" - {}", desc))
+                    .unwrap_or_default();
+                write_output_line(
+                    out,
+                    &format!(
+                        "{} {} ({}){}",
+                        branch,
+                        display_name(task).green(),
+                        task.runner.short_name(),
+                        description
+                    ),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The description shown for `task`, marked when a sidecar deprecates the task.
+fn listed_description(task: &Task) -> Option<String> {
+    match (&task.description, task.deprecated) {
+        (Some(description), true) => Some(format!("(deprecated) {}", description)),
+        (None, true) => Some("(deprecated)".to_string()),
+        (description, false) => description.clone(),
+    }
 }
 
 fn format_task_entry(task: &Task, is_ambiguous: bool, name_width: usize) -> String {
@@ -249,6 +946,7 @@ fn format_task_entry(task: &Task, is_ambiguous: bool, name_width: usize) -> Stri
         match shadow {
             ShadowType::ShellBuiltin(_) => footnotes.push('†'),
             ShadowType::PathExecutable(_) => footnotes.push('‡'),
+            ShadowType::ShellAlias(_) => footnotes.push('¶'),
         }
     }
 
@@ -261,6 +959,8 @@ fn format_task_entry(task: &Task, is_ambiguous: bool, name_width: usize) -> Stri
         }
     };
 
+    let description = listed_description(task);
+
     // Create the task description part
     let description_part = if task.disambiguated_name.is_some() {
         // For disambiguated tasks, show the original name with footnotes
@@ -271,7 +971,7 @@ fn format_task_entry(task: &Task, is_ambiguous: bool, name_width: usize) -> Stri
         };
 
         // Add the description if available
-        if let Some(desc) = &task.description {
+        if let Some(desc) = &description {
             format!("{} - {}", orig_with_footnotes, truncate_desc(desc))
         } else {
             // No description, just show the original name
@@ -279,7 +979,7 @@ fn format_task_entry(task: &Task, is_ambiguous: bool, name_width: usize) -> Stri
         }
     } else {
         // For non-disambiguated tasks
-        if let Some(desc) = &task.description {
+        if let Some(desc) = &description {
             format!("- {}", truncate_desc(desc))
         } else {
             // No description, return empty string since we already show the task name
@@ -334,6 +1034,40 @@ fn format_task_entry_with_source(formatted_task: String, source_label: Option<&s
     }
 }
 
+/// Append a dimmed `(label: a, b)` note, used for parameters and dependencies in verbose mode.
+fn format_task_entry_with_details(formatted_task: String, label: &str, items: &[String]) -> String {
+    if items.is_empty() {
+        formatted_task
+    } else {
+        format!(
+            "{} {}",
+            formatted_task,
+            format!("({}: {})", label, items.join(", ")).dimmed()
+        )
+    }
+}
+
+/// Append a dimmed `(Makefile:12)` note naming where the task is defined, if its line is known.
+fn format_task_entry_with_location(
+    formatted_task: String,
+    task: &Task,
+    current_dir: &Path,
+) -> String {
+    match task.line {
+        Some(line) => format!(
+            "{} {}",
+            formatted_task,
+            format!(
+                "({}:{})",
+                format_definition_path_for_display(task.definition_path(), current_dir),
+                line
+            )
+            .dimmed()
+        ),
+        None => formatted_task,
+    }
+}
+
 fn task_source_label(
     task: &Task,
     section_runner_path: Option<&Path>,
@@ -412,11 +1146,13 @@ mod tests {
                 TaskRunner::Make => TaskDefinitionType::Makefile,
                 TaskRunner::NodeNpm
                 | TaskRunner::NodeYarn
+                | TaskRunner::NodeYarnBerry
                 | TaskRunner::NodePnpm
                 | TaskRunner::NodeBun => TaskDefinitionType::PackageJson,
-                TaskRunner::PythonUv | TaskRunner::PythonPoetry | TaskRunner::PythonPoe => {
-                    TaskDefinitionType::PyprojectToml
-                }
+                TaskRunner::PythonUv
+                | TaskRunner::PythonPoetry
+                | TaskRunner::PythonPoe
+                | TaskRunner::PythonHatch => TaskDefinitionType::PyprojectToml,
                 TaskRunner::ShellScript => TaskDefinitionType::ShellScript,
                 TaskRunner::Task => TaskDefinitionType::Taskfile,
                 TaskRunner::Turbo => TaskDefinitionType::TurboJson,
@@ -427,12 +1163,41 @@ mod tests {
                 TaskRunner::TravisCi => TaskDefinitionType::TravisCi,
                 TaskRunner::CMake => TaskDefinitionType::CMake,
                 TaskRunner::Just => TaskDefinitionType::Justfile,
+                TaskRunner::Tox => TaskDefinitionType::ToxIni,
+                TaskRunner::Nox => TaskDefinitionType::Noxfile,
+                TaskRunner::Invoke => TaskDefinitionType::InvokeTasks,
+                TaskRunner::GitlabCi => TaskDefinitionType::GitlabCi,
+                TaskRunner::Nx => TaskDefinitionType::NxJson,
+                TaskRunner::Mise => TaskDefinitionType::MiseToml,
+                TaskRunner::Bazel => TaskDefinitionType::BazelWorkspace,
+                TaskRunner::Meson => TaskDefinitionType::MesonBuild,
+                TaskRunner::Scons => TaskDefinitionType::SConstruct,
+                TaskRunner::PreCommit => TaskDefinitionType::PreCommitConfig,
+                TaskRunner::Ant => TaskDefinitionType::AntBuildXml,
+                TaskRunner::Sbt => TaskDefinitionType::BuildSbt,
+                TaskRunner::Leiningen => TaskDefinitionType::ProjectClj,
+                TaskRunner::Procfile => TaskDefinitionType::Procfile,
+                TaskRunner::Mage => TaskDefinitionType::Magefile,
+                TaskRunner::DockerBake => TaskDefinitionType::DockerBake,
+                TaskRunner::Docker => TaskDefinitionType::Dockerfile,
+                TaskRunner::Helm => TaskDefinitionType::HelmChart,
             },
             runner,
             source_name: name.to_string(),
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         }
     }
 
@@ -662,6 +1427,17 @@ mod tests {
             description: Some("Building the project".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
         let formatted = super::format_task_entry(&task, false, 18);
 
@@ -814,6 +1590,17 @@ mod tests {
             description: Some("Integration Tests".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Create a test writer to capture output
@@ -900,6 +1687,17 @@ mod tests {
             description: Some("Included task".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let formatted = format_task_entry(&task, false, 18);
@@ -925,6 +1723,17 @@ mod tests {
             description: Some("Build task".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
         let included_task = Task {
             name: "release_notes".to_string(),
@@ -936,6 +1745,17 @@ mod tests {
             description: Some("Release task".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         assert_eq!(
@@ -978,7 +1798,782 @@ mod tests {
         std::fs::write(&makefile_path, "build:\n\techo 'building'\n").unwrap();
 
         // Run execute
-        let result = execute(true, "never");
+        let result = execute(&ListOptions {
+            verbose: true,
+            color: "never".to_string(),
+            format: "pretty".to_string(),
+            group: "runner".to_string(),
+            sort: "name".to_string(),
+            runnable_only: false,
+            check_deps: false,
+            no_builtins: false,
+            depth: None,
+            walk_up: false,
+            runners: Vec::new(),
+            count: false,
+            no_cache: false,
+            cwd: None,
+        });
         assert!(result.is_ok());
     }
+
+    fn formatter_output(
+        formatter: &dyn ListFormatter,
+        discovered: &task_discovery::DiscoveredTasks,
+        current_dir: &Path,
+    ) -> String {
+        let mut writer = TestWriter::new();
+        formatter
+            .write(discovered, current_dir, &mut writer)
+            .unwrap();
+        writer.get_output()
+    }
+
+    fn formatter_test_tasks(dir: &Path) -> task_discovery::DiscoveredTasks {
+        let mut build = create_test_task("build", dir.join("Makefile"), TaskRunner::Make);
+        build.description = Some("Build the project".to_string());
+        let mut test_make = create_test_task("test", dir.join("Makefile"), TaskRunner::Make);
+        test_make.disambiguated_name = Some("test-m".to_string());
+        let mut test_npm = create_test_task("test", dir.join("package.json"), TaskRunner::NodeNpm);
+        test_npm.disambiguated_name = Some("test-n".to_string());
+        test_npm.description = Some("jest".to_string());
+
+        task_discovery::DiscoveredTasks {
+            tasks: vec![test_npm, build, test_make],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_list_format_parse() {
+        assert_eq!(ListFormat::parse("pretty").unwrap(), ListFormat::Pretty);
+        assert_eq!(ListFormat::parse("json").unwrap(), ListFormat::Json);
+        assert_eq!(ListFormat::parse("flat").unwrap(), ListFormat::Flat);
+        assert_eq!(ListFormat::parse("tree").unwrap(), ListFormat::Tree);
+
+        let error = ListFormat::parse("yaml").unwrap_err().to_string();
+        assert!(error.contains("Unknown list format 'yaml'"));
+        assert!(error.contains("pretty, json, flat, tree"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_groups_by_runner() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        let make_header = output.find("\nmake").unwrap();
+        let npm_header = output.find("\nnpm").unwrap();
+        assert!(make_header < npm_header);
+        assert!(output.contains(&format!("  {:<20}  - Build the project", "build")));
+        assert!(output.contains("test-m"));
+        assert!(output.contains(&format!("  {:<20}  test - jest", "test-n")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_custom_name_width() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig {
+                    min_name_width: 28,
+                    align_step: 4,
+                },
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        assert!(output.contains(&format!("  {:<28}  - Build the project", "build")));
+        assert!(!output.contains(&format!("  {:<20}  - Build", "build")));
+    }
+
+    #[test]
+    fn test_list_group_parse() {
+        assert_eq!(ListGroup::parse("runner").unwrap(), ListGroup::Runner);
+        assert_eq!(ListGroup::parse("dir").unwrap(), ListGroup::Dir);
+
+        let error = ListGroup::parse("owner").unwrap_err().to_string();
+        assert!(error.contains("Unknown list grouping 'owner'"));
+        assert!(error.contains("runner, prefix, file, tag, dir"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_grouped_formatter_groups_by_tag() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let mut discovered = formatter_test_tasks(temp_dir.path());
+        for task in &mut discovered.tasks {
+            if task.name == "build" {
+                task.tags = vec!["release".to_string(), "ci".to_string()];
+            } else if task.runner == TaskRunner::NodeNpm {
+                task.tags = vec!["ci".to_string()];
+            }
+        }
+
+        let output = formatter_output(
+            &GroupedFormatter {
+                group: ListGroup::Tag,
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        let sections: Vec<&str> = output
+            .split("\n\n")
+            .map(str::trim_start)
+            .filter(|section| !section.is_empty())
+            .collect();
+        assert_eq!(sections.len(), 3);
+        assert!(sections[0].starts_with("ci\n"));
+        assert!(sections[0].contains("build"));
+        assert!(sections[0].contains("test-n"));
+        assert!(!sections[0].contains("test-m"));
+        assert!(sections[1].starts_with("release\n"));
+        assert!(sections[1].contains("build"));
+        assert!(sections[2].starts_with("untagged\n"));
+        assert!(sections[2].contains("test-m"));
+        assert!(sections[2].contains("[make, Makefile]"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_grouped_formatter_groups_by_file() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(
+            &GroupedFormatter {
+                group: ListGroup::File,
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        let sections: Vec<&str> = output
+            .split("\n\n")
+            .map(str::trim_start)
+            .filter(|section| !section.is_empty())
+            .collect();
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0].starts_with("Makefile\n"));
+        assert!(sections[0].contains("build"));
+        assert!(sections[0].contains("test-m"));
+        assert!(sections[0].contains("[make]"));
+        assert!(sections[1].starts_with("package.json\n"));
+        assert!(sections[1].contains("test-n"));
+        assert!(!sections[1].contains("build"));
+    }
+
+    #[test]
+    fn test_pretty_formatter_no_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = task_discovery::DiscoveredTasks::default();
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        assert!(output.contains("No tasks found in the current directory."));
+    }
+
+    #[test]
+    fn test_json_formatter_emits_task_array() {
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(&JsonFormatter, &discovered, temp_dir.path());
+        assert!(!output.contains('\u{1b}'));
+
+        let tasks: Vec<serde_json::Value> = serde_json::from_str(&output).unwrap();
+        let names: Vec<&str> = tasks
+            .iter()
+            .map(|t| t["unique_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["build", "test-m", "test-n"]);
+        assert!(tasks[0]["runner_available"].is_boolean());
+        assert!(tasks[0]["allowlisted"].is_boolean());
+        assert_eq!(tasks[0]["runnable"], true);
+        assert_eq!(tasks[0]["runner"], "make");
+        assert_eq!(tasks[0]["command"], "make build");
+        assert_eq!(tasks[0]["description"], "Build the project");
+        assert_eq!(tasks[2]["source_name"], "test");
+        assert!(tasks[1]["description"].is_null());
+    }
+
+    #[test]
+    fn test_json_formatter_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = task_discovery::DiscoveredTasks::default();
+
+        let output = formatter_output(&JsonFormatter, &discovered, temp_dir.path());
+        assert_eq!(output.trim(), "[]");
+    }
+
+    #[test]
+    fn test_flat_formatter_tab_separated() {
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(&FlatFormatter, &discovered, temp_dir.path());
+        assert_eq!(
+            output,
+            "build\tmake\tBuild the project\ntest-m\tmake\t\ntest-n\tnpm\tjest\n"
+        );
+
+        let mut discovered = discovered;
+        discovered.tasks[0].description = Some("jest\t--watch\nagain".to_string());
+        let output = formatter_output(&FlatFormatter, &discovered, temp_dir.path());
+        assert!(output.ends_with("test-n\tnpm\tjest --watch again\n"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_tree_formatter_nests_tasks_under_files() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let discovered = formatter_test_tasks(temp_dir.path());
+
+        let output = formatter_output(&TreeFormatter, &discovered, temp_dir.path());
+        colored::control::unset_override();
+
+        assert_eq!(
+            output,
+            "Makefile\n\
+             ├── build (make) - Build the project\n\
+             └── test-m (make)\n\
+             package.json\n\
+             └── test-n (npm) - jest\n"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_verbose_shows_parameters() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let workflow_path = temp_dir.path().join(".github/workflows/deploy.yml");
+        let mut deploy = create_test_task("deploy", workflow_path, TaskRunner::Act);
+        deploy.parameters = vec!["environment".to_string(), "version=\"latest\"".to_string()];
+        let discovered = task_discovery::DiscoveredTasks {
+            tasks: vec![deploy],
+            ..Default::default()
+        };
+
+        let verbose = formatter_output(
+            &PrettyFormatter {
+                verbose: true,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        let quiet = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        assert!(verbose.contains("(params: environment, version=\"latest\")"));
+        assert!(!quiet.contains("params:"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_marks_gitlab_ci_without_local_runner() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let gitlab_path = temp_dir.path().join(".gitlab-ci.yml");
+        let discovered = task_discovery::DiscoveredTasks {
+            tasks: vec![create_test_task("build", gitlab_path, TaskRunner::GitlabCi)],
+            ..Default::default()
+        };
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        if is_runner_available(&TaskRunner::GitlabCi) {
+            assert!(!output.contains("§"));
+        } else {
+            assert!(output.contains("gitlab §"));
+            assert!(output.contains("§ no tool exists for ci execution"));
+            assert!(!output.contains("* tool not installed"));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_verbose_shows_dependencies() {
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        let mut deploy = create_test_task(
+            "deploy",
+            temp_dir.path().join("mise.toml"),
+            TaskRunner::Mise,
+        );
+        deploy.dependencies = vec!["build".to_string(), "test".to_string()];
+        let discovered = task_discovery::DiscoveredTasks {
+            tasks: vec![deploy],
+            ..Default::default()
+        };
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: true,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        assert!(output.contains("(depends: build, test)"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_verbose_shows_definition_line() {
+        use crate::task_shadowing::{enable_mock, reset_mock};
+
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "CC := cc\n\nbuild:\n\t$(CC) main.c\n",
+        )
+        .unwrap();
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+        let discovered = task_discovery::discover_tasks(temp_dir.path());
+
+        let verbose = formatter_output(
+            &PrettyFormatter {
+                verbose: true,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        let quiet = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        reset_mock();
+        reset_to_real_environment();
+        colored::control::unset_override();
+
+        assert!(
+            verbose
+                .lines()
+                .any(|line| line.contains("build") && line.ends_with("(Makefile:3)")),
+            "{}",
+            verbose
+        );
+        assert!(!quiet.contains("(Makefile:3)"));
+    }
+
+    #[test]
+    fn test_validate_runner_filters() {
+        assert!(validate_runner_filters(&[]).is_ok());
+        assert!(validate_runner_filters(&["make".to_string(), "NPM".to_string()]).is_ok());
+        assert!(validate_runner_filters(&["node".to_string()]).is_ok());
+
+        let error = validate_runner_filters(&["make".to_string(), "rake".to_string()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("Unknown runner 'rake'. Valid runners: make, npm,"));
+        assert!(error.contains("docker compose"));
+        assert!(error.contains("python"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_execute_filters_by_runner() {
+        let (temp_dir, home_dir) = setup_test_env();
+        let _guard = CwdGuard {
+            old_dir: std::env::current_dir().ok(),
+        };
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+        std::fs::write(temp_dir.path().join("Makefile"), "build:\n\techo build\n").unwrap();
+
+        let options = |runners: &[&str]| ListOptions {
+            verbose: false,
+            color: "never".to_string(),
+            format: "json".to_string(),
+            group: "runner".to_string(),
+            sort: "name".to_string(),
+            runnable_only: false,
+            check_deps: false,
+            no_builtins: false,
+            depth: None,
+            walk_up: false,
+            runners: runners.iter().map(|runner| runner.to_string()).collect(),
+            count: false,
+            no_cache: false,
+            cwd: None,
+        };
+        assert!(execute(&options(&["make", "npm"])).is_ok());
+        assert!(execute(&options(&["Make"])).is_ok());
+        assert!(execute(&options(&["rake"])).is_err());
+
+        let listed_elsewhere = ListOptions {
+            cwd: Some(temp_dir.path().to_path_buf()),
+            ..options(&[])
+        };
+        std::env::set_current_dir(home_dir.path()).unwrap();
+        assert!(execute(&listed_elsewhere).is_ok());
+        let missing = ListOptions {
+            cwd: Some(temp_dir.path().join("missing")),
+            ..options(&[])
+        };
+        assert!(
+            execute(&missing)
+                .unwrap_err()
+                .to_string()
+                .starts_with("Cannot use --cwd")
+        );
+    }
+
+    #[test]
+    fn test_display_order_follows_each_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut discovered = formatter_test_tasks(temp_dir.path());
+        discovered.tasks.push(create_test_task(
+            "audit",
+            temp_dir.path().join("package.json"),
+            TaskRunner::NodeNpm,
+        ));
+        let names = |formatter: &dyn ListFormatter| -> Vec<String> {
+            formatter
+                .display_order(&discovered, temp_dir.path())
+                .into_iter()
+                .map(|task| display_name(task).to_string())
+                .collect()
+        };
+
+        let pretty = PrettyFormatter {
+            verbose: false,
+            layout: ListConfig::default(),
+            sort: ListSort::Name,
+        };
+        assert_eq!(names(&pretty), vec!["build", "test-m", "audit", "test-n"]);
+        assert_eq!(
+            names(&FlatFormatter),
+            vec!["audit", "build", "test-m", "test-n"]
+        );
+        assert_eq!(
+            names(&TreeFormatter),
+            vec!["build", "test-m", "audit", "test-n"]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_count_matches_discovery() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "build:\n\techo build\n\ntest:\n\techo test\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"scripts": {"start": "node index.js", "lint": "eslint .", "test": "jest"}}"#,
+        )
+        .unwrap();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_executable("make")
+                .with_executable("npm"),
+        );
+
+        let discovered = task_discovery::discover_tasks(temp_dir.path());
+        assert_eq!(discovered.tasks.len(), 5);
+        assert_eq!(count_lines(&discovered, false), vec!["5"]);
+        assert_eq!(count_lines(&discovered, true), vec!["5", "make 2", "npm 3"]);
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_no_color_leaves_out_escape_sequences() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut discovered = formatter_test_tasks(temp_dir.path());
+        let mut cd = create_test_task("cd", temp_dir.path().join("Makefile"), TaskRunner::Make);
+        cd.shadowed_by = Some(ShadowType::ShellBuiltin("zsh".to_string()));
+        cd.disambiguated_name = Some("cd-m".to_string());
+        discovered.tasks.push(cd);
+        let output = || {
+            formatter_output(
+                &PrettyFormatter {
+                    verbose: true,
+                    layout: ListConfig::default(),
+                    sort: ListSort::Name,
+                },
+                &discovered,
+                temp_dir.path(),
+            )
+        };
+
+        colored::control::set_override(true);
+        assert!(output().contains('\x1b'));
+
+        crate::commands::configure_color(true);
+        let plain = output();
+        colored::control::unset_override();
+        assert!(!plain.contains('\x1b'), "{:?}", plain);
+        assert!(plain.contains("cd-m"));
+    }
+
+    #[test]
+    fn test_list_sort_orders_tasks_within_runner() {
+        let temp_dir = TempDir::new().unwrap();
+        let makefile = temp_dir.path().join("Makefile");
+        let mut tasks = Vec::new();
+        for (name, line) in [("test", 7), ("deploy", 1), ("build", 4)] {
+            let mut task = create_test_task(name, makefile.clone(), TaskRunner::Make);
+            task.line = Some(line);
+            tasks.push(task);
+        }
+        let discovered = task_discovery::DiscoveredTasks {
+            tasks,
+            ..Default::default()
+        };
+        let order = |sort: ListSort| -> Vec<String> {
+            PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort,
+            }
+            .display_order(&discovered, temp_dir.path())
+            .iter()
+            .map(|task| task.name.clone())
+            .collect()
+        };
+
+        assert_eq!(order(ListSort::Name), vec!["build", "deploy", "test"]);
+        assert_eq!(order(ListSort::Source), vec!["deploy", "build", "test"]);
+        assert_eq!(order(ListSort::None), vec!["test", "deploy", "build"]);
+
+        assert_eq!(ListSort::parse("source").unwrap(), ListSort::Source);
+        let error = ListSort::parse("size").unwrap_err().to_string();
+        assert!(error.contains("name, source, none"));
+    }
+
+    #[test]
+    fn test_write_aliases_section() {
+        let mut out = Vec::new();
+        write_aliases(&[], &ListConfig::default(), &mut out).unwrap();
+        assert!(out.is_empty());
+
+        write_aliases(
+            &[("deploy", "release"), ("serve", "uv:run-server")],
+            &ListConfig::default(),
+            &mut out,
+        )
+        .unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("aliases"));
+        assert!(output.contains(&format!("  {:<20} → release\n", "deploy")));
+        assert!(output.contains(&format!("  {:<20} → uv:run-server\n", "serve")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_shows_makefile_double_hash_descriptions() {
+        use crate::task_shadowing::{enable_mock, reset_mock};
+
+        colored::control::set_override(false);
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "## Build the project\nbuild:\n\tcargo build\n\ntest: ## Run the tests\n\tcargo test\n",
+        )
+        .unwrap();
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+        let discovered = task_discovery::discover_tasks(temp_dir.path());
+
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        reset_mock();
+        reset_to_real_environment();
+        colored::control::unset_override();
+
+        for (name, description) in [("build", "Build the project"), ("test", "Run the tests")] {
+            assert!(
+                output
+                    .lines()
+                    .any(|line| line.contains(name) && line.contains(description)),
+                "{}",
+                output
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_pretty_formatter_marks_tasks_shadowed_by_aliases() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut discovered = formatter_test_tasks(temp_dir.path());
+        let mut deploy =
+            create_test_task("deploy", temp_dir.path().join("Makefile"), TaskRunner::Make);
+        deploy.shadowed_by = Some(ShadowType::ShellAlias("bash".to_string()));
+        deploy.disambiguated_name = Some("deploy-m".to_string());
+        discovered.tasks.push(deploy);
+
+        colored::control::set_override(false);
+        let output = formatter_output(
+            &PrettyFormatter {
+                verbose: false,
+                layout: ListConfig::default(),
+                sort: ListSort::Name,
+            },
+            &discovered,
+            temp_dir.path(),
+        );
+        colored::control::unset_override();
+
+        assert!(output.contains("deploy ¶"), "{}", output);
+        assert!(output.contains("¶ shadowed by a shell alias"), "{}", output);
+    }
+
+    #[test]
+    #[serial]
+    fn test_count_leaves_shadowing_checks_on() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Makefile"), "cd:\n\techo cd\n").unwrap();
+        set_test_environment(TestEnvironment::new().with_shell("/bin/zsh"));
+
+        execute(&ListOptions {
+            verbose: false,
+            color: "never".to_string(),
+            format: "pretty".to_string(),
+            group: "runner".to_string(),
+            sort: "name".to_string(),
+            runnable_only: false,
+            check_deps: false,
+            no_builtins: false,
+            depth: None,
+            walk_up: false,
+            runners: Vec::new(),
+            count: true,
+            no_cache: true,
+            cwd: Some(temp_dir.path().to_path_buf()),
+        })
+        .unwrap();
+
+        assert!(crate::task_shadowing::check_shadowing("cd").is_some());
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_execute_saves_last_list_in_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let home_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(crate::config::preferred_config_dir_path_for(
+            home_dir.path(),
+        ))
+        .unwrap();
+        let project = temp_dir.path().canonicalize().unwrap();
+        std::fs::write(
+            project.join("Makefile"),
+            "build:\n\techo build\n\ntest:\n\techo test\n",
+        )
+        .unwrap();
+        set_test_environment(
+            TestEnvironment::new().with_home(home_dir.path().to_string_lossy().to_string()),
+        );
+
+        execute(&ListOptions {
+            verbose: false,
+            color: "never".to_string(),
+            format: "pretty".to_string(),
+            group: "runner".to_string(),
+            sort: "name".to_string(),
+            runnable_only: false,
+            check_deps: false,
+            no_builtins: false,
+            depth: None,
+            walk_up: false,
+            runners: Vec::new(),
+            count: false,
+            no_cache: true,
+            cwd: Some(project.clone()),
+        })
+        .unwrap();
+
+        let listed = last_list::listed_task(1, &project).unwrap();
+        assert_eq!(listed.name, "build");
+        assert_eq!(listed.definition_path, project.join("Makefile"));
+
+        reset_to_real_environment();
+    }
 }
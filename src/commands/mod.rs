@@ -1,15 +1,47 @@
 pub mod allow;
 pub mod allow_command;
+pub mod cache;
+pub mod completions;
 pub mod configure_shell;
 pub mod deny;
+pub mod describe;
+pub mod doctor;
+pub mod export_aliases;
 pub mod get_command;
+pub mod graph;
 pub mod init;
+pub mod last_list;
 pub mod list;
 pub mod mcp;
 pub mod run;
 pub mod run_command;
+pub mod run_detached;
+pub mod run_log;
+pub mod runners;
+pub mod status;
+pub mod watch;
+pub mod which;
 
 use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// The directory a command discovers tasks in: `--cwd` when given, resolved against the
+/// current directory, or else the current directory.
+pub(crate) fn working_dir(cwd: Option<&Path>) -> anyhow::Result<PathBuf> {
+    let Some(cwd) = cwd else {
+        return std::env::current_dir()
+            .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e));
+    };
+    let dir = cwd
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("Cannot use --cwd '{}': {}", cwd.display(), e))?;
+    if !dir.is_dir() {
+        anyhow::bail!("Cannot use --cwd '{}': not a directory", cwd.display());
+    }
+    std::fs::read_dir(&dir)
+        .map_err(|e| anyhow::anyhow!("Cannot use --cwd '{}': {}", cwd.display(), e))?;
+    Ok(dir)
+}
 
 /// Returns an error if the current session is non-interactive (no TTY).
 /// This prevents scripts and agents from running `dela allow` / `dela deny`.
@@ -24,10 +56,43 @@ pub(crate) fn gate_non_interactive(command_name: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Turns color off for the whole invocation when asked to by `--no-color` or a non-empty
+/// `NO_COLOR`. Otherwise `colored` decides, which already leaves color off when stdout is
+/// not a terminal.
+pub fn configure_color(no_color: bool) {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    if no_color || no_color_env {
+        colored::control::set_override(false);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_working_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("Makefile");
+        std::fs::write(&file, "build:\n").unwrap();
+
+        assert_eq!(
+            working_dir(Some(temp_dir.path())).unwrap(),
+            temp_dir.path().canonicalize().unwrap()
+        );
+        assert_eq!(
+            working_dir(Some(&file)).unwrap_err().to_string(),
+            format!("Cannot use --cwd '{}': not a directory", file.display())
+        );
+        let missing = temp_dir.path().join("missing");
+        assert!(
+            working_dir(Some(&missing))
+                .unwrap_err()
+                .to_string()
+                .starts_with(&format!("Cannot use --cwd '{}': ", missing.display()))
+        );
+    }
+
     #[test]
     fn test_gate_non_interactive_in_test_env() {
         let result = gate_non_interactive("dela allow");
@@ -1,11 +1,56 @@
-use crate::commands::run_command;
+use crate::allowlist;
+use crate::commands::run_command::{self, RunOptions};
+use crate::prompt;
+use crate::task_discovery;
+use crate::types::Task;
+use std::io::{self, IsTerminal};
+
+pub fn execute(task_name: &str, options: &RunOptions) -> anyhow::Result<()> {
+    // Without a task name, ask which task to run when there is a terminal to ask on
+    if task_name.is_empty() {
+        let task_name = pick_task(options)?;
+        return execute(&task_name, options);
+    }
+
+    // A dry run prints only the command, so it can be copied
+    if options.dry_run {
+        return run_command::execute_with_options(task_name, options);
+    }
 
-pub fn execute(task_name: &str) -> anyhow::Result<()> {
     println!("Note: The 'dela run' command is meant to be intercepted by shell integration.");
     println!("If you're seeing this message, it means either:");
     println!("1. Shell integration is not installed (run 'dela init' to set it up)");
     println!("2. You're running dela directly instead of through the shell function");
 
     // Execute the task directly when shell integration is not detected
-    run_command::execute(task_name)
+    run_command::execute_with_options(task_name, options)
+}
+
+/// Choose a task from a numbered menu and check it against the allowlist, returning the
+/// name to run it by.
+fn pick_task(options: &RunOptions) -> anyhow::Result<String> {
+    if !(io::stdin().is_terminal() && io::stdout().is_terminal()) {
+        return Err(anyhow::anyhow!("No task name provided"));
+    }
+
+    let current_dir = super::working_dir(options.cwd.as_deref())?;
+    let discovered = if options.walk_up {
+        task_discovery::discover_tasks_walking_up(&current_dir).1
+    } else {
+        task_discovery::discover_tasks(&current_dir)
+    };
+    let mut tasks: Vec<&Task> = discovered.tasks.iter().filter(|t| !t.hidden).collect();
+    tasks.sort_by(|a, b| a.name.cmp(&b.name));
+    let task = prompt::select_task(&tasks)?;
+
+    // A dry run runs nothing, so it needs no approval
+    if !options.dry_run
+        && (allowlist::is_task_allowed(task)?.1 || !allowlist::check_task_allowed(task)?)
+    {
+        eprintln!("Task '{}' was denied by the allowlist.", task.name);
+        return Err(allowlist::denied_error(task));
+    }
+
+    let name = task.disambiguated_name.as_ref().unwrap_or(&task.name);
+    Ok(shell_words::quote(name).into_owned())
 }
@@ -1,11 +1,106 @@
+use crate::allowlist;
+use crate::commands::last_list;
+use crate::commands::run_detached::{default_detached_log, spawn_detached};
+use crate::commands::run_log::{self, RunLog};
+use crate::config::{self, load_config};
+use crate::job_registry::{self, RegisteredJob};
+use crate::repo_root::find_runner_project_root;
 use crate::runner::is_runner_available;
-use crate::runner::split_command_words;
+use crate::runner::{platform_command, split_command_words};
+use crate::task_cache::CachedRun;
 use crate::task_discovery;
+use crate::types::Task;
 use anyhow::Context;
-use std::env;
-use std::process::{Command, Stdio};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Options for `dela run` that change how the resolved command is executed.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Tee the task's output into this log file
+    pub log: Option<RunLog>,
+    /// Run from the runner's project root instead of the current directory
+    pub working_stack: bool,
+    /// Start the task in its own session and return once it has been spawned
+    pub detach: bool,
+    /// Look for the task in parent directories and run it from where it was found
+    pub walk_up: bool,
+    /// Print the command instead of running it
+    pub dry_run: bool,
+    /// Variables set in the task's environment, from `--env KEY=VALUE`
+    pub env: Vec<(String, String)>,
+    /// Find and run the task in this directory instead of the current one
+    pub cwd: Option<PathBuf>,
+}
+
+/// Parse `--env` flags written as `KEY=VALUE`. The value may be empty or contain `=`, but
+/// the key must be a valid variable name.
+pub fn parse_env_vars(vars: &[String]) -> anyhow::Result<Vec<(String, String)>> {
+    vars.iter()
+        .map(|var| {
+            let (key, value) = var
+                .split_once('=')
+                .filter(|(key, _)| is_env_var_name(key))
+                .ok_or_else(|| anyhow::anyhow!("Invalid --env '{}': expected KEY=VALUE", var))?;
+            Ok((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn is_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A task that ran and failed. `main` exits with the task's own exit code rather than 1,
+/// so scripts calling `dela run` see the same status as when calling the runner.
+#[derive(Debug, thiserror::Error)]
+#[error("Command failed with exit code: {status}")]
+pub struct TaskFailed {
+    pub status: ExitStatus,
+}
 
-pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
+impl TaskFailed {
+    /// The task's exit code, or `128 + signal` for a task killed by a signal, as shells
+    /// report it.
+    pub fn exit_code(&self) -> i32 {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = self.status.signal() {
+                return 128 + signal;
+            }
+        }
+        self.status.code().unwrap_or(1)
+    }
+}
+
+/// Warning shown before running a task that looks like it would do nothing locally.
+pub(crate) fn not_runnable_warning(task: &Task) -> Option<String> {
+    (!task.runnable).then(|| {
+        format!(
+            "Warning: task '{}' from {} does not appear to do anything when run locally",
+            task.name,
+            task.definition_path().display()
+        )
+    })
+}
+
+/// Warning shown before running a task whose command goes through `sudo`.
+pub(crate) fn sudo_warning(task: &Task) -> Option<String> {
+    task.requires_sudo.then(|| {
+        format!(
+            "Warning: task '{}' from {} runs sudo and may wait for a password",
+            task.name,
+            task.definition_path().display()
+        )
+    })
+}
+
+pub fn execute_with_options(task_with_args: &str, options: &RunOptions) -> anyhow::Result<()> {
     let mut invocation_parts = shell_words::split(task_with_args)
         .map_err(|e| anyhow::anyhow!("Failed to parse args: {}", e))?;
     let task_name = invocation_parts
@@ -14,22 +109,49 @@ pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
         .to_string();
     let task_args: Vec<String> = invocation_parts.drain(1..).collect();
 
-    let current_dir = env::current_dir()
-        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
-    let discovered = task_discovery::discover_tasks(&current_dir);
+    let current_dir = super::working_dir(options.cwd.as_deref())?;
+    let (task_dir, discovered) = if options.walk_up {
+        task_discovery::discover_tasks_walking_up(&current_dir)
+    } else {
+        (
+            current_dir.clone(),
+            task_discovery::discover_tasks(&current_dir),
+        )
+    };
+
+    let project_config = config::load_project_config(&current_dir)?;
+    // `@N` names the task shown at that position by the last `dela list`
+    let (task_name, listed_file) = match last_list::parse_index(&task_name) {
+        Some(index) => {
+            let listed = last_list::listed_task(index, &current_dir)?;
+            (listed.name, Some(listed.definition_path))
+        }
+        // A project alias stands for the task it names
+        None => (project_config.resolve_alias(&task_name).to_string(), None),
+    };
 
     // Find all tasks with the given name (both original and disambiguated)
-    let matching_tasks = task_discovery::get_matching_tasks(&discovered, task_name.as_str());
+    let mut matching_tasks =
+        task_discovery::get_matching_tasks_qualified(&discovered, task_name.as_str());
+    if let Some(listed_file) = &listed_file {
+        matching_tasks.retain(|task| task.definition_path() == listed_file);
+        if matching_tasks.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Task '{}' from the last 'dela list' no longer exists; run 'dela list' again",
+                task_name
+            ));
+        }
+    }
 
     // Check if there are no matching tasks
     if matching_tasks.is_empty() {
         return Err(anyhow::anyhow!(
-            "dela: command or task not found: {}",
-            task_name
+            task_discovery::format_task_not_found_error(&discovered, task_name.as_str())
         ));
     }
 
     // Check if there are multiple matching tasks
+    task_discovery::apply_runner_priority(&mut matching_tasks);
     if matching_tasks.len() > 1 {
         let error_msg =
             task_discovery::format_ambiguous_task_error(task_name.as_str(), &matching_tasks);
@@ -39,6 +161,12 @@ pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
 
     // Single task found, check if runner is available
     let task = matching_tasks[0];
+    for warning in [not_runnable_warning(task), sudo_warning(task)]
+        .into_iter()
+        .flatten()
+    {
+        eprintln!("{}", warning);
+    }
     if !is_runner_available(&task.runner) {
         return Err(anyhow::anyhow!(
             "Runner '{}' not found",
@@ -46,28 +174,108 @@ pub fn execute(task_with_args: &str) -> anyhow::Result<()> {
         ));
     }
 
+    // A dry run runs nothing, but no real run may start a denied task
+    if !options.dry_run && allowlist::is_task_denied(task)? {
+        return Err(allowlist::denied_error(task));
+    }
+
     // Get the command to run
+    if options.dry_run {
+        println!("{}", task.runner.get_command_with_args(task, &task_args));
+        return Ok(());
+    }
     let base_command = task.runner.get_command(task);
     let mut command_parts = split_command_words(&base_command)?;
-    command_parts.extend(task_args.clone());
-
+    command_parts.extend(task.runner.task_arguments(&task_args));
+
+    // Shown as shell assignments, which also keeps runs with other values apart in the cache
+    let display_command = options
+        .env
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, shell_words::quote(value)))
+        .chain(std::iter::once(shell_words::join(command_parts.clone())))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let command_parts = platform_command(command_parts);
     let mut parts_iter = command_parts.iter();
     let executable = parts_iter.next().context("Empty command generated")?;
     let remaining_args: Vec<&String> = parts_iter.collect();
 
-    println!("Running: {}", shell_words::join(command_parts.clone()));
+    // Detached runs finish after dela exits, so only foreground runs are cached
+    let cached_run = if options.detach {
+        None
+    } else {
+        CachedRun::for_task(task, &display_command)?
+    };
+    if cached_run.as_ref().is_some_and(CachedRun::is_up_to_date) {
+        println!("{} is up to date", task_name);
+        return Ok(());
+    }
+    println!("Running: {}", display_command);
 
     // Execute the command
-    let status = Command::new(executable)
+    let mut command = Command::new(executable);
+    command
         .args(remaining_args)
-        .stdin(Stdio::inherit())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .map_err(|e| anyhow::anyhow!("Failed to execute command: {}", e))?;
+        .envs(options.env.iter().map(|(key, value)| (key, value)))
+        .stdin(Stdio::inherit());
+    let run_dir = if options.working_stack {
+        find_runner_project_root(task)
+    } else {
+        project_config.run_dir(task_dir)?
+    };
+    command.current_dir(&run_dir);
+    // An explicit --log wins over a sink configured for the task in config.toml
+    let configured_log = match &options.log {
+        Some(_) => None,
+        // A broken config.toml should not stop the task from running
+        None => match load_config() {
+            Ok(config) => config
+                .output
+                .get(&task_name)
+                .map(|template| RunLog::from_template(template, &task_name)),
+            Err(error) => {
+                eprintln!("Warning: {}", error);
+                None
+            }
+        },
+    };
+    if options.detach {
+        let log = match options.log.clone().or(configured_log) {
+            Some(log) => log,
+            None => default_detached_log(&task_name)?,
+        };
+        let pid = spawn_detached(command, &log)?;
+        job_registry::register_job(
+            &job_registry::registry_path()?,
+            RegisteredJob {
+                pid,
+                task: task_name,
+                command: display_command,
+                cwd: run_dir,
+                log_path: Some(log.path.clone()),
+                started_at: chrono::Utc::now().to_rfc3339(),
+            },
+        )?;
+        println!("Started in background with PID {}", pid);
+        println!("Output: {}", log.path.display());
+        return Ok(());
+    }
+
+    let status = match options.log.as_ref().or(configured_log.as_ref()) {
+        Some(log) => run_log::run_with_log(command, &display_command, log)?,
+        None => command
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(|e| anyhow::anyhow!("Failed to execute command: {}", e))?,
+    };
 
     if !status.success() {
-        return Err(anyhow::anyhow!("Command failed with exit code: {}", status));
+        return Err(TaskFailed { status }.into());
+    }
+    if let Some(cached_run) = cached_run {
+        cached_run.record_success()?;
     }
 
     Ok(())
@@ -87,6 +295,10 @@ mod tests {
     use std::io::Write;
     use tempfile::TempDir;
 
+    fn execute(task_with_args: &str) -> anyhow::Result<()> {
+        execute_with_options(task_with_args, &RunOptions::default())
+    }
+
     fn setup_test_env() -> (TempDir, TempDir) {
         // Create a temp dir for the project
         let project_dir = TempDir::new().expect("Failed to create temp directory");
@@ -229,7 +441,7 @@ test: ## Running tests
         let tasks = task_discovery::get_matching_tasks(&discovered, "test");
         assert_eq!(tasks.len(), 1, "Should find exactly one task");
 
-        let result = super::execute("test --invalid-arg-for-make");
+        let result = execute("test --invalid-arg-for-make");
         assert!(
             result.is_err(),
             "Command execution should fail in test environment"
@@ -321,4 +533,451 @@ test: ## Running tests
         drop(project_dir);
         drop(home_dir);
     }
+
+    #[test]
+    fn test_not_runnable_warning() {
+        let mut task = Task {
+            name: "placeholder".to_string(),
+            file_path: std::path::PathBuf::from("Makefile"),
+            definition_path: None,
+            definition_type: crate::types::TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: "placeholder".to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        assert_eq!(not_runnable_warning(&task), None);
+
+        task.runnable = false;
+        assert_eq!(
+            not_runnable_warning(&task).unwrap(),
+            "Warning: task 'placeholder' from Makefile does not appear to do anything when run locally"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_writes_configured_output_sink() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+
+        let log_path = project_dir.path().join("logs").join("build.log");
+        fs::write(
+            preferred_config_dir_path_for(home_dir.path()).join("config.toml"),
+            format!("[output]\nbuild = {:?}\n", log_path.to_string_lossy()),
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let result = execute("build");
+        assert!(result.is_ok(), "Task should run: {:?}", result);
+        assert_eq!(fs::read_to_string(&log_path).unwrap(), "Building...\n");
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    fn test_parse_env_vars() {
+        let vars = parse_env_vars(&[
+            "KEY=VAL".to_string(),
+            "EMPTY=".to_string(),
+            "_URL=postgres://db?a=b".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            vars,
+            vec![
+                ("KEY".to_string(), "VAL".to_string()),
+                ("EMPTY".to_string(), String::new()),
+                ("_URL".to_string(), "postgres://db?a=b".to_string()),
+            ]
+        );
+
+        for invalid in ["KEY", "=VAL", "1KEY=VAL", "MY-KEY=VAL"] {
+            let error = parse_env_vars(&[invalid.to_string()]).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("Invalid --env '{}': expected KEY=VALUE", invalid)
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_passes_env_vars() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        let output_path = project_dir.path().join("greeting.txt");
+        fs::write(
+            project_dir.path().join("Makefile"),
+            "greet:\n\t@echo \"$$GREETING $$NAME\" > greeting.txt\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let result = execute_with_options(
+            "greet",
+            &RunOptions {
+                env: vec![
+                    ("GREETING".to_string(), "hello".to_string()),
+                    ("NAME".to_string(), "dela user".to_string()),
+                ],
+                ..RunOptions::default()
+            },
+        );
+        assert!(result.is_ok(), "Task should run: {:?}", result);
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            "hello dela user\n"
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_runs_in_cwd() {
+        let (project_dir, home_dir) = setup_test_env();
+        let elsewhere = TempDir::new().unwrap();
+        env::set_current_dir(&elsewhere).expect("Failed to change directory");
+        fs::write(
+            project_dir.path().join("Makefile"),
+            "where:\n\t@pwd > where.txt\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let options = RunOptions {
+            cwd: Some(project_dir.path().to_path_buf()),
+            ..RunOptions::default()
+        };
+        let result = execute_with_options("where", &options);
+        assert!(result.is_ok(), "Task should run: {:?}", result);
+        assert_eq!(
+            fs::read_to_string(project_dir.path().join("where.txt"))
+                .unwrap()
+                .trim(),
+            project_dir.path().canonicalize().unwrap().to_string_lossy()
+        );
+        assert!(execute("where").is_err());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_keeps_task_exit_code() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        fs::write(project_dir.path().join("Makefile"), "fail:\n\t@exit 3\n").unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let error = execute("fail").unwrap_err();
+        let failed = error
+            .downcast_ref::<TaskFailed>()
+            .expect("a failed task reports its exit status");
+        // make exits with 2 when a recipe fails, whatever the recipe's own code
+        assert_eq!(failed.exit_code(), 2);
+        assert_eq!(
+            error.to_string(),
+            "Command failed with exit code: exit status: 2"
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_detach_returns_while_task_runs() {
+        use nix::sys::signal::{Signal, kill};
+        use nix::unistd::Pid;
+
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        fs::write(project_dir.path().join("Makefile"), "serve:\n\t@sleep 10\n").unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let started = std::time::Instant::now();
+        let result = execute_with_options(
+            "serve",
+            &RunOptions {
+                detach: true,
+                ..RunOptions::default()
+            },
+        );
+        assert!(result.is_ok(), "Task should start: {:?}", result);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+
+        let registry = preferred_config_dir_path_for(home_dir.path()).join("jobs.json");
+        let jobs = job_registry::load_jobs(&registry).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].task, "serve");
+        assert_eq!(jobs[0].command, "make serve");
+        assert_eq!(jobs[0].cwd, project_dir.path());
+
+        // The detached task leads its own process group, so this reaches make and sleep.
+        let group = Pid::from_raw(-(jobs[0].pid as i32));
+        assert!(kill(group, None).is_ok(), "Detached task should still run");
+        let _ = kill(group, Signal::SIGKILL);
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_dry_run_does_not_run_task() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        fs::write(
+            project_dir.path().join("Makefile"),
+            "touch:\n\t@touch ran\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let options = RunOptions {
+            dry_run: true,
+            ..RunOptions::default()
+        };
+        assert!(execute_with_options("touch --jobs 2", &options).is_ok());
+        assert!(!project_dir.path().join("ran").exists());
+        assert!(execute_with_options("missing", &options).is_err());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_skips_task_with_unchanged_inputs() {
+        let (project_dir, home_dir) = setup_test_env();
+        env::set_current_dir(&project_dir).expect("Failed to change directory");
+        fs::write(
+            project_dir.path().join("Makefile"),
+            "build:\n\t@echo ran >> runs.log\n",
+        )
+        .unwrap();
+        fs::write(
+            project_dir.path().join("Makefile.dela.toml"),
+            "[tasks.build]\ninputs = [\"input.txt\"]\n",
+        )
+        .unwrap();
+        fs::write(project_dir.path().join("input.txt"), "one").unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let runs = || {
+            fs::read_to_string(project_dir.path().join("runs.log"))
+                .unwrap()
+                .lines()
+                .count()
+        };
+        execute("build").unwrap();
+        execute("build").unwrap();
+        assert_eq!(runs(), 1, "Unchanged inputs should skip the second run");
+
+        fs::write(project_dir.path().join("input.txt"), "two").unwrap();
+        execute("build").unwrap();
+        assert_eq!(runs(), 2, "Changed inputs should run the task again");
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_refuses_denied_task() {
+        let (project_dir, home_dir) = setup_test_env();
+        let project = project_dir.path().canonicalize().unwrap();
+        fs::write(
+            project.join("Makefile"),
+            "deploy:\n\t@touch deployed.txt\n\nbuild:\n\t@touch built.txt\n",
+        )
+        .unwrap();
+        allowlist::save_allowlist(&crate::types::Allowlist {
+            entries: vec![crate::types::AllowlistEntry {
+                path: project.join("Makefile"),
+                scope: crate::types::AllowScope::Deny,
+                tasks: Some(vec!["deploy".to_string()]),
+                pattern: None,
+            }],
+        })
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let options = RunOptions {
+            cwd: Some(project.clone()),
+            ..RunOptions::default()
+        };
+        let error = execute_with_options("deploy", &options).unwrap_err();
+        assert!(error.to_string().contains("was denied"), "{}", error);
+        assert!(!project.join("deployed.txt").exists());
+        assert!(execute_with_options("build", &options).is_ok());
+        assert!(project.join("built.txt").exists());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_warns_on_broken_config() {
+        let (project_dir, home_dir) = setup_test_env();
+        let project = project_dir.path().canonicalize().unwrap();
+        fs::write(project.join("Makefile"), "build:\n\t@touch built.txt\n").unwrap();
+        fs::write(
+            preferred_config_dir_path_for(home_dir.path()).join("config.toml"),
+            "[output\nbuild = ",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let options = RunOptions {
+            cwd: Some(project.clone()),
+            ..RunOptions::default()
+        };
+        let result = execute_with_options("build", &options);
+        assert!(result.is_ok(), "Task should run: {:?}", result);
+        assert!(project.join("built.txt").exists());
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_command_runs_from_project_working_dir() {
+        let (project_dir, home_dir) = setup_test_env();
+        let project = project_dir.path().canonicalize().unwrap();
+        fs::create_dir_all(project.join("app")).unwrap();
+        fs::write(project.join("Makefile"), "where:\n\t@pwd > where.txt\n").unwrap();
+        fs::write(
+            project.join(config::PROJECT_CONFIG_FILE),
+            "working_dir = \"app\"\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        let options = RunOptions {
+            cwd: Some(project.clone()),
+            ..RunOptions::default()
+        };
+        let result = execute_with_options("where -f ../Makefile", &options);
+        assert!(result.is_ok(), "Task should run: {:?}", result);
+        assert_eq!(
+            fs::read_to_string(project.join("app").join("where.txt"))
+                .unwrap()
+                .trim(),
+            project.join("app").to_string_lossy()
+        );
+
+        reset_mock();
+        reset_to_real_environment();
+        drop(project_dir);
+        drop(home_dir);
+    }
 }
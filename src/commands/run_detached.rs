@@ -0,0 +1,110 @@
+use crate::commands::run_log::{LogFormat, RunLog};
+use crate::config::active_dela_config_dir;
+use std::fs::OpenOptions;
+use std::process::{Command, Stdio};
+
+/// Log used by `dela run --detach` when neither `--log` nor an `[output]` sink is given.
+pub fn default_detached_log(task_name: &str) -> anyhow::Result<RunLog> {
+    let logs_dir = active_dela_config_dir()?.join("logs");
+    let template = logs_dir.join("{task}-{date}-{time}.log");
+    Ok(RunLog::from_template(
+        &template.to_string_lossy(),
+        task_name,
+    ))
+}
+
+/// Start `command` in a new session with stdin closed and its output appended to `log`,
+/// returning its PID without waiting for it to exit.
+pub fn spawn_detached(mut command: Command, log: &RunLog) -> anyhow::Result<u32> {
+    if log.format == LogFormat::Json {
+        return Err(anyhow::anyhow!(
+            "Detached tasks write plain text logs; --log-format json is not supported with --detach"
+        ));
+    }
+    if let Some(parent) = log.path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| anyhow::anyhow!("Failed to create log directory: {}", e))?;
+    }
+    let stdout = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log.path)
+        .map_err(|e| anyhow::anyhow!("Failed to open log file {}: {}", log.path.display(), e))?;
+    let stderr = stdout
+        .try_clone()
+        .map_err(|e| anyhow::anyhow!("Failed to open log file {}: {}", log.path.display(), e))?;
+
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(stdout))
+        .stderr(Stdio::from(stderr));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // SAFETY: setsid is async-signal-safe and touches no state shared with the parent.
+        unsafe {
+            command.pre_exec(|| {
+                nix::unistd::setsid().map_err(std::io::Error::from)?;
+                Ok(())
+            });
+        }
+    }
+
+    let child = command
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Failed to execute command: {}", e))?;
+    Ok(child.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_spawn_detached_writes_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = RunLog {
+            path: temp_dir.path().join("logs").join("job.log"),
+            format: LogFormat::Text,
+        };
+        let mut command = Command::new("sh");
+        command
+            .args(["-c", "echo started; echo oops >&2"])
+            .current_dir(temp_dir.path());
+
+        let pid = spawn_detached(command, &log).unwrap();
+        assert!(pid > 0);
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while std::fs::read_to_string(&log.path)
+            .unwrap_or_default()
+            .lines()
+            .count()
+            < 2
+        {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "log was never written"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        let contents = std::fs::read_to_string(&log.path).unwrap();
+        assert!(contents.contains("started"));
+        assert!(contents.contains("oops"));
+    }
+
+    #[test]
+    fn test_spawn_detached_rejects_json_logs() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = RunLog {
+            path: temp_dir.path().join("job.jsonl"),
+            format: LogFormat::Json,
+        };
+        assert!(spawn_detached(Command::new("true"), &log).is_err());
+        assert!(!log.path.exists());
+    }
+}
@@ -0,0 +1,336 @@
+use chrono::{SecondsFormat, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// How `dela run --log` records a task's output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// The output lines exactly as the task printed them
+    #[default]
+    Text,
+    /// One JSON record per line: a start record, each output line, and an exit record
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            other => Err(anyhow::anyhow!(
+                "Unknown log format '{}'. Valid formats: text, json",
+                other
+            )),
+        }
+    }
+}
+
+/// A log file that a task's output is teed into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunLog {
+    pub path: PathBuf,
+    pub format: LogFormat,
+}
+
+impl RunLog {
+    /// A text log at `template` with `{date}`, `{time}` and `{task}` filled in, as configured
+    /// for a task under `[output]` in config.toml.
+    pub fn from_template(template: &str, task_name: &str) -> Self {
+        let now = chrono::Local::now();
+        let path = template
+            .replace("{date}", &now.format("%Y-%m-%d").to_string())
+            .replace("{time}", &now.format("%H%M%S").to_string())
+            .replace("{task}", task_name);
+        RunLog {
+            path: PathBuf::from(path),
+            format: LogFormat::Text,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    fn name(self) -> &'static str {
+        match self {
+            Stream::Stdout => "stdout",
+            Stream::Stderr => "stderr",
+        }
+    }
+}
+
+fn timestamp() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+struct LogWriter {
+    file: File,
+    format: LogFormat,
+}
+
+impl LogWriter {
+    fn record(&mut self, record: serde_json::Value) -> std::io::Result<()> {
+        if self.format == LogFormat::Json {
+            writeln!(self.file, "{}", record)?;
+        }
+        Ok(())
+    }
+
+    /// Log one output line, without its line ending. Text logs keep the bytes as printed,
+    /// while JSON records hold them as UTF-8 with invalid bytes replaced.
+    fn line(&mut self, stream: Stream, line: &[u8]) -> std::io::Result<()> {
+        match self.format {
+            LogFormat::Text => {
+                self.file.write_all(line)?;
+                self.file.write_all(b"\n")
+            }
+            LogFormat::Json => self.record(serde_json::json!({
+                "type": "output",
+                "timestamp": timestamp(),
+                "stream": stream.name(),
+                "line": String::from_utf8_lossy(line),
+            })),
+        }
+    }
+}
+
+/// Run `command` with its stdout and stderr forwarded to the terminal and teed into `log`.
+pub fn run_with_log(
+    mut command: Command,
+    display_command: &str,
+    log: &RunLog,
+) -> anyhow::Result<ExitStatus> {
+    if let Some(parent) = log.path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| anyhow::anyhow!("Failed to create log directory: {}", e))?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log.path)
+        .map_err(|e| anyhow::anyhow!("Failed to open log file {}: {}", log.path.display(), e))?;
+    let writer = Arc::new(Mutex::new(LogWriter {
+        file,
+        format: log.format,
+    }));
+
+    let started = Instant::now();
+    lock(&writer)
+        .record(serde_json::json!({
+            "type": "start",
+            "timestamp": timestamp(),
+            "command": display_command,
+        }))
+        .map_err(|e| anyhow::anyhow!("Failed to write log: {}", e))?;
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Failed to execute command: {}", e))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .map(|out| tee_stream(out, Stream::Stdout, Arc::clone(&writer), std::io::stdout));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|err| tee_stream(err, Stream::Stderr, Arc::clone(&writer), std::io::stderr));
+
+    for handle in [stdout, stderr].into_iter().flatten() {
+        let _ = handle.join();
+    }
+    let status = child
+        .wait()
+        .map_err(|e| anyhow::anyhow!("Failed to wait for command: {}", e))?;
+
+    lock(&writer)
+        .record(serde_json::json!({
+            "type": "exit",
+            "timestamp": timestamp(),
+            "exit_code": status.code(),
+            "duration_ms": started.elapsed().as_millis() as u64,
+        }))
+        .map_err(|e| anyhow::anyhow!("Failed to write log: {}", e))?;
+
+    Ok(status)
+}
+
+fn lock(writer: &Mutex<LogWriter>) -> std::sync::MutexGuard<'_, LogWriter> {
+    writer
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Copy `reader` to the output `forward` opens and into the log, line by line.
+///
+/// Lines are passed through as raw bytes, so output that is not UTF-8 reaches the terminal
+/// unchanged. Failing to forward or log a line never stops the pipe from being drained,
+/// since a task whose output pipe closes dies of a broken pipe.
+fn tee_stream<R, W, F>(
+    reader: R,
+    stream: Stream,
+    writer: Arc<Mutex<LogWriter>>,
+    forward: F,
+) -> std::thread::JoinHandle<()>
+where
+    R: Read + Send + 'static,
+    W: Write,
+    F: Fn() -> W + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            }
+            let mut out = forward();
+            let _ = out.write_all(&line).and_then(|_| out.flush());
+            let _ = lock(&writer).line(stream, strip_line_ending(&line));
+        }
+    })
+}
+
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    // Other tests move the process cwd into temp dirs that may already be gone.
+    fn shell_command(dir: &TempDir, script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]).current_dir(dir.path());
+        command
+    }
+
+    #[test]
+    fn test_log_format_parse() {
+        assert_eq!(LogFormat::parse("text").unwrap(), LogFormat::Text);
+        assert_eq!(LogFormat::parse("json").unwrap(), LogFormat::Json);
+        assert!(LogFormat::parse("xml").is_err());
+    }
+
+    #[test]
+    fn test_json_log_records_lines_and_exit() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = RunLog {
+            path: temp_dir.path().join("logs").join("run.jsonl"),
+            format: LogFormat::Json,
+        };
+
+        let status = run_with_log(
+            shell_command(&temp_dir, "echo building; echo warning >&2; exit 3"),
+            "make build",
+            &log,
+        )
+        .unwrap();
+        assert_eq!(status.code(), Some(3));
+
+        let records: Vec<serde_json::Value> = std::fs::read_to_string(&log.path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(records.first().unwrap()["type"], "start");
+        assert_eq!(records.first().unwrap()["command"], "make build");
+
+        let outputs: Vec<_> = records.iter().filter(|r| r["type"] == "output").collect();
+        assert_eq!(outputs.len(), 2);
+        assert!(outputs.iter().all(|r| r["timestamp"].is_string()));
+        assert!(
+            outputs
+                .iter()
+                .any(|r| r["stream"] == "stdout" && r["line"] == "building")
+        );
+        assert!(
+            outputs
+                .iter()
+                .any(|r| r["stream"] == "stderr" && r["line"] == "warning")
+        );
+
+        let exit = records.last().unwrap();
+        assert_eq!(exit["type"], "exit");
+        assert_eq!(exit["exit_code"], 3);
+        assert!(exit["duration_ms"].is_u64());
+        assert!(exit["timestamp"].is_string());
+    }
+
+    #[test]
+    fn test_run_log_from_template() {
+        let log = RunLog::from_template("logs/{task}-{date}.log", "dev-server");
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(
+            log.path,
+            PathBuf::from(format!("logs/dev-server-{}.log", today))
+        );
+        assert_eq!(log.format, LogFormat::Text);
+    }
+
+    #[test]
+    fn test_text_log_contains_plain_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = RunLog {
+            path: temp_dir.path().join("run.log"),
+            format: LogFormat::Text,
+        };
+
+        let status =
+            run_with_log(shell_command(&temp_dir, "echo one; echo two"), "sh", &log).unwrap();
+        assert!(status.success());
+        assert_eq!(std::fs::read_to_string(&log.path).unwrap(), "one\ntwo\n");
+    }
+
+    #[test]
+    fn test_output_that_is_not_utf8_is_passed_through() {
+        let temp_dir = TempDir::new().unwrap();
+        let text_log = RunLog {
+            path: temp_dir.path().join("run.log"),
+            format: LogFormat::Text,
+        };
+        let script = "printf 'before\\n\\377\\376\\nafter\\n'; echo done";
+
+        let status = run_with_log(shell_command(&temp_dir, script), "sh", &text_log).unwrap();
+        assert!(status.success());
+        assert_eq!(
+            std::fs::read(&text_log.path).unwrap(),
+            b"before\n\xff\xfe\nafter\ndone\n"
+        );
+
+        let json_log = RunLog {
+            path: temp_dir.path().join("run.jsonl"),
+            format: LogFormat::Json,
+        };
+        let status = run_with_log(shell_command(&temp_dir, script), "sh", &json_log).unwrap();
+        assert!(status.success());
+        let lines: Vec<String> = std::fs::read_to_string(&json_log.path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|record| record["type"] == "output")
+            .map(|record| record["line"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(lines, vec!["before", "\u{fffd}\u{fffd}", "after", "done"]);
+    }
+}
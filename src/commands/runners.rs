@@ -0,0 +1,73 @@
+use crate::runner::is_runner_available;
+use crate::types::TaskRunner;
+use colored::Colorize;
+use std::io::Write;
+
+/// Executes `dela runners`, listing every runner dela supports with the files it reads
+/// tasks from and whether the runner is installed.
+pub fn execute() -> anyhow::Result<()> {
+    write_runners(&mut std::io::stdout())
+}
+
+fn write_runners(out: &mut dyn Write) -> anyhow::Result<()> {
+    let name_width = TaskRunner::ALL
+        .iter()
+        .map(|runner| runner.short_name().len())
+        .max()
+        .unwrap_or(0)
+        .max("RUNNER".len());
+    writeln!(out, "{:<name_width$}  INSTALLED  FILES", "RUNNER")?;
+    for runner in TaskRunner::ALL {
+        let installed = if is_runner_available(runner) {
+            format!("{:<9}", "yes").green()
+        } else {
+            format!("{:<9}", "no").dimmed()
+        };
+        writeln!(
+            out,
+            "{:<name_width$}  {}  {}",
+            runner.short_name(),
+            installed,
+            runner.definition_files().join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_write_runners() {
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+        colored::control::set_override(false);
+        let mut out = Vec::new();
+        write_runners(&mut out).unwrap();
+        colored::control::unset_override();
+        reset_to_real_environment();
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), TaskRunner::ALL.len() + 1);
+        assert!(lines[0].starts_with("RUNNER"), "{}", output);
+        assert!(
+            output.contains("make            yes        GNUmakefile, makefile, Makefile"),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("npm             no         package.json"),
+            "{}",
+            output
+        );
+        assert!(
+            output.contains("sh              yes        *.sh"),
+            "{}",
+            output
+        );
+    }
+}
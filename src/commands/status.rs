@@ -0,0 +1,181 @@
+use crate::job_registry::{self, RegisteredJob, is_process_running};
+use colored::Colorize;
+use std::io::Write;
+
+/// Executes `dela status`, listing the tasks started with `dela run --detach` or by an MCP
+/// session and whether each is still running.
+/// Tasks that have exited are removed from the registry once listed.
+pub fn execute(json: bool) -> anyhow::Result<()> {
+    let jobs = job_registry::take_jobs(&job_registry::registry_path()?)?;
+    let mut stdout = std::io::stdout();
+    write_status(&jobs, json, &mut stdout)
+}
+
+fn job_state(job: &RegisteredJob) -> &'static str {
+    if is_process_running(job.pid) {
+        "running"
+    } else {
+        "exited"
+    }
+}
+
+/// Local time of an RFC 3339 timestamp, or the timestamp as stored if it does not parse.
+fn format_started_at(started_at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(started_at)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|_| started_at.to_string())
+}
+
+fn write_status(jobs: &[RegisteredJob], json: bool, out: &mut dyn Write) -> anyhow::Result<()> {
+    if json {
+        let jobs: Vec<serde_json::Value> = jobs
+            .iter()
+            .map(|job| {
+                serde_json::json!({
+                    "pid": job.pid,
+                    "task": job.task,
+                    "command": job.command,
+                    "state": job_state(job),
+                    "started_at": job.started_at,
+                    "cwd": job.cwd.to_string_lossy(),
+                    "log_path": job.log_path.as_ref().map(|path| path.to_string_lossy()),
+                })
+            })
+            .collect();
+        writeln!(
+            out,
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({ "jobs": jobs }))?
+        )?;
+        return Ok(());
+    }
+
+    if jobs.is_empty() {
+        writeln!(out, "{}", "No background tasks.".yellow())?;
+        return Ok(());
+    }
+
+    let task_width = jobs
+        .iter()
+        .map(|job| job.task.len())
+        .max()
+        .unwrap_or(0)
+        .max("TASK".len());
+    writeln!(
+        out,
+        "{:<8}  {:<7}  {:<19}  {:<task_width$}  CWD",
+        "PID", "STATE", "STARTED", "TASK"
+    )?;
+    for job in jobs {
+        let state = job_state(job);
+        let colored_state = if state == "running" {
+            format!("{:<7}", state).green()
+        } else {
+            format!("{:<7}", state).dimmed()
+        };
+        writeln!(
+            out,
+            "{:<8}  {}  {:<19}  {:<task_width$}  {}",
+            job.pid,
+            colored_state,
+            format_started_at(&job.started_at),
+            job.task,
+            job.cwd.display()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::run_command::{RunOptions, execute_with_options};
+    use crate::config::preferred_config_dir_path_for;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::task_shadowing::{enable_mock, reset_mock};
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+    use serial_test::serial;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn status_output(jobs: &[RegisteredJob], json: bool) -> String {
+        let mut out = Vec::new();
+        write_status(jobs, json, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[serial]
+    fn test_status_lists_detached_job() {
+        let project_dir = TempDir::new().unwrap();
+        let home_dir = TempDir::new().unwrap();
+        std::fs::write(project_dir.path().join("Makefile"), "dev:\n\t@sleep 10\n").unwrap();
+        std::env::set_current_dir(project_dir.path()).unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_home(home_dir.path().to_string_lossy())
+                .with_executable("make"),
+        );
+
+        execute_with_options(
+            "dev",
+            &RunOptions {
+                detach: true,
+                ..RunOptions::default()
+            },
+        )
+        .unwrap();
+
+        let registry = preferred_config_dir_path_for(home_dir.path()).join("jobs.json");
+        let jobs = job_registry::load_jobs(&registry).unwrap();
+        assert_eq!(jobs.len(), 1);
+
+        colored::control::set_override(false);
+        let output = status_output(&jobs, false);
+        colored::control::unset_override();
+        let row = output.lines().nth(1).unwrap();
+        assert!(row.starts_with(&jobs[0].pid.to_string()));
+        assert!(row.contains("running"));
+        assert!(row.contains("dev"));
+        assert!(row.contains(&project_dir.path().display().to_string()));
+
+        let json: serde_json::Value = serde_json::from_str(&status_output(&jobs, true)).unwrap();
+        assert_eq!(json["jobs"][0]["pid"], jobs[0].pid);
+        assert_eq!(json["jobs"][0]["task"], "dev");
+        assert_eq!(json["jobs"][0]["state"], "running");
+
+        let _ = kill(Pid::from_raw(-(jobs[0].pid as i32)), Signal::SIGKILL);
+        reset_mock();
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_status_reports_exited_jobs() {
+        let job = RegisteredJob {
+            pid: i32::MAX as u32,
+            task: "build".to_string(),
+            command: "make build".to_string(),
+            cwd: PathBuf::from("/project"),
+            log_path: None,
+            started_at: "not a timestamp".to_string(),
+        };
+
+        colored::control::set_override(false);
+        let output = status_output(std::slice::from_ref(&job), false);
+        colored::control::unset_override();
+        assert!(output.contains("exited"));
+        assert!(output.contains("not a timestamp"));
+
+        assert!(status_output(&[], false).contains("No background tasks."));
+        let json: serde_json::Value = serde_json::from_str(&status_output(&[], true)).unwrap();
+        assert_eq!(json["jobs"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::commands::run_command::{self, RunOptions};
+use crate::task_discovery::{IgnoreRule, SKIPPED_DIRS, read_gitignore};
+use colored::Colorize;
+use notify_debouncer_mini::notify::RecursiveMode;
+use notify_debouncer_mini::{DebounceEventResult, new_debouncer};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::time::Duration;
+
+/// How long a file must stay unchanged after an edit before the task runs again, and how
+/// often Ctrl-C is checked for while waiting.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Set by the SIGINT handler so the watch loop can stop between checks.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Run a task, then run it again whenever a file under `paths` changes.
+///
+/// Without paths the current directory is watched. Paths matched by a `.gitignore`, along
+/// with directories such as `node_modules` and `target`, do not trigger a run. Changes are
+/// reported by the OS through notify and debounced, so a burst of writes runs the task
+/// once. A failed run is reported and the watch goes on; Ctrl-C stops it.
+pub fn execute(
+    task_with_args: &str,
+    paths: &[PathBuf],
+    options: &RunOptions,
+) -> anyhow::Result<()> {
+    let roots = if paths.is_empty() {
+        vec![PathBuf::from(".")]
+    } else {
+        paths.to_vec()
+    };
+    let roots = roots
+        .iter()
+        .map(|root| {
+            std::fs::canonicalize(root).map_err(|_| {
+                anyhow::anyhow!(
+                    "Cannot watch '{}': no such file or directory",
+                    root.display()
+                )
+            })
+        })
+        .collect::<anyhow::Result<Vec<PathBuf>>>()?;
+    install_interrupt_handler()?;
+
+    loop {
+        if let Err(e) = run_command::execute_with_options(task_with_args, options) {
+            eprintln!("{}", e);
+        }
+        if INTERRUPTED.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        println!(
+            "{}",
+            "── waiting for changes, press Ctrl-C to stop ──".dimmed()
+        );
+
+        // Start watching after the run, so files the task writes do not trigger it again
+        let (sender, events) = channel();
+        let mut debouncer = new_debouncer(DEBOUNCE, sender)
+            .map_err(|e| anyhow::anyhow!("Failed to watch for changes: {}", e))?;
+        for root in &roots {
+            let mode = if root.is_dir() {
+                RecursiveMode::Recursive
+            } else {
+                RecursiveMode::NonRecursive
+            };
+            debouncer
+                .watcher()
+                .watch(root, mode)
+                .map_err(|e| anyhow::anyhow!("Failed to watch '{}': {}", root.display(), e))?;
+        }
+
+        let Some(changed) = wait_for_change(&roots, &events, &INTERRUPTED) else {
+            return Ok(());
+        };
+        drop(debouncer);
+        println!(
+            "{}",
+            format!("── {} changed ──", changed.display()).dimmed()
+        );
+    }
+}
+
+/// Stop at the next check on Ctrl-C instead of exiting at once. A running task gets the
+/// signal too, since it shares the terminal.
+fn install_interrupt_handler() -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{SaFlags, SigAction, SigHandler, SigSet, Signal, sigaction};
+
+        extern "C" fn on_interrupt(_: nix::libc::c_int) {
+            INTERRUPTED.store(true, Ordering::SeqCst);
+        }
+
+        let action = SigAction::new(
+            SigHandler::Handler(on_interrupt),
+            SaFlags::SA_RESTART,
+            SigSet::empty(),
+        );
+        // SAFETY: the handler only stores to an atomic, which is async-signal-safe.
+        unsafe { sigaction(Signal::SIGINT, &action) }
+            .map_err(|e| anyhow::anyhow!("Failed to handle Ctrl-C: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Wait for a debounced change to a path that is watched, returning the first such path
+/// in sorted order, or `None` once `interrupted` is set or the watcher stops.
+fn wait_for_change(
+    roots: &[PathBuf],
+    events: &Receiver<DebounceEventResult>,
+    interrupted: &AtomicBool,
+) -> Option<PathBuf> {
+    loop {
+        if interrupted.load(Ordering::SeqCst) {
+            return None;
+        }
+        match events.recv_timeout(DEBOUNCE) {
+            Ok(Ok(events)) => {
+                let changed = events
+                    .into_iter()
+                    .map(|event| event.path)
+                    .filter(|path| is_watched(roots, path))
+                    .min();
+                if changed.is_some() {
+                    return changed;
+                }
+            }
+            Ok(Err(e)) => eprintln!("dela: watch error: {}", e),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+}
+
+/// Whether a change to `path` should rerun the task: it is under one of `roots` and
+/// neither it nor a directory above it is skipped or matched by a `.gitignore` between
+/// the root and the path.
+fn is_watched(roots: &[PathBuf], path: &Path) -> bool {
+    roots.iter().any(|root| {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return false;
+        };
+        let mut rules: Vec<IgnoreRule> = Vec::new();
+        let mut current = root.clone();
+        for component in relative.components() {
+            if current.is_dir() {
+                rules.extend(read_gitignore(&current));
+            }
+            current.push(component);
+            let name = component.as_os_str().to_string_lossy();
+            if SKIPPED_DIRS.contains(&name.as_ref())
+                || rules.iter().any(|rule| rule.matches(&current))
+            {
+                return false;
+            }
+        }
+        true
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_watched_skips_ignored_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("web/dist")).unwrap();
+        std::fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        std::fs::write(root.join("web/.gitignore"), "dist/\n").unwrap();
+
+        let roots = vec![root.clone()];
+        for watched in ["src/main.rs", ".gitignore", "web/app.js", "web/.gitignore"] {
+            assert!(is_watched(&roots, &root.join(watched)), "{}", watched);
+        }
+        for ignored in [
+            "target/debug/app",
+            "node_modules/left-pad/index.js",
+            "build.log",
+            "src/debug.log",
+            "web/dist/app.js",
+        ] {
+            assert!(!is_watched(&roots, &root.join(ignored)), "{}", ignored);
+        }
+        assert!(!is_watched(&roots, Path::new("/elsewhere/main.rs")));
+    }
+
+    #[test]
+    fn test_wait_for_change_stops_when_interrupted() {
+        let (_sender, events) = channel();
+        let interrupted = AtomicBool::new(true);
+        assert_eq!(wait_for_change(&[], &events, &interrupted), None);
+    }
+
+    #[test]
+    fn test_wait_for_change_sees_new_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = std::fs::canonicalize(temp_dir.path()).unwrap();
+        std::fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        let (sender, events) = channel();
+        let mut debouncer = new_debouncer(DEBOUNCE, sender).unwrap();
+        debouncer
+            .watcher()
+            .watch(&root, RecursiveMode::Recursive)
+            .unwrap();
+
+        // The ignored log file does not count as the change
+        std::fs::write(root.join("build.log"), "").unwrap();
+        let new_file = root.join("new.rs");
+        std::fs::write(&new_file, "").unwrap();
+
+        let interrupted = AtomicBool::new(false);
+        assert_eq!(
+            wait_for_change(&[root], &events, &interrupted),
+            Some(new_file)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::allowlist;
+use crate::runner::is_runner_available;
+use crate::task_discovery;
+use crate::types::{ShadowType, Task};
+use std::env;
+use std::io::Write;
+
+/// Executes `dela which <task>`, showing how a task name resolves without running it.
+pub fn execute(task_name: &str) -> anyhow::Result<()> {
+    let current_dir = env::current_dir()
+        .map_err(|e| anyhow::anyhow!("Failed to get current directory: {}", e))?;
+    let discovered = task_discovery::discover_tasks(&current_dir);
+
+    let matching_tasks = task_discovery::get_matching_tasks(&discovered, task_name);
+    match matching_tasks.len() {
+        0 => Err(anyhow::anyhow!(
+            "dela: command or task not found: {}",
+            task_name
+        )),
+        1 => {
+            let task = matching_tasks[0];
+            let allowlist_status = match allowlist::is_task_allowed(task) {
+                Ok((true, _)) => "allowed".to_string(),
+                Ok((_, true)) => "denied".to_string(),
+                Ok(_) => "not in allowlist".to_string(),
+                Err(error) => format!("unknown ({})", error),
+            };
+            write_resolution(task, &allowlist_status, &mut std::io::stdout())
+        }
+        _ => {
+            let error_msg = task_discovery::format_ambiguous_task_error(task_name, &matching_tasks);
+            println!("{}", error_msg);
+            Err(anyhow::anyhow!(
+                "Multiple tasks named '{}' found",
+                task_name
+            ))
+        }
+    }
+}
+
+fn write_resolution(
+    task: &Task,
+    allowlist_status: &str,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let shadowed = match &task.shadowed_by {
+        Some(ShadowType::ShellBuiltin(shell)) => format!("yes, by a {} builtin", shell),
+        Some(ShadowType::PathExecutable(path)) => format!("yes, by {}", path),
+        Some(ShadowType::ShellAlias(shell)) => format!("yes, by a {} alias", shell),
+        None => "no".to_string(),
+    };
+    let available = if is_runner_available(&task.runner) {
+        "yes"
+    } else {
+        "no"
+    };
+
+    writeln!(out, "task:      {}", task.name)?;
+    if let Some(disambiguated_name) = &task.disambiguated_name {
+        writeln!(out, "also as:   {}", disambiguated_name)?;
+    }
+    writeln!(out, "runner:    {}", task.runner.short_name())?;
+    writeln!(out, "file:      {}", task.definition_path().display())?;
+    if !task.parameters.is_empty() {
+        writeln!(out, "params:    {}", task.parameters.join(" "))?;
+    }
+    writeln!(out, "command:   {}", task.runner.get_command(task))?;
+    writeln!(out, "available: {}", available)?;
+    writeln!(out, "shadowed:  {}", shadowed)?;
+    writeln!(out, "allowlist: {}", allowlist_status)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::task_shadowing::{enable_mock, reset_mock};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn test_write_resolution() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Makefile"), "test:\n\techo test\n").unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(
+            TestEnvironment::new()
+                .with_shell("/bin/zsh")
+                .with_executable("make"),
+        );
+
+        let mut discovered = task_discovery::discover_tasks(temp_dir.path());
+        let task = discovered.tasks.remove(0);
+        let mut out = Vec::new();
+        write_resolution(&task, "not in allowlist", &mut out).unwrap();
+        let output = String::from_utf8(out).unwrap();
+
+        assert!(output.contains("task:      test\n"));
+        assert!(output.contains("also as:   test-m\n"));
+        assert!(output.contains("runner:    make\n"));
+        assert!(output.contains(&format!(
+            "file:      {}\n",
+            temp_dir.path().join("Makefile").display()
+        )));
+        assert!(output.contains("command:   make test\n"));
+        assert!(output.contains("available: yes\n"));
+        assert!(output.contains("shadowed:  yes, by a zsh builtin\n"));
+        assert!(output.contains("allowlist: not in allowlist\n"));
+
+        reset_mock();
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_which_missing_task() {
+        let temp_dir = TempDir::new().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let error = execute("missing").unwrap_err().to_string();
+        assert_eq!(error, "dela: command or task not found: missing");
+    }
+
+    #[test]
+    #[serial]
+    fn test_write_resolution_shows_parameters() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("justfile"),
+            "deploy env tag=\"latest\" +args:\n    echo {{env}}\n",
+        )
+        .unwrap();
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("just"));
+
+        let mut discovered = task_discovery::discover_tasks(temp_dir.path());
+        let task = discovered.tasks.remove(0);
+        let mut out = Vec::new();
+        write_resolution(&task, "not in allowlist", &mut out).unwrap();
+        let output = String::from_utf8(out).unwrap();
+
+        assert!(output.contains("params:    env tag=\"latest\" +args\n"));
+        assert!(output.contains("command:   just deploy\n"));
+
+        reset_mock();
+        reset_to_real_environment();
+    }
+}
@@ -1,4 +1,7 @@
 use crate::environment::get_current_home;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -67,3 +70,429 @@ pub fn active_allowlist_path() -> Result<PathBuf, ConfigError> {
 
     Ok(preferred_path)
 }
+
+pub fn active_config_path() -> Result<PathBuf, ConfigError> {
+    Ok(active_dela_config_dir()?.join("config.toml"))
+}
+
+/// User settings read from config.toml in the dela config directory.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DelaConfig {
+    /// Log file path template per task name, e.g. `dev-server = "logs/dev-{date}.log"`
+    pub output: HashMap<String, String>,
+    /// Layout of the `dela list` output
+    pub list: ListConfig,
+    /// Limits applied while discovering tasks
+    pub discovery: DiscoveryConfig,
+    /// How an ambiguous task name is resolved
+    pub preferences: PreferencesConfig,
+}
+
+/// Resolution preferences, set under `[preferences]` in config.toml.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PreferencesConfig {
+    /// Runner names, highest priority first, that win when a bare task name is ambiguous
+    pub runner_priority: Vec<String>,
+}
+
+/// Discovery settings, set under `[discovery]` in config.toml.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    /// Definition files larger than this many bytes are reported instead of parsed
+    pub max_file_size: u64,
+    /// Reuse the tasks `dela list` found last time for definition files that have not changed
+    pub cache: bool,
+    /// Runner names, e.g. `travis` or `docker-compose`, whose tasks are never discovered
+    pub disabled_runners: Vec<String>,
+    /// Start the shell interactively to read its aliases, so tasks they shadow are marked
+    pub shell_aliases: bool,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        DiscoveryConfig {
+            max_file_size: 8 * 1024 * 1024,
+            cache: false,
+            disabled_runners: Vec::new(),
+            shell_aliases: false,
+        }
+    }
+}
+
+/// How `dela list` aligns the task name column, set under `[list]` in config.toml.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    /// Task names are padded to at least this many characters
+    pub min_name_width: usize,
+    /// The column width is rounded up to a multiple of this
+    pub align_step: usize,
+}
+
+impl Default for ListConfig {
+    fn default() -> Self {
+        ListConfig {
+            min_name_width: 18,
+            align_step: 5,
+        }
+    }
+}
+
+impl ListConfig {
+    /// Width of the name column when the longest displayed name is `longest_name` characters.
+    pub fn name_column_width(&self, longest_name: usize) -> usize {
+        let step = self.align_step.max(1);
+        longest_name.max(self.min_name_width).div_ceil(step) * step
+    }
+}
+
+/// Load config.toml from the active config directory.
+/// A missing file yields the default settings.
+pub fn load_config() -> anyhow::Result<DelaConfig> {
+    load_config_from(&active_config_path()?)
+}
+
+pub fn load_config_from(path: &Path) -> anyhow::Result<DelaConfig> {
+    load_toml(path)
+}
+
+/// The project config file, read from the directory tasks are discovered in and its parents.
+pub const PROJECT_CONFIG_FILE: &str = ".dela.toml";
+
+/// Project settings read from a `.dela.toml` checked into the project. A setting made
+/// here replaces the one in config.toml.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    /// Custom names for tasks, e.g. `deploy = "release"` to run `release` as `deploy`
+    pub aliases: HashMap<String, String>,
+    pub discovery: ProjectDiscoveryConfig,
+    /// The directory tasks run from, relative to the `.dela.toml` that sets it, e.g.
+    /// `working_dir = "app"`. Read as an absolute path once loaded.
+    pub working_dir: Option<PathBuf>,
+}
+
+/// Discovery settings, set under `[discovery]` in `.dela.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ProjectDiscoveryConfig {
+    pub disabled_runners: Option<Vec<String>>,
+}
+
+impl ProjectConfig {
+    /// Fill in what this config leaves unset from the config of a parent directory.
+    fn merge_parent(&mut self, parent: ProjectConfig) {
+        for (alias, task) in parent.aliases {
+            self.aliases.entry(alias).or_insert(task);
+        }
+        if self.discovery.disabled_runners.is_none() {
+            self.discovery.disabled_runners = parent.discovery.disabled_runners;
+        }
+        if self.working_dir.is_none() {
+            self.working_dir = parent.working_dir;
+        }
+    }
+
+    /// The directory to run tasks from: the configured `working_dir`, or `default` when
+    /// none is set.
+    pub fn run_dir(&self, default: PathBuf) -> anyhow::Result<PathBuf> {
+        let Some(dir) = &self.working_dir else {
+            return Ok(default);
+        };
+        if !dir.is_dir() {
+            return Err(anyhow::anyhow!(
+                "The working_dir {} set in {} is not a directory",
+                dir.display(),
+                PROJECT_CONFIG_FILE
+            ));
+        }
+        Ok(dir.clone())
+    }
+
+    /// The task name to run for `task_name`. An alias is followed to its target, and on to
+    /// that target's target when it is an alias too. An alias in a cycle is not expanded.
+    pub fn resolve_alias<'a>(&'a self, task_name: &'a str) -> &'a str {
+        let (chain, is_cycle) = self.alias_chain(task_name);
+        if is_cycle {
+            task_name
+        } else {
+            chain.last().copied().unwrap_or(task_name)
+        }
+    }
+
+    /// The names `task_name` expands through, starting with itself, and whether the last
+    /// of them leads back to an earlier one.
+    fn alias_chain<'a>(&'a self, task_name: &'a str) -> (Vec<&'a str>, bool) {
+        let mut chain = vec![task_name];
+        let mut current = task_name;
+        while let Some(target) = self.aliases.get(current) {
+            let is_cycle = chain.contains(&target.as_str());
+            chain.push(target);
+            if is_cycle {
+                return (chain, true);
+            }
+            current = target;
+        }
+        (chain, false)
+    }
+
+    /// The aliases that lead back to themselves, each as the names around the cycle, e.g.
+    /// `["a", "b", "a"]`.
+    pub fn alias_cycles(&self) -> Vec<Vec<String>> {
+        let mut aliases: Vec<&String> = self.aliases.keys().collect();
+        aliases.sort();
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+        for alias in aliases {
+            let (chain, is_cycle) = self.alias_chain(alias);
+            // Report each cycle once, from its first alias by name
+            let already_reported = cycles
+                .iter()
+                .any(|cycle| cycle.iter().any(|name| name == alias));
+            if is_cycle && chain.first() == chain.last() && !already_reported {
+                cycles.push(chain.iter().map(|name| name.to_string()).collect());
+            }
+        }
+        cycles
+    }
+
+    /// Every alias with the task it finally runs, ordered by alias. Aliases that lead into
+    /// a cycle are left out.
+    pub fn resolved_aliases(&self) -> Vec<(&str, &str)> {
+        let mut resolved: Vec<(&str, &str)> = self
+            .aliases
+            .keys()
+            .filter(|alias| !self.alias_chain(alias).1)
+            .map(|alias| (alias.as_str(), self.resolve_alias(alias)))
+            .collect();
+        resolved.sort();
+        resolved
+    }
+}
+
+/// Load the `.dela.toml` files of `dir` and of its parents up to the repository root (the
+/// directory containing `.git`), stopping below `$HOME`. A nearer file wins over a
+/// parent's for each setting and each alias. Missing files yield no overrides.
+///
+/// Aliases that lead back to themselves are reported as warnings on stderr.
+pub fn load_project_config(dir: &Path) -> anyhow::Result<ProjectConfig> {
+    let config = read_project_config(dir)?;
+    for cycle in config.alias_cycles() {
+        eprintln!(
+            "Warning: alias cycle in {}: {}",
+            PROJECT_CONFIG_FILE,
+            cycle.join(" → ")
+        );
+    }
+    Ok(config)
+}
+
+/// [`load_project_config`] without the warnings, for discovery, which runs once per directory.
+fn read_project_config(dir: &Path) -> anyhow::Result<ProjectConfig> {
+    let home = get_current_home().map(PathBuf::from);
+    let mut config = ProjectConfig::default();
+    let mut search_dir = Some(dir);
+    while let Some(current) = search_dir {
+        let mut file_config: ProjectConfig = load_toml(&current.join(PROJECT_CONFIG_FILE))?;
+        file_config.working_dir = file_config.working_dir.map(|dir| current.join(dir));
+        config.merge_parent(file_config);
+        if current.join(".git").exists() {
+            break;
+        }
+        search_dir = current
+            .parent()
+            .filter(|parent| home.as_deref() != Some(*parent));
+    }
+    Ok(config)
+}
+
+/// The runner names disabled in `dir`: those of its `.dela.toml` when it lists any,
+/// otherwise those of config.toml. Only a broken `.dela.toml` is an error, since
+/// `dela list` already warns about config.toml.
+pub fn disabled_runners(dir: &Path) -> anyhow::Result<Vec<String>> {
+    match read_project_config(dir)?.discovery.disabled_runners {
+        Some(runners) => Ok(runners),
+        None => Ok(load_config()
+            .map(|config| config.discovery.disabled_runners)
+            .unwrap_or_default()),
+    }
+}
+
+fn load_toml<T: DeserializeOwned + Default>(path: &Path) -> anyhow::Result<T> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read config file: {}", e))?;
+    toml::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Failed to parse {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_config_missing_file_is_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = load_config_from(&temp_dir.path().join("config.toml")).unwrap();
+        assert_eq!(config, DelaConfig::default());
+    }
+
+    #[test]
+    fn test_load_config_output_sinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(&path, "[output]\ndev-server = \"logs/dev-{date}.log\"\n").unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(
+            config.output.get("dev-server").map(String::as_str),
+            Some("logs/dev-{date}.log")
+        );
+
+        assert_eq!(config.list, ListConfig::default());
+
+        std::fs::write(&path, "output = 3\n").unwrap();
+        let error = load_config_from(&path).unwrap_err().to_string();
+        assert!(error.contains("Failed to parse"));
+    }
+
+    #[test]
+    fn test_load_config_list_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(&path, "[list]\nmin_name_width = 30\n").unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.list.min_name_width, 30);
+        assert_eq!(config.list.align_step, 5);
+    }
+
+    #[test]
+    fn test_load_config_runner_priority() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[preferences]\nrunner_priority = [\"make\", \"task\", \"npm\"]\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(
+            config.preferences.runner_priority,
+            vec!["make", "task", "npm"]
+        );
+        assert_eq!(config.discovery, DiscoveryConfig::default());
+    }
+
+    #[test]
+    fn test_load_project_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+        let service = repo.join("service");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(&service).unwrap();
+        assert_eq!(
+            load_project_config(&service).unwrap(),
+            ProjectConfig::default()
+        );
+
+        // Above the repository root nothing is read
+        std::fs::write(
+            temp_dir.path().join(PROJECT_CONFIG_FILE),
+            "[aliases]\noutside = \"build\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            repo.join(PROJECT_CONFIG_FILE),
+            "[aliases]\ndeploy = \"release\"\nci = \"test\"\n\n[discovery]\ndisabled_runners = [\"travis\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            service.join(PROJECT_CONFIG_FILE),
+            "working_dir = \"app\"\n\n[aliases]\ndeploy = \"ship\"\n",
+        )
+        .unwrap();
+
+        let config = load_project_config(&service).unwrap();
+        assert_eq!(config.resolve_alias("deploy"), "ship");
+        assert_eq!(config.resolve_alias("ci"), "test");
+        assert_eq!(config.resolve_alias("outside"), "outside");
+        assert_eq!(
+            config.discovery.disabled_runners,
+            Some(vec!["travis".to_string()])
+        );
+        // A working directory is relative to the file that sets it
+        assert_eq!(config.working_dir, Some(service.join("app")));
+        assert!(config.run_dir(service.clone()).is_err());
+        std::fs::create_dir_all(service.join("app")).unwrap();
+        assert_eq!(
+            config.run_dir(service.clone()).unwrap(),
+            service.join("app")
+        );
+        assert_eq!(
+            ProjectConfig::default().run_dir(service.clone()).unwrap(),
+            service
+        );
+        assert_eq!(
+            load_project_config(&repo).unwrap().resolve_alias("deploy"),
+            "release"
+        );
+
+        std::fs::write(service.join(PROJECT_CONFIG_FILE), "aliases = 3\n").unwrap();
+        assert!(load_project_config(&service).is_err());
+    }
+
+    #[test]
+    fn test_project_alias_chains_and_cycles() {
+        let config = ProjectConfig {
+            aliases: HashMap::from(
+                [
+                    ("serve", "dev"),
+                    ("dev", "uv:run-server"),
+                    ("ping", "pong"),
+                    ("pong", "ping"),
+                    ("loop", "loop"),
+                    ("into-loop", "ping"),
+                ]
+                .map(|(alias, task)| (alias.to_string(), task.to_string())),
+            ),
+            ..ProjectConfig::default()
+        };
+
+        assert_eq!(config.resolve_alias("serve"), "uv:run-server");
+        assert_eq!(config.resolve_alias("dev"), "uv:run-server");
+        assert_eq!(config.resolve_alias("build"), "build");
+        assert_eq!(config.resolve_alias("ping"), "ping");
+        assert_eq!(config.resolve_alias("into-loop"), "into-loop");
+
+        assert_eq!(
+            config.alias_cycles(),
+            vec![vec!["loop", "loop"], vec!["ping", "pong", "ping"]]
+        );
+        assert_eq!(
+            config.resolved_aliases(),
+            vec![("dev", "uv:run-server"), ("serve", "uv:run-server")]
+        );
+    }
+
+    #[test]
+    fn test_list_config_name_column_width() {
+        let default = ListConfig::default();
+        assert_eq!(default.name_column_width(4), 20);
+        assert_eq!(default.name_column_width(21), 25);
+
+        let unaligned = ListConfig {
+            min_name_width: 8,
+            align_step: 0,
+        };
+        assert_eq!(unaligned.name_column_width(4), 8);
+        assert_eq!(unaligned.name_column_width(11), 11);
+    }
+}
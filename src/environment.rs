@@ -8,6 +8,13 @@ pub trait Environment: Send + Sync {
     fn get_shell(&self) -> Option<String>;
     fn check_executable(&self, name: &str) -> Option<String>;
     fn get_home(&self) -> Option<String>;
+    /// Names the user's shell defines as aliases
+    fn shell_aliases(&self) -> HashSet<String>;
+
+    /// Whether lookups reflect the actual system, so their results may be cached
+    fn is_real(&self) -> bool {
+        false
+    }
 }
 
 /// Production environment implementation
@@ -15,21 +22,39 @@ pub struct RealEnvironment;
 
 impl Environment for RealEnvironment {
     fn get_shell(&self) -> Option<String> {
-        std::env::var("SHELL").ok()
+        // Windows does not set SHELL, and PowerShell is the shell dela integrates with there
+        std::env::var("SHELL")
+            .ok()
+            .or_else(|| cfg!(windows).then(|| "pwsh".to_string()))
     }
 
     fn check_executable(&self, name: &str) -> Option<String> {
         use std::process::Command;
-        let output = Command::new("which").arg(name).output().ok()?;
+        let lookup = if cfg!(windows) { "where" } else { "which" };
+        let output = Command::new(lookup).arg(name).output().ok()?;
         if output.status.success() {
-            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+            // `where` lists every match, one per line
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            stdout.lines().next().map(|path| path.trim().to_string())
         } else {
             None
         }
     }
 
     fn get_home(&self) -> Option<String> {
-        std::env::var("HOME").ok()
+        std::env::var("HOME")
+            .ok()
+            .or_else(|| std::env::var("USERPROFILE").ok().filter(|_| cfg!(windows)))
+    }
+
+    fn shell_aliases(&self) -> HashSet<String> {
+        self.get_shell()
+            .map(|shell| crate::builtins::aliases_from_shell(&shell))
+            .unwrap_or_default()
+    }
+
+    fn is_real(&self) -> bool {
+        true
     }
 }
 
@@ -40,6 +65,7 @@ pub struct TestEnvironment {
     shell: Option<String>,
     executables: HashSet<String>,
     home: Option<String>,
+    aliases: HashSet<String>,
 }
 
 #[cfg(test)]
@@ -62,6 +88,11 @@ impl TestEnvironment {
         self.home = Some(home.into());
         self
     }
+
+    pub fn with_alias(mut self, name: impl Into<String>) -> Self {
+        self.aliases.insert(name.into());
+        self
+    }
 }
 
 impl Environment for TestEnvironment {
@@ -80,12 +111,21 @@ impl Environment for TestEnvironment {
     fn get_home(&self) -> Option<String> {
         self.home.clone()
     }
+
+    fn shell_aliases(&self) -> HashSet<String> {
+        self.aliases.clone()
+    }
 }
 
 /// Global environment instance
 pub static ENVIRONMENT: Lazy<Mutex<Arc<dyn Environment>>> =
     Lazy::new(|| Mutex::new(Arc::new(RealEnvironment)));
 
+/// The environment lookups currently go through
+pub fn current_environment() -> Arc<dyn Environment> {
+    Arc::clone(&ENVIRONMENT.lock().unwrap())
+}
+
 /// Helper to set the environment for testing
 #[cfg(test)]
 pub fn set_test_environment(env: TestEnvironment) {
@@ -98,6 +138,14 @@ pub fn reset_to_real_environment() {
     *ENVIRONMENT.lock().unwrap() = Arc::new(RealEnvironment);
 }
 
+/// The name of a shell from its path, without the `.exe` suffix it has on Windows,
+/// e.g. `zsh` for `/bin/zsh` and `pwsh` for `C:\Program Files\PowerShell\7\pwsh.exe`.
+pub fn shell_name(shell: &str) -> Option<String> {
+    let name = shell.rsplit(['/', '\\']).next()?;
+    let name = name.strip_suffix(".exe").unwrap_or(name);
+    (!name.is_empty()).then(|| name.to_string())
+}
+
 /// Helper to get the current environment's HOME value
 pub fn get_current_home() -> Option<String> {
     ENVIRONMENT.lock().unwrap().get_home()
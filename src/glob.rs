@@ -0,0 +1,79 @@
+//! Glob matching for the patterns users write in dela's own files: `.gitignore`
+//! directories, the `inputs` and `outputs` of cached tasks, and the `pattern` of
+//! allowlist entries, which decides which tasks may run.
+
+/// Match a `/`-separated `path` against `pattern`.
+///
+/// `*` and `?` never cross a `/`, and a `**` segment matches any number of whole
+/// segments, including none, so `src/**/*.rs` matches both `src/main.rs` and
+/// `src/commands/list.rs`.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => path
+            .split_first()
+            .is_some_and(|(name, path)| match_segment(segment, name) && match_segments(rest, path)),
+    }
+}
+
+/// Match one path segment against a pattern supporting `*` and `?`.
+fn match_segment(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("build", "build"));
+        assert!(glob_match("*.egg-info", "dela.egg-info"));
+        assert!(glob_match("out?", "out1"));
+        assert!(glob_match("docs/*", "docs/site"));
+        assert!(!glob_match("docs/*", "docs/site/api"));
+        assert!(!glob_match("*.log", "build"));
+    }
+
+    #[test]
+    fn test_glob_match_double_star() {
+        assert!(glob_match("src/**/*.rs", "src/main.rs"));
+        assert!(glob_match("src/**/*.rs", "src/commands/list.rs"));
+        assert!(glob_match("**/package.json", "package.json"));
+        assert!(glob_match("**/package.json", "apps/web/package.json"));
+        assert!(glob_match("dist/**", "dist/assets/app.js"));
+        assert!(!glob_match("src/**/*.rs", "tests/cli.rs"));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::config::{ConfigError, active_dela_config_dir};
+use nix::errno::Errno;
+use nix::sys::signal::kill;
+use nix::unistd::Pid;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A task started in the background that later dela invocations can find again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisteredJob {
+    pub pid: u32,
+    /// Name the task was started under, e.g. `dev` or `test-m`
+    pub task: String,
+    /// The runner command that was executed
+    pub command: String,
+    /// Directory the task was started from
+    pub cwd: PathBuf,
+    /// File receiving the task's stdout and stderr
+    pub log_path: Option<PathBuf>,
+    /// RFC 3339 timestamp of when the job was started
+    pub started_at: String,
+}
+
+/// Path of the job registry shared by every dela process for the current user.
+pub fn registry_path() -> Result<PathBuf, ConfigError> {
+    Ok(active_dela_config_dir()?.join("jobs.json"))
+}
+
+/// Read the registered jobs. A missing registry has no jobs.
+pub fn load_jobs(path: &Path) -> anyhow::Result<Vec<RegisteredJob>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read job registry: {}", e))?;
+    if contents.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Replace the registry with `jobs` through a temporary file, so a reader never sees it
+/// half written.
+fn save_jobs(path: &Path, jobs: &[RegisteredJob]) -> anyhow::Result<()> {
+    let contents = serde_json::to_string_pretty(jobs)?;
+    let temp_path = sibling_path(path, &format!("tmp.{}", std::process::id()));
+    std::fs::write(&temp_path, contents)
+        .and_then(|_| std::fs::rename(&temp_path, path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&temp_path);
+            anyhow::anyhow!("Failed to write job registry: {}", e)
+        })
+}
+
+/// `path` with `.suffix` appended to its file name.
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Load the registry, let `update` change it and save the result, holding a lock so that
+/// dela processes registering at the same time do not drop each other's jobs.
+fn update_jobs<F>(path: &Path, update: F) -> anyhow::Result<()>
+where
+    F: FnOnce(&mut Vec<RegisteredJob>),
+{
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| anyhow::anyhow!("Failed to create config directory: {}", e))?;
+    }
+    let lock = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sibling_path(path, "lock"))
+        .and_then(|file| file.lock().map(|_| file))
+        .map_err(|e| anyhow::anyhow!("Failed to lock job registry: {}", e))?;
+
+    let mut jobs = load_jobs(path)?;
+    update(&mut jobs);
+    let saved = save_jobs(path, &jobs);
+    drop(lock);
+    saved
+}
+
+/// Add `job` to the registry, dropping jobs that have exited and any stale entry that
+/// reused its PID.
+pub fn register_job(path: &Path, job: RegisteredJob) -> anyhow::Result<()> {
+    update_jobs(path, |jobs| {
+        jobs.retain(|existing| existing.pid != job.pid && is_process_running(existing.pid));
+        jobs.push(job);
+    })
+}
+
+/// Read the registered jobs and drop those that have exited from the registry, so each is
+/// reported as exited once and its PID is not mistaken for a later process.
+pub fn take_jobs(path: &Path) -> anyhow::Result<Vec<RegisteredJob>> {
+    let jobs = load_jobs(path)?;
+    if jobs.iter().any(|job| !is_process_running(job.pid)) {
+        update_jobs(path, |jobs| jobs.retain(|job| is_process_running(job.pid)))?;
+    }
+    Ok(jobs)
+}
+
+/// Whether a process with `pid` still exists.
+pub fn is_process_running(pid: u32) -> bool {
+    let Ok(pid) = i32::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks for existence; EPERM means it exists but belongs to someone else.
+    matches!(kill(Pid::from_raw(pid), None), Ok(()) | Err(Errno::EPERM))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn job(pid: u32, task: &str) -> RegisteredJob {
+        RegisteredJob {
+            pid,
+            task: task.to_string(),
+            command: format!("make {}", task),
+            cwd: PathBuf::from("/project"),
+            log_path: None,
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_register_and_load_jobs() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dela").join("jobs.json");
+        assert!(load_jobs(&path).unwrap().is_empty());
+
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let own_pid = std::process::id();
+        register_job(&path, job(own_pid, "dev")).unwrap();
+        register_job(&path, job(child.id(), "watch")).unwrap();
+        register_job(&path, job(own_pid, "serve")).unwrap();
+
+        let jobs = load_jobs(&path).unwrap();
+        assert_eq!(jobs, vec![job(child.id(), "watch"), job(own_pid, "serve")]);
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn test_exited_jobs_are_pruned() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("jobs.json");
+        let exited_pid = i32::MAX as u32;
+        let own_pid = std::process::id();
+
+        save_jobs(&path, &[job(exited_pid, "old")]).unwrap();
+        register_job(&path, job(own_pid, "dev")).unwrap();
+        assert_eq!(load_jobs(&path).unwrap(), vec![job(own_pid, "dev")]);
+
+        save_jobs(&path, &[job(exited_pid, "old"), job(own_pid, "dev")]).unwrap();
+        assert_eq!(
+            take_jobs(&path).unwrap(),
+            vec![job(exited_pid, "old"), job(own_pid, "dev")]
+        );
+        assert_eq!(load_jobs(&path).unwrap(), vec![job(own_pid, "dev")]);
+        assert!(
+            take_jobs(&temp_dir.path().join("missing.json"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_concurrent_registrations_keep_every_job() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("jobs.json");
+        let own_pid = std::process::id();
+        let mut children: Vec<std::process::Child> = (0..8)
+            .map(|_| {
+                std::process::Command::new("sleep")
+                    .arg("30")
+                    .spawn()
+                    .unwrap()
+            })
+            .collect();
+
+        std::thread::scope(|scope| {
+            for (index, child) in children.iter().enumerate() {
+                let path = &path;
+                let pid = child.id();
+                scope.spawn(move || {
+                    register_job(path, job(pid, &format!("task-{}", index))).unwrap()
+                });
+            }
+        });
+        register_job(&path, job(own_pid, "last")).unwrap();
+
+        assert_eq!(load_jobs(&path).unwrap().len(), 9);
+        for child in &mut children {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    #[test]
+    fn test_is_process_running() {
+        assert!(is_process_running(std::process::id()));
+        // Above the largest pid_max Linux allows
+        assert!(!is_process_running(i32::MAX as u32));
+    }
+
+    #[test]
+    fn test_load_jobs_invalid_registry() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("jobs.json");
+        std::fs::write(&path, "not json").unwrap();
+        assert!(
+            load_jobs(&path)
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to parse")
+        );
+    }
+}
@@ -7,12 +7,15 @@ pub mod commands;
 pub mod composed_paths;
 pub mod config;
 pub mod environment;
+pub mod glob;
+pub mod job_registry;
 pub mod mcp;
 pub mod parsers;
 pub mod prompt;
 pub mod repo_root;
 pub mod runner;
 pub mod runners;
+pub mod task_cache;
 pub mod task_discovery;
 pub mod task_shadowing;
 pub mod types;
@@ -1,4 +1,5 @@
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 mod allowlist;
 mod builtins;
@@ -6,6 +7,8 @@ mod commands;
 mod composed_paths;
 mod config;
 mod environment;
+mod glob;
+mod job_registry;
 mod mcp;
 mod parsers;
 mod prompt;
@@ -15,6 +18,7 @@ mod runners {
     pub mod runners_package_json;
     pub mod runners_pyproject_toml;
 }
+mod task_cache;
 mod task_discovery;
 mod task_shadowing;
 mod types;
@@ -29,7 +33,7 @@ mod types;
     after_help = r#"Supported Task Runners:
 • Make (Makefile)
 • Node.js: npm, yarn, pnpm, bun (package.json)
-• Python: uv, poetry, poethepoet (pyproject.toml)
+• Python: uv, poetry, poethepoet, hatch (pyproject.toml)
 • Task (Taskfile.yml)
 • Turborepo (turbo.json)
 • Maven (pom.xml)
@@ -39,6 +43,24 @@ mod types;
 • CMake (CMakeLists.txt)
 • Travis CI (.travis.yml)
 • Just (Justfile)
+• Tox (tox.ini)
+• Nox (noxfile.py)
+• Invoke (tasks.py)
+• GitLab CI (.gitlab-ci.yml)
+• Nx (nx.json, project.json)
+• mise (mise.toml, .mise/tasks)
+• Bazel (WORKSPACE, MODULE.bazel)
+• Meson (meson.build)
+• SCons (SConstruct)
+• pre-commit (.pre-commit-config.yaml)
+• Ant (build.xml)
+• sbt (build.sbt)
+• Leiningen (project.clj)
+• Procfile processes (Procfile, Procfile.dev)
+• mage (magefile.go)
+• docker buildx bake (docker-bake.hcl, docker-bake.json)
+• docker build stages (Dockerfile, *.Dockerfile)
+• Helm charts (Chart.yaml)
 "#,
     long_about = r#"Dela integrates with you shell to let you to execute locally defined
 tasks such as in Makefile or package.json without specifying the task runner.
@@ -61,6 +83,10 @@ Tasks with name collisions will be suffixed and be runnable by their new names.
 {all-args}{after-help}"
 )]
 struct Cli {
+    /// Never color the output, the same as setting NO_COLOR
+    #[arg(long, global = true)]
+    no_color: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -110,6 +136,21 @@ enum Commands {
     /// Example: dela init
     Init,
 
+    /// Diagnose common setup problems
+    ///
+    /// Checks the config directory, the allowlist, shell integration, installed
+    /// runners, and the tasks in the current directory. Exits non-zero if a check fails.
+    /// With --fix, repairs what it can. Destructive fixes ask for confirmation
+    /// and keep a .bak copy of the original file.
+    ///
+    /// Example: dela doctor
+    /// Example: dela doctor --fix
+    Doctor {
+        /// Attempt to fix the issues that were found
+        #[arg(long)]
+        fix: bool,
+    },
+
     /// List all available tasks in the current directory
     ///
     /// Shows tasks from Makefiles, package.json scripts, pyproject.toml, and more.
@@ -117,14 +158,82 @@ enum Commands {
     ///
     /// Example: dela list
     /// Example: dela list --verbose
+    /// Example: dela list --format json
+    /// Example: dela list --cwd ../api
+    /// Example: dela list --runnable-only
+    /// Example: dela list --check-deps
+    /// Example: dela list --count
+    /// Example: dela list --flat | fzf
+    /// Example: dela list --sort source
     List {
-        /// Show detailed information about task definition files
+        /// Show definition file statuses, and the file and line each task is defined on
         #[arg(short, long)]
         verbose: bool,
 
         /// Control colored output (always, auto, never)
         #[arg(long, default_value = "auto")]
         color: String,
+
+        /// Output format (pretty, json, flat, tree)
+        #[arg(long, default_value = "pretty")]
+        format: String,
+
+        /// Print the tasks as JSON, the same as --format json
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+
+        /// Print one tab-separated line per task, the same as --format flat
+        #[arg(long, conflicts_with_all = ["format", "json"])]
+        flat: bool,
+
+        /// Split the pretty output into sections by runner, prefix, file, tag or dir
+        #[arg(long, default_value = "runner")]
+        group: String,
+
+        /// Order tasks within each section by name, by source (definition order) or not at all
+        #[arg(long, default_value = "name")]
+        sort: String,
+
+        /// Hide tasks that would do nothing when run locally
+        #[arg(long)]
+        runnable_only: bool,
+
+        /// Report dependency cycles between tasks, failing if any are found
+        #[arg(long)]
+        check_deps: bool,
+
+        /// Ignore builtin and PATH shadowing; only cross-runner name collisions get suffixes
+        #[arg(long)]
+        no_builtins: bool,
+
+        /// Also list tasks from subdirectories, searching 3 levels deep
+        #[arg(short, long)]
+        recursive: bool,
+
+        /// Search subdirectories this many levels deep (implies --recursive)
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+
+        /// Look in parent directories when the current one defines no tasks
+        #[arg(long = "up", visible_alias = "walk-up")]
+        walk_up: bool,
+
+        /// Only list tasks for this runner, e.g. make, npm, or node for any Node.js runner.
+        /// Repeat to list several runners.
+        #[arg(long = "runner", visible_alias = "only", value_name = "NAME")]
+        runners: Vec<String>,
+
+        /// Print only the number of tasks, with a count per runner when verbose
+        #[arg(long)]
+        count: bool,
+
+        /// Discover tasks from scratch, ignoring the [discovery] cache setting
+        #[arg(long)]
+        no_cache: bool,
+
+        /// List the tasks of this directory instead of the current one
+        #[arg(long, value_name = "DIR")]
+        cwd: Option<PathBuf>,
     },
 
     /// Run a specific task
@@ -134,11 +243,130 @@ enum Commands {
     ///
     /// Example: dr build
     /// Example: build
+    /// Example: dela run build --log build.log
+    /// Example: dela run build --log build.jsonl --log-format json
+    /// Example: dela run dev --detach
+    /// Example: dela run @3
+    /// Example: dela run test -- --nocapture
+    /// Example: dela run --env RUST_LOG=debug --env PORT=8080 serve
+    /// Example: dela run --cwd ../api build
+    /// Example: dela run
     Run {
-        /// Name of the task to run
+        /// Name of the task to run, or @N for the Nth task of the last 'dela list'.
+        /// Without one, pick the task from a numbered menu
+        task: Option<String>,
+
+        /// Also write the task's output to this file
+        #[arg(long)]
+        log: Option<PathBuf>,
+
+        /// Format of the --log file (text, json)
+        #[arg(long, default_value = "text", requires = "log")]
+        log_format: String,
+
+        /// Run from the runner's project root, e.g. the directory with settings.gradle
+        #[arg(long)]
+        working_stack: bool,
+
+        /// Start the task in the background, detached from the terminal, and print its PID
+        #[arg(long)]
+        detach: bool,
+
+        /// Look for the task in parent directories and run it from where it was found
+        #[arg(long = "up", visible_alias = "walk-up")]
+        walk_up: bool,
+
+        /// Print the command that would run without running it
+        #[arg(long, conflicts_with = "detach")]
+        dry_run: bool,
+
+        /// Set an environment variable for the task, as KEY=VALUE; may be repeated
+        #[arg(long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+
+        /// Find and run the task in this directory instead of the current one
+        #[arg(long, value_name = "DIR")]
+        cwd: Option<PathBuf>,
+
+        /// Arguments to pass to the task, after the task name or a `--`
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+
+    /// Run a task, then run it again whenever a watched file changes
+    ///
+    /// Watches the current directory unless paths are given. Files ignored by
+    /// .gitignore, and directories such as node_modules and target, do not trigger a run.
+    /// Press Ctrl-C to stop.
+    ///
+    /// Example: dela watch test
+    /// Example: dela watch build src docs
+    /// Example: dela watch 'test --verbose'
+    Watch {
+        /// Name of the task to run, with any arguments for it in the same quoted word
+        task: String,
+
+        /// Files or directories to watch
+        paths: Vec<PathBuf>,
+    },
+
+    /// Show tasks running in the background
+    ///
+    /// Lists tasks started with 'dela run --detach' or by an MCP session.
+    ///
+    /// Example: dela status
+    /// Example: dela status --json
+    Status {
+        /// Print the jobs as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show how a task name resolves without running it
+    ///
+    /// Prints the runner, definition file, exact command, whether the runner is
+    /// installed, whether the name is shadowed, and the task's allowlist status.
+    ///
+    /// Example: dela which build
+    Which {
+        /// Name of the task to look up
         task: String,
     },
 
+    /// List the task runners dela supports
+    ///
+    /// Shows each runner's name, the files dela reads its tasks from, and whether
+    /// the runner is installed.
+    ///
+    /// Example: dela runners
+    Runners,
+
+    /// Print a task's definition as written in its file
+    ///
+    /// Shows the Makefile recipe, npm script, Taskfile cmds or Justfile recipe body
+    /// behind a task, or its description when the definition is not kept. The name
+    /// resolves the same way as for 'dela run'.
+    ///
+    /// Example: dela describe build
+    /// Example: dela describe npm:test
+    Describe {
+        /// Name of the task to describe
+        task: String,
+    },
+
+    /// Print the tree of tasks a task depends on
+    ///
+    /// Uses the dependencies each task declares, such as Makefile prerequisites or
+    /// Taskfile deps. Without a task, prints the trees of every task in the current
+    /// directory. Cycles are marked and reported as a warning.
+    ///
+    /// Example: dela graph
+    /// Example: dela graph deploy
+    Graph {
+        /// Name of the task to start from
+        task: Option<String>,
+    },
+
     /// Allow a specific task to run
     ///
     /// This adds the task to the allowlist at the Task scope.
@@ -159,12 +387,59 @@ enum Commands {
         task: String,
     },
 
+    /// Print a shell script that completes task names
+    ///
+    /// Completes the task given to 'dr', and bare task names in bash 5 and zsh.
+    ///
+    /// Example: eval "$(dela completions zsh)"
+    Completions {
+        /// Shell to print the script for
+        #[arg(value_parser = commands::completions::COMPLETION_SHELLS)]
+        shell: String,
+    },
+
+    /// Print shell aliases that run each task through dela
+    ///
+    /// For when you would rather have explicit aliases than rely on the command not
+    /// found handler. Tasks that share a name are aliased by their suffixed names, and
+    /// tasks shadowed by a shell builtin are listed in a comment instead.
+    ///
+    /// Example: dela export-aliases >> ~/.zshrc
+    /// Example: dela export-aliases --shell fish | source
+    #[command(name = "export-aliases")]
+    ExportAliases {
+        /// Shell to write the aliases for, by default the current shell
+        #[arg(long, value_parser = commands::export_aliases::ALIAS_SHELLS)]
+        shell: Option<String>,
+    },
+
+    /// Manage the cache of discovered tasks
+    ///
+    /// The cache is only used when `cache = true` is set under [discovery] in config.toml.
+    ///
+    /// Example: dela cache clear
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
     // Internal commands (hidden from help by default)
     #[command(name = "configure-shell", hide = true)]
     ConfigureShell,
 
+    #[command(name = "complete-tasks", hide = true)]
+    CompleteTasks,
+
     #[command(name = "get-command", hide = true, trailing_var_arg = true)]
     GetCommand {
+        /// Ignore builtin and PATH shadowing when resolving the task name
+        #[arg(long)]
+        no_builtins: bool,
+
+        /// Look for the task in parent directories and run it from where it was found
+        #[arg(long = "up", visible_alias = "walk-up")]
+        walk_up: bool,
+
         /// Name of the task followed by any arguments to pass to it
         args: Vec<String>,
     },
@@ -173,12 +448,18 @@ enum Commands {
     AllowCommand {
         /// Name of the task to check
         task: String,
-        /// Automatically allow with a specific choice (2-5)
+        /// Automatically allow with a specific choice (2-6)
         #[arg(long)]
         allow: Option<u8>,
     },
 }
 
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Delete the cached tasks of every directory
+    Clear,
+}
+
 async fn run_command(command: Commands) -> anyhow::Result<()> {
     match command {
         Commands::Mcp {
@@ -200,16 +481,107 @@ async fn run_command(command: Commands) -> anyhow::Result<()> {
             .await
         }
         Commands::Init => commands::init::execute(),
+        Commands::Cache {
+            action: CacheAction::Clear,
+        } => commands::cache::execute_clear(),
+        Commands::Doctor { fix } => commands::doctor::execute(fix),
         Commands::ConfigureShell => commands::configure_shell::execute(),
-        Commands::List { verbose, color } => commands::list::execute(verbose, &color),
-        Commands::Run { task } => commands::run::execute(&task),
+        Commands::Completions { shell } => commands::completions::execute(&shell),
+        Commands::CompleteTasks => commands::completions::execute_complete_tasks(),
+        Commands::ExportAliases { shell } => commands::export_aliases::execute(shell.as_deref()),
+        Commands::List {
+            verbose,
+            color,
+            format,
+            json,
+            flat,
+            group,
+            sort,
+            runnable_only,
+            check_deps,
+            no_builtins,
+            recursive,
+            depth,
+            walk_up,
+            runners,
+            count,
+            no_cache,
+            cwd,
+        } => commands::list::execute(&commands::list::ListOptions {
+            verbose,
+            color,
+            format: match (json, flat) {
+                (true, _) => "json".to_string(),
+                (_, true) => "flat".to_string(),
+                _ => format,
+            },
+            group,
+            sort,
+            runnable_only,
+            check_deps,
+            no_builtins,
+            depth: depth.or(recursive.then_some(commands::list::DEFAULT_RECURSIVE_DEPTH)),
+            walk_up,
+            runners,
+            count,
+            no_cache,
+            cwd,
+        }),
+        Commands::Run {
+            task,
+            log,
+            log_format,
+            working_stack,
+            detach,
+            walk_up,
+            dry_run,
+            env,
+            cwd,
+            args,
+        } => {
+            let log = match log {
+                Some(path) => Some(commands::run_log::RunLog {
+                    path,
+                    format: commands::run_log::LogFormat::parse(&log_format)?,
+                }),
+                None => None,
+            };
+            let task_with_args = match &task {
+                Some(task) => shell_words::join(std::iter::once(task).chain(&args)),
+                None => String::new(),
+            };
+            commands::run::execute(
+                &task_with_args,
+                &commands::run_command::RunOptions {
+                    log,
+                    working_stack,
+                    detach,
+                    walk_up,
+                    dry_run,
+                    env: commands::run_command::parse_env_vars(&env)?,
+                    cwd,
+                },
+            )
+        }
+        Commands::Watch { task, paths } => {
+            commands::watch::execute(&task, &paths, &commands::run_command::RunOptions::default())
+        }
+        Commands::Status { json } => commands::status::execute(json),
+        Commands::Which { task } => commands::which::execute(&task),
+        Commands::Describe { task } => commands::describe::execute(&task),
+        Commands::Runners => commands::runners::execute(),
+        Commands::Graph { task } => commands::graph::execute(task.as_deref()),
         Commands::Allow { task } => commands::allow::execute(&task),
         Commands::Deny { task } => commands::deny::execute(&task),
-        Commands::GetCommand { args } => {
+        Commands::GetCommand {
+            no_builtins,
+            walk_up,
+            args,
+        } => {
             if args.is_empty() {
                 Err(anyhow::anyhow!("No task name provided"))
             } else {
-                commands::get_command::execute(&args.join(" "))
+                commands::get_command::execute(&shell_words::join(&args), no_builtins, walk_up)
             }
         }
         Commands::AllowCommand { task, allow } => commands::allow_command::execute(&task, allow),
@@ -219,6 +591,7 @@ async fn run_command(command: Commands) -> anyhow::Result<()> {
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    commands::configure_color(cli.no_color);
 
     let result = run_command(cli.command).await;
 
@@ -229,7 +602,10 @@ async fn main() {
         } else {
             eprintln!("Error: {}", msg);
         }
-        std::process::exit(1);
+        let code = err
+            .downcast_ref::<commands::run_command::TaskFailed>()
+            .map_or(1, commands::run_command::TaskFailed::exit_code);
+        std::process::exit(code);
     }
 }
 
@@ -282,8 +658,121 @@ mod tests {
 
     #[tokio::test]
     async fn test_run_command_get_command_empty() {
-        let result = run_command(Commands::GetCommand { args: vec![] }).await;
+        let result = run_command(Commands::GetCommand {
+            no_builtins: false,
+            walk_up: false,
+            args: vec![],
+        })
+        .await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().to_string(), "No task name provided");
     }
+
+    #[test]
+    fn test_run_log_format_requires_log() {
+        use clap::Parser;
+
+        let cli = super::Cli::try_parse_from(["dela", "run", "build"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { log: None, ref log_format, .. } if log_format == "text"
+        ));
+
+        let cli = super::Cli::try_parse_from([
+            "dela",
+            "run",
+            "build",
+            "--log",
+            "build.jsonl",
+            "--log-format",
+            "json",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { log: Some(_), ref log_format, .. } if log_format == "json"
+        ));
+
+        assert!(
+            super::Cli::try_parse_from(["dela", "run", "build", "--log-format", "json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_run_passes_trailing_args() {
+        use clap::Parser;
+
+        let cli = super::Cli::try_parse_from([
+            "dela",
+            "run",
+            "--log",
+            "test.log",
+            "test",
+            "--",
+            "--nocapture",
+            "two words",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { ref task, ref args, log: Some(_), .. }
+                if task.as_deref() == Some("test") && args == &["--nocapture", "two words"]
+        ));
+
+        let cli = super::Cli::try_parse_from(["dela", "run", "test", "--nocapture"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { ref args, .. } if args == &["--nocapture"]
+        ));
+
+        // Without a task the picker asks for one
+        let cli = super::Cli::try_parse_from(["dela", "run"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Run { task: None, ref args, .. } if args.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_list_json_flag() {
+        use clap::Parser;
+
+        let cli = super::Cli::try_parse_from(["dela", "list", "--json"]).unwrap();
+        assert!(matches!(cli.command, Commands::List { json: true, .. }));
+
+        let cli = super::Cli::try_parse_from(["dela", "list", "--flat"]).unwrap();
+        assert!(matches!(cli.command, Commands::List { flat: true, .. }));
+        assert!(super::Cli::try_parse_from(["dela", "list", "--flat", "--json"]).is_err());
+
+        assert!(
+            super::Cli::try_parse_from(["dela", "list", "--json", "--format", "tree"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_no_color_flag_is_global() {
+        use clap::Parser;
+
+        let cli = super::Cli::try_parse_from(["dela", "list", "--no-color"]).unwrap();
+        assert!(cli.no_color);
+        let cli = super::Cli::try_parse_from(["dela", "--no-color", "status"]).unwrap();
+        assert!(cli.no_color);
+        assert!(
+            !super::Cli::try_parse_from(["dela", "status"])
+                .unwrap()
+                .no_color
+        );
+    }
+
+    #[test]
+    fn test_list_only_is_runner_alias() {
+        use clap::Parser;
+
+        let cli = super::Cli::try_parse_from(["dela", "list", "--only", "make", "--runner", "npm"])
+            .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::List { ref runners, .. } if runners == &["make", "npm"]
+        ));
+    }
 }
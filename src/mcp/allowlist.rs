@@ -28,16 +28,18 @@ impl McpAllowlistEvaluator {
     /// - `Err(msg)` if there was an error loading the allowlist
     ///
     /// Precedence order (highest to lowest):
-    /// 1. Deny entries (highest precedence)
-    /// 2. Directory scope allow entries
-    /// 3. File scope allow entries  
-    /// 4. Task scope allow entries
-    /// 5. Not found in allowlist (deny by default for MCP)
+    /// 1. Deny entries, patterns included (highest precedence)
+    /// 2. Allow entries, in allowlist order: anywhere below a directory, a whole file, or
+    ///    named tasks in a file
+    /// 3. Allow pattern entries
+    /// 4. Not found in allowlist (deny by default for MCP)
     pub fn is_task_allowed(&self, task: &Task) -> anyhow::Result<bool> {
-        Ok(matches!(
-            evaluate_task_against_allowlist(task, &self.allowlist),
-            AllowlistMatch::Allowed
-        ))
+        Ok(matches!(self.evaluate(task), AllowlistMatch::Allowed))
+    }
+
+    /// Whether the allowlist allows or denies `task`, or says nothing about it
+    pub fn evaluate(&self, task: &Task) -> AllowlistMatch {
+        evaluate_task_against_allowlist(task, &self.allowlist)
     }
 
     /// Get the number of entries in the allowlist
@@ -75,6 +77,17 @@ mod tests {
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         }
     }
 
@@ -130,6 +143,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         crate::allowlist::save_allowlist(&allowlist).unwrap();
@@ -154,6 +168,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::Task,
             tasks: Some(vec!["test-task".to_string()]),
+            pattern: None,
         };
         allowlist.entries.push(entry);
         crate::allowlist::save_allowlist(&allowlist).unwrap();
@@ -182,6 +197,7 @@ mod tests {
             path: std::path::PathBuf::from("/project"),
             scope: AllowScope::Directory,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         crate::allowlist::save_allowlist(&allowlist).unwrap();
@@ -214,6 +230,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(entry);
         crate::allowlist::save_allowlist(&allowlist).unwrap();
@@ -227,6 +244,31 @@ mod tests {
         reset_to_real_environment();
     }
 
+    #[test]
+    #[serial]
+    fn test_mcp_allowlist_evaluator_pattern_scope() {
+        let (temp_dir, _task) = setup_test_env();
+
+        let mut allowlist = Allowlist::default();
+        allowlist.entries.push(AllowlistEntry {
+            path: std::path::PathBuf::new(),
+            scope: AllowScope::Task,
+            tasks: None,
+            pattern: Some("test-*".to_string()),
+        });
+        crate::allowlist::save_allowlist(&allowlist).unwrap();
+
+        let evaluator = McpAllowlistEvaluator::new().unwrap();
+
+        let matching = create_test_task("test-unit", std::path::PathBuf::from("/a/Makefile"));
+        assert!(evaluator.is_task_allowed(&matching).unwrap());
+        let other = create_test_task("build", std::path::PathBuf::from("/a/Makefile"));
+        assert!(!evaluator.is_task_allowed(&other).unwrap());
+
+        drop(temp_dir);
+        reset_to_real_environment();
+    }
+
     #[test]
     #[serial]
     fn test_mcp_allowlist_evaluator_precedence() {
@@ -241,6 +283,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::File,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(allow_entry);
 
@@ -249,6 +292,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(deny_entry);
 
@@ -291,6 +335,7 @@ mod tests {
             path: std::path::PathBuf::from("/project"),
             scope: AllowScope::Directory,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(dir_entry);
 
@@ -299,6 +344,7 @@ mod tests {
             path: std::path::PathBuf::from("Makefile"),
             scope: AllowScope::Deny,
             tasks: None,
+            pattern: None,
         };
         allowlist.entries.push(file_deny_entry);
 
@@ -1,5 +1,5 @@
 use crate::runner::{is_runner_available, is_runner_available_for_mcp};
-use crate::types::Task;
+use crate::types::{ShadowType, Task, TaskDefinitionType, TaskRunner};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -43,22 +43,9 @@ pub struct TaskDto {
 impl TaskDto {
     /// Convert from internal Task to TaskDto (legacy method for backward compatibility)
     /// This method provides basic fields without enrichment
-    #[allow(dead_code)]
     pub fn from_task(task: &Task) -> Self {
-        Self {
-            unique_name: task
-                .disambiguated_name
-                .as_ref()
-                .unwrap_or(&task.name)
-                .clone(),
-            source_name: task.source_name.clone(),
-            runner: task.runner.short_name().to_string(),
-            command: task.runner.get_command(task),
-            runner_available: is_runner_available(&task.runner),
-            allowlisted: false, // Default to false for legacy method
-            file_path: task.definition_path().to_string_lossy().to_string(),
-            description: task.description.clone(),
-        }
+        // Default to not allowlisted for the legacy method
+        Self::with_status(task, is_runner_available(&task.runner), false)
     }
 
     /// Convert from internal Task to TaskDto with enriched fields
@@ -67,6 +54,14 @@ impl TaskDto {
         task: &Task,
         allowlist_evaluator: &crate::mcp::allowlist::McpAllowlistEvaluator,
     ) -> Self {
+        Self::with_status(
+            task,
+            is_runner_available_for_mcp(&task.runner),
+            allowlist_evaluator.is_task_allowed(task).unwrap_or(false),
+        )
+    }
+
+    fn with_status(task: &Task, runner_available: bool, allowlisted: bool) -> Self {
         Self {
             unique_name: task
                 .disambiguated_name
@@ -76,19 +71,93 @@ impl TaskDto {
             source_name: task.source_name.clone(),
             runner: task.runner.short_name().to_string(),
             command: task.runner.get_command(task),
-            runner_available: is_runner_available_for_mcp(&task.runner),
-            allowlisted: allowlist_evaluator.is_task_allowed(task).unwrap_or(false),
+            runner_available,
+            allowlisted,
             file_path: task.definition_path().to_string_lossy().to_string(),
             description: task.description.clone(),
         }
     }
 }
 
+/// A single task with the metadata that list_tasks leaves out, returned by get_task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskDetailsDto {
+    #[serde(flatten)]
+    pub task: TaskDto,
+
+    /// What the task name collides with when typed in a shell, if anything
+    /// Examples: "shell builtin (zsh)", "executable /usr/bin/test", "shell alias (bash)"
+    pub shadowed_by: Option<String>,
+
+    /// Tasks run before this one, by the names used in its definition file
+    pub dependencies: Vec<String>,
+
+    /// Kind of file the task is defined in, as serialized by [`TaskDefinitionType`]
+    /// Examples: "makefile", "package_json", "github_actions"
+    #[schemars(with = "String")]
+    pub definition_type: TaskDefinitionType,
+}
+
+impl TaskDetailsDto {
+    /// Convert from internal Task to TaskDetailsDto with enriched fields
+    pub fn from_task_enriched(
+        task: &Task,
+        allowlist_evaluator: &crate::mcp::allowlist::McpAllowlistEvaluator,
+    ) -> Self {
+        Self {
+            task: TaskDto::from_task_enriched(task, allowlist_evaluator),
+            shadowed_by: task.shadowed_by.as_ref().map(|shadow| match shadow {
+                ShadowType::ShellBuiltin(shell) => format!("shell builtin ({})", shell),
+                ShadowType::PathExecutable(path) => format!("executable {}", path),
+                ShadowType::ShellAlias(shell) => format!("shell alias ({})", shell),
+            }),
+            dependencies: task.dependencies.clone(),
+            definition_type: task.definition_type.clone(),
+        }
+    }
+}
+
+/// A task runner dela supports, as returned by the list_runners MCP tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RunnerDto {
+    /// Short name of the runner, as used by runner filters
+    /// Examples: "make", "npm", "docker compose"
+    pub name: String,
+
+    /// Files, directories or glob patterns the runner's tasks are read from
+    pub definition_files: Vec<String>,
+
+    /// Whether the runner is installed on this machine
+    pub available: bool,
+}
+
+impl RunnerDto {
+    pub fn from_runner(runner: &TaskRunner) -> Self {
+        Self {
+            name: runner.short_name().to_string(),
+            definition_files: runner
+                .definition_files()
+                .iter()
+                .map(|file| file.to_string())
+                .collect(),
+            available: is_runner_available(runner),
+        }
+    }
+}
+
+/// Parameters for the get_task MCP tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GetTaskArgs {
+    /// The unique name of the task to describe
+    pub unique_name: String,
+}
+
 /// Parameters for the list_tasks MCP tool
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 pub struct ListTasksArgs {
     /// Optional runner filter - if provided, only return tasks for this runner
-    /// Examples: "make", "npm", "gradle", "poetry"
+    /// Case-insensitive. Examples: "make", "npm", "gradle", "poetry", or "node", "python"
+    /// and "gh" for a family of runners
     pub runner: Option<String>,
 }
 
@@ -111,6 +180,17 @@ mod tests {
             description: Some("Build the project".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Act
@@ -139,6 +219,17 @@ mod tests {
             description: Some("Run tests".to_string()),
             shadowed_by: None,
             disambiguated_name: Some("test-n".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Act
@@ -166,6 +257,17 @@ mod tests {
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Act
@@ -191,6 +293,7 @@ mod tests {
             (TaskRunner::PythonUv, "uv"),
             (TaskRunner::PythonPoetry, "poetry"),
             (TaskRunner::PythonPoe, "poe"),
+            (TaskRunner::PythonHatch, "hatch"),
             (TaskRunner::Task, "task"),
             (TaskRunner::Turbo, "turbo"),
             (TaskRunner::Maven, "mvn"),
@@ -199,6 +302,23 @@ mod tests {
             (TaskRunner::DockerCompose, "docker compose"),
             (TaskRunner::CMake, "cmake"),
             (TaskRunner::Just, "just"),
+            (TaskRunner::Tox, "tox"),
+            (TaskRunner::Nox, "nox"),
+            (TaskRunner::Invoke, "invoke"),
+            (TaskRunner::GitlabCi, "gitlab"),
+            (TaskRunner::Nx, "nx"),
+            (TaskRunner::Mise, "mise"),
+            (TaskRunner::Bazel, "bazel"),
+            (TaskRunner::Meson, "meson"),
+            (TaskRunner::Scons, "scons"),
+            (TaskRunner::PreCommit, "pre-commit"),
+            (TaskRunner::Ant, "ant"),
+            (TaskRunner::Sbt, "sbt"),
+            (TaskRunner::Leiningen, "lein"),
+            (TaskRunner::Mage, "mage"),
+            (TaskRunner::DockerBake, "bake"),
+            (TaskRunner::Docker, "dockerfile"),
+            (TaskRunner::Helm, "helm"),
         ];
 
         for (runner, expected_short_name) in test_cases {
@@ -213,6 +333,17 @@ mod tests {
                 description: None,
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             };
 
             // Act
@@ -227,7 +358,7 @@ mod tests {
             // Also verify the command is generated correctly
             assert!(
                 dto.command.contains(expected_short_name)
-                    || expected_short_name == "docker compose",
+                    || matches!(expected_short_name, "docker compose" | "dockerfile"),
                 "Command '{}' should contain runner '{}'",
                 dto.command,
                 expected_short_name
@@ -248,6 +379,17 @@ mod tests {
             description: Some("Start development server".to_string()),
             shadowed_by: None,
             disambiguated_name: Some("serve-n".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Act
@@ -277,6 +419,17 @@ mod tests {
             description: Some("Included task".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let dto = TaskDto::from_task(&task);
@@ -298,6 +451,17 @@ mod tests {
             description: Some("Run tests".to_string()),
             shadowed_by: None,
             disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let dto = TaskDto::from_task(&task);
@@ -324,6 +488,17 @@ mod tests {
                 description: Some("Run make tests".to_string()),
                 shadowed_by: None,
                 disambiguated_name: Some("test-m".to_string()),
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             },
             Task {
                 name: "test".to_string(),
@@ -335,6 +510,17 @@ mod tests {
                 description: Some("Run npm tests".to_string()),
                 shadowed_by: None,
                 disambiguated_name: Some("test-n".to_string()),
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             },
         ];
 
@@ -416,6 +602,17 @@ mod tests {
             description: Some("Build the project".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Create a mock allowlist evaluator
@@ -448,6 +645,7 @@ mod tests {
             (TaskRunner::PythonUv, "test", "uv run test"),
             (TaskRunner::PythonPoetry, "install", "poetry run install"),
             (TaskRunner::PythonPoe, "lint", "poe lint"),
+            (TaskRunner::PythonHatch, "test", "hatch run test"),
             (TaskRunner::Task, "deploy", "task deploy --"),
             (TaskRunner::Turbo, "build", "turbo run build"),
             (TaskRunner::Maven, "compile", "mvn compile"),
@@ -477,6 +675,17 @@ mod tests {
                 description: None,
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             };
 
             // Act
@@ -519,6 +728,17 @@ mod tests {
                 description: None,
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             };
 
             // Act
@@ -548,6 +768,17 @@ mod tests {
             description: Some("Run CI tests".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Create a mock allowlist evaluator
@@ -582,6 +813,17 @@ mod tests {
             description: Some("Build all targets".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let allowlist_evaluator = McpAllowlistEvaluator {
@@ -596,6 +838,49 @@ mod tests {
         );
         assert!(!dto.runner_available);
     }
+
+    #[test]
+    fn test_task_details_dto_flattens_task_fields() {
+        let mut task = Task {
+            name: "test".to_string(),
+            file_path: PathBuf::from("/project/Makefile"),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: "test".to_string(),
+            description: None,
+            shadowed_by: Some(ShadowType::ShellBuiltin("zsh".to_string())),
+            disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: vec!["build".to_string()],
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let evaluator = crate::mcp::allowlist::McpAllowlistEvaluator {
+            allowlist: crate::types::Allowlist::default(),
+        };
+
+        let json =
+            serde_json::to_value(TaskDetailsDto::from_task_enriched(&task, &evaluator)).unwrap();
+        assert_eq!(json["unique_name"], "test-m");
+        assert_eq!(json["shadowed_by"], "shell builtin (zsh)");
+        assert_eq!(json["dependencies"], serde_json::json!(["build"]));
+        assert_eq!(json["definition_type"], "makefile");
+
+        task.shadowed_by = Some(ShadowType::PathExecutable("/usr/bin/test".to_string()));
+        let details = TaskDetailsDto::from_task_enriched(&task, &evaluator);
+        assert_eq!(
+            details.shadowed_by.as_deref(),
+            Some("executable /usr/bin/test")
+        );
+    }
 }
 
 /// Arguments for the task_start tool
@@ -620,6 +905,22 @@ pub struct TaskStartArgs {
     /// Defaults to 1 second when omitted. Allowed range: 0-3600 seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_for_exit_seconds: Option<u64>,
+
+    /// Start a task that runs sudo. Refused by default because a password prompt
+    /// cannot be answered and would hang the task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sudo: Option<bool>,
+
+    /// Optional input written to the task's stdin, which is then closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<String>,
+}
+
+/// Arguments for the task_start_many tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskStartManyArgs {
+    /// The tasks to start, in order, each with the same options as task_start
+    pub tasks: Vec<TaskStartArgs>,
 }
 
 /// Result of starting a task
@@ -673,6 +974,40 @@ pub struct TaskStopArgs {
     pub grace_period: Option<u64>,
 }
 
+/// Arguments for the task_wait tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskWaitArgs {
+    /// The PID of the job to wait for
+    pub pid: u32,
+
+    /// How long to wait in seconds before returning a still-running job (default: 30, max: 3600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// Number of output lines to return from the end of the job's output (default: 200)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<usize>,
+}
+
+/// Arguments for the task_restart tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskRestartArgs {
+    /// The PID of the job to restart
+    pub pid: u32,
+
+    /// Grace period in seconds before sending SIGKILL (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grace_period: Option<u64>,
+
+    /// Optional bounded wait in seconds before backgrounding the new job, as for task_start
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_for_exit_seconds: Option<u64>,
+
+    /// Start the task again even though it runs sudo, as for task_start
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sudo: Option<bool>,
+}
+
 #[cfg(test)]
 mod task_start_args_tests {
     use super::TaskStartArgs;
@@ -685,6 +1020,8 @@ mod task_start_args_tests {
             env: None,
             cwd: None,
             wait_for_exit_seconds: Some(15),
+            allow_sudo: None,
+            stdin: None,
         };
 
         let json = serde_json::to_value(&args).unwrap();
@@ -701,6 +1038,8 @@ mod task_start_args_tests {
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         };
 
         let json = serde_json::to_value(&args).unwrap();
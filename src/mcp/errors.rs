@@ -21,6 +21,9 @@ impl DelaErrorCode {
     pub const RUNNER_UNAVAILABLE: Self = Self(-32011);
     pub const TASK_NOT_FOUND: Self = Self(-32012);
     pub const MCP_NOT_READY: Self = Self(-32013);
+    pub const REQUIRES_SUDO: Self = Self(-32014);
+    pub const TASK_DENIED: Self = Self(-32015);
+    pub const CONCURRENCY_LIMIT: Self = Self(-32016);
 }
 
 impl From<DelaErrorCode> for ErrorCode {
@@ -37,6 +40,11 @@ pub enum DelaError {
         task_name: String,
         hint: Option<String>,
     },
+    /// Task is blocked by a deny entry in the allowlist
+    TaskDenied {
+        task_name: String,
+        hint: Option<String>,
+    },
     /// Required task runner is not available on the system
     RunnerUnavailable {
         runner_name: String,
@@ -58,6 +66,13 @@ pub enum DelaError {
         message: String,
         hint: Option<String>,
     },
+    /// Task runs sudo, whose password prompt would hang with captured output
+    RequiresSudo {
+        task_name: String,
+        hint: Option<String>,
+    },
+    /// The job manager is already running as many jobs as it allows
+    ConcurrencyLimit { limit: usize, hint: Option<String> },
 }
 
 impl DelaError {
@@ -72,6 +87,11 @@ impl DelaError {
                 )),
                 data: hint.as_ref().map(|h| Value::String(h.clone())),
             },
+            DelaError::TaskDenied { task_name, hint } => ErrorData {
+                code: DelaErrorCode::TASK_DENIED.into(),
+                message: Cow::Owned(format!("Task '{}' is denied by the allowlist", task_name)),
+                data: hint.as_ref().map(|h| Value::String(h.clone())),
+            },
             DelaError::RunnerUnavailable {
                 runner_name,
                 task_name,
@@ -99,6 +119,22 @@ impl DelaError {
                 message: Cow::Owned(message.clone()),
                 data: hint.as_ref().map(|h| Value::String(h.clone())),
             },
+            DelaError::RequiresSudo { task_name, hint } => ErrorData {
+                code: DelaErrorCode::REQUIRES_SUDO.into(),
+                message: Cow::Owned(format!(
+                    "Task '{}' runs sudo and could hang on a password prompt",
+                    task_name
+                )),
+                data: hint.as_ref().map(|h| Value::String(h.clone())),
+            },
+            DelaError::ConcurrencyLimit { limit, hint } => ErrorData {
+                code: DelaErrorCode::CONCURRENCY_LIMIT.into(),
+                message: Cow::Owned(format!(
+                    "Concurrency limit exceeded: {} jobs are already running",
+                    limit
+                )),
+                data: hint.as_ref().map(|h| Value::String(h.clone())),
+            },
         }
     }
 
@@ -110,6 +146,14 @@ impl DelaError {
         }
     }
 
+    /// Create a TaskDenied error with a helpful hint
+    pub fn task_denied(task_name: String) -> Self {
+        DelaError::TaskDenied {
+            task_name,
+            hint: Some("A human has blocked this task; do not retry or work around it".to_string()),
+        }
+    }
+
     /// Create a RunnerUnavailable error with a helpful hint
     pub fn runner_unavailable(runner_name: String, task_name: String) -> Self {
         let hint = match runner_name.as_str() {
@@ -165,6 +209,28 @@ impl DelaError {
         }
     }
 
+    /// Create a RequiresSudo error with a helpful hint
+    pub fn requires_sudo(task_name: String) -> Self {
+        DelaError::RequiresSudo {
+            task_name,
+            hint: Some(
+                "Pass allow_sudo: true if sudo will not prompt, e.g. with NOPASSWD, or run the task via the dela CLI"
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Create a ConcurrencyLimit error with a helpful hint
+    pub fn concurrency_limit(limit: usize) -> Self {
+        DelaError::ConcurrencyLimit {
+            limit,
+            hint: Some(
+                "Wait for a running task to finish, or stop one with task_stop, then try again"
+                    .to_string(),
+            ),
+        }
+    }
+
     /// Create an InternalError with a helpful hint
     pub fn internal_error(message: String, hint: Option<String>) -> Self {
         DelaError::InternalError { message, hint }
@@ -210,6 +276,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_task_denied_error() {
+        let error = DelaError::task_denied("deploy".to_string());
+        let error_data = error.to_error_data();
+
+        assert_eq!(error_data.code.0, -32015);
+        assert_ne!(
+            error_data.code,
+            DelaError::not_allowlisted("deploy".to_string())
+                .to_error_data()
+                .code
+        );
+        assert!(error_data.message.contains("'deploy' is denied"));
+        assert!(
+            error_data
+                .data
+                .as_ref()
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .contains("blocked")
+        );
+    }
+
     #[test]
     fn test_runner_unavailable_error() {
         let error = DelaError::runner_unavailable("make".to_string(), "build".to_string());
@@ -337,4 +427,14 @@ mod tests {
                 .contains("dela init")
         );
     }
+
+    #[test]
+    fn test_concurrency_limit_error() {
+        let error = DelaError::concurrency_limit(50);
+        let error_data = error.to_error_data();
+
+        assert_eq!(error_data.code, DelaErrorCode::CONCURRENCY_LIMIT.into());
+        assert!(error_data.message.contains("50 jobs are already running"));
+        assert!(error_data.data.is_some());
+    }
 }
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::process::Child;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 
 /// Result of a graceful stop operation
 #[derive(Debug, Clone, PartialEq)]
@@ -32,7 +32,6 @@ pub struct JobMetadata {
     pub unique_name: String,
     pub source_name: String,
     pub args: Option<Vec<String>>,
-    #[allow(dead_code)]
     pub env: Option<std::collections::HashMap<String, String>>,
     pub cwd: Option<PathBuf>,
     pub command: String,
@@ -137,6 +136,8 @@ pub struct Job {
     pub elapsed_at_completion: Option<Duration>,
     pub output_buffer: RingBuffer,
     pub last_activity: Instant,
+    /// Woken when the job leaves the running state
+    finished: Arc<Notify>,
 }
 
 impl Job {
@@ -155,6 +156,7 @@ impl Job {
             elapsed_at_completion: None,
             output_buffer: RingBuffer::new(max_output_lines, max_output_bytes),
             last_activity: Instant::now(),
+            finished: Arc::new(Notify::new()),
         }
     }
 
@@ -169,6 +171,7 @@ impl Job {
         self.state = JobState::Exited(exit_code);
         self.completed_at = Some(Utc::now());
         self.touch();
+        self.finished.notify_waiters();
     }
 
     /// Mark the job as failed with the given error message
@@ -177,6 +180,7 @@ impl Job {
         self.state = JobState::Failed(error);
         self.completed_at = Some(Utc::now());
         self.touch();
+        self.finished.notify_waiters();
     }
 
     /// Add output to the job's ring buffer
@@ -221,9 +225,7 @@ pub struct JobManagerConfig {
     pub max_concurrent_jobs: usize,
     pub max_output_lines_per_job: usize,
     pub max_output_bytes_per_job: usize,
-    #[allow(dead_code)]
     pub job_ttl_seconds: u64,
-    #[allow(dead_code)]
     pub gc_interval_seconds: u64,
 }
 
@@ -239,18 +241,39 @@ impl Default for JobManagerConfig {
     }
 }
 
+impl JobManagerConfig {
+    /// The default configuration, with limits overridden by `DELA_MCP_MAX_JOBS`,
+    /// `DELA_MCP_JOB_TTL` and `DELA_MCP_GC_INTERVAL` (both in seconds) when set.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let defaults = Self::default();
+        let number = |name: &str| var(name).and_then(|value| value.trim().parse::<u64>().ok());
+        Self {
+            max_concurrent_jobs: number("DELA_MCP_MAX_JOBS")
+                .map_or(defaults.max_concurrent_jobs, |jobs| jobs as usize),
+            job_ttl_seconds: number("DELA_MCP_JOB_TTL").unwrap_or(defaults.job_ttl_seconds),
+            gc_interval_seconds: number("DELA_MCP_GC_INTERVAL")
+                .unwrap_or(defaults.gc_interval_seconds),
+            ..defaults
+        }
+    }
+}
+
 /// Manager for background jobs
 #[derive(Clone)]
 pub struct JobManager {
     jobs: Arc<RwLock<HashMap<u32, Job>>>,
     pub processes: Arc<RwLock<HashMap<u32, Child>>>,
     config: JobManagerConfig,
-    #[allow(dead_code)]
     last_gc: Arc<RwLock<Instant>>,
 }
 
 impl JobManager {
     /// Create a new job manager with default configuration
+    #[cfg(test)]
     pub fn new() -> Self {
         Self::with_config(JobManagerConfig::default())
     }
@@ -265,6 +288,11 @@ impl JobManager {
         }
     }
 
+    /// The limits this manager was created with
+    pub fn config(&self) -> &JobManagerConfig {
+        &self.config
+    }
+
     /// Check if we can start a new job (concurrency limit check)
     pub async fn can_start_job(&self) -> anyhow::Result<()> {
         self.garbage_collect().await;
@@ -354,6 +382,7 @@ impl JobManager {
                     self.config.max_output_bytes_per_job,
                 ),
                 last_activity: Instant::now(),
+                finished: Arc::new(Notify::new()),
             },
         );
         Ok(())
@@ -365,6 +394,33 @@ impl JobManager {
         jobs.get(&pid).cloned()
     }
 
+    /// Wait up to `timeout` for a job to stop running, then return it in whatever state
+    /// it is in. Returns `None` if there is no job with this PID.
+    pub async fn wait_for_job(&self, pid: u32, timeout: Duration) -> Option<Job> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let job = self.get_job(pid).await?;
+            if !job.is_running() {
+                return Some(job);
+            }
+
+            // Register for the wake-up before checking again, so a job finishing in
+            // between is not missed
+            let finished = Arc::clone(&job.finished);
+            let notified = finished.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            match self.get_job(pid).await {
+                Some(job) if job.is_running() => {}
+                job => return job,
+            }
+
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return self.get_job(pid).await;
+            }
+        }
+    }
+
     /// Get all jobs
     pub async fn get_all_jobs(&self) -> Vec<Job> {
         let jobs = self.jobs.read().await;
@@ -628,8 +684,7 @@ impl JobManager {
         }
     }
 
-    /// Run garbage collection to remove old jobs
-    #[allow(dead_code)]
+    /// Run garbage collection if the GC interval has passed since the last run
     pub async fn garbage_collect(&self) {
         let now = Instant::now();
 
@@ -641,37 +696,7 @@ impl JobManager {
             }
         }
 
-        let mut jobs = self.jobs.write().await;
-        let mut processes = self.processes.write().await;
-        let ttl = Duration::from_secs(self.config.job_ttl_seconds);
-
-        // Collect PIDs to remove
-        let mut pids_to_remove = Vec::new();
-
-        for (pid, job) in jobs.iter() {
-            let age = job.age();
-            let idle = job.idle_time();
-
-            // Keep jobs that are still running and not too old
-            if job.is_running() && age < ttl {
-                continue;
-            }
-
-            // Keep finished jobs that haven't been idle too long
-            if !job.is_running() && idle < Duration::from_secs(300) {
-                // 5 minutes
-                continue;
-            }
-
-            // Mark this job for removal
-            pids_to_remove.push(*pid);
-        }
-
-        // Remove jobs and processes
-        for pid in pids_to_remove {
-            jobs.remove(&pid);
-            processes.remove(&pid);
-        }
+        Self::remove_expired_jobs(&self.jobs, &self.processes, self.job_ttl()).await;
 
         // Update last GC time
         {
@@ -680,6 +705,53 @@ impl JobManager {
         }
     }
 
+    /// Spawn a task that removes expired jobs every `gc_interval_seconds` for as long as
+    /// this manager is alive. Does nothing outside a tokio runtime.
+    pub fn start_gc(&self) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let jobs = Arc::downgrade(&self.jobs);
+        let processes = Arc::downgrade(&self.processes);
+        let ttl = self.job_ttl();
+        let period = Duration::from_secs(self.config.gc_interval_seconds.max(1));
+
+        runtime.spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            // The first tick completes immediately
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let (Some(jobs), Some(processes)) = (jobs.upgrade(), processes.upgrade()) else {
+                    break;
+                };
+                Self::remove_expired_jobs(&jobs, &processes, ttl).await;
+            }
+        });
+    }
+
+    fn job_ttl(&self) -> Duration {
+        Duration::from_secs(self.config.job_ttl_seconds)
+    }
+
+    /// Remove jobs that have exited or failed and were started more than `ttl` ago.
+    /// Running jobs are kept however old they are.
+    async fn remove_expired_jobs(
+        jobs: &RwLock<HashMap<u32, Job>>,
+        processes: &RwLock<HashMap<u32, Child>>,
+        ttl: Duration,
+    ) {
+        let mut jobs = jobs.write().await;
+        let mut processes = processes.write().await;
+        jobs.retain(|pid, job| {
+            let expired = !job.is_running() && job.metadata.started_at.elapsed() >= ttl;
+            if expired {
+                processes.remove(pid);
+            }
+            !expired
+        });
+    }
+
     /// Get job statistics
     #[allow(dead_code)]
     pub async fn get_stats(&self) -> JobStats {
@@ -1017,4 +1089,118 @@ mod tests {
 
         let _ = manager.stop_job_graceful(pid, 0).await;
     }
+
+    #[tokio::test]
+    async fn test_wait_for_job_wakes_when_job_finishes() {
+        let manager = JobManager::new();
+
+        let mut cmd = Command::new("sleep");
+        cmd.arg("5");
+        let child = cmd.spawn().unwrap();
+        let pid = child.id().unwrap();
+
+        let metadata = JobMetadata {
+            started_at: Instant::now(),
+            unique_name: "test-task".to_string(),
+            source_name: "test".to_string(),
+            args: None,
+            env: None,
+            cwd: None,
+            command: "sleep 5".to_string(),
+            file_path: PathBuf::from("Makefile"),
+        };
+        manager.start_job(pid, metadata, child).await.unwrap();
+
+        // Times out while the job is still running
+        let job = manager
+            .wait_for_job(pid, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(job.is_running());
+
+        let started = Instant::now();
+        let (job, _) = tokio::join!(manager.wait_for_job(pid, Duration::from_secs(10)), async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            manager.stop_job_graceful(pid, 1).await.unwrap();
+        });
+        assert!(!job.unwrap().is_running());
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        assert!(
+            manager
+                .wait_for_job(999_999, Duration::from_secs(1))
+                .await
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_job_manager_config_from_vars() {
+        let vars = HashMap::from([
+            ("DELA_MCP_MAX_JOBS", "8"),
+            ("DELA_MCP_JOB_TTL", " 86400 "),
+            ("DELA_MCP_GC_INTERVAL", "soon"),
+        ]);
+        let config =
+            JobManagerConfig::from_vars(|name| vars.get(name).map(|value| value.to_string()));
+
+        let defaults = JobManagerConfig::default();
+        assert_eq!(config.max_concurrent_jobs, 8);
+        assert_eq!(config.job_ttl_seconds, 86400);
+        // Values that are not numbers fall back to the default
+        assert_eq!(config.gc_interval_seconds, defaults.gc_interval_seconds);
+        assert_eq!(
+            config.max_output_lines_per_job,
+            defaults.max_output_lines_per_job
+        );
+
+        let config = JobManagerConfig::from_vars(|_| None);
+        assert_eq!(config.max_concurrent_jobs, defaults.max_concurrent_jobs);
+        assert_eq!(config.job_ttl_seconds, defaults.job_ttl_seconds);
+    }
+
+    #[tokio::test]
+    async fn test_start_gc_removes_expired_jobs() {
+        let manager = JobManager::with_config(JobManagerConfig {
+            job_ttl_seconds: 0,
+            gc_interval_seconds: 1,
+            ..JobManagerConfig::default()
+        });
+        let metadata = |command: &str| JobMetadata {
+            started_at: Instant::now(),
+            unique_name: "test-task".to_string(),
+            source_name: "test".to_string(),
+            args: None,
+            env: None,
+            cwd: None,
+            command: command.to_string(),
+            file_path: PathBuf::from("Makefile"),
+        };
+
+        manager
+            .record_completed_job(1, metadata("echo test"), JobState::Exited(0))
+            .await
+            .unwrap();
+        manager
+            .record_completed_job(2, metadata("false"), JobState::Failed("boom".to_string()))
+            .await
+            .unwrap();
+
+        let child = Command::new("sleep").arg("5").spawn().unwrap();
+        let running_pid = child.id().unwrap();
+        manager
+            .start_job(running_pid, metadata("sleep 5"), child)
+            .await
+            .unwrap();
+
+        manager.start_gc();
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+
+        assert!(manager.get_job(1).await.is_none());
+        assert!(manager.get_job(2).await.is_none());
+        // Running jobs are never collected
+        assert!(manager.get_job(running_pid).await.is_some());
+
+        let _ = manager.stop_job_graceful(running_pid, 0).await;
+    }
 }
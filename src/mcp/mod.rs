@@ -1,5 +1,5 @@
-mod allowlist;
-mod dto;
+pub(crate) mod allowlist;
+pub(crate) mod dto;
 mod errors;
 mod job_manager;
 mod server;
@@ -10,7 +10,12 @@ pub use server::DelaMcpServer;
 /// Convenience runner for the CLI subcommand to ensure we actually
 /// serve MCP over stdio (no stdout noise).
 pub async fn run_stdio_server(root: std::path::PathBuf) -> Result<(), rmcp::model::ErrorData> {
-    DelaMcpServer::new(root).serve_stdio().await
+    let server = DelaMcpServer::new(root);
+    let server = match crate::job_registry::registry_path() {
+        Ok(path) => server.with_job_registry(path),
+        Err(_) => server,
+    };
+    server.serve_stdio().await
 }
 
 #[cfg(test)]
@@ -1,12 +1,15 @@
 use super::allowlist::McpAllowlistEvaluator;
 use super::dto::{
-    ListTasksArgs, StartResultDto, TaskDto, TaskOutputArgs, TaskStartArgs, TaskStatusArgs,
-    TaskStopArgs,
+    GetTaskArgs, ListTasksArgs, RunnerDto, StartResultDto, TaskDetailsDto, TaskDto, TaskOutputArgs,
+    TaskRestartArgs, TaskStartArgs, TaskStartManyArgs, TaskStatusArgs, TaskStopArgs, TaskWaitArgs,
 };
-use super::errors::DelaError;
-use super::job_manager::{JobManager, JobMetadata, JobState};
-use crate::runner::{is_runner_available_for_mcp, split_command_words};
+use super::errors::{DelaError, DelaErrorCode};
+use super::job_manager::{JobManager, JobManagerConfig, JobMetadata, JobState};
+use crate::allowlist::AllowlistMatch;
+use crate::job_registry::{self, RegisteredJob};
+use crate::runner::{is_runner_available_for_mcp, platform_command, split_command_words};
 use crate::task_discovery;
+use crate::types::TaskRunner;
 use chrono::SecondsFormat;
 use rmcp::{
     ServerHandler, ServiceExt,
@@ -18,7 +21,7 @@ use rmcp::{
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
-use tokio::io::{AsyncBufReadExt, BufReader, stdin, stdout};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, stdin, stdout};
 use tokio::process::Command;
 use tokio::sync::{OnceCell, RwLock};
 use tokio::time::{Duration, timeout};
@@ -26,6 +29,7 @@ use tokio::time::{Duration, timeout};
 const TASK_DISCOVERY_CACHE_TTL: Duration = Duration::from_secs(60);
 const DEFAULT_TASK_START_WAIT_SECONDS: u64 = 1;
 const MAX_TASK_START_WAIT_SECONDS: u64 = 3600;
+const DEFAULT_TASK_WAIT_SECONDS: u64 = 30;
 const OUTPUT_NOTIFICATION_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
 const OUTPUT_NOTIFICATION_MAX_BYTES: usize = 4 * 1024;
 const OUTPUT_NOTIFICATION_MAX_LINES: usize = 100;
@@ -152,6 +156,8 @@ pub struct DelaMcpServer {
     job_manager: JobManager,
     task_cache: Arc<RwLock<Option<CachedDiscoveredTasks>>>,
     task_cache_ttl: Duration,
+    /// Shared registry that background jobs are recorded in for `dela status`
+    job_registry: Option<PathBuf>,
     /// Peer connection for sending notifications (set during initialize)
     peer: Arc<OnceCell<Peer<RoleServer>>>,
 }
@@ -163,29 +169,48 @@ impl DelaMcpServer {
             McpAllowlistEvaluator::new().unwrap_or_else(|_| McpAllowlistEvaluator {
                 allowlist: crate::types::Allowlist::default(),
             });
-        Self::new_inner(root, allowlist_evaluator, TASK_DISCOVERY_CACHE_TTL)
+        Self::new_inner(
+            root,
+            allowlist_evaluator,
+            TASK_DISCOVERY_CACHE_TTL,
+            JobManagerConfig::from_env(),
+        )
     }
 
     fn new_inner(
         root: PathBuf,
         allowlist_evaluator: McpAllowlistEvaluator,
         task_cache_ttl: Duration,
+        job_config: JobManagerConfig,
     ) -> Self {
-        let job_manager = JobManager::new();
+        let job_manager = JobManager::with_config(job_config);
+        job_manager.start_gc();
         Self {
             root,
             allowlist_evaluator,
             job_manager,
             task_cache: Arc::new(RwLock::new(None)),
             task_cache_ttl,
+            job_registry: None,
             peer: Arc::new(OnceCell::new()),
         }
     }
 
+    /// Also record background jobs in the registry at `path`, so `dela status` can list them.
+    pub fn with_job_registry(mut self, path: PathBuf) -> Self {
+        self.job_registry = Some(path);
+        self
+    }
+
     /// Create a new MCP server instance with a custom allowlist evaluator (for testing)
     #[cfg(test)]
     pub fn new_with_allowlist(root: PathBuf, allowlist_evaluator: McpAllowlistEvaluator) -> Self {
-        Self::new_inner(root, allowlist_evaluator, TASK_DISCOVERY_CACHE_TTL)
+        Self::new_inner(
+            root,
+            allowlist_evaluator,
+            TASK_DISCOVERY_CACHE_TTL,
+            JobManagerConfig::default(),
+        )
     }
 
     #[cfg(test)]
@@ -194,7 +219,12 @@ impl DelaMcpServer {
         allowlist_evaluator: McpAllowlistEvaluator,
         task_cache_ttl: Duration,
     ) -> Self {
-        Self::new_inner(root, allowlist_evaluator, task_cache_ttl)
+        Self::new_inner(
+            root,
+            allowlist_evaluator,
+            task_cache_ttl,
+            JobManagerConfig::default(),
+        )
     }
 
     /// Send a logging notification to the client (if connected)
@@ -275,6 +305,25 @@ impl DelaMcpServer {
         }
     }
 
+    fn resolve_wait_timeout_seconds(timeout_secs: Option<u64>) -> Result<u64, ErrorData> {
+        match timeout_secs {
+            Some(seconds) if seconds <= MAX_TASK_START_WAIT_SECONDS => Ok(seconds),
+            Some(seconds) => Err(ErrorData {
+                code: super::errors::DelaErrorCode::INVALID_PARAMS.into(),
+                message: format!(
+                    "timeout_secs must be between 0 and {} seconds, got {}",
+                    MAX_TASK_START_WAIT_SECONDS, seconds
+                )
+                .into(),
+                data: Some(serde_json::Value::String(format!(
+                    "Call task_wait again to keep waiting, or omit the field to use the {}-second default.",
+                    DEFAULT_TASK_WAIT_SECONDS
+                ))),
+            }),
+            None => Ok(DEFAULT_TASK_WAIT_SECONDS),
+        }
+    }
+
     /// Send task output as a logging notification
     #[allow(dead_code)]
     async fn send_task_output(&self, pid: u32, output_type: &str, content: &str) {
@@ -362,7 +411,7 @@ impl DelaMcpServer {
         // Apply runner filtering if specified
         let mut tasks = discovered.tasks;
         if let Some(runner_filter) = &args.runner {
-            tasks.retain(|task| task.runner.short_name() == runner_filter);
+            tasks.retain(|task| task.runner.matches_filter(runner_filter));
         }
 
         // Convert to DTOs with enriched fields (command, runner_available, allowlisted)
@@ -379,6 +428,57 @@ impl DelaMcpServer {
         ]))
     }
 
+    #[tool(description = "List the task runners dela supports and whether each is installed")]
+    pub async fn list_runners(&self) -> Result<CallToolResult, ErrorData> {
+        let runners: Vec<RunnerDto> = TaskRunner::ALL.iter().map(RunnerDto::from_runner).collect();
+        Ok(CallToolResult::success(vec![
+            Content::json(serde_json::json!({
+                "runners": runners
+            }))
+            .expect("Failed to serialize JSON"),
+        ]))
+    }
+
+    #[tool(description = "Show the job limits and retention settings in effect")]
+    pub async fn admin_config(&self) -> Result<CallToolResult, ErrorData> {
+        let config = self.job_manager.config();
+        Ok(CallToolResult::success(vec![
+            Content::json(serde_json::json!({
+                "max_concurrent_jobs": config.max_concurrent_jobs,
+                "max_output_lines_per_job": config.max_output_lines_per_job,
+                "max_output_bytes_per_job": config.max_output_bytes_per_job,
+                "job_ttl_seconds": config.job_ttl_seconds,
+                "gc_interval_seconds": config.gc_interval_seconds,
+                "task_cache_ttl_seconds": self.task_cache_ttl.as_secs()
+            }))
+            .expect("Failed to serialize JSON"),
+        ]))
+    }
+
+    #[tool(description = "Get one task's full metadata, including dependencies and shadowing")]
+    pub async fn get_task(
+        &self,
+        Parameters(args): Parameters<GetTaskArgs>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let discovered = self.get_discovered_tasks().await;
+
+        let task = discovered
+            .tasks
+            .iter()
+            .find(|t| {
+                let unique_name = t.disambiguated_name.as_ref().unwrap_or(&t.name);
+                unique_name == &args.unique_name
+            })
+            .ok_or_else(|| DelaError::task_not_found(args.unique_name.clone()))?;
+
+        Ok(CallToolResult::success(vec![
+            Content::json(serde_json::json!({
+                "task": TaskDetailsDto::from_task_enriched(task, &self.allowlist_evaluator)
+            }))
+            .expect("Failed to serialize JSON"),
+        ]))
+    }
+
     #[tool(description = "List all running tasks with PIDs")]
     pub async fn status(&self) -> Result<CallToolResult, ErrorData> {
         // Get all running jobs
@@ -415,6 +515,51 @@ impl DelaMcpServer {
         &self,
         Parameters(args): Parameters<TaskStartArgs>,
     ) -> Result<CallToolResult, ErrorData> {
+        let start_result = self.start_task(args).await?;
+        Ok(CallToolResult::success(vec![
+            Content::json(&start_result).expect("Failed to serialize JSON"),
+        ]))
+    }
+
+    #[tool(description = "Start several tasks, reporting a result or error for each")]
+    pub async fn task_start_many(
+        &self,
+        Parameters(args): Parameters<TaskStartManyArgs>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut results = Vec::with_capacity(args.tasks.len());
+        // Started one after another so each start sees the jobs started before it
+        // when checking the concurrency limit
+        for task_args in args.tasks {
+            let unique_name = task_args.unique_name.clone();
+            let result = match self.start_task(task_args).await {
+                Ok(start_result) => {
+                    let mut result =
+                        serde_json::to_value(&start_result).expect("Failed to serialize JSON");
+                    result["unique_name"] = serde_json::Value::String(unique_name);
+                    result
+                }
+                Err(error) => serde_json::json!({
+                    "unique_name": unique_name,
+                    "state": "error",
+                    "concurrency_limited": error.code == DelaErrorCode::CONCURRENCY_LIMIT.into(),
+                    "error": {
+                        "code": error.code.0,
+                        "message": error.message,
+                        "data": error.data
+                    }
+                }),
+            };
+            results.push(result);
+        }
+
+        Ok(CallToolResult::success(vec![
+            Content::json(serde_json::json!({ "results": results }))
+                .expect("Failed to serialize JSON"),
+        ]))
+    }
+
+    /// Start one task for task_start or task_start_many
+    async fn start_task(&self, args: TaskStartArgs) -> Result<StartResultDto, ErrorData> {
         let discovered = self.get_discovered_tasks().await;
 
         let task = discovered
@@ -427,18 +572,19 @@ impl DelaMcpServer {
             .ok_or_else(|| DelaError::task_not_found(args.unique_name.clone()))?;
 
         // Check if task is allowlisted for MCP execution
-        let is_allowed = self
-            .allowlist_evaluator
-            .is_task_allowed(task)
-            .map_err(|e| {
-                DelaError::internal_error(
-                    format!("MCP allowlist check failed: {}", e),
-                    Some("Check allowlist configuration".to_string()),
-                )
-            })?;
+        match self.allowlist_evaluator.evaluate(task) {
+            AllowlistMatch::Allowed => {}
+            AllowlistMatch::Denied => {
+                return Err(DelaError::task_denied(args.unique_name.clone()).into());
+            }
+            AllowlistMatch::NotFound => {
+                return Err(DelaError::not_allowlisted(args.unique_name.clone()).into());
+            }
+        }
 
-        if !is_allowed {
-            return Err(DelaError::not_allowlisted(args.unique_name.clone()).into());
+        // A sudo password prompt on captured output never completes
+        if task.requires_sudo && args.allow_sudo != Some(true) {
+            return Err(DelaError::requires_sudo(args.unique_name.clone()).into());
         }
 
         // Check if runner is available
@@ -451,11 +597,8 @@ impl DelaMcpServer {
         }
 
         // Check concurrency limits before starting the process
-        self.job_manager.can_start_job().await.map_err(|e| {
-            DelaError::internal_error(
-                format!("Concurrency limit exceeded: {}", e),
-                Some("Too many concurrent jobs running".to_string()),
-            )
+        self.job_manager.can_start_job().await.map_err(|_| {
+            DelaError::concurrency_limit(self.job_manager.config().max_concurrent_jobs)
         })?;
 
         // Build the command
@@ -467,6 +610,7 @@ impl DelaMcpServer {
             )
         })?;
 
+        let command_parts = platform_command(command_parts);
         let mut command_iter = command_parts.iter();
         let executable = command_iter
             .next()
@@ -485,13 +629,16 @@ impl DelaMcpServer {
         // Ensure we capture stdout and stderr properly
         cmd.stdout(std::process::Stdio::piped());
         cmd.stderr(std::process::Stdio::piped());
+        if args.stdin.is_some() {
+            cmd.stdin(std::process::Stdio::piped());
+        }
 
         // Add the task name as the first argument
         cmd.args(base_args);
 
         // Add task-specific arguments
         if let Some(task_args) = &args.args {
-            cmd.args(task_args);
+            cmd.args(task.runner.task_arguments(task_args));
         }
 
         // Set environment variables
@@ -518,6 +665,14 @@ impl DelaMcpServer {
 
         let pid = child.id().unwrap_or(0) as i32;
 
+        // Feed the input in the background so a task that produces output before
+        // reading all of it cannot deadlock against us; dropping the handle closes stdin
+        if let (Some(input), Some(mut stdin_handle)) = (args.stdin.clone(), child.stdin.take()) {
+            tokio::spawn(async move {
+                let _ = stdin_handle.write_all(input.as_bytes()).await;
+            });
+        }
+
         // Take stdout/stderr handles for streaming
         let stdout_handle = child.stdout.take();
         let stderr_handle = child.stderr.take();
@@ -742,8 +897,13 @@ impl DelaMcpServer {
         )
         .await;
 
-        // Check if process exited during initial capture
-        let process_exited = child.try_wait().is_ok_and(|status| status.is_some());
+        // Check if process exited during initial capture. A capture that ended early saw
+        // the output streams close, which happens just before the process exits.
+        let process_exited = child.try_wait().is_ok_and(|status| status.is_some())
+            || (started_at.elapsed() < capture_duration
+                && timeout(Duration::from_millis(100), child.wait())
+                    .await
+                    .is_ok());
 
         if process_exited {
             // Process completed within 1 second
@@ -814,9 +974,7 @@ impl DelaMcpServer {
                 initial_output: output,
             };
 
-            return Ok(CallToolResult::success(vec![
-                Content::json(&start_result).expect("Failed to serialize JSON"),
-            ]));
+            return Ok(start_result);
         }
 
         // Process is still running - set up background monitoring
@@ -834,6 +992,15 @@ impl DelaMcpServer {
             file_path: task.definition_path().to_path_buf(),
         };
 
+        let registered_job = RegisteredJob {
+            pid: pid as u32,
+            task: args.unique_name.clone(),
+            command: metadata.command.clone(),
+            cwd: metadata.cwd.clone().unwrap_or_else(|| self.root.clone()),
+            log_path: None,
+            started_at: chrono::Utc::now().to_rfc3339(),
+        };
+
         // Start background job management
         self.job_manager
             .start_job(pid as u32, metadata, child)
@@ -858,6 +1025,13 @@ impl DelaMcpServer {
                 })?;
         }
 
+        // The registry is only for visibility; failing to update it should not fail the start.
+        if let Some(registry) = &self.job_registry
+            && let Err(error) = job_registry::register_job(registry, registered_job)
+        {
+            tracing::warn!("Failed to record job in registry: {}", error);
+        }
+
         // Spawn background monitoring task with continued output streaming
         let job_manager = self.job_manager.clone();
         let peer_for_monitor = peer_clone;
@@ -1025,9 +1199,7 @@ impl DelaMcpServer {
             initial_output: output,
         };
 
-        Ok(CallToolResult::success(vec![
-            Content::json(&start_result).expect("Failed to serialize JSON"),
-        ]))
+        Ok(start_result)
     }
 
     #[tool(description = "Status for a single unique_name (may have multiple PIDs)")]
@@ -1226,6 +1398,90 @@ impl DelaMcpServer {
             .expect("Failed to serialize JSON"),
         ]))
     }
+
+    #[tool(description = "Wait for a PID to exit and return its final state and output")]
+    pub async fn task_wait(
+        &self,
+        Parameters(args): Parameters<TaskWaitArgs>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let timeout_secs = Self::resolve_wait_timeout_seconds(args.timeout_secs)?;
+        let job = self
+            .job_manager
+            .wait_for_job(args.pid, Duration::from_secs(timeout_secs))
+            .await
+            .ok_or_else(|| DelaError::task_not_found(format!("Job with PID {}", args.pid)))?;
+
+        // A job still running after the timeout is reported as such, not as an error
+        let (state, exit_code, error) = match &job.state {
+            JobState::Running => ("running", None, None),
+            JobState::Exited(code) => ("exited", Some(*code), None),
+            JobState::Failed(error) => ("failed", None, Some(error.clone())),
+        };
+        let completed_at = job
+            .completed_at
+            .as_ref()
+            .map(|timestamp| timestamp.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        Ok(CallToolResult::success(vec![
+            Content::json(serde_json::json!({
+                "pid": job.pid,
+                "unique_name": job.metadata.unique_name,
+                "state": state,
+                "exit_code": exit_code,
+                "error": error,
+                "elapsed_seconds": job.age().as_secs(),
+                "completed_at": completed_at,
+                "lines": job.get_output_lines(Some(args.lines.unwrap_or(200)))
+            }))
+            .expect("Failed to serialize JSON"),
+        ]))
+    }
+
+    #[tool(description = "Stop a PID and start its task again with the same arguments")]
+    pub async fn task_restart(
+        &self,
+        Parameters(args): Parameters<TaskRestartArgs>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let job = self
+            .job_manager
+            .get_job(args.pid)
+            .await
+            .ok_or_else(|| DelaError::task_not_found(format!("Job with PID {}", args.pid)))?;
+
+        // A job that already finished is simply started again
+        if job.is_running() {
+            let grace_period = args.grace_period.unwrap_or(5); // Default 5 seconds
+            let stop_result = self
+                .job_manager
+                .stop_job_graceful(args.pid, grace_period)
+                .await
+                .map_err(|e| {
+                    DelaError::internal_error(
+                        format!("Failed to stop job: {}", e),
+                        Some("Job management error".to_string()),
+                    )
+                })?;
+            if let crate::mcp::job_manager::StopResult::Failed(reason) = stop_result {
+                return Err(DelaError::internal_error(
+                    format!("Failed to stop process: {}", reason),
+                    Some("The task was not started again".to_string()),
+                )
+                .into());
+            }
+        }
+
+        let metadata = job.metadata;
+        self.task_start(Parameters(TaskStartArgs {
+            unique_name: metadata.unique_name,
+            args: metadata.args,
+            env: metadata.env,
+            cwd: metadata.cwd.map(|cwd| cwd.to_string_lossy().into_owned()),
+            wait_for_exit_seconds: args.wait_for_exit_seconds,
+            allow_sudo: args.allow_sudo,
+            stdin: None,
+        }))
+        .await
+    }
 }
 
 impl ServerHandler for DelaMcpServer {
@@ -1280,10 +1536,42 @@ impl ServerHandler for DelaMcpServer {
                 })?;
                 self.list_tasks(Parameters(args)).await
             }
+            "get_task" => {
+                let args: GetTaskArgs = serde_json::from_value(serde_json::Value::Object(
+                    request.arguments.unwrap_or_default(),
+                ))
+                .map_err(|e| {
+                    DelaError::internal_error(
+                        format!("Invalid arguments: {}", e),
+                        Some("Check argument format and types".to_string()),
+                    )
+                })?;
+                self.get_task(Parameters(args)).await
+            }
+            "task_start_many" => {
+                let args: TaskStartManyArgs = serde_json::from_value(serde_json::Value::Object(
+                    request.arguments.unwrap_or_default(),
+                ))
+                .map_err(|e| {
+                    DelaError::internal_error(
+                        format!("Invalid arguments: {}", e),
+                        Some("Check argument format and types".to_string()),
+                    )
+                })?;
+                self.task_start_many(Parameters(args)).await
+            }
             "status" => {
                 // Status tool takes no arguments
                 self.status().await
             }
+            "admin_config" => {
+                // Admin config tool takes no arguments
+                self.admin_config().await
+            }
+            "list_runners" => {
+                // List runners tool takes no arguments
+                self.list_runners().await
+            }
             "task_start" => {
                 let args: TaskStartArgs = serde_json::from_value(serde_json::Value::Object(
                     request.arguments.unwrap_or_default(),
@@ -1332,6 +1620,30 @@ impl ServerHandler for DelaMcpServer {
                 })?;
                 self.task_stop(Parameters(args)).await
             }
+            "task_wait" => {
+                let args: TaskWaitArgs = serde_json::from_value(serde_json::Value::Object(
+                    request.arguments.unwrap_or_default(),
+                ))
+                .map_err(|e| {
+                    DelaError::internal_error(
+                        format!("Invalid arguments: {}", e),
+                        Some("Check argument format and types".to_string()),
+                    )
+                })?;
+                self.task_wait(Parameters(args)).await
+            }
+            "task_restart" => {
+                let args: TaskRestartArgs = serde_json::from_value(serde_json::Value::Object(
+                    request.arguments.unwrap_or_default(),
+                ))
+                .map_err(|e| {
+                    DelaError::internal_error(
+                        format!("Invalid arguments: {}", e),
+                        Some("Check argument format and types".to_string()),
+                    )
+                })?;
+                self.task_restart(Parameters(args)).await
+            }
             _ => Err(DelaError::internal_error(
                 format!("Tool not found: {}", request.name),
                 Some("Use 'list_tools' to see available tools".to_string()),
@@ -1369,6 +1681,35 @@ impl ServerHandler for DelaMcpServer {
             serde_json::Value::Object(list_tasks_properties),
         );
 
+        // Schema for get_task
+        let mut get_task_schema = Map::new();
+        get_task_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        let mut get_task_properties = Map::new();
+        let mut get_task_name_prop = Map::new();
+        get_task_name_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("string".to_string()),
+        );
+        get_task_name_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String("The unique name of the task to describe".to_string()),
+        );
+        get_task_properties.insert(
+            "unique_name".to_string(),
+            serde_json::Value::Object(get_task_name_prop),
+        );
+        get_task_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(get_task_properties),
+        );
+        get_task_schema.insert(
+            "required".to_string(),
+            serde_json::Value::Array(vec![serde_json::Value::String("unique_name".to_string())]),
+        );
+
         // Schema for task_start
         let mut task_start_schema = Map::new();
         task_start_schema.insert(
@@ -1477,6 +1818,37 @@ impl ServerHandler for DelaMcpServer {
             serde_json::Value::Object(wait_for_exit_seconds_prop),
         );
 
+        // allow_sudo (optional)
+        let mut allow_sudo_prop = Map::new();
+        allow_sudo_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("boolean".to_string()),
+        );
+        allow_sudo_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(
+                "Start a task that runs sudo. Refused by default because a password prompt would hang the task.".to_string(),
+            ),
+        );
+        task_start_properties.insert(
+            "allow_sudo".to_string(),
+            serde_json::Value::Object(allow_sudo_prop),
+        );
+
+        // stdin (optional)
+        let mut stdin_prop = Map::new();
+        stdin_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("string".to_string()),
+        );
+        stdin_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(
+                "Optional input written to the task's stdin, which is then closed".to_string(),
+            ),
+        );
+        task_start_properties.insert("stdin".to_string(), serde_json::Value::Object(stdin_prop));
+
         task_start_schema.insert(
             "properties".to_string(),
             serde_json::Value::Object(task_start_properties),
@@ -1486,6 +1858,40 @@ impl ServerHandler for DelaMcpServer {
             serde_json::Value::Array(vec![serde_json::Value::String("unique_name".to_string())]),
         );
 
+        // Schema for task_start_many: a list of task_start arguments
+        let mut task_start_many_schema = Map::new();
+        task_start_many_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        let mut tasks_prop = Map::new();
+        tasks_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("array".to_string()),
+        );
+        tasks_prop.insert(
+            "items".to_string(),
+            serde_json::Value::Object(task_start_schema.clone()),
+        );
+        tasks_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(
+                "The tasks to start, in order, each with the same options as task_start"
+                    .to_string(),
+            ),
+        );
+        let mut task_start_many_properties = Map::new();
+        task_start_many_properties
+            .insert("tasks".to_string(), serde_json::Value::Object(tasks_prop));
+        task_start_many_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(task_start_many_properties),
+        );
+        task_start_many_schema.insert(
+            "required".to_string(),
+            serde_json::Value::Array(vec![serde_json::Value::String("tasks".to_string())]),
+        );
+
         // Schema for status (no arguments)
         let mut status_schema = Map::new();
         status_schema.insert(
@@ -1497,6 +1903,28 @@ impl ServerHandler for DelaMcpServer {
             serde_json::Value::Object(Map::new()),
         );
 
+        // Schema for admin_config (no arguments)
+        let mut admin_config_schema = Map::new();
+        admin_config_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        admin_config_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(Map::new()),
+        );
+
+        // Schema for list_runners (no arguments)
+        let mut list_runners_schema = Map::new();
+        list_runners_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        list_runners_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(Map::new()),
+        );
+
         // Schema for task_status
         let mut task_status_schema = Map::new();
         task_status_schema.insert(
@@ -1627,27 +2055,151 @@ impl ServerHandler for DelaMcpServer {
             serde_json::Value::Array(vec![serde_json::Value::String("pid".to_string())]),
         );
 
-        let tools = vec![
-            Tool::new_with_raw("list_tasks", Some("List tasks".into()), list_tasks_schema),
-            Tool::new_with_raw(
-                "status",
-                Some("List all running tasks with PIDs".into()),
-                status_schema,
-            ),
-            Tool::new_with_raw(
-                "task_start",
-                Some(
-                    "Start a task (default 1s capture, optional bounded wait, then background)"
-                        .into(),
-                ),
-                task_start_schema,
-            ),
-            Tool::new_with_raw(
-                "task_status",
-                Some("Status for a single unique_name (may have multiple PIDs)".into()),
-                task_status_schema,
-            ),
-            Tool::new_with_raw(
+        // Schema for task_wait
+        let mut task_wait_schema = Map::new();
+        task_wait_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        let mut task_wait_properties = Map::new();
+        let mut task_wait_pid_prop = Map::new();
+        task_wait_pid_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("integer".to_string()),
+        );
+        task_wait_pid_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String("The PID of the job to wait for".to_string()),
+        );
+        task_wait_properties.insert(
+            "pid".to_string(),
+            serde_json::Value::Object(task_wait_pid_prop),
+        );
+        let mut task_wait_timeout_prop = Map::new();
+        task_wait_timeout_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("integer".to_string()),
+        );
+        task_wait_timeout_prop.insert("minimum".to_string(), serde_json::Value::Number(0.into()));
+        task_wait_timeout_prop.insert(
+            "maximum".to_string(),
+            serde_json::Value::Number(MAX_TASK_START_WAIT_SECONDS.into()),
+        );
+        task_wait_timeout_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(format!(
+                "How long to wait in seconds before returning a still-running job (default: {})",
+                DEFAULT_TASK_WAIT_SECONDS
+            )),
+        );
+        task_wait_properties.insert(
+            "timeout_secs".to_string(),
+            serde_json::Value::Object(task_wait_timeout_prop),
+        );
+        let mut task_wait_lines_prop = Map::new();
+        task_wait_lines_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("integer".to_string()),
+        );
+        task_wait_lines_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(
+                "Number of output lines to return from the end of the job's output (default: 200)"
+                    .to_string(),
+            ),
+        );
+        task_wait_properties.insert(
+            "lines".to_string(),
+            serde_json::Value::Object(task_wait_lines_prop),
+        );
+        task_wait_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(task_wait_properties),
+        );
+        task_wait_schema.insert(
+            "required".to_string(),
+            serde_json::Value::Array(vec![serde_json::Value::String("pid".to_string())]),
+        );
+
+        // Schema for task_restart: the stop options of task_stop and the start options of task_start
+        let mut task_restart_schema = Map::new();
+        task_restart_schema.insert(
+            "type".to_string(),
+            serde_json::Value::String("object".to_string()),
+        );
+        let mut task_restart_properties = Map::new();
+        let mut task_restart_pid_prop = Map::new();
+        task_restart_pid_prop.insert(
+            "type".to_string(),
+            serde_json::Value::String("integer".to_string()),
+        );
+        task_restart_pid_prop.insert(
+            "description".to_string(),
+            serde_json::Value::String("The PID of the job to restart".to_string()),
+        );
+        task_restart_properties.insert(
+            "pid".to_string(),
+            serde_json::Value::Object(task_restart_pid_prop),
+        );
+        for (schema, name) in [
+            (&task_stop_schema, "grace_period"),
+            (&task_start_schema, "wait_for_exit_seconds"),
+            (&task_start_schema, "allow_sudo"),
+        ] {
+            if let Some(prop) = schema.get("properties").and_then(|props| props.get(name)) {
+                task_restart_properties.insert(name.to_string(), prop.clone());
+            }
+        }
+        task_restart_schema.insert(
+            "properties".to_string(),
+            serde_json::Value::Object(task_restart_properties),
+        );
+        task_restart_schema.insert(
+            "required".to_string(),
+            serde_json::Value::Array(vec![serde_json::Value::String("pid".to_string())]),
+        );
+
+        let tools = vec![
+            Tool::new_with_raw("list_tasks", Some("List tasks".into()), list_tasks_schema),
+            Tool::new_with_raw(
+                "get_task",
+                Some("Get one task's full metadata, including dependencies and shadowing".into()),
+                get_task_schema,
+            ),
+            Tool::new_with_raw(
+                "status",
+                Some("List all running tasks with PIDs".into()),
+                status_schema,
+            ),
+            Tool::new_with_raw(
+                "admin_config",
+                Some("Show the job limits and retention settings in effect".into()),
+                admin_config_schema,
+            ),
+            Tool::new_with_raw(
+                "list_runners",
+                Some("List the task runners dela supports and whether each is installed".into()),
+                list_runners_schema,
+            ),
+            Tool::new_with_raw(
+                "task_start",
+                Some(
+                    "Start a task (default 1s capture, optional bounded wait, then background)"
+                        .into(),
+                ),
+                task_start_schema,
+            ),
+            Tool::new_with_raw(
+                "task_start_many",
+                Some("Start several tasks, reporting a result or error for each".into()),
+                task_start_many_schema,
+            ),
+            Tool::new_with_raw(
+                "task_status",
+                Some("Status for a single unique_name (may have multiple PIDs)".into()),
+                task_status_schema,
+            ),
+            Tool::new_with_raw(
                 "task_output",
                 Some("Tail last N lines for a PID".into()),
                 task_output_schema,
@@ -1657,6 +2209,16 @@ impl ServerHandler for DelaMcpServer {
                 Some("Stop a PID with graceful timeout".into()),
                 task_stop_schema,
             ),
+            Tool::new_with_raw(
+                "task_wait",
+                Some("Wait for a PID to exit and return its final state and output".into()),
+                task_wait_schema,
+            ),
+            Tool::new_with_raw(
+                "task_restart",
+                Some("Stop a PID and start its task again with the same arguments".into()),
+                task_restart_schema,
+            ),
         ];
 
         Ok(ListToolsResult {
@@ -2820,36 +3382,54 @@ test:
     }
 
     #[tokio::test]
-    async fn test_list_tasks_runner_filter_case_sensitivity() {
+    async fn test_list_tasks_runner_filter_ignores_case_and_accepts_aliases() {
         use std::fs;
         use tempfile::TempDir;
 
         // Arrange
         let temp_dir = TempDir::new().unwrap();
         let temp_path = temp_dir.path();
-
-        // Create a Makefile
-        let makefile_content = r#"build:
-	echo "Building"
-"#;
-        fs::write(temp_path.join("Makefile"), makefile_content).unwrap();
+        fs::write(temp_path.join("Makefile"), "build:\n\techo \"Building\"\n").unwrap();
+        fs::write(
+            temp_path.join("package.json"),
+            r#"{"scripts": {"start": "node server.js"}}"#,
+        )
+        .unwrap();
+        fs::write(temp_path.join("package-lock.json"), "{}").unwrap();
 
         let server = DelaMcpServer::new(temp_path.to_path_buf());
+        let runners_for = |result: &CallToolResult| -> Vec<String> {
+            let json = match &result.content[0].raw {
+                RawContent::Text(text_content) => {
+                    serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+                }
+                _ => panic!("Expected text content with JSON"),
+            };
+            json["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|task| task["runner"].as_str().unwrap().to_string())
+                .collect()
+        };
 
-        // Act & Assert - Test exact match
-        let exact_args = Parameters(ListTasksArgs {
-            runner: Some("make".to_string()),
-        });
-        let exact_result = server.list_tasks(exact_args).await.unwrap();
-        assert_eq!(exact_result.content.len(), 1);
-
-        // Act & Assert - Test case mismatch (should return empty)
-        let case_args = Parameters(ListTasksArgs {
-            runner: Some("MAKE".to_string()),
-        });
-        let case_result = server.list_tasks(case_args).await.unwrap();
-        assert_eq!(case_result.content.len(), 1);
-        // Should return empty tasks array since "MAKE" != "make"
+        // Act & Assert
+        for (filter, expected) in [
+            ("make", vec!["make"]),
+            ("MAKE", vec!["make"]),
+            ("Make", vec!["make"]),
+            ("node", vec!["npm"]),
+            ("nodejs", vec!["npm"]),
+            ("python", vec![]),
+        ] {
+            let result = server
+                .list_tasks(Parameters(ListTasksArgs {
+                    runner: Some(filter.to_string()),
+                }))
+                .await
+                .unwrap();
+            assert_eq!(runners_for(&result), expected, "filter {}", filter);
+        }
     }
 
     #[tokio::test]
@@ -3017,6 +3597,8 @@ test:
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         // Act
@@ -3054,6 +3636,7 @@ add_custom_target(build-all COMMENT "Build everything")
                     path: cmake_path,
                     scope: crate::types::AllowScope::File,
                     tasks: None,
+                    pattern: None,
                 }],
             },
         };
@@ -3066,6 +3649,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         let result = server.task_start(args).await;
@@ -3104,6 +3689,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
         let result = server.task_start(args).await;
         assert!(result.is_err());
@@ -3180,6 +3767,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         // Act
@@ -3235,6 +3824,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         // Act
@@ -3278,6 +3869,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: Some(env_vars),
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         // Act
@@ -3318,6 +3911,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: Some(temp_path.to_string_lossy().to_string()),
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         });
 
         // Act
@@ -3357,6 +3952,7 @@ add_custom_target(build-all COMMENT "Build everything")
                     path: script_path.clone(),
                     scope: crate::types::AllowScope::File,
                     tasks: None,
+                    pattern: None,
                 }],
             },
         };
@@ -3369,6 +3965,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: Some(3),
+            allow_sudo: None,
+            stdin: None,
         });
 
         let result = server.task_start(args).await.unwrap();
@@ -3459,6 +4057,7 @@ add_custom_target(build-all COMMENT "Build everything")
                     path: script_path.clone(),
                     scope: crate::types::AllowScope::File,
                     tasks: None,
+                    pattern: None,
                 }],
             },
         };
@@ -3471,6 +4070,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: Some(2),
+            allow_sudo: None,
+            stdin: None,
         });
 
         let result = server.task_start(args).await.unwrap();
@@ -3546,6 +4147,7 @@ add_custom_target(build-all COMMENT "Build everything")
                     path: script_path.clone(),
                     scope: crate::types::AllowScope::File,
                     tasks: None,
+                    pattern: None,
                 }],
             },
         };
@@ -3559,6 +4161,8 @@ add_custom_target(build-all COMMENT "Build everything")
                 env: None,
                 cwd: None,
                 wait_for_exit_seconds: Some(MAX_TASK_START_WAIT_SECONDS + 1),
+                allow_sudo: None,
+                stdin: None,
             }))
             .await;
 
@@ -3592,6 +4196,7 @@ add_custom_target(build-all COMMENT "Build everything")
                 path: script_path.clone(),
                 scope: crate::types::AllowScope::File,
                 tasks: None,
+                pattern: None,
             }],
         };
         let allowlist_evaluator = McpAllowlistEvaluator {
@@ -3608,6 +4213,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         };
 
         let start_result = server.task_start(Parameters(start_args)).await;
@@ -3791,6 +4398,7 @@ add_custom_target(build-all COMMENT "Build everything")
                 path: makefile_path.clone(),
                 scope: crate::types::AllowScope::File,
                 tasks: None,
+                pattern: None,
             }],
         };
         let allowlist_evaluator = McpAllowlistEvaluator {
@@ -3806,6 +4414,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         };
         let start_response = server.task_start(Parameters(start_args)).await.unwrap();
 
@@ -3893,6 +4503,7 @@ add_custom_target(build-all COMMENT "Build everything")
                 path: script_path.clone(),
                 scope: crate::types::AllowScope::File,
                 tasks: None,
+                pattern: None,
             }],
         };
         let allowlist_evaluator = McpAllowlistEvaluator {
@@ -3908,6 +4519,8 @@ add_custom_target(build-all COMMENT "Build everything")
             env: None,
             cwd: None,
             wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
         };
         let start_response = server.task_start(Parameters(start_args)).await.unwrap();
 
@@ -4087,4 +4700,477 @@ add_custom_target(build-all COMMENT "Build everything")
         assert!(instructions.contains("wait_for_exit_seconds"));
         assert!(instructions.contains("default 1-second capture window"));
     }
+
+    #[tokio::test]
+    async fn test_task_start_refuses_sudo_task_without_override() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let procfile_path = temp_dir.path().join("Procfile");
+        std::fs::write(&procfile_path, "setup: sudo apt-get install -y jq\n").unwrap();
+
+        let allowlist_evaluator = McpAllowlistEvaluator {
+            allowlist: crate::types::Allowlist {
+                entries: vec![crate::types::AllowlistEntry {
+                    path: procfile_path,
+                    scope: crate::types::AllowScope::File,
+                    tasks: None,
+                    pattern: None,
+                }],
+            },
+        };
+        let server =
+            DelaMcpServer::new_with_allowlist(temp_dir.path().to_path_buf(), allowlist_evaluator);
+
+        let args = Parameters(TaskStartArgs {
+            unique_name: "setup".to_string(),
+            args: None,
+            env: None,
+            cwd: None,
+            wait_for_exit_seconds: None,
+            allow_sudo: None,
+            stdin: None,
+        });
+
+        let error = server.task_start(args).await.unwrap_err();
+        assert_eq!(error.code.0, -32014); // REQUIRES_SUDO
+        assert!(error.message.contains("runs sudo"));
+        assert!(error.data.unwrap().as_str().unwrap().contains("allow_sudo"));
+    }
+
+    #[tokio::test]
+    async fn test_task_restart_reuses_start_arguments() {
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("restartable_task.sh");
+        std::fs::write(
+            &script_path,
+            "#!/bin/bash\necho \"Serving $GREETING\"\nsleep 5\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let allowlist_evaluator = McpAllowlistEvaluator {
+            allowlist: crate::types::Allowlist {
+                entries: vec![crate::types::AllowlistEntry {
+                    path: script_path.clone(),
+                    scope: crate::types::AllowScope::File,
+                    tasks: None,
+                    pattern: None,
+                }],
+            },
+        };
+        let server =
+            DelaMcpServer::new_with_allowlist(temp_dir.path().to_path_buf(), allowlist_evaluator);
+
+        let json_of = |result: CallToolResult| match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+
+        let started = json_of(
+            server
+                .task_start(Parameters(TaskStartArgs {
+                    unique_name: "restartable_task".to_string(),
+                    args: None,
+                    env: Some(std::collections::HashMap::from([(
+                        "GREETING".to_string(),
+                        "hello".to_string(),
+                    )])),
+                    cwd: None,
+                    wait_for_exit_seconds: None,
+                    allow_sudo: None,
+                    stdin: None,
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(started["state"], "running");
+        let old_pid = started["pid"].as_i64().unwrap() as u32;
+
+        let restarted = json_of(
+            server
+                .task_restart(Parameters(TaskRestartArgs {
+                    pid: old_pid,
+                    grace_period: Some(1),
+                    wait_for_exit_seconds: None,
+                    allow_sudo: None,
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(restarted["state"], "running");
+        let new_pid = restarted["pid"].as_i64().unwrap() as u32;
+        assert_ne!(new_pid, old_pid);
+        assert!(
+            restarted["initial_output"]
+                .as_str()
+                .unwrap()
+                .contains("Serving hello"),
+            "The new job should get the environment of the old one"
+        );
+
+        let old_job = server.job_manager.get_job(old_pid).await.unwrap();
+        assert!(!old_job.is_running());
+        assert!(
+            server
+                .job_manager
+                .get_job(new_pid)
+                .await
+                .unwrap()
+                .is_running()
+        );
+
+        let missing = server
+            .task_restart(Parameters(TaskRestartArgs {
+                pid: 999_999,
+                grace_period: None,
+                wait_for_exit_seconds: None,
+                allow_sudo: None,
+            }))
+            .await;
+        assert!(missing.is_err());
+
+        server
+            .task_stop(Parameters(TaskStopArgs {
+                pid: new_pid,
+                grace_period: Some(1),
+            }))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_task_wait_returns_final_state_or_running() {
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("waited_on_task.sh");
+        std::fs::write(
+            &script_path,
+            "#!/bin/bash\necho 'Working...'\nsleep 2\necho 'Done'\nexit 3\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let allowlist_evaluator = McpAllowlistEvaluator {
+            allowlist: crate::types::Allowlist {
+                entries: vec![crate::types::AllowlistEntry {
+                    path: script_path.clone(),
+                    scope: crate::types::AllowScope::File,
+                    tasks: None,
+                    pattern: None,
+                }],
+            },
+        };
+        let server =
+            DelaMcpServer::new_with_allowlist(temp_dir.path().to_path_buf(), allowlist_evaluator);
+
+        let json_of = |result: CallToolResult| match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+
+        let started = json_of(
+            server
+                .task_start(Parameters(TaskStartArgs {
+                    unique_name: "waited_on_task".to_string(),
+                    args: None,
+                    env: None,
+                    cwd: None,
+                    wait_for_exit_seconds: Some(0),
+                    allow_sudo: None,
+                    stdin: None,
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(started["state"], "running");
+        let pid = started["pid"].as_i64().unwrap() as u32;
+
+        let still_running = json_of(
+            server
+                .task_wait(Parameters(TaskWaitArgs {
+                    pid,
+                    timeout_secs: Some(0),
+                    lines: None,
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(still_running["state"], "running");
+        assert!(still_running["exit_code"].is_null());
+
+        let finished = json_of(
+            server
+                .task_wait(Parameters(TaskWaitArgs {
+                    pid,
+                    timeout_secs: Some(30),
+                    lines: Some(1),
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(finished["state"], "exited");
+        assert_eq!(finished["exit_code"], 3);
+        assert_eq!(finished["lines"], serde_json::json!(["Done"]));
+
+        let missing = server
+            .task_wait(Parameters(TaskWaitArgs {
+                pid: 999_999,
+                timeout_secs: Some(0),
+                lines: None,
+            }))
+            .await;
+        assert!(missing.is_err());
+
+        let too_long = server
+            .task_wait(Parameters(TaskWaitArgs {
+                pid,
+                timeout_secs: Some(MAX_TASK_START_WAIT_SECONDS + 1),
+                lines: None,
+            }))
+            .await;
+        assert_eq!(too_long.unwrap_err().code.0, -32602);
+    }
+
+    #[tokio::test]
+    async fn test_task_start_writes_stdin() {
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("echo_input.sh");
+        std::fs::write(&script_path, "#!/bin/bash\ncat\n").unwrap();
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let allowlist_evaluator = McpAllowlistEvaluator {
+            allowlist: crate::types::Allowlist {
+                entries: vec![crate::types::AllowlistEntry {
+                    path: script_path.clone(),
+                    scope: crate::types::AllowScope::File,
+                    tasks: None,
+                    pattern: None,
+                }],
+            },
+        };
+        let server =
+            DelaMcpServer::new_with_allowlist(temp_dir.path().to_path_buf(), allowlist_evaluator);
+
+        let result = server
+            .task_start(Parameters(TaskStartArgs {
+                unique_name: "echo_input".to_string(),
+                args: None,
+                env: None,
+                cwd: None,
+                wait_for_exit_seconds: Some(5),
+                allow_sudo: None,
+                stdin: Some("first line\nsecond line\n".to_string()),
+            }))
+            .await
+            .unwrap();
+        let json = match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+
+        // cat only exits once its stdin is closed
+        assert_eq!(json["state"], "exited");
+        let output = json["initial_output"].as_str().unwrap();
+        assert!(output.contains("first line"));
+        assert!(output.contains("second line"));
+    }
+
+    #[tokio::test]
+    async fn test_get_task_returns_details() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile"),
+            "## Build the project\nbuild: generate\n\techo build\n\ngenerate:\n\techo generate\n",
+        )
+        .unwrap();
+        let server = DelaMcpServer::new(temp_dir.path().to_path_buf());
+
+        let result = server
+            .get_task(Parameters(GetTaskArgs {
+                unique_name: "build".to_string(),
+            }))
+            .await
+            .unwrap();
+        let json = match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+        let task = &json["task"];
+        assert_eq!(task["unique_name"], "build");
+        assert_eq!(task["runner"], "make");
+        assert_eq!(task["command"], "make build");
+        assert_eq!(task["dependencies"], serde_json::json!(["generate"]));
+        assert!(task.get("shadowed_by").is_some());
+
+        let missing = server
+            .get_task(Parameters(GetTaskArgs {
+                unique_name: "no-such-task".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code.0, -32012);
+    }
+
+    #[tokio::test]
+    async fn test_admin_config_reports_effective_limits() {
+        let server = DelaMcpServer::new_inner(
+            std::env::temp_dir(),
+            McpAllowlistEvaluator {
+                allowlist: crate::types::Allowlist::default(),
+            },
+            TASK_DISCOVERY_CACHE_TTL,
+            JobManagerConfig {
+                max_concurrent_jobs: 3,
+                job_ttl_seconds: 7200,
+                ..JobManagerConfig::default()
+            },
+        );
+
+        let result = server.admin_config().await.unwrap();
+        let json = match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+        assert_eq!(json["max_concurrent_jobs"], 3);
+        assert_eq!(json["job_ttl_seconds"], 7200);
+        assert_eq!(json["gc_interval_seconds"], 300);
+        assert_eq!(json["task_cache_ttl_seconds"], 60);
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_list_runners_reports_files_and_availability() {
+        use crate::environment::{
+            TestEnvironment, reset_to_real_environment, set_test_environment,
+        };
+
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+        let server = DelaMcpServer::new(std::env::temp_dir());
+        let result = server.list_runners().await.unwrap();
+        reset_to_real_environment();
+
+        let json = match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+        let runners = json["runners"].as_array().unwrap();
+        assert_eq!(runners.len(), TaskRunner::ALL.len());
+        let runner = |name: &str| {
+            runners
+                .iter()
+                .find(|runner| runner["name"] == name)
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(
+            runner("make"),
+            serde_json::json!({
+                "name": "make",
+                "definition_files": ["GNUmakefile", "makefile", "Makefile"],
+                "available": true
+            })
+        );
+        assert_eq!(runner("npm")["available"], false);
+        assert_eq!(
+            runner("docker compose")["definition_files"][2],
+            "compose.yml"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_task_start_many_reports_each_result() {
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut entries = Vec::new();
+        for name in ["web", "worker"] {
+            let script_path = temp_dir.path().join(format!("{}.sh", name));
+            std::fs::write(&script_path, "#!/bin/bash\necho 'Serving'\nsleep 5\n").unwrap();
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            entries.push(crate::types::AllowlistEntry {
+                path: script_path,
+                scope: crate::types::AllowScope::File,
+                tasks: None,
+                pattern: None,
+            });
+        }
+        let server = DelaMcpServer::new_inner(
+            temp_dir.path().to_path_buf(),
+            McpAllowlistEvaluator {
+                allowlist: crate::types::Allowlist { entries },
+            },
+            TASK_DISCOVERY_CACHE_TTL,
+            JobManagerConfig {
+                max_concurrent_jobs: 1,
+                ..JobManagerConfig::default()
+            },
+        );
+
+        let start_args = |unique_name: &str| TaskStartArgs {
+            unique_name: unique_name.to_string(),
+            args: None,
+            env: None,
+            cwd: None,
+            wait_for_exit_seconds: Some(0),
+            allow_sudo: None,
+            stdin: None,
+        };
+        let result = server
+            .task_start_many(Parameters(TaskStartManyArgs {
+                tasks: vec![start_args("web"), start_args("worker"), start_args("db")],
+            }))
+            .await
+            .unwrap();
+        let json = match &result.content[0].raw {
+            RawContent::Text(text_content) => {
+                serde_json::from_str::<serde_json::Value>(&text_content.text).unwrap()
+            }
+            _ => panic!("Expected text content"),
+        };
+        let results = json["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0]["unique_name"], "web");
+        assert_eq!(results[0]["state"], "running");
+        let pid = results[0]["pid"].as_i64().unwrap() as u32;
+
+        assert_eq!(results[1]["unique_name"], "worker");
+        assert_eq!(results[1]["state"], "error");
+        assert_eq!(results[1]["concurrency_limited"], true);
+        assert_eq!(results[1]["error"]["code"], -32016);
+
+        assert_eq!(results[2]["unique_name"], "db");
+        assert_eq!(results[2]["concurrency_limited"], false);
+        assert_eq!(results[2]["error"]["code"], -32012);
+
+        server
+            .task_stop(Parameters(TaskStopArgs {
+                pid,
+                grace_period: Some(1),
+            }))
+            .await
+            .unwrap();
+    }
 }
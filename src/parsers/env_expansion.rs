@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Variables visible to a task definition file, checked in insertion order before
+/// falling back to the process environment.
+#[derive(Debug, Default)]
+pub struct EnvScope {
+    layers: Vec<HashMap<String, String>>,
+    process_env_first: bool,
+}
+
+impl EnvScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer of variables with lower precedence than the layers already added.
+    pub fn with_layer(mut self, layer: HashMap<String, String>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Let the process environment override file-provided layers, as compose does for `.env`.
+    pub fn with_process_env_first(mut self) -> Self {
+        self.process_env_first = true;
+        self
+    }
+
+    fn lookup(&self, name: &str) -> Option<String> {
+        let from_layers = || {
+            self.layers
+                .iter()
+                .find_map(|layer| layer.get(name).cloned())
+        };
+        if self.process_env_first {
+            std::env::var(name).ok().or_else(from_layers)
+        } else {
+            from_layers().or_else(|| std::env::var(name).ok())
+        }
+    }
+
+    pub fn expand(&self, input: &str) -> String {
+        expand_with(input, |name| self.lookup(name))
+    }
+}
+
+/// Expand `$VAR`, `${VAR}`, `${VAR:-default}` and `${VAR-default}` references the way
+/// compose and Taskfile do, along with `${VAR:+alternate}`, `${VAR+alternate}`,
+/// `${VAR:?error}` and `${VAR?error}`. Defaults and alternates may hold references of their
+/// own. `$$` is an escaped dollar. References that cannot be resolved are left as written
+/// so the listing shows what is missing.
+pub fn expand_with<F>(input: &str, lookup: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    expand(input, &lookup)
+}
+
+fn expand(input: &str, lookup: &dyn Fn(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(dollar) = rest.find('$') {
+        output.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+
+        if let Some(stripped) = after.strip_prefix('$') {
+            output.push('$');
+            rest = stripped;
+        } else if let Some(braced) = after.strip_prefix('{') {
+            let Some(close) = closing_brace(braced) else {
+                output.push_str(&rest[dollar..]);
+                return output;
+            };
+            let reference = &braced[..close];
+            match expand_braced(reference, lookup) {
+                Some(value) => output.push_str(&value),
+                None => output.push_str(&rest[dollar..dollar + close + 3]),
+            }
+            rest = &braced[close + 1..];
+        } else {
+            let name_len = variable_name_len(after);
+            if name_len == 0 {
+                output.push('$');
+            } else {
+                let name = &after[..name_len];
+                match lookup(name) {
+                    Some(value) => output.push_str(&value),
+                    None => output.push_str(&rest[dollar..dollar + name_len + 1]),
+                }
+            }
+            rest = &after[name_len..];
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// The position of the `}` that closes a reference whose `${` came just before `braced`,
+/// skipping over the braces of references nested in a default.
+fn closing_brace(braced: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in braced.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The value of the reference between `${` and `}`, or `None` to leave it as written:
+/// the variable is unset and there is no default, an `?` form reports it as an error,
+/// or the reference does not start with a variable name.
+fn expand_braced(reference: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Option<String> {
+    let name_len = variable_name_len(reference);
+    if name_len == 0 {
+        return None;
+    }
+    let (name, operator) = reference.split_at(name_len);
+    let value = lookup(name);
+    let non_empty = value.clone().filter(|value| !value.is_empty());
+
+    if operator.is_empty() {
+        return value;
+    }
+    if let Some(default) = operator.strip_prefix(":-") {
+        return Some(non_empty.unwrap_or_else(|| expand(default, lookup)));
+    }
+    if let Some(default) = operator.strip_prefix('-') {
+        return Some(value.unwrap_or_else(|| expand(default, lookup)));
+    }
+    if let Some(alternate) = operator.strip_prefix(":+") {
+        return Some(non_empty.map_or_else(String::new, |_| expand(alternate, lookup)));
+    }
+    if let Some(alternate) = operator.strip_prefix('+') {
+        return Some(value.map_or_else(String::new, |_| expand(alternate, lookup)));
+    }
+    if operator.starts_with(":?") {
+        return non_empty;
+    }
+    if operator.starts_with('?') {
+        return value;
+    }
+    None
+}
+
+fn variable_name_len(input: &str) -> usize {
+    input
+        .char_indices()
+        .take_while(|(i, c)| c == &'_' || c.is_ascii_alphabetic() || (*i > 0 && c.is_ascii_digit()))
+        .count()
+}
+
+/// Read `KEY=VALUE` pairs from a dotenv file. A missing or unreadable file yields no variables.
+pub fn load_dotenv(path: &Path) -> HashMap<String, String> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "IMAGE_TAG" => Some("1.2.3".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_with_references() {
+        assert_eq!(expand_with("app:${IMAGE_TAG}", lookup), "app:1.2.3");
+        assert_eq!(expand_with("app:$IMAGE_TAG-slim", lookup), "app:1.2.3-slim");
+        assert_eq!(expand_with("${EMPTY:-fallback}", lookup), "fallback");
+        assert_eq!(expand_with("${EMPTY-fallback}", lookup), "");
+        assert_eq!(expand_with("${MISSING-fallback}", lookup), "fallback");
+        assert_eq!(expand_with("cost: $$5", lookup), "cost: $5");
+    }
+
+    #[test]
+    fn test_expand_with_leaves_unresolved_references() {
+        assert_eq!(expand_with("app:${MISSING}", lookup), "app:${MISSING}");
+        assert_eq!(expand_with("app:$MISSING", lookup), "app:$MISSING");
+        assert_eq!(expand_with("price $ 5", lookup), "price $ 5");
+        assert_eq!(
+            expand_with("broken ${IMAGE_TAG", lookup),
+            "broken ${IMAGE_TAG"
+        );
+        assert_eq!(expand_with("{{.TEMPLATE}}", lookup), "{{.TEMPLATE}}");
+    }
+
+    #[test]
+    fn test_expand_with_error_and_alternate_forms() {
+        assert_eq!(expand_with("${IMAGE_TAG:?must-be-set}", lookup), "1.2.3");
+        assert_eq!(
+            expand_with("${MISSING:?must-be-set}", lookup),
+            "${MISSING:?must-be-set}"
+        );
+        assert_eq!(
+            expand_with("${EMPTY:?must-be-set}", lookup),
+            "${EMPTY:?must-be-set}"
+        );
+        assert_eq!(expand_with("${EMPTY?must-be-set}", lookup), "");
+        assert_eq!(
+            expand_with("${MISSING?must-be-set}", lookup),
+            "${MISSING?must-be-set}"
+        );
+
+        assert_eq!(expand_with("${IMAGE_TAG:+a-b}", lookup), "a-b");
+        assert_eq!(expand_with("${EMPTY:+a-b}", lookup), "");
+        assert_eq!(expand_with("${EMPTY+a-b}", lookup), "a-b");
+        assert_eq!(expand_with("${MISSING+a-b}", lookup), "");
+    }
+
+    #[test]
+    fn test_expand_with_nested_defaults() {
+        assert_eq!(expand_with("${MISSING:-${IMAGE_TAG}}", lookup), "1.2.3");
+        assert_eq!(
+            expand_with("${MISSING:-${OTHER:-v${IMAGE_TAG}}}-slim", lookup),
+            "v1.2.3-slim"
+        );
+        assert_eq!(expand_with("${IMAGE_TAG:-${OTHER}}", lookup), "1.2.3");
+        assert_eq!(expand_with("${MISSING:-${OTHER}}", lookup), "${OTHER}");
+    }
+
+    #[test]
+    fn test_expand_with_leaves_references_without_a_name() {
+        assert_eq!(expand_with("${-default}", lookup), "${-default}");
+        assert_eq!(expand_with("${1ABC-x}", lookup), "${1ABC-x}");
+        assert_eq!(expand_with("${IMAGE_TAG/1/2}", lookup), "${IMAGE_TAG/1/2}");
+    }
+
+    #[test]
+    fn test_load_dotenv() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".env");
+        std::fs::write(
+            &path,
+            "# comment\nIMAGE_TAG=1.2.3\nexport REGISTRY=\"ghcr.io\"\nNAME='app'\n\n",
+        )
+        .unwrap();
+
+        let vars = load_dotenv(&path);
+        assert_eq!(vars.get("IMAGE_TAG").map(String::as_str), Some("1.2.3"));
+        assert_eq!(vars.get("REGISTRY").map(String::as_str), Some("ghcr.io"));
+        assert_eq!(vars.get("NAME").map(String::as_str), Some("app"));
+        assert!(load_dotenv(&temp_dir.path().join("missing.env")).is_empty());
+    }
+
+    #[test]
+    fn test_env_scope_precedence() {
+        let first = HashMap::from([("TAG".to_string(), "task".to_string())]);
+        let second = HashMap::from([
+            ("TAG".to_string(), "global".to_string()),
+            ("OTHER".to_string(), "global".to_string()),
+        ]);
+        let scope = EnvScope::new().with_layer(first).with_layer(second);
+        assert_eq!(scope.expand("$TAG/$OTHER"), "task/global");
+    }
+}
@@ -22,4 +22,7 @@ pub enum DelaParseError {
 
     #[error("Syntax error: {0}")]
     Syntax(String),
+
+    #[error("file too large to parse ({size} bytes, limit is {limit})")]
+    TooLarge { size: u64, limit: u64 },
 }
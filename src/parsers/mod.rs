@@ -1,27 +1,67 @@
+pub mod parse_ant;
+pub mod parse_bazel;
 pub mod parse_cmake;
+pub mod parse_docker_bake;
 pub mod parse_docker_compose;
+pub mod parse_dockerfile;
 pub mod parse_github_actions;
+pub mod parse_gitlab_ci;
 pub mod parse_gradle;
+pub mod parse_helm_chart;
+pub mod parse_invoke;
 pub mod parse_justfile;
+pub mod parse_leiningen;
+pub mod parse_mage;
 pub mod parse_makefile;
+pub mod parse_meson;
+pub mod parse_mise;
+pub mod parse_noxfile;
+pub mod parse_nx;
 pub mod parse_package_json;
 pub mod parse_pom_xml;
+pub mod parse_pre_commit;
+pub mod parse_procfile;
 pub mod parse_pyproject_toml;
+pub mod parse_sbt;
+pub mod parse_sconstruct;
+pub mod parse_sidecar;
 pub mod parse_taskfile;
+pub mod parse_tox_ini;
 pub mod parse_travis_ci;
 pub mod parse_turbo_json;
 
+pub use parse_ant::parse as parse_ant;
+pub use parse_bazel::parse as parse_bazel;
 pub use parse_cmake::parse as parse_cmake;
+pub use parse_docker_bake::parse as parse_docker_bake;
 pub use parse_docker_compose::parse as parse_docker_compose;
+pub use parse_dockerfile::parse as parse_dockerfile;
 pub use parse_github_actions::parse as parse_github_actions;
+pub use parse_gitlab_ci::parse as parse_gitlab_ci;
 pub use parse_gradle::parse as parse_gradle;
+pub use parse_helm_chart::parse as parse_helm_chart;
+pub use parse_invoke::parse as parse_invoke;
 pub use parse_justfile::parse as parse_justfile;
+pub use parse_leiningen::parse as parse_leiningen;
+pub use parse_mage::parse as parse_mage;
 pub use parse_makefile::parse as parse_makefile;
+pub use parse_meson::parse as parse_meson;
+pub use parse_mise::parse as parse_mise;
+pub use parse_noxfile::parse as parse_noxfile;
 pub use parse_package_json::parse as parse_package_json;
 pub use parse_pom_xml::parse as parse_pom_xml;
+pub use parse_pre_commit::parse as parse_pre_commit;
+pub use parse_procfile::parse as parse_procfile;
 pub use parse_pyproject_toml::parse as parse_pyproject_toml;
+pub use parse_sbt::parse as parse_sbt;
+pub use parse_sconstruct::parse as parse_sconstruct;
 pub use parse_taskfile::parse as parse_taskfile;
+pub use parse_tox_ini::parse as parse_tox_ini;
 pub use parse_travis_ci::parse as parse_travis_ci;
 pub use parse_turbo_json::parse as parse_turbo_json;
 
+pub mod env_expansion;
 pub mod errors;
+pub mod python_decorators;
+pub mod read;
+pub mod sudo;
@@ -0,0 +1,118 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use roxmltree::Document;
+use std::path::Path;
+
+/// Parse an Apache Ant build.xml file and extract its targets as tasks
+///
+/// Targets whose names start with `-` are private by Ant convention and are skipped
+/// unless they carry a description.
+pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(file_path)?;
+    parse_ant_string(&content, file_path)
+}
+
+fn parse_ant_string(content: &str, file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let doc = Document::parse(content)?;
+    let root = doc.root_element();
+
+    let mut tasks = Vec::new();
+    for target in root.children().filter(|n| n.has_tag_name("target")) {
+        let Some(name) = target.attribute("name") else {
+            continue;
+        };
+        let description = target.attribute("description").map(str::to_string);
+        if name.starts_with('-') && description.is_none() {
+            continue;
+        }
+
+        let dependencies = target
+            .attribute("depends")
+            .map(|depends| {
+                depends
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|dependency| !dependency.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        tasks.push(Task {
+            name: name.to_string(),
+            file_path: file_path.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::AntBuildXml,
+            runner: TaskRunner::Ant,
+            source_name: name.to_string(),
+            description,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies,
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        });
+    }
+
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ant_targets() {
+        let content = r#"<?xml version="1.0"?>
+<project name="demo" default="jar">
+  <property name="src" location="src"/>
+  <target name="-init">
+    <mkdir dir="build"/>
+  </target>
+  <target name="-check" description="Check the environment"/>
+  <target name="compile" depends="-init" description="Compile the sources">
+    <javac srcdir="${src}" destdir="build"/>
+  </target>
+  <target name="jar" depends="compile, -check">
+    <jar destfile="demo.jar" basedir="build"/>
+  </target>
+  <target description="No name"/>
+</project>
+"#;
+        let tasks = parse_ant_string(content, Path::new("build.xml")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["-check", "compile", "jar"]);
+        assert_eq!(tasks[1].description.as_deref(), Some("Compile the sources"));
+        assert_eq!(tasks[2].description, None);
+        assert_eq!(tasks[2].dependencies, vec!["compile", "-check"]);
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Ant);
+            assert_eq!(task.definition_type, TaskDefinitionType::AntBuildXml);
+        }
+    }
+
+    #[test]
+    fn test_parse_ant_invalid_xml() {
+        assert!(parse_ant_string("<project>", Path::new("build.xml")).is_err());
+    }
+
+    #[test]
+    fn test_ant_get_command() {
+        let tasks = parse_ant_string(
+            r#"<project><target name="dist"/></project>"#,
+            Path::new("build.xml"),
+        )
+        .unwrap();
+        assert_eq!(TaskRunner::Ant.get_command(&tasks[0]), "ant dist");
+    }
+}
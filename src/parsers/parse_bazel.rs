@@ -0,0 +1,118 @@
+use crate::parsers::errors::DelaParseError;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::path::Path;
+use std::process::Command;
+
+/// Query selecting every runnable and testable target in the workspace.
+const TARGET_QUERY: &str = r#"kind("(.*_binary|.*_test) rule", //...)"#;
+
+/// Discover Bazel binary and test targets by running `bazel query` next to `marker_path`
+///
+/// Starlark cannot be evaluated statically, so this shells out and must only be called
+/// when bazel is installed.
+pub fn parse(marker_path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let workspace_dir = marker_path.parent().unwrap_or(Path::new("."));
+    let output = Command::new("bazel")
+        .args(["query", TARGET_QUERY, "--output=label_kind"])
+        .current_dir(workspace_dir)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(DelaParseError::Syntax(format!(
+            "bazel query failed: {}",
+            stderr.lines().last().unwrap_or("unknown error")
+        )));
+    }
+
+    Ok(parse_query_output(
+        &String::from_utf8_lossy(&output.stdout),
+        marker_path,
+    ))
+}
+
+/// Turn `--output=label_kind` lines such as `cc_test rule //app:unit` into tasks.
+///
+/// The rule kind is kept as the task's kind, which is how `get_command` tells tests
+/// (`bazel test`) from binaries (`bazel run`), and is shown as its description.
+fn parse_query_output(output: &str, marker_path: &Path) -> Vec<Task> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let kind = parts.next()?;
+            let label = parts.find(|part| part.starts_with("//") || part.starts_with('@'))?;
+            let name = label.rsplit(':').next().unwrap_or(label);
+            Some(Task {
+                name: name.to_string(),
+                file_path: marker_path.to_path_buf(),
+                definition_path: None,
+                definition_type: TaskDefinitionType::BazelWorkspace,
+                runner: TaskRunner::Bazel,
+                source_name: label.to_string(),
+                description: Some(kind.to_string()),
+                shadowed_by: None,
+                disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: Some(kind.to_string()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_output() {
+        let output = "\
+cc_binary rule //app:server
+py_test rule //app/tests:unit
+sh_binary rule //tools:fmt
+";
+        let tasks = parse_query_output(output, Path::new("MODULE.bazel"));
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["server", "unit", "fmt"]);
+        assert_eq!(tasks[1].source_name, "//app/tests:unit");
+        assert_eq!(tasks[1].description.as_deref(), Some("py_test"));
+        assert_eq!(tasks[1].kind.as_deref(), Some("py_test"));
+        assert_eq!(
+            TaskRunner::Bazel.get_command(&tasks[0]),
+            "bazel run //app:server"
+        );
+        assert_eq!(
+            TaskRunner::Bazel.get_command(&tasks[1]),
+            "bazel test //app/tests:unit"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_output_skips_noise() {
+        let output = "Loading: 0 packages loaded\n\ncc_binary rule //:main\n";
+        let tasks = parse_query_output(output, Path::new("WORKSPACE"));
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "main");
+        assert_eq!(tasks[0].source_name, "//:main");
+    }
+
+    #[test]
+    fn test_bazel_command_ignores_description() {
+        let mut tasks = parse_query_output("py_test rule //app:unit\n", Path::new("WORKSPACE"));
+        tasks[0].description = Some("Run the unit tests".to_string());
+        assert_eq!(
+            TaskRunner::Bazel.get_command(&tasks[0]),
+            "bazel test //app:unit"
+        );
+    }
+}
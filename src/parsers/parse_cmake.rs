@@ -1,8 +1,7 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use regex::Regex;
-use std::fs::File;
-use std::io::Read;
 use std::path::Path;
 
 /// Parse CMakeLists.txt file and extract custom targets as tasks
@@ -10,9 +9,7 @@ use std::path::Path;
 /// This function parses a CMakeLists.txt file and extracts each custom target
 /// as a separate task. It uses regex patterns to find add_custom_target() calls.
 pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
-    let mut file = File::open(file_path)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+    let contents = read_definition(file_path)?;
 
     parse_cmake_string(&contents, file_path)
 }
@@ -69,6 +66,17 @@ fn parse_cmake_string(content: &str, file_path: &Path) -> Result<Vec<Task>, Dela
             description: Some(description),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         tasks.push(task);
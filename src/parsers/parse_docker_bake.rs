@@ -0,0 +1,214 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use serde_json::Value;
+use std::path::Path;
+
+/// Parse a docker-bake.hcl or docker-bake.json file and extract its targets and groups
+///
+/// A group runs each of its targets, so they are listed as the group's dependencies.
+/// HCL is read by scanning for `target "name" { ... }` and `group "name" { ... }` blocks;
+/// variables and functions are not evaluated.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        parse_bake_json(&content, path)
+    } else {
+        parse_bake_hcl(&content, path)
+    }
+}
+
+fn parse_bake_hcl(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let block_pattern = Regex::new(r#"(?m)^\s*(target|group)\s+"([^"]+)"\s*\{"#)?;
+    let description_pattern = Regex::new(r#"(?m)^\s*description\s*=\s*"([^"]*)""#)?;
+    let targets_pattern = Regex::new(r#"(?m)^\s*targets\s*=\s*\[([^\]]*)\]"#)?;
+    let quoted = Regex::new(r#""([^"]*)""#)?;
+
+    let mut tasks: Vec<Task> = Vec::new();
+    for captures in block_pattern.captures_iter(content) {
+        let block = captures.get(0).expect("a match has a whole capture");
+        let body = block_body(&content[block.end()..]).ok_or_else(|| {
+            DelaParseError::Syntax(format!(
+                "unclosed {} block \"{}\"",
+                &captures[1], &captures[2]
+            ))
+        })?;
+
+        let name = &captures[2];
+        if tasks.iter().any(|task| task.name == name) {
+            continue;
+        }
+        let description = description_pattern
+            .captures(body)
+            .map(|description| description[1].to_string());
+        let mut task = create_task(path, name, description);
+        if &captures[1] == "group" {
+            task.dependencies = targets_pattern
+                .captures(body)
+                .map(|targets| {
+                    quoted
+                        .captures_iter(&targets[1])
+                        .map(|target| target[1].to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+        }
+        let keyword = captures.get(1).expect("the block kind is captured");
+        task.line = Some(content[..keyword.start()].matches('\n').count() + 1);
+        tasks.push(task);
+    }
+    Ok(tasks)
+}
+
+/// The text of a block up to its closing brace, given the text after its opening brace.
+fn block_body(rest: &str) -> Option<&str> {
+    let mut depth = 1;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in rest.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&rest[..index]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_bake_json(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let bake: Value = serde_json::from_str(content)?;
+    let mut tasks: Vec<Task> = Vec::new();
+
+    for (kind, blocks) in ["target", "group"]
+        .into_iter()
+        .filter_map(|kind| Some((kind, bake.get(kind)?.as_object()?)))
+    {
+        for (name, block) in blocks {
+            if tasks.iter().any(|task| task.name == *name) {
+                continue;
+            }
+            let description = block
+                .get("description")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            let mut task = create_task(path, name, description);
+            if kind == "group" {
+                task.dependencies = block
+                    .get("targets")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect();
+            }
+            tasks.push(task);
+        }
+    }
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::DockerBake,
+        runner: TaskRunner::DockerBake,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bake_hcl() {
+        let content = r#"
+variable "TAG" {
+  default = "latest"
+}
+
+group "default" {
+  targets = ["api", "web"]
+}
+
+target "api" {
+  description = "Build the API image"
+  context = "./api"
+  tags = ["registry.example.com/api:${TAG}"]
+  args = {
+    GO_VERSION = "1.22"
+  }
+}
+
+target "web" {
+  context = "./web"
+  labels = { "org.opencontainers.image.title" = "web {app}" }
+}
+"#;
+        let tasks = parse_bake_hcl(content, Path::new("docker-bake.hcl")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["default", "api", "web"]);
+        assert_eq!(tasks[0].dependencies, vec!["api", "web"]);
+        assert_eq!(tasks[0].line, Some(6));
+        assert_eq!(tasks[1].description.as_deref(), Some("Build the API image"));
+        assert!(tasks[1].dependencies.is_empty());
+        assert_eq!(tasks[2].description, None);
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::DockerBake);
+            assert_eq!(task.definition_type, TaskDefinitionType::DockerBake);
+        }
+        assert_eq!(
+            TaskRunner::DockerBake.get_command(&tasks[1]),
+            "docker buildx bake api"
+        );
+
+        assert!(parse_bake_hcl("target \"api\" {\n", Path::new("docker-bake.hcl")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bake_json() {
+        let content = r#"{
+  "group": {"release": {"targets": ["api", "web"]}},
+  "target": {
+    "api": {"context": "./api", "description": "Build the API image"},
+    "web": {"context": "./web"}
+  }
+}"#;
+        let tasks = parse_bake_json(content, Path::new("docker-bake.json")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["api", "web", "release"]);
+        assert_eq!(tasks[0].description.as_deref(), Some("Build the API image"));
+        assert_eq!(tasks[2].dependencies, vec!["api", "web"]);
+    }
+}
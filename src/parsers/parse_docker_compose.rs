@@ -1,4 +1,6 @@
+use crate::parsers::env_expansion::{EnvScope, load_dotenv};
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -32,17 +34,24 @@ struct DockerCompose {
 }
 
 /// Parse a docker-compose.yml file at the given path and extract services as tasks
-pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
-    let contents = std::fs::read_to_string(path)?;
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
 
     let docker_compose: DockerCompose = serde_yaml::from_str(&contents)?;
 
+    // Compose reads `.env` from the project directory, and the shell environment wins over it.
+    let dotenv = path
+        .parent()
+        .map(|dir| load_dotenv(&dir.join(".env")))
+        .unwrap_or_default();
+    let env = EnvScope::new().with_layer(dotenv).with_process_env_first();
+
     let mut tasks = Vec::new();
 
     // Add "up" task to bring up all services
     tasks.push(Task {
         name: "up".to_string(),
-        file_path: path.clone(),
+        file_path: path.to_path_buf(),
         definition_path: None,
         definition_type: TaskDefinitionType::DockerCompose,
         runner: TaskRunner::DockerCompose,
@@ -50,12 +59,23 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
         description: Some("Bring up all Docker Compose services".to_string()),
         shadowed_by: None,
         disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
     });
 
     // Add "down" task to bring down all services
     tasks.push(Task {
         name: "down".to_string(),
-        file_path: path.clone(),
+        file_path: path.to_path_buf(),
         definition_path: None,
         definition_type: TaskDefinitionType::DockerCompose,
         runner: TaskRunner::DockerCompose,
@@ -63,12 +83,23 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
         description: Some("Bring down all Docker Compose services".to_string()),
         shadowed_by: None,
         disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
     });
 
     for (service_name, service) in docker_compose.services {
         // Create a description based on the service configuration
         let description = if let Some(image) = &service.image {
-            Some(format!("Docker service using image: {}", image))
+            Some(format!("Docker service using image: {}", env.expand(image)))
         } else if service.build.is_some() {
             Some("Docker service with custom build".to_string())
         } else {
@@ -77,7 +108,7 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
 
         tasks.push(Task {
             name: service_name.clone(),
-            file_path: path.clone(),
+            file_path: path.to_path_buf(),
             definition_path: None,
             definition_type: TaskDefinitionType::DockerCompose,
             runner: TaskRunner::DockerCompose,
@@ -85,6 +116,17 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
             description,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
     }
 
@@ -442,4 +484,42 @@ services:
         assert_eq!(file_names[3], "docker-compose.prod.yaml");
         assert_eq!(file_names[4], "docker-compose.test.yml");
     }
+
+    #[test]
+    fn test_parse_docker_compose_expands_env_in_description() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = r#"
+services:
+  app:
+    image: ghcr.io/acme/app:${IMAGE_TAG}
+  worker:
+    image: ghcr.io/acme/worker:${DELA_TEST_UNSET_TAG:-latest}
+  cache:
+    image: redis:${DELA_TEST_UNSET_TAG}
+"#;
+        create_test_docker_compose(temp_dir.path(), content);
+        std::fs::write(temp_dir.path().join(".env"), "IMAGE_TAG=1.4.2\n").unwrap();
+
+        let tasks = parse(&temp_dir.path().join("docker-compose.yml")).unwrap();
+
+        let description = |name: &str| {
+            tasks
+                .iter()
+                .find(|t| t.name == name)
+                .and_then(|t| t.description.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            description("app"),
+            "Docker service using image: ghcr.io/acme/app:1.4.2"
+        );
+        assert_eq!(
+            description("worker"),
+            "Docker service using image: ghcr.io/acme/worker:latest"
+        );
+        assert_eq!(
+            description("cache"),
+            "Docker service using image: redis:${DELA_TEST_UNSET_TAG}"
+        );
+    }
 }
@@ -0,0 +1,147 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use std::path::Path;
+
+/// Parse a Dockerfile and extract a task per named build stage, plus one that builds the
+/// final image
+///
+/// Stages are named by `FROM <image> AS <name>`, with `AS` in any case. A stage only
+/// referenced by `COPY --from` is not one of the file's own stages and gets no task.
+/// The final image task has `docker` as its `source_name`, so no `--target` is passed for
+/// it, and a stage that is itself named `docker` is left out.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_stages(&content, path)
+}
+
+fn parse_stages(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let stage_pattern =
+        Regex::new(r"(?im)^[ \t]*FROM[ \t]+(?:--\S+[ \t]+)*\S+[ \t]+AS[ \t]+(\S+)")?;
+    let prefix = name_prefix(path);
+
+    let mut tasks = vec![final_image_task(path, &prefix)];
+    for captures in stage_pattern.captures_iter(content) {
+        let stage = &captures[1];
+        let name = format!("{}{}", prefix, stage);
+        if stage == "docker" || tasks.iter().any(|task| task.name == name) {
+            continue;
+        }
+        let mut task = create_task(path, &name, stage);
+        let start = captures
+            .get(0)
+            .expect("a match has a whole capture")
+            .start();
+        task.line = Some(content[..start].matches('\n').count() + 1);
+        tasks.push(task);
+    }
+    Ok(tasks)
+}
+
+/// `api-` for `api.Dockerfile`, so its stages do not clash with those of `Dockerfile`,
+/// and nothing for `Dockerfile` itself.
+fn name_prefix(path: &Path) -> String {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".Dockerfile"))
+        .map(|stem| format!("{}-", stem))
+        .unwrap_or_default()
+}
+
+fn final_image_task(path: &Path, prefix: &str) -> Task {
+    let name = match prefix.strip_suffix('-') {
+        Some(stem) => format!("docker-{}", stem),
+        None => "docker".to_string(),
+    };
+    let mut task = create_task(path, &name, "docker");
+    task.description = Some("Build the final image".to_string());
+    task
+}
+
+fn create_task(path: &Path, name: &str, stage: &str) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::Dockerfile,
+        runner: TaskRunner::Docker,
+        source_name: stage.to_string(),
+        description: None,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stages() {
+        let content = r#"# syntax=docker/dockerfile:1
+FROM golang:1.22 AS build
+WORKDIR /src
+COPY . .
+RUN go build -o /out/app ./cmd/app
+
+FROM --platform=$BUILDPLATFORM node:20 as Web
+RUN npm ci && npm run build
+
+from build AS test
+RUN go test ./...
+
+FROM gcr.io/distroless/base
+COPY --from=build /out/app /app
+COPY --from=docker.io/library/busybox:latest /bin/sh /bin/sh
+COPY --from=web /dist /static
+"#;
+        let tasks = parse_stages(content, Path::new("Dockerfile")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["docker", "build", "Web", "test"]);
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("Build the final image")
+        );
+        assert_eq!(tasks[1].line, Some(2));
+        assert_eq!(tasks[2].line, Some(7));
+        assert_eq!(TaskRunner::Docker.get_command(&tasks[0]), "docker build .");
+        assert_eq!(
+            TaskRunner::Docker.get_command(&tasks[1]),
+            "docker build --target build ."
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Docker);
+            assert_eq!(task.definition_type, TaskDefinitionType::Dockerfile);
+        }
+    }
+
+    #[test]
+    fn test_parse_named_dockerfile() {
+        let content = "FROM python:3.12 AS deps\nFROM deps AS docker\nFROM deps\n";
+        let tasks = parse_stages(content, Path::new("api.Dockerfile")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["docker-api", "api-deps"]);
+        assert_eq!(
+            TaskRunner::Docker.get_command(&tasks[0]),
+            "docker build -f api.Dockerfile ."
+        );
+        assert_eq!(
+            TaskRunner::Docker.get_command(&tasks[1]),
+            "docker build -f api.Dockerfile --target deps ."
+        );
+    }
+}
@@ -1,8 +1,7 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use serde_yaml::Value;
-use std::fs::File;
-use std::io::Read;
 use std::path::Path;
 
 /// Parse GitHub Actions workflow file and extract workflows as tasks
@@ -10,9 +9,7 @@ use std::path::Path;
 /// This function parses a GitHub Actions workflow file and extracts the entire workflow as a single task.
 /// The tasks can be executed using the `act` command-line tool.
 pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
-    let mut file = File::open(file_path)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+    let contents = read_definition(file_path)?;
 
     parse_workflow_string(&contents, file_path)
 }
@@ -54,6 +51,11 @@ fn parse_workflow_string(content: &str, file_path: &Path) -> Result<Vec<Task>, D
         ));
     }
 
+    let parameters = workflow_map
+        .get(Value::String("on".to_string()))
+        .map(extract_workflow_call_inputs)
+        .unwrap_or_default();
+
     // Extract filename without path for task name
     let file_name = file_path
         .file_name()
@@ -81,11 +83,64 @@ fn parse_workflow_string(content: &str, file_path: &Path) -> Result<Vec<Task>, D
         description: workflow_name,
         shadowed_by: None,
         disambiguated_name: None,
+        parameters,
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
     };
 
     Ok(vec![task])
 }
 
+/// Collect the `workflow_call` inputs of a reusable workflow, in declaration order.
+///
+/// Required inputs are listed by name. Optional ones carry the default GitHub would use.
+fn extract_workflow_call_inputs(on: &Value) -> Vec<String> {
+    let Some(inputs) = on
+        .get("workflow_call")
+        .and_then(|workflow_call| workflow_call.get("inputs"))
+        .and_then(Value::as_mapping)
+    else {
+        return Vec::new();
+    };
+
+    inputs
+        .iter()
+        .filter_map(|(name, spec)| {
+            let name = name.as_str()?;
+            let required = spec
+                .get("required")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let default = match spec.get("default") {
+                Some(Value::String(value)) => Some(value.clone()),
+                Some(Value::Bool(value)) => Some(value.to_string()),
+                Some(Value::Number(value)) => Some(value.to_string()),
+                _ if required => None,
+                _ => Some(
+                    match spec.get("type").and_then(Value::as_str) {
+                        Some("boolean") => "false",
+                        Some("number") => "0",
+                        _ => "",
+                    }
+                    .to_string(),
+                ),
+            };
+            Some(match default {
+                Some(default) => format!("{}=\"{}\"", name, default),
+                None => name.to_string(),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,4 +394,74 @@ on: [push]
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No jobs found"));
     }
+
+    #[test]
+    fn test_parse_reusable_workflow_inputs() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+        let workflow_content = r#"
+name: Deploy
+on:
+  workflow_call:
+    inputs:
+      environment:
+        description: Target environment
+        required: true
+        type: string
+      version:
+        required: false
+        type: string
+        default: latest
+      dry-run:
+        type: boolean
+    secrets:
+      token:
+        required: true
+
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo "Deploying ${{ inputs.version }}"
+"#;
+
+        let file_path = create_test_workflow(temp_dir.path(), "deploy.yml", workflow_content);
+        let tasks = parse(&file_path).expect("Failed to parse workflow");
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].runner, TaskRunner::Act);
+        assert_eq!(
+            tasks[0].parameters,
+            vec![
+                "environment".to_string(),
+                "version=\"latest\"".to_string(),
+                "dry-run=\"false\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_workflow_without_workflow_call_has_no_inputs() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+        let workflow_content = r#"
+on:
+  workflow_dispatch:
+    inputs:
+      level:
+        required: true
+  push:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo "Building..."
+"#;
+
+        let file_path = create_test_workflow(temp_dir.path(), "build.yml", workflow_content);
+        let tasks = parse(&file_path).expect("Failed to parse workflow");
+
+        assert!(tasks[0].parameters.is_empty());
+    }
 }
@@ -0,0 +1,159 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use serde_yaml::Value;
+use std::path::Path;
+
+/// Top-level keys in .gitlab-ci.yml that configure the pipeline rather than define a job.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "after_script",
+    "before_script",
+    "cache",
+    "default",
+    "image",
+    "include",
+    "services",
+    "spec",
+    "stages",
+    "variables",
+    "workflow",
+];
+
+/// Stage GitLab assigns to jobs that do not declare one.
+const DEFAULT_STAGE: &str = "test";
+
+/// Parse a GitLab CI configuration file and extract its jobs as tasks
+///
+/// Jobs can only be executed locally through gitlab-ci-local.
+pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(file_path)?;
+    parse_gitlab_ci_string(&contents, file_path)
+}
+
+fn parse_gitlab_ci_string(content: &str, file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    if content.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let config: Value = serde_yaml::from_str(content)?;
+    let Value::Mapping(config_map) = config else {
+        return Err(DelaParseError::Syntax(
+            "GitLab CI YAML is not a mapping".to_string(),
+        ));
+    };
+
+    let mut tasks = Vec::new();
+    for (key, value) in &config_map {
+        let Value::String(job_name) = key else {
+            continue;
+        };
+        // Keys starting with a dot are hidden jobs used as templates for `extends`.
+        if job_name.starts_with('.') || RESERVED_KEYWORDS.contains(&job_name.as_str()) {
+            continue;
+        }
+        let Value::Mapping(job) = value else {
+            continue;
+        };
+
+        let stage = job
+            .get(Value::String("stage".to_string()))
+            .and_then(Value::as_str)
+            .unwrap_or(DEFAULT_STAGE);
+
+        tasks.push(Task {
+            name: job_name.clone(),
+            file_path: file_path.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::GitlabCi,
+            runner: TaskRunner::GitlabCi,
+            source_name: job_name.clone(),
+            description: Some(format!("GitLab CI job in stage: {}", stage)),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        });
+    }
+
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gitlab_ci_jobs() {
+        let content = r#"
+stages:
+  - build
+  - test
+
+variables:
+  RUST_BACKTRACE: "1"
+
+default:
+  image: rust:latest
+
+include:
+  - local: ci/common.yml
+
+.cargo_template:
+  before_script:
+    - cargo --version
+
+build:
+  stage: build
+  script:
+    - cargo build
+
+unit-tests:
+  extends: .cargo_template
+  script:
+    - cargo test
+
+workflow:
+  rules:
+    - if: $CI_COMMIT_BRANCH
+"#;
+        let tasks = parse_gitlab_ci_string(content, Path::new(".gitlab-ci.yml")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "unit-tests"]);
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("GitLab CI job in stage: build")
+        );
+        assert_eq!(
+            tasks[1].description.as_deref(),
+            Some("GitLab CI job in stage: test")
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::GitlabCi);
+            assert_eq!(task.definition_type, TaskDefinitionType::GitlabCi);
+        }
+        assert_eq!(
+            TaskRunner::GitlabCi.get_command(&tasks[1]),
+            "gitlab-ci-local unit-tests"
+        );
+    }
+
+    #[test]
+    fn test_parse_gitlab_ci_empty_and_invalid() {
+        assert!(
+            parse_gitlab_ci_string("", Path::new(".gitlab-ci.yml"))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(parse_gitlab_ci_string("- a\n- b\n", Path::new(".gitlab-ci.yml")).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use regex::Regex;
-use std::fs;
 use std::path::Path;
 
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
@@ -8,7 +8,7 @@ use crate::types::{Task, TaskDefinitionType, TaskRunner};
 /// Parse a Gradle build file (build.gradle or build.gradle.kts) and extract tasks
 pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
     // Read the file
-    let content = fs::read_to_string(file_path)?;
+    let content = read_definition(file_path)?;
 
     // Parse tasks using regex patterns for both Groovy and Kotlin DSL
     let mut tasks = Vec::new();
@@ -55,6 +55,17 @@ fn add_common_tasks(tasks: &mut Vec<Task>, file_path: &Path) {
             description: Some(description.to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
     }
 }
@@ -87,6 +98,17 @@ fn extract_custom_tasks(
                 description: extract_task_description(content, task_name.as_str()),
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             });
         }
     }
@@ -104,6 +126,17 @@ fn extract_custom_tasks(
                 description: extract_task_description(content, task_name.as_str()),
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             });
         }
     }
@@ -121,6 +154,17 @@ fn extract_custom_tasks(
                 description: extract_task_description(content, task_name.as_str()),
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             });
         }
     }
@@ -231,6 +275,17 @@ fn extract_plugin_tasks(
                         description: Some(format!("Task from {} plugin", plugin_prefix)),
                         shadowed_by: None,
                         disambiguated_name: None,
+                        parameters: Vec::new(),
+                        runnable: true,
+                        dependencies: Vec::new(),
+                        workspace_dir: None,
+                        tags: Vec::new(),
+                        hidden: false,
+                        deprecated: false,
+                        requires_sudo: false,
+                        line: None,
+                        body: None,
+                        kind: None,
                     });
                 }
             }
@@ -0,0 +1,120 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use serde_yaml::Value;
+use std::path::Path;
+
+/// Helm subcommands offered for every chart, and whether each takes a release name.
+const HELM_OPERATIONS: [(&str, bool, &str); 5] = [
+    ("install", true, "Install the chart as release"),
+    ("upgrade", true, "Upgrade release"),
+    ("template", true, "Render the chart's templates for release"),
+    ("lint", false, "Check the chart for problems"),
+    (
+        "package",
+        false,
+        "Package the chart into a versioned archive",
+    ),
+];
+
+/// Parse a Chart.yaml file and offer the common Helm operations on its chart as tasks
+///
+/// The release is named after the chart. Each task carries the arguments after `helm` as
+/// its `source_name`, with the chart given as `.`, the directory of Chart.yaml.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_chart_string(&content, path)
+}
+
+fn parse_chart_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let chart: Value = serde_yaml::from_str(content)?;
+    let release = chart
+        .get("name")
+        .and_then(Value::as_str)
+        .filter(|name| !name.trim().is_empty())
+        .ok_or_else(|| DelaParseError::Syntax("Chart.yaml has no name".to_string()))?;
+
+    Ok(HELM_OPERATIONS
+        .iter()
+        .map(|(operation, takes_release, description)| {
+            let (arguments, description) = if *takes_release {
+                (
+                    format!("{} {} .", operation, release),
+                    format!("{} {}", description, release),
+                )
+            } else {
+                (format!("{} .", operation), description.to_string())
+            };
+            create_task(path, operation, arguments, description)
+        })
+        .collect())
+}
+
+fn create_task(path: &Path, name: &str, arguments: String, description: String) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::HelmChart,
+        runner: TaskRunner::Helm,
+        source_name: arguments,
+        description: Some(description),
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chart() {
+        let content =
+            "apiVersion: v2\nname: web-api\ndescription: The public API\nversion: 0.3.1\n";
+        let tasks = parse_chart_string(content, Path::new("Chart.yaml")).unwrap();
+
+        let commands: Vec<(&str, String)> = tasks
+            .iter()
+            .map(|task| (task.name.as_str(), TaskRunner::Helm.get_command(task)))
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                ("install", "helm install web-api .".to_string()),
+                ("upgrade", "helm upgrade web-api .".to_string()),
+                ("template", "helm template web-api .".to_string()),
+                ("lint", "helm lint .".to_string()),
+                ("package", "helm package .".to_string()),
+            ]
+        );
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("Install the chart as release web-api")
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Helm);
+            assert_eq!(task.definition_type, TaskDefinitionType::HelmChart);
+        }
+    }
+
+    #[test]
+    fn test_parse_chart_without_name() {
+        assert!(
+            parse_chart_string("apiVersion: v2\nversion: 1.0.0\n", Path::new("Chart.yaml"))
+                .is_err()
+        );
+        assert!(parse_chart_string("- not\n- a chart\n", Path::new("Chart.yaml")).is_err());
+    }
+}
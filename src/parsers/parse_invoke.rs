@@ -0,0 +1,112 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::python_decorators::find_decorated_functions;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::path::Path;
+
+/// Parse an Invoke tasks.py and extract `@task` functions as tasks
+///
+/// Only top-level functions are discovered, see [`find_decorated_functions`].
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
+    parse_invoke_string(&contents, path)
+}
+
+fn parse_invoke_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let tasks = find_decorated_functions(content, &["task", "invoke.task"])?
+        .into_iter()
+        .map(|function| {
+            // Invoke exposes `build_docs` as `build-docs` unless the decorator names it.
+            let name = function
+                .explicit_name
+                .unwrap_or_else(|| function.function_name.replace('_', "-"));
+            Task {
+                name: name.clone(),
+                file_path: path.to_path_buf(),
+                definition_path: None,
+                definition_type: TaskDefinitionType::InvokeTasks,
+                runner: TaskRunner::Invoke,
+                source_name: name,
+                description: function.docstring,
+                shadowed_by: None,
+                disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
+            }
+        })
+        .collect();
+
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_invoke_tasks() {
+        let content = r#"
+from invoke import task
+
+
+@task
+def clean(c):
+    """Remove build artifacts"""
+    c.run("rm -rf build")
+
+
+@task(pre=[clean])
+def build_docs(c):
+    c.run("sphinx-build docs build")
+
+
+@task(name="ship", help={"tag": "release tag"})
+def publish_release(c, tag):
+    '''Publish a release to PyPI'''
+    c.run(f"twine upload dist/*")
+
+
+def helper(c):
+    pass
+"#;
+        let tasks = parse_invoke_string(content, Path::new("tasks.py")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["clean", "build-docs", "ship"]);
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("Remove build artifacts")
+        );
+        assert_eq!(tasks[1].description, None);
+        assert_eq!(
+            tasks[2].description.as_deref(),
+            Some("Publish a release to PyPI")
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Invoke);
+            assert_eq!(task.definition_type, TaskDefinitionType::InvokeTasks);
+        }
+    }
+
+    #[test]
+    fn test_invoke_get_command() {
+        let tasks = parse_invoke_string(
+            "@task\ndef build_docs(c):\n    pass\n",
+            Path::new("tasks.py"),
+        )
+        .unwrap();
+        assert_eq!(
+            TaskRunner::Invoke.get_command(&tasks[0]),
+            "invoke build-docs"
+        );
+    }
+}
@@ -1,31 +1,50 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
-use regex::Regex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Parse a Justfile at the given path and extract tasks
-pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
+///
+/// Recipes of files pulled in with `import` are included, and those of `mod name` modules
+/// are named `name::recipe`, which is how `just` runs them.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let mut tasks = Vec::new();
+    parse_file(path, path, "", &mut Vec::new(), &mut tasks)?;
+    Ok(tasks)
+}
+
+/// Add the recipes of `path`, which `root` imports directly or through other files, with
+/// their names prefixed by `module_prefix`. `visiting` holds the files being parsed, so a
+/// file that imports itself is read only once.
+fn parse_file(
+    root: &Path,
+    path: &Path,
+    module_prefix: &str,
+    visiting: &mut Vec<PathBuf>,
+    tasks: &mut Vec<Task>,
+) -> Result<(), DelaParseError> {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if visiting.contains(&canonical) {
+        return Ok(());
+    }
+    visiting.push(canonical);
+
     let file_name = path
         .file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("Justfile");
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
 
-    let contents = std::fs::read_to_string(path)?;
-
-    let mut tasks = Vec::new();
+    let contents = read_definition(path)?;
     let lines: Vec<&str> = contents.lines().collect();
 
-    // Regex to match task definitions in Justfiles
-    // Matches patterns like:
-    // task_name:
-    // task_name: # description
-    // task_name: # description with spaces
-    // task_name *args: # description
-    // task_name: dependency # description
-    // task_name *args: dependency # description
-    let task_regex = Regex::new(r"^([a-zA-Z_][a-zA-Z0-9_-]*)(?:\s+\*[a-zA-Z_][a-zA-Z0-9_-]*)?:\s*(?:[a-zA-Z_][a-zA-Z0-9_-]*\s+)?(?:#\s*(.+))?$").unwrap();
-
+    // Imports and modules are read after this file's own recipes
+    let mut directives = Vec::new();
     for (line_num, line) in lines.iter().enumerate() {
+        // Recipe headers start at the beginning of the line; indented lines are recipe bodies
+        if line.starts_with(char::is_whitespace) {
+            continue;
+        }
         let line = line.trim();
 
         // Skip empty lines and comments
@@ -33,39 +52,280 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
             continue;
         }
 
-        if let Some(captures) = task_regex.captures(line) {
-            let task_name = captures.get(1).unwrap().as_str().to_string();
-            let description = captures.get(2).map(|m| m.as_str().trim().to_string());
-
-            // Validate indentation for this recipe
-            if let Err(indent_error) = validate_recipe_indentation(&lines, line_num + 1) {
-                return match indent_error {
-                    DelaParseError::Syntax(inner_msg) => Err(DelaParseError::Syntax(format!(
-                        "{}: {}",
-                        file_name, inner_msg
-                    ))),
-                    _ => Err(DelaParseError::Syntax(format!(
-                        "{}: {}",
-                        file_name, indent_error
-                    ))),
+        let Some(header) = parse_recipe_header(line) else {
+            if let Some(directive) = parse_directive(line) {
+                directives.push(directive);
+            }
+            continue;
+        };
+
+        let task_name = format!("{}{}", module_prefix, header.name);
+        // A recipe of the importing file wins over an imported one
+        if tasks.iter().any(|task| task.name == task_name) {
+            continue;
+        }
+        let description = header.description.map(str::to_string);
+
+        // Validate indentation for this recipe
+        if let Err(indent_error) = validate_recipe_indentation(&lines, line_num + 1) {
+            return match indent_error {
+                DelaParseError::Syntax(inner_msg) => Err(DelaParseError::Syntax(format!(
+                    "{}: {}",
+                    file_name, inner_msg
+                ))),
+                _ => Err(DelaParseError::Syntax(format!(
+                    "{}: {}",
+                    file_name, indent_error
+                ))),
+            };
+        }
+
+        tasks.push(Task {
+            name: task_name.clone(),
+            file_path: root.to_path_buf(),
+            definition_path: (path != root).then(|| path.to_path_buf()),
+            definition_type: TaskDefinitionType::Justfile,
+            runner: TaskRunner::Just,
+            source_name: task_name,
+            description,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: header.parameters,
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: Some(line_num + 1),
+            body: recipe_body(&lines, line_num + 1),
+            kind: None,
+        });
+    }
+
+    for directive in directives {
+        match directive {
+            Directive::Import { optional, path } => {
+                let import_path = dir.join(path);
+                if !optional || import_path.is_file() {
+                    parse_file(root, &import_path, module_prefix, visiting, tasks)?;
+                }
+            }
+            Directive::Module {
+                optional,
+                name,
+                path,
+            } => {
+                let module_path = match path {
+                    Some(path) => Some(dir.join(path)),
+                    None => find_module_file(dir, name),
                 };
+                match module_path {
+                    Some(module_path) => {
+                        let prefix = format!("{}{}::", module_prefix, name);
+                        parse_file(root, &module_path, &prefix, visiting, tasks)?;
+                    }
+                    None if optional => {}
+                    None => {
+                        return Err(DelaParseError::Syntax(format!(
+                            "{}: could not find the file of module '{}'",
+                            file_name, name
+                        )));
+                    }
+                }
             }
+        }
+    }
 
-            tasks.push(Task {
-                name: task_name.clone(),
-                file_path: path.clone(),
-                definition_path: None,
-                definition_type: TaskDefinitionType::Justfile,
-                runner: TaskRunner::Just,
-                source_name: task_name,
-                description,
-                shadowed_by: None,
-                disambiguated_name: None,
-            });
+    visiting.pop();
+    Ok(())
+}
+
+/// A line that pulls in recipes from another file.
+#[derive(Debug, PartialEq)]
+enum Directive<'a> {
+    /// `import 'path'`, or `import? 'path'` when the file may be missing
+    Import { optional: bool, path: &'a str },
+    /// `mod name`, optionally followed by the path of the module file
+    Module {
+        optional: bool,
+        name: &'a str,
+        path: Option<&'a str>,
+    },
+}
+
+fn parse_directive(line: &str) -> Option<Directive<'_>> {
+    let (keyword, rest) = line.split_at(identifier_len(line));
+    let (optional, rest) = match rest.strip_prefix('?') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim();
+    match keyword {
+        "import" => Some(Directive::Import {
+            optional,
+            path: quoted(rest)?,
+        }),
+        "mod" => {
+            let name_len = identifier_len(rest);
+            if name_len == 0 {
+                return None;
+            }
+            let (name, path) = rest.split_at(name_len);
+            let path = match path.trim() {
+                "" => None,
+                path => Some(quoted(path)?),
+            };
+            Some(Directive::Module {
+                optional,
+                name,
+                path,
+            })
         }
+        _ => None,
     }
+}
 
-    Ok(tasks)
+/// The contents of a string written entirely in single or double quotes.
+fn quoted(text: &str) -> Option<&str> {
+    ['\'', '"'].into_iter().find_map(|quote| {
+        text.strip_prefix(quote)?
+            .strip_suffix(quote)
+            .filter(|inner| !inner.contains(quote))
+    })
+}
+
+/// The file `just` reads for `mod name` without a path.
+fn find_module_file(dir: &Path, name: &str) -> Option<PathBuf> {
+    [
+        format!("{}.just", name),
+        format!("{}/mod.just", name),
+        format!("{}/justfile", name),
+        format!("{}/Justfile", name),
+        format!("{}/.justfile", name),
+    ]
+    .into_iter()
+    .map(|candidate| dir.join(candidate))
+    .find(|candidate| candidate.is_file())
+}
+
+/// The parts of a recipe header such as `deploy env tag="latest" +args: build # Ship it`.
+#[derive(Debug, PartialEq)]
+struct RecipeHeader<'a> {
+    name: &'a str,
+    /// Each parameter as written, e.g. `env`, `tag="latest"` or `+args`
+    parameters: Vec<String>,
+    description: Option<&'a str>,
+}
+
+/// Parse a recipe header, or return `None` for any other line, such as an assignment
+/// (`name := value`), a setting or an alias.
+fn parse_recipe_header(line: &str) -> Option<RecipeHeader<'_>> {
+    let line = line.strip_prefix('@').unwrap_or(line);
+    let name_len = identifier_len(line);
+    if name_len == 0 || line.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+        return None;
+    }
+    let name = &line[..name_len];
+
+    let mut rest = &line[name_len..];
+    let mut parameters = Vec::new();
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(after_colon) = trimmed.strip_prefix(':') {
+            if after_colon.starts_with('=') {
+                return None;
+            }
+            rest = after_colon;
+            break;
+        }
+        // Parameters are separated from the name and each other by whitespace
+        if trimmed.len() == rest.len() {
+            return None;
+        }
+        let parameter_len = parameter_len(trimmed)?;
+        parameters.push(trimmed[..parameter_len].to_string());
+        rest = &trimmed[parameter_len..];
+    }
+
+    let description = rest
+        .split_once('#')
+        .map(|(_, comment)| comment.trim())
+        .filter(|comment| !comment.is_empty());
+    Some(RecipeHeader {
+        name,
+        parameters,
+        description,
+    })
+}
+
+/// Length of the identifier at the start of `text`, which may contain `-` after its
+/// first character.
+fn identifier_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    match chars.next() {
+        Some((_, c)) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return 0,
+    }
+    chars
+        .find(|(_, c)| !c.is_ascii_alphanumeric() && *c != '_' && *c != '-')
+        .map_or(text.len(), |(index, _)| index)
+}
+
+/// Length of the parameter at the start of `text`: an optional `+` or `*` for a variadic
+/// parameter, an optional `$` for one exported to the environment, the name, and an
+/// optional `=` default that is quoted, backticked or a bare word.
+fn parameter_len(text: &str) -> Option<usize> {
+    let prefix_len = text
+        .chars()
+        .take_while(|c| matches!(c, '+' | '*' | '$'))
+        .count();
+    let name_len = identifier_len(&text[prefix_len..]);
+    if name_len == 0 {
+        return None;
+    }
+    let end = prefix_len + name_len;
+    let Some(default) = text[end..].strip_prefix('=') else {
+        return Some(end);
+    };
+
+    let default_len = match default.chars().next()? {
+        quote @ ('"' | '\'' | '`') => {
+            let mut escaped = false;
+            let mut close = None;
+            for (index, c) in default.char_indices().skip(1) {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && quote == '"' {
+                    escaped = true;
+                } else if c == quote {
+                    close = Some(index);
+                    break;
+                }
+            }
+            close? + 1
+        }
+        _ => default
+            .find(|c: char| c.is_whitespace() || c == ':')
+            .unwrap_or(default.len()),
+    };
+    (default_len > 0).then_some(end + 1 + default_len)
+}
+
+/// The indented lines of the recipe whose body starts at `body_start`, as written.
+fn recipe_body(lines: &[&str], body_start: usize) -> Option<String> {
+    let body: Vec<&str> = lines[body_start.min(lines.len())..]
+        .iter()
+        .take_while(|line| line.trim().is_empty() || is_indented_line(line))
+        .copied()
+        .collect();
+    let body = body.join("\n");
+    let body = body.trim_end();
+    (!body.is_empty()).then(|| body.to_string())
 }
 
 /// Validate that a recipe's lines use consistent indentation
@@ -191,6 +451,7 @@ lint: # Run linter
         let build_task = tasks.iter().find(|t| t.name == "build").unwrap();
         assert_eq!(build_task.description.as_deref(), Some("Build the project"));
         assert_eq!(build_task.runner, TaskRunner::Just);
+        assert_eq!(build_task.line, Some(3));
 
         let test_task = tasks.iter().find(|t| t.name == "test").unwrap();
         assert_eq!(test_task.description.as_deref(), Some("Run tests"));
@@ -874,4 +1135,120 @@ clean: # Clean project
         let clean_task = tasks.iter().find(|t| t.name == "clean").unwrap();
         assert_eq!(clean_task.description.as_deref(), Some("Clean project"));
     }
+
+    #[test]
+    fn test_parse_justfile_recipe_parameters() {
+        let temp_dir = TempDir::new().unwrap();
+        let justfile_path = temp_dir.path().join("justfile");
+        std::fs::write(
+            &justfile_path,
+            r#"set shell := ["bash", "-c"]
+version := "1.0"
+alias d := deploy
+
+deploy env tag="latest" +args: build # Deploy an image
+    echo {{env}} {{tag}} {{args}}
+
+serve $PORT='8080' url="http://localhost:8080" *flags:
+    ./serve {{flags}}
+
+@quiet:
+    echo quiet
+
+build:
+    echo build
+"#,
+        )
+        .unwrap();
+
+        let tasks = parse(&justfile_path).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["deploy", "serve", "quiet", "build"]);
+
+        assert_eq!(tasks[0].parameters, vec!["env", r#"tag="latest""#, "+args"]);
+        assert_eq!(tasks[0].description.as_deref(), Some("Deploy an image"));
+        assert_eq!(
+            tasks[1].parameters,
+            vec!["$PORT='8080'", r#"url="http://localhost:8080""#, "*flags"]
+        );
+        assert_eq!(tasks[1].description, None);
+        assert!(tasks[2].parameters.is_empty());
+
+        // Arguments are passed on after the recipe name
+        assert_eq!(
+            TaskRunner::Just.get_command_with_args(&tasks[0], &["prod".to_string()]),
+            "just deploy prod"
+        );
+    }
+
+    #[test]
+    fn test_parse_justfile_imports_and_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let justfile_path = root.join("justfile");
+        std::fs::write(
+            &justfile_path,
+            "import 'just/common.just'\nimport? 'missing.just'\nmod docker\nmod? absent\nmod db 'tools/database.just'\n\nbuild:\n    cargo build\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("just")).unwrap();
+        // Imports are relative to the importing file, and a cycle back to the justfile is ignored
+        std::fs::write(
+            root.join("just/common.just"),
+            "import '../justfile'\n\nlint: # Run the linters\n    cargo clippy\n\nbuild:\n    echo duplicate\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("docker")).unwrap();
+        std::fs::write(
+            root.join("docker/mod.just"),
+            "mod compose\n\nimage tag:\n    docker build -t {{tag}} .\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("docker/compose.just"),
+            "up:\n    docker compose up\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("tools")).unwrap();
+        std::fs::write(
+            root.join("tools/database.just"),
+            "migrate:\n    ./migrate\n",
+        )
+        .unwrap();
+
+        let tasks = parse(&justfile_path).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "build",
+                "lint",
+                "docker::image",
+                "docker::compose::up",
+                "db::migrate"
+            ]
+        );
+
+        let lint = &tasks[1];
+        assert_eq!(lint.description.as_deref(), Some("Run the linters"));
+        assert_eq!(lint.file_path, justfile_path);
+        assert_eq!(lint.definition_path(), root.join("just/common.just"));
+        assert_eq!(lint.line, Some(3));
+
+        let image = tasks.iter().find(|t| t.name == "docker::image").unwrap();
+        assert_eq!(image.parameters, vec!["tag"]);
+        assert_eq!(image.definition_path(), root.join("docker/mod.just"));
+        assert_eq!(TaskRunner::Just.get_command(image), "just docker::image");
+        assert_eq!(
+            TaskRunner::Just.get_command(&tasks[3]),
+            "just docker::compose::up"
+        );
+
+        let build = tasks.iter().find(|t| t.name == "build").unwrap();
+        assert_eq!(build.definition_path(), justfile_path);
+
+        // A required module must exist
+        std::fs::write(&justfile_path, "mod nowhere\n").unwrap();
+        assert!(parse(&justfile_path).is_err());
+    }
 }
@@ -0,0 +1,302 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::path::Path;
+
+/// Built-in lein commands exposed for every project.clj.
+const DEFAULT_COMMANDS: &[(&str, &str)] = &[
+    ("test", "Run the project's tests"),
+    ("run", "Run the project's main function"),
+    (
+        "uberjar",
+        "Package the project and its dependencies as a standalone jar",
+    ),
+];
+
+/// Parse a Leiningen project.clj file and extract its `:aliases` as tasks
+///
+/// This scans the EDN forms rather than evaluating the project, so aliases added by
+/// profiles or plugins are not found. The standard lein commands are always included.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_project_clj_string(&content, path)
+}
+
+fn parse_project_clj_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let mut tasks: Vec<Task> = DEFAULT_COMMANDS
+        .iter()
+        .map(|(name, description)| create_task(path, name, Some(description.to_string())))
+        .collect();
+
+    for (name, definition) in find_aliases(content)? {
+        let description = Some(format!("Alias for {}", collapse_whitespace(&definition)));
+        let task = create_task(path, &name, description);
+        // An alias with a built-in's name replaces it.
+        match tasks.iter_mut().find(|existing| existing.name == name) {
+            Some(existing) => *existing = task,
+            None => tasks.push(task),
+        }
+    }
+
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::ProjectClj,
+        runner: TaskRunner::Leiningen,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Return `(name, definition source)` for each entry in the top-level `:aliases {...}` map.
+///
+/// Only `:aliases` directly inside `defproject` counts; profile-specific aliases are skipped.
+fn find_aliases(content: &str) -> Result<Vec<(String, String)>, DelaParseError> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut depth = 0usize;
+    let mut index = 0;
+
+    while index < chars.len() {
+        match chars[index] {
+            '"' => {
+                index = skip_string(&chars, index)?;
+                continue;
+            }
+            ';' => {
+                index = skip_comment(&chars, index);
+                continue;
+            }
+            '\\' => index += 1,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ':' if depth == 1 && keyword_at(&chars, index) == ":aliases" => {
+                index = skip_whitespace(&chars, index + ":aliases".len());
+                if chars.get(index) != Some(&'{') {
+                    return Err(DelaParseError::Syntax(
+                        ":aliases is not followed by a map".to_string(),
+                    ));
+                }
+                return parse_alias_map(&chars, index + 1);
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    Ok(Vec::new())
+}
+
+fn parse_alias_map(chars: &[char], start: usize) -> Result<Vec<(String, String)>, DelaParseError> {
+    let mut aliases = Vec::new();
+    let mut index = skip_whitespace(chars, start);
+
+    while chars.get(index).is_some_and(|c| *c != '}') {
+        let key_end = skip_form(chars, index)?;
+        let key: String = chars[index..key_end].iter().collect();
+        index = skip_whitespace(chars, key_end);
+        if chars.get(index).is_none_or(|c| *c == '}') {
+            return Err(DelaParseError::Syntax(format!(
+                "alias {} has no definition",
+                key
+            )));
+        }
+
+        let value_end = skip_form(chars, index)?;
+        let value: String = chars[index..value_end].iter().collect();
+        index = skip_whitespace(chars, value_end);
+
+        let name = key.trim_matches('"').trim_start_matches(':');
+        if !name.is_empty() {
+            aliases.push((name.to_string(), value));
+        }
+    }
+
+    if index >= chars.len() {
+        return Err(DelaParseError::Syntax(
+            "unterminated :aliases map".to_string(),
+        ));
+    }
+    Ok(aliases)
+}
+
+fn keyword_at(chars: &[char], start: usize) -> String {
+    chars[start..]
+        .iter()
+        .take_while(|c| !is_delimiter(**c))
+        .collect()
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || c == ',' || "()[]{}\";".contains(c)
+}
+
+/// Skip whitespace, commas (whitespace in EDN) and comments.
+fn skip_whitespace(chars: &[char], mut index: usize) -> usize {
+    while let Some(&c) = chars.get(index) {
+        if c.is_whitespace() || c == ',' {
+            index += 1;
+        } else if c == ';' {
+            index = skip_comment(chars, index);
+        } else {
+            break;
+        }
+    }
+    index
+}
+
+fn skip_comment(chars: &[char], index: usize) -> usize {
+    chars[index..]
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(chars.len(), |offset| index + offset + 1)
+}
+
+fn skip_string(chars: &[char], start: usize) -> Result<usize, DelaParseError> {
+    let mut index = start + 1;
+    while let Some(&c) = chars.get(index) {
+        match c {
+            '\\' => index += 2,
+            '"' => return Ok(index + 1),
+            _ => index += 1,
+        }
+    }
+    Err(DelaParseError::Syntax("unterminated string".to_string()))
+}
+
+/// Return the index just past the form starting at `start`, including nested collections.
+fn skip_form(chars: &[char], start: usize) -> Result<usize, DelaParseError> {
+    let mut index = start;
+    // Metadata such as `^:replace` is its own form, attached to the one after it.
+    if chars.get(index) == Some(&'^') {
+        let metadata_end = skip_form(chars, index + 1)?;
+        return skip_form(chars, skip_whitespace(chars, metadata_end));
+    }
+    // Reader macros such as `#'` or `@` prefix the form they apply to.
+    while let Some('#' | '\'' | '@' | '`' | '~') = chars.get(index) {
+        index += 1;
+    }
+
+    match chars.get(index) {
+        Some('"') => skip_string(chars, index),
+        Some('(' | '[' | '{') => {
+            let mut depth = 0usize;
+            while let Some(&c) = chars.get(index) {
+                match c {
+                    '"' => {
+                        index = skip_string(chars, index)?;
+                        continue;
+                    }
+                    ';' => {
+                        index = skip_comment(chars, index);
+                        continue;
+                    }
+                    '\\' => index += 1,
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(index + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                index += 1;
+            }
+            Err(DelaParseError::Syntax("unbalanced brackets".to_string()))
+        }
+        Some(_) => {
+            let length = chars[index..]
+                .iter()
+                .take_while(|c| !is_delimiter(**c))
+                .count();
+            Ok(index + length.max(1))
+        }
+        None => Err(DelaParseError::Syntax("unexpected end of file".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project_clj_aliases() {
+        let content = r#"
+(defproject demo "0.1.0-SNAPSHOT"
+  :description "A demo; not an alias"
+  :dependencies [[org.clojure/clojure "1.11.1"]]
+  ;; :aliases {"commented" ["run"]}
+  :aliases {"lint" ["do" ["clean"] ["check"], "kibit"]
+            "kaocha" ["with-profile" "+test" "run" "-m" "kaocha.runner"]
+            "test" ^:replace ["kaocha"]
+            :fmt ["cljfmt" "fix"]}
+  :profiles {:dev {:aliases {"nested" ["run"]}}})
+"#;
+        let tasks = parse_project_clj_string(content, Path::new("project.clj")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["test", "run", "uberjar", "lint", "kaocha", "fmt"]
+        );
+        assert_eq!(
+            tasks[3].description.as_deref(),
+            Some(r#"Alias for ["do" ["clean"] ["check"], "kibit"]"#)
+        );
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some(r#"Alias for ^:replace ["kaocha"]"#)
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Leiningen);
+            assert_eq!(task.definition_type, TaskDefinitionType::ProjectClj);
+        }
+    }
+
+    #[test]
+    fn test_parse_project_clj_without_aliases() {
+        let content = "(defproject demo \"0.1.0\"\n  :main demo.core\n  :profiles {:dev {:aliases {\"repl\" [\"run\"]}}})\n";
+        let tasks = parse_project_clj_string(content, Path::new("project.clj")).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test", "run", "uberjar"]);
+    }
+
+    #[test]
+    fn test_parse_project_clj_unbalanced_aliases() {
+        let content = "(defproject demo \"0.1.0\"\n  :aliases {\"lint\" [\"do\" [\"clean\"]}\n";
+        assert!(parse_project_clj_string(content, Path::new("project.clj")).is_err());
+    }
+
+    #[test]
+    fn test_leiningen_get_command() {
+        let tasks = parse_project_clj_string(
+            "(defproject demo \"0.1.0\" :aliases {\"lint\" [\"eastwood\"]})",
+            Path::new("project.clj"),
+        )
+        .unwrap();
+        let lint = tasks.iter().find(|t| t.name == "lint").unwrap();
+        assert_eq!(TaskRunner::Leiningen.get_command(lint), "lein lint");
+    }
+}
@@ -0,0 +1,190 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use std::path::Path;
+
+/// Parse a magefile.go and extract its exported functions as mage targets
+///
+/// Only files carrying the `mage` build tag are magefiles. Methods on a `mg.Namespace`
+/// type become `namespace:target` tasks. Mage matches target names case-insensitively
+/// and lists them in lowercase, so task names are lowercased too.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_mage_string(&content, path)
+}
+
+fn parse_mage_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    if !has_mage_build_tag(content) {
+        return Ok(Vec::new());
+    }
+
+    let func_pattern = Regex::new(
+        r"^func\s+(?:\(\s*(?:[A-Za-z_][A-Za-z0-9_]*\s+)?([A-Z][A-Za-z0-9_]*)\s*\)\s*)?([A-Z][A-Za-z0-9_]*)\s*\(([^)]*)\)",
+    )?;
+    let namespace_pattern = Regex::new(r"^type\s+([A-Z][A-Za-z0-9_]*)\s+mg\.Namespace\b")?;
+    let namespaces: Vec<&str> = content
+        .lines()
+        .filter_map(|line| namespace_pattern.captures(line))
+        .filter_map(|captures| captures.get(1).map(|name| name.as_str()))
+        .collect();
+
+    let mut tasks = Vec::new();
+    let mut doc_lines: Vec<&str> = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if let Some(comment) = line.strip_prefix("//") {
+            doc_lines.push(comment.trim());
+            continue;
+        }
+        let doc = std::mem::take(&mut doc_lines);
+
+        let Some(captures) = func_pattern.captures(line) else {
+            continue;
+        };
+        let name = match captures.get(1) {
+            Some(receiver) if namespaces.contains(&receiver.as_str()) => format!(
+                "{}:{}",
+                receiver.as_str().to_lowercase(),
+                captures[2].to_lowercase()
+            ),
+            // Methods on other types are helpers, not targets
+            Some(_) => continue,
+            None => captures[2].to_lowercase(),
+        };
+        if tasks.iter().any(|task: &Task| task.name == name) {
+            continue;
+        }
+
+        let description = Some(doc.join(" ")).filter(|description| !description.is_empty());
+        let mut task = create_task(path, &name, description);
+        task.parameters = target_arguments(&captures[3]);
+        task.line = Some(index + 1);
+        tasks.push(task);
+    }
+
+    Ok(tasks)
+}
+
+/// Whether the file is limited to mage builds by a `//go:build` or `// +build` line.
+fn has_mage_build_tag(content: &str) -> bool {
+    content.lines().any(|line| {
+        let line = line.trim();
+        line.strip_prefix("//go:build")
+            .or_else(|| line.strip_prefix("// +build"))
+            .is_some_and(|constraint| {
+                constraint
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .any(|tag| tag == "mage")
+            })
+    })
+}
+
+/// The arguments mage asks for on the command line, which are every parameter except a
+/// leading `context.Context`.
+fn target_arguments(parameters: &str) -> Vec<String> {
+    parameters
+        .split(',')
+        .map(str::trim)
+        .filter(|parameter| !parameter.is_empty() && !parameter.ends_with("context.Context"))
+        .filter_map(|parameter| parameter.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::Magefile,
+        runner: TaskRunner::Mage,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mage_targets() {
+        let content = r#"//go:build mage
+
+package main
+
+import (
+	"context"
+
+	"github.com/magefile/mage/mg"
+)
+
+type Docker mg.Namespace
+
+// Build compiles the binary.
+func Build() error {
+	return nil
+}
+
+// Deploy ships the binary to an environment.
+func Deploy(ctx context.Context, env string, replicas int) error {
+	mg.Deps(Build)
+	return nil
+}
+
+// Image builds the container image.
+func (Docker) Image() error {
+	return nil
+}
+
+func helper() {}
+
+type builder struct{}
+
+func (b builder) Run() {}
+"#;
+        let tasks = parse_mage_string(content, Path::new("magefile.go")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "deploy", "docker:image"]);
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("Build compiles the binary.")
+        );
+        assert_eq!(tasks[0].line, Some(14));
+        assert_eq!(tasks[1].parameters, vec!["env", "replicas"]);
+        assert_eq!(
+            tasks[2].description.as_deref(),
+            Some("Image builds the container image.")
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Mage);
+            assert_eq!(task.definition_type, TaskDefinitionType::Magefile);
+        }
+        assert_eq!(TaskRunner::Mage.get_command(&tasks[2]), "mage docker:image");
+    }
+
+    #[test]
+    fn test_parse_mage_requires_build_tag() {
+        let content = "package main\n\nfunc Build() error { return nil }\n";
+        let tasks = parse_mage_string(content, Path::new("magefile.go")).unwrap();
+        assert!(tasks.is_empty());
+
+        let legacy = format!("// +build mage\n\n{}", content);
+        let tasks = parse_mage_string(&legacy, Path::new("magefile.go")).unwrap();
+        assert_eq!(tasks.len(), 1);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::parsers::sudo::starts_with_sudo;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use makefile_lossless::Makefile;
 use regex::Regex;
@@ -13,9 +15,91 @@ pub struct MakefileInclude {
 }
 
 /// Parse a Makefile at the given path and extract tasks
+///
+/// Target-specific variables such as `build: CFLAGS = -O2` are listed as the target's
+/// parameters rather than being read as prerequisites. Inline recipes such as
+/// `test: build ; cargo test` are split off before the rule is parsed. A `## comment` after
+/// the targets, or on the line above the rule, is the task's description.
 pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
-    let content = std::fs::read_to_string(path)?;
+    let content = blank_define_blocks(&read_definition(path)?);
+    let (content, target_variables) = take_target_variables(&content);
+    let (content, inline_recipes) = take_inline_recipes(&content);
+    let descriptions = documented_targets(&content);
+
+    let mut tasks = parse_content(&content, path)?;
+    for task in &mut tasks {
+        if let Some(description) = descriptions.get(&task.name) {
+            task.description = Some(description.clone());
+        }
+        if let Some(variables) = target_variables.get(&task.name) {
+            task.parameters.extend(variables.iter().cloned());
+        }
+        if let Some(recipes) = inline_recipes.get(&task.name) {
+            if task.body.is_none() {
+                task.body = Some(recipes.join("\n")).filter(|body| !body.is_empty());
+            }
+            task.runnable = true;
+            task.requires_sudo |= recipes.iter().any(|recipe| starts_with_sudo(recipe));
+        }
+        task.line = target_line(&content, &task.name);
+    }
+    Ok(tasks)
+}
+
+/// The 1-based line of the first rule that lists `name` as a target.
+fn target_line(content: &str, name: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| {
+            rule_targets(line)
+                .is_some_and(|targets| targets.split_whitespace().any(|target| target == name))
+        })
+        .map(|index| index + 1)
+}
+
+/// The targets of a rule line, or `None` for recipes, comments, and assignments.
+fn rule_targets(line: &str) -> Option<&str> {
+    if line.starts_with('\t') || line.trim_start().starts_with('#') {
+        return None;
+    }
+    let (targets, rest) = line.split_once(':')?;
+    (!rest.starts_with('=')).then_some(targets)
+}
+
+/// Descriptions written in the self-documenting `target: ## description` style, keyed by
+/// target. A rule without a trailing `##` comment takes the `##` lines right above it.
+fn documented_targets(content: &str) -> HashMap<String, String> {
+    let mut descriptions = HashMap::new();
+    let mut doc_lines: Vec<&str> = Vec::new();
+
+    for line in content.lines() {
+        if let Some(doc) = line.trim_start().strip_prefix("##") {
+            doc_lines.push(doc.trim());
+            continue;
+        }
+        let doc = std::mem::take(&mut doc_lines);
 
+        let Some(targets) = rule_targets(line) else {
+            continue;
+        };
+        let description = match line.split_once("##") {
+            Some((_, trailing)) => trailing.trim().to_string(),
+            None => doc.join(" "),
+        };
+        if description.is_empty() {
+            continue;
+        }
+        for target in targets.split_whitespace() {
+            descriptions
+                .entry(target.to_string())
+                .or_insert_with(|| description.clone());
+        }
+    }
+
+    descriptions
+}
+
+fn parse_content(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
     // Special case for the test_discover_tasks_with_invalid_makefile test
     if content.contains("<hello>not a make file</hello>") {
         return Err(DelaParseError::Syntax("Invalid syntax".to_string()));
@@ -23,15 +107,15 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
 
     // Special case for testing regex parsing - look for a marker in the content
     if content.contains("# TEST_FORCE_REGEX_PARSING") {
-        return extract_tasks_regex(&content, path);
+        return extract_tasks_regex(content, path);
     }
 
     // Try standard parsing first
-    match Makefile::read(std::io::Cursor::new(&content)) {
+    match Makefile::read(std::io::Cursor::new(content)) {
         Ok(makefile) => extract_tasks(&makefile, path),
         Err(e) => {
             // If standard parsing fails, try regex-based parsing as fallback
-            match extract_tasks_regex(&content, path) {
+            match extract_tasks_regex(content, path) {
                 Ok(tasks) => Ok(tasks),
                 Err(_) => Err(DelaParseError::Syntax(format!(
                     "Failed to parse Makefile: {}",
@@ -74,8 +158,26 @@ fn extract_tasks(makefile: &Makefile, path: &Path) -> Result<Vec<Task>, DelaPars
             }
         });
 
-        // Only add the task if it hasn't been seen before
-        if !tasks_map.contains_key(&name) {
+        // A rule with no prerequisites and no commands does nothing when made
+        let runnable = rule.prerequisites().next().is_some()
+            || rule
+                .recipes()
+                .any(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'));
+
+        let prerequisites: Vec<String> = rule.prerequisites().collect();
+        let requires_sudo = rule.recipes().any(|line| starts_with_sudo(&line));
+        let body = Some(rule.recipes().collect::<Vec<_>>().join("\n"))
+            .filter(|body| !body.trim().is_empty());
+
+        // Only add the task if it hasn't been seen before; the recipe may come from a later rule
+        if let Some(task) = tasks_map.get_mut(&name) {
+            task.runnable |= runnable;
+            task.requires_sudo |= requires_sudo;
+            task.dependencies.extend(prerequisites);
+            if task.body.is_none() {
+                task.body = body;
+            }
+        } else {
             tasks_map.insert(
                 name.clone(),
                 Task {
@@ -88,6 +190,17 @@ fn extract_tasks(makefile: &Makefile, path: &Path) -> Result<Vec<Task>, DelaPars
                     description,
                     shadowed_by: None,
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable,
+                    dependencies: prerequisites,
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo,
+                    line: None,
+                    body,
+                    kind: None,
                 },
             );
         }
@@ -99,7 +212,7 @@ fn extract_tasks(makefile: &Makefile, path: &Path) -> Result<Vec<Task>, DelaPars
 
 /// Extract Makefile include directives from a file.
 pub fn extract_include_directives(path: &Path) -> Result<Vec<MakefileInclude>, DelaParseError> {
-    let content = std::fs::read_to_string(path)?;
+    let content = read_definition(path)?;
     Ok(extract_include_directives_from_str(&content))
 }
 
@@ -195,6 +308,128 @@ fn collapse_line_continuations(content: &str) -> String {
     collapsed
 }
 
+/// Blank out the bodies of `define NAME ... endef` blocks, keeping line numbers intact.
+///
+/// Canned recipes are variables rather than targets, but their bodies often look like
+/// rules and would otherwise be reported as tasks or derail the parser.
+fn blank_define_blocks(content: &str) -> String {
+    let mut depth = 0usize;
+    let mut output = String::with_capacity(content.len());
+
+    for line in content.split_inclusive('\n') {
+        let mut words = line.split_whitespace();
+        let first = match words.next() {
+            Some("override" | "export" | "private") => words.next(),
+            first => first,
+        };
+        match first {
+            Some("define") => depth += 1,
+            Some("endef") if depth > 0 => {
+                depth -= 1;
+                output.push('\n');
+                continue;
+            }
+            _ => {}
+        }
+
+        if depth == 0 {
+            output.push_str(line);
+        } else if line.ends_with('\n') {
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Blank out target-specific variable assignments such as `build: CFLAGS = -O2`, keeping
+/// line numbers intact, and return them as `NAME="value"` keyed by target.
+///
+/// These lines set a variable while the target is made; they do not define a rule, and
+/// the parser would otherwise read the assignment as prerequisites.
+fn take_target_variables(content: &str) -> (String, HashMap<String, Vec<String>>) {
+    let assignment = Regex::new(
+        r"^([^\t#:=][^:=]*?)\s*:\s*(?:(?:export|unexport|override|private)\s+)*([A-Za-z_][A-Za-z0-9_.-]*)\s*(?:\?=|::=|:=|\+=|!=|=)(.*)$",
+    )
+    .expect("target variable pattern is valid");
+    let mut variables: HashMap<String, Vec<String>> = HashMap::new();
+    let mut output = String::with_capacity(content.len());
+    let mut lines = content.split_inclusive('\n');
+
+    while let Some(line) = lines.next() {
+        let Some(captures) = assignment.captures(line.trim_end_matches(['\n', '\r'])) else {
+            output.push_str(line);
+            continue;
+        };
+
+        let mut value = captures[3].to_string();
+        let mut blanked_lines = 1;
+        while value.trim_end().ends_with('\\') {
+            let Some(next) = lines.next() else {
+                break;
+            };
+            value = format!(
+                "{} {}",
+                value.trim_end().trim_end_matches('\\').trim_end(),
+                next.trim()
+            );
+            blanked_lines += 1;
+        }
+        let value = strip_trailing_comment(&value).trim();
+        let variable = format!("{}=\"{}\"", &captures[2], value);
+        for target in captures[1].split_whitespace() {
+            variables
+                .entry(target.to_string())
+                .or_default()
+                .push(variable.clone());
+        }
+        output.push_str(&"\n".repeat(blanked_lines));
+    }
+
+    (output, variables)
+}
+
+/// Cut the inline recipe off rules such as `test: build ; cargo test`, keeping line numbers
+/// intact, and return the recipes keyed by target.
+///
+/// The parser would otherwise read the recipe as more prerequisites of the rule.
+fn take_inline_recipes(content: &str) -> (String, HashMap<String, Vec<String>>) {
+    let mut recipes: HashMap<String, Vec<String>> = HashMap::new();
+    let mut output = String::with_capacity(content.len());
+
+    for line in content.split_inclusive('\n') {
+        let Some((rule, recipe)) = split_inline_recipe(line.trim_end_matches(['\n', '\r'])) else {
+            output.push_str(line);
+            continue;
+        };
+        let targets = rule.split_once(':').map_or(rule, |(targets, _)| targets);
+        for target in targets.split_whitespace() {
+            recipes
+                .entry(target.to_string())
+                .or_default()
+                .push(recipe.trim().to_string());
+        }
+        output.push_str(rule.trim_end());
+        output.push('\n');
+    }
+
+    (output, recipes)
+}
+
+/// Split a rule line at the `;` that starts its inline recipe, if it has one.
+fn split_inline_recipe(line: &str) -> Option<(&str, &str)> {
+    if line.starts_with('\t') || line.trim_start().starts_with('#') {
+        return None;
+    }
+    let colon = line.find(':')?;
+    // An `=` before the colon, or right after it, makes the line an assignment
+    if line[..colon].contains('=') || line[colon..].trim_start_matches(':').starts_with('=') {
+        return None;
+    }
+    let semicolon = colon + strip_trailing_comment(&line[colon..]).find(';')?;
+    Some((&line[..semicolon], &line[semicolon + 1..]))
+}
+
 fn strip_trailing_comment(input: &str) -> &str {
     let mut in_single = false;
     let mut in_double = false;
@@ -225,6 +460,12 @@ fn contains_dynamic_make_syntax(token: &str) -> bool {
     token.contains('$') || token.contains('*') || token.contains('?') || token.contains('[')
 }
 
+/// Words that start a make directive rather than a rule
+const MAKE_DIRECTIVES: &[&str] = &[
+    "ifeq", "ifneq", "ifdef", "ifndef", "else", "endif", "define", "endef", "include", "-include",
+    "sinclude", "export", "unexport", "override", "private", "vpath", "undefine",
+];
+
 /// Extract tasks using regex as a fallback method when standard parsing fails
 fn extract_tasks_regex(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
     let mut tasks_map: HashMap<String, Task> = HashMap::new();
@@ -255,6 +496,21 @@ fn extract_tasks_regex(content: &str, path: &Path) -> Result<Vec<Task>, DelaPars
             continue;
         }
 
+        // Skip directives such as `ifeq (a,b:c)` or `vpath %.c src:lib`
+        if name_part
+            .split_whitespace()
+            .next()
+            .is_some_and(|word| MAKE_DIRECTIVES.contains(&word))
+        {
+            continue;
+        }
+
+        let prerequisites: Vec<String> = strip_trailing_comment(rest_of_line)
+            .split_whitespace()
+            .filter(|prerequisite| *prerequisite != "|")
+            .map(str::to_string)
+            .collect();
+
         // Handle multiple targets on the same line (e.g. `build test:`)
         for name in name_part.split_whitespace() {
             // Skip pattern rules, dot targets, and underscore-prefixed targets (private)
@@ -265,7 +521,9 @@ fn extract_tasks_regex(content: &str, path: &Path) -> Result<Vec<Task>, DelaPars
             let name = name.to_string();
 
             // Only add the task if it hasn't been seen before
-            if !tasks_map.contains_key(&name) {
+            if let Some(task) = tasks_map.get_mut(&name) {
+                task.dependencies.extend(prerequisites.iter().cloned());
+            } else {
                 tasks_map.insert(
                     name.clone(),
                     Task {
@@ -278,6 +536,17 @@ fn extract_tasks_regex(content: &str, path: &Path) -> Result<Vec<Task>, DelaPars
                         description: None, // No descriptions in fallback mode
                         shadowed_by: None,
                         disambiguated_name: None,
+                        parameters: Vec::new(),
+                        runnable: true,
+                        dependencies: prerequisites.clone(),
+                        workspace_dir: None,
+                        tags: Vec::new(),
+                        hidden: false,
+                        deprecated: false,
+                        requires_sudo: false,
+                        line: None,
+                        body: None,
+                        kind: None,
                     },
                 );
             }
@@ -791,4 +1060,173 @@ build:
         assert_eq!(includes[0].path, PathBuf::from("first.mk"));
         assert_eq!(includes[1].path, PathBuf::from("second.mk"));
     }
+
+    #[test]
+    fn test_parse_skips_define_blocks() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = r#"define RUN_CHECKS
+	@echo checking $(1)
+lint: fake
+	cargo clippy
+endef
+
+override define BANNER
+release:
+endef
+
+build:
+	$(call RUN_CHECKS,build)
+
+test:
+	cargo test
+"#;
+        let makefile_path = create_test_makefile(temp_dir.path(), content);
+
+        let mut tasks = parse(&makefile_path).unwrap();
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "test"]);
+        assert!(tasks.iter().all(|task| task.runnable));
+    }
+
+    #[test]
+    fn test_parse_marks_empty_recipes_not_runnable() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = r#"placeholder:
+
+all: build
+
+build:
+	cargo build
+
+later:
+
+later:
+	@echo "defined twice""#;
+        let makefile_path = create_test_makefile(temp_dir.path(), content);
+
+        let tasks = parse(&makefile_path).unwrap();
+        let runnable = |name: &str| tasks.iter().find(|t| t.name == name).unwrap().runnable;
+        assert!(!runnable("placeholder"));
+        assert!(runnable("all"));
+        assert!(runnable("build"));
+        assert!(runnable("later"));
+
+        let all = tasks.iter().find(|t| t.name == "all").unwrap();
+        assert_eq!(all.dependencies, vec!["build"]);
+    }
+
+    #[test]
+    fn test_parse_target_specific_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "CC := gcc\n\
+\n\
+build: CFLAGS = -O2 # optimized\n\
+build: export LDFLAGS := -s\n\
+build: main.o\n\
+\t$(CC) $(CFLAGS) -o app main.o\n\
+\n\
+debug release: override DEFINES += -DLOG \\\n\
+\t-DTRACE\n\
+debug: build\n\
+\n\
+coverage: private COVERAGE ?= 1\n";
+        let makefile_path = create_test_makefile(temp_dir.path(), content);
+
+        let mut tasks = parse(&makefile_path).unwrap();
+        tasks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "debug"], "no phantom tasks");
+
+        let build = &tasks[0];
+        assert_eq!(build.dependencies, vec!["main.o"]);
+        assert!(build.runnable);
+        assert_eq!(build.parameters, vec![r#"CFLAGS="-O2""#, r#"LDFLAGS="-s""#]);
+
+        let debug = &tasks[1];
+        assert_eq!(debug.dependencies, vec!["build"]);
+        assert_eq!(debug.parameters, vec![r#"DEFINES="-DLOG -DTRACE""#]);
+    }
+
+    #[test]
+    fn test_parse_flags_sudo_recipes() {
+        let temp_dir = TempDir::new().unwrap();
+        let content =
+            "install:\n\t@sudo cp bin/app /usr/local/bin\n\nbuild:\n\techo sudo is not run here\n";
+        let makefile_path = create_test_makefile(temp_dir.path(), content);
+
+        let tasks = parse(&makefile_path).unwrap();
+        let requires_sudo =
+            |name: &str| tasks.iter().find(|t| t.name == name).unwrap().requires_sudo;
+        assert!(requires_sudo("install"));
+        assert!(!requires_sudo("build"));
+    }
+
+    #[test]
+    fn test_parse_records_target_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = create_test_makefile(
+            temp_dir.path(),
+            "VERSION := 1.0\n\n# Build it\nbuild:\n\techo build\n\nlint test: build\n\techo check\n",
+        );
+        let tasks = parse(&path).unwrap();
+        let line_of = |name: &str| tasks.iter().find(|t| t.name == name).unwrap().line;
+        assert_eq!(line_of("build"), Some(4));
+        assert_eq!(line_of("lint"), Some(7));
+    }
+
+    #[test]
+    fn test_parse_skips_assignments_and_conditionals() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "CC=gcc\nCFLAGS := -O2\nPREFIX ?= /usr/local\nLDFLAGS += -lm\nURL = http://example.com:8080\nOUT != echo out\nexport PATH := $(PATH):/opt/bin\noverride DEBUG = 1\ndefine BANNER\nbanner: not a target\nendef\n\nifeq ($(CC),gcc)\nWARN = -Wall\nelse ifeq ($(CC),clang)\nWARN = -Weverything\nelse\nWARN =\nendif\nifdef CI\nci: build\nendif\n\nbuild: ; @echo build\ntest: build ; $(CC) test.c\nclean:\n\trm -f *.o\n";
+        let path = create_test_makefile(temp_dir.path(), content);
+
+        let tasks = parse(&path).unwrap();
+        let mut names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["build", "ci", "clean", "test"]);
+
+        let build = tasks.iter().find(|t| t.name == "build").unwrap();
+        assert!(build.runnable);
+        assert!(build.dependencies.is_empty());
+        let test = tasks.iter().find(|t| t.name == "test").unwrap();
+        assert!(test.runnable);
+        assert_eq!(test.dependencies, vec!["build"]);
+        assert_eq!(test.line, Some(25));
+    }
+
+    #[test]
+    fn test_parse_double_hash_descriptions() {
+        let temp_dir = TempDir::new().unwrap();
+        let content = "## Build the release binaries\nbuild:\n\t@echo \"Building\"\n\tcargo build\n\ntest: build ## Run the test suite\n\tcargo test\n\n## Not this one\nlint: ## Check formatting; then lint\n\tcargo fmt --check\n\n## Remove build output\n\nclean:\n\t@echo \"Cleaning\"\n";
+        let path = create_test_makefile(temp_dir.path(), content);
+
+        let tasks = parse(&path).unwrap();
+        let description = |name: &str| {
+            tasks
+                .iter()
+                .find(|t| t.name == name)
+                .unwrap()
+                .description
+                .clone()
+        };
+        assert_eq!(
+            description("build").as_deref(),
+            Some("Build the release binaries")
+        );
+        assert_eq!(description("test").as_deref(), Some("Run the test suite"));
+        assert_eq!(
+            description("lint").as_deref(),
+            Some("Check formatting; then lint")
+        );
+        // A blank line separates the comment from the rule, so the @echo text is used
+        assert_eq!(description("clean").as_deref(), Some("Cleaning"));
+
+        let test = tasks.iter().find(|t| t.name == "test").unwrap();
+        assert_eq!(test.dependencies, vec!["build"]);
+        let lint = tasks.iter().find(|t| t.name == "lint").unwrap();
+        assert!(lint.dependencies.is_empty());
+    }
 }
@@ -0,0 +1,173 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use std::path::Path;
+
+/// Parse a meson.build and extract `executable`, `test` and `run_target` targets as tasks
+///
+/// The target kind is kept as the task's kind, which is how `get_command` picks between
+/// `meson test` and `meson compile`, and is shown as its description. Tests have names
+/// of their own in Meson, so a test named like a build target is listed as `<name>-test`.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_meson_string(&content, path)
+}
+
+fn parse_meson_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    // `\s*` also spans newlines, so a call whose name is on the next line still matches.
+    // The negative context avoids method calls like `foo.test(` and names like `my_test(`.
+    let call_pattern =
+        Regex::new(r#"(?:^|[^\w.])(executable|test|run_target)\s*\(\s*'((?:[^'\\]|\\.)*)'"#)?;
+    let code = strip_comments(content);
+
+    let is_test = |task: &Task| task.kind.as_deref() == Some("test");
+    let mut tasks: Vec<Task> = Vec::new();
+    for captures in call_pattern.captures_iter(&code) {
+        let kind = &captures[1];
+        let name = &captures[2];
+        if name.is_empty()
+            || tasks
+                .iter()
+                .any(|task| task.source_name == name && is_test(task) == (kind == "test"))
+        {
+            continue;
+        }
+        tasks.push(Task {
+            name: name.to_string(),
+            file_path: path.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::MesonBuild,
+            runner: TaskRunner::Meson,
+            source_name: name.to_string(),
+            description: Some(kind.to_string()),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: Some(kind.to_string()),
+        });
+    }
+
+    let build_targets: Vec<String> = tasks
+        .iter()
+        .filter(|task| !is_test(task))
+        .map(|task| task.source_name.clone())
+        .collect();
+    for task in tasks.iter_mut() {
+        if is_test(task) && build_targets.contains(&task.source_name) {
+            task.name = format!("{}-test", task.source_name);
+        }
+    }
+
+    Ok(tasks)
+}
+
+/// Remove `#` comments, leaving `#` inside string literals alone.
+fn strip_comments(content: &str) -> String {
+    let mut code = String::with_capacity(content.len());
+    for line in content.lines() {
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in line.chars() {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '\'' {
+                    in_string = false;
+                }
+            } else if c == '\'' {
+                in_string = true;
+            } else if c == '#' {
+                break;
+            }
+            code.push(c);
+        }
+        code.push('\n');
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meson_targets() {
+        let content = r#"
+project('demo', 'c')
+
+app = executable('demo-app', 'main.c',
+  install : true)
+
+test_exe = executable(
+  'unit-tests',
+  'tests.c',
+)
+test('unit', test_exe, args : ['--color=#auto'])
+
+run_target('docs',
+  command : ['doxygen'])
+
+# executable('old-tool', 'old.c')
+#test('disabled',
+#  test_exe)
+summary = test_exe.test('not-a-target')
+"#;
+        let tasks = parse_meson_string(content, Path::new("meson.build")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["demo-app", "unit-tests", "unit", "docs"]);
+        assert_eq!(tasks[2].description.as_deref(), Some("test"));
+        assert_eq!(tasks[2].kind.as_deref(), Some("test"));
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Meson);
+            assert_eq!(task.definition_type, TaskDefinitionType::MesonBuild);
+        }
+    }
+
+    #[test]
+    fn test_meson_get_command() {
+        let content =
+            "executable('app', 'main.c')\ntest('smoke', app)\nrun_target('fmt', command : 'x')\n";
+        let tasks = parse_meson_string(content, Path::new("meson.build")).unwrap();
+
+        assert_eq!(
+            TaskRunner::Meson.get_command(&tasks[0]),
+            "meson compile app"
+        );
+        assert_eq!(TaskRunner::Meson.get_command(&tasks[1]), "meson test smoke");
+        assert_eq!(
+            TaskRunner::Meson.get_command(&tasks[2]),
+            "meson compile fmt"
+        );
+    }
+
+    #[test]
+    fn test_meson_test_named_like_a_target() {
+        let content = "app = executable('app', 'main.c')\ntest('app', app)\n";
+        let mut tasks = parse_meson_string(content, Path::new("meson.build")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["app", "app-test"]);
+        assert_eq!(
+            TaskRunner::Meson.get_command(&tasks[0]),
+            "meson compile app"
+        );
+        assert_eq!(TaskRunner::Meson.get_command(&tasks[1]), "meson test app");
+
+        // A sidecar description does not change which command runs
+        tasks[1].description = Some("Run the app tests".to_string());
+        assert_eq!(TaskRunner::Meson.get_command(&tasks[1]), "meson test app");
+    }
+}
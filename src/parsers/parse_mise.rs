@@ -0,0 +1,232 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::fs;
+use std::path::Path;
+
+/// Parse a mise.toml (or .mise.toml) and extract its `[tasks.NAME]` tables as tasks
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    let toml: toml::Value = toml::from_str(&content)?;
+
+    let Some(tasks) = toml.get("tasks") else {
+        return Ok(Vec::new());
+    };
+    let Some(tasks) = tasks.as_table() else {
+        return Err(DelaParseError::Syntax(
+            "Failed to parse mise config: 'tasks' must be a table".to_string(),
+        ));
+    };
+
+    Ok(tasks
+        .iter()
+        .map(|(name, config)| {
+            // `name = "command"` is shorthand for a task with only `run`
+            let description = config
+                .get("description")
+                .and_then(toml::Value::as_str)
+                .map(str::to_string);
+            let dependencies = match config.get("depends") {
+                Some(toml::Value::String(dependency)) => vec![dependency.clone()],
+                Some(toml::Value::Array(dependencies)) => dependencies
+                    .iter()
+                    .filter_map(toml::Value::as_str)
+                    .map(str::to_string)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            create_task(
+                name,
+                path,
+                TaskDefinitionType::MiseToml,
+                description,
+                dependencies,
+            )
+        })
+        .collect())
+}
+
+/// Expose each executable file directly inside a `.mise/tasks` directory as a task
+///
+/// Descriptions come from a `#MISE description="..."` header comment, as mise reads them.
+pub fn parse_task_dir(dir: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let mut tasks = Vec::new();
+
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        if !path.is_file() || !is_executable(&path) {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        // Any executable can sit here, so a large binary is left without a description
+        let description = read_definition(&path)
+            .ok()
+            .and_then(|content| header_description(&content));
+        tasks.push(create_task(
+            &name,
+            &path,
+            TaskDefinitionType::MiseTaskDir,
+            description,
+            Vec::new(),
+        ));
+    }
+
+    tasks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(tasks)
+}
+
+fn create_task(
+    name: &str,
+    path: &Path,
+    definition_type: TaskDefinitionType,
+    description: Option<String>,
+    dependencies: Vec<String>,
+) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type,
+        runner: TaskRunner::Mise,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies,
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+fn header_description(content: &str) -> Option<String> {
+    content
+        .lines()
+        .take_while(|line| line.starts_with('#') || line.trim().is_empty())
+        .filter_map(|line| {
+            line.strip_prefix("#MISE")
+                .or_else(|| line.strip_prefix("# [MISE]"))
+        })
+        .find_map(|directive| {
+            let value = directive.trim().strip_prefix("description")?;
+            let value = value.trim_start().strip_prefix('=')?.trim();
+            Some(value.trim_matches('"').to_string())
+        })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path)
+        .map(|metadata| metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_mise_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mise.toml");
+        fs::write(
+            &path,
+            r#"
+[tools]
+node = "20"
+
+[tasks]
+lint = "eslint ."
+
+[tasks.build]
+description = "Build the app"
+run = "npm run build"
+
+[tasks.deploy]
+description = "Deploy to production"
+depends = ["build", "lint"]
+run = "./deploy.sh"
+
+[tasks.release]
+depends = "deploy"
+run = "gh release create"
+"#,
+        )
+        .unwrap();
+
+        let tasks = parse(&path).unwrap();
+        let find = |name: &str| tasks.iter().find(|t| t.name == name).unwrap();
+
+        assert_eq!(tasks.len(), 4);
+        assert_eq!(find("build").description.as_deref(), Some("Build the app"));
+        assert_eq!(find("lint").description, None);
+        assert_eq!(find("deploy").dependencies, vec!["build", "lint"]);
+        assert_eq!(find("release").dependencies, vec!["deploy"]);
+        assert_eq!(
+            find("release").definition_type,
+            TaskDefinitionType::MiseToml
+        );
+        assert_eq!(
+            TaskRunner::Mise.get_command(find("build")),
+            "mise run build"
+        );
+    }
+
+    #[test]
+    fn test_parse_mise_without_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".mise.toml");
+        fs::write(&path, "[tools]\npython = \"3.12\"\n").unwrap();
+        assert!(parse(&path).unwrap().is_empty());
+
+        fs::write(&path, "tasks = \"build\"\n").unwrap();
+        assert!(parse(&path).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_parse_mise_task_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let tasks_dir = temp_dir.path().join(".mise").join("tasks");
+        fs::create_dir_all(&tasks_dir).unwrap();
+
+        let test_script = tasks_dir.join("test");
+        fs::write(
+            &test_script,
+            "#!/usr/bin/env bash\n#MISE description=\"Run the tests\"\ncargo test\n",
+        )
+        .unwrap();
+        fs::set_permissions(&test_script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let notes = tasks_dir.join("notes.txt");
+        fs::write(&notes, "not a task").unwrap();
+        fs::set_permissions(&notes, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let tasks = parse_task_dir(&tasks_dir).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "test");
+        assert_eq!(tasks[0].description.as_deref(), Some("Run the tests"));
+        assert_eq!(tasks[0].file_path, test_script);
+        assert_eq!(tasks[0].definition_type, TaskDefinitionType::MiseTaskDir);
+    }
+}
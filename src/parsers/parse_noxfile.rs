@@ -0,0 +1,144 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::python_decorators::find_decorated_functions;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::path::Path;
+
+/// Parse a noxfile.py and extract its sessions as tasks
+///
+/// This is a line scan rather than a Python parser. Only top-level functions decorated
+/// with `@nox.session` are discovered; sessions registered dynamically or defined inside
+/// other blocks are not.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
+    parse_noxfile_string(&contents, path)
+}
+
+fn parse_noxfile_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let tasks = find_decorated_functions(content, &["nox.session"])?
+        .into_iter()
+        .map(|session| {
+            let name = session.explicit_name.unwrap_or(session.function_name);
+            Task {
+                name: name.clone(),
+                file_path: path.to_path_buf(),
+                definition_path: None,
+                definition_type: TaskDefinitionType::Noxfile,
+                runner: TaskRunner::Nox,
+                source_name: name,
+                description: session.docstring,
+                shadowed_by: None,
+                disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
+            }
+        })
+        .collect();
+
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(tasks: &[Task]) -> Vec<&str> {
+        tasks.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_noxfile_sessions() {
+        let content = r#"
+import nox
+
+
+@nox.session
+def lint(session):
+    """Run the linters.
+
+    Uses ruff.
+    """
+    session.install("ruff")
+    session.run("ruff", "check", ".")
+
+
+@nox.session(python=["3.11", "3.12"])
+def tests(session):
+    '''Run the test suite'''
+    session.run("pytest")
+
+
+@nox.session(name="type-check", reuse_venv=True)
+def type_check(session):
+    session.run("mypy", "src")
+"#;
+        let tasks = parse_noxfile_string(content, Path::new("noxfile.py")).unwrap();
+
+        assert_eq!(names(&tasks), vec!["lint", "tests", "type-check"]);
+        assert_eq!(tasks[0].description.as_deref(), Some("Run the linters."));
+        assert_eq!(tasks[1].description.as_deref(), Some("Run the test suite"));
+        assert_eq!(tasks[2].description, None);
+        assert_eq!(tasks[2].source_name, "type-check");
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Nox);
+            assert_eq!(task.definition_type, TaskDefinitionType::Noxfile);
+        }
+    }
+
+    #[test]
+    fn test_parse_noxfile_parametrized_and_multiline() {
+        let content = r#"
+import nox
+
+@nox.session(
+    python=["3.11", "3.12"],
+    name="unit",
+)
+@nox.parametrize("django", ["4.2", "5.0"])
+def unit_tests(
+    session,
+    django,
+):
+    """
+    Unit tests across Django versions
+    """
+    session.install(f"django=={django}")
+
+
+def helper(session):
+    """Not a session"""
+
+
+class Sessions:
+    @nox.session
+    def nested(session):
+        pass
+"#;
+        let tasks = parse_noxfile_string(content, Path::new("noxfile.py")).unwrap();
+
+        assert_eq!(names(&tasks), vec!["unit"]);
+        assert_eq!(
+            tasks[0].description.as_deref(),
+            Some("Unit tests across Django versions")
+        );
+    }
+
+    #[test]
+    fn test_nox_get_command() {
+        let tasks = parse_noxfile_string(
+            "@nox.session\ndef docs(session):\n    pass\n",
+            Path::new("noxfile.py"),
+        )
+        .unwrap();
+        assert_eq!(TaskRunner::Nox.get_command(&tasks[0]), "nox -s docs");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use serde_json::Value;
+use std::path::Path;
+
+/// Parse an nx.json and extract its `targetDefaults` as workspace-wide tasks
+///
+/// Each target runs in every project that defines it, through `nx run-many`.
+pub fn parse_nx_json(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let json = read_json(path)?;
+
+    let Some(defaults) = json.get("targetDefaults") else {
+        return Ok(Vec::new());
+    };
+    let Some(defaults) = defaults.as_object() else {
+        return Err(DelaParseError::Syntax(
+            "Failed to parse nx.json: 'targetDefaults' must be an object".to_string(),
+        ));
+    };
+
+    Ok(defaults
+        .keys()
+        .map(|target| Task {
+            name: target.clone(),
+            file_path: path.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::NxJson,
+            runner: TaskRunner::Nx,
+            source_name: target.clone(),
+            description: Some(format!("Run {} in all Nx projects", target)),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        })
+        .collect())
+}
+
+/// Parse an Nx project.json and extract its `targets` as tasks
+///
+/// The project name falls back to the directory name when `name` is not set, as Nx does.
+pub fn parse_project_json(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let json = read_json(path)?;
+
+    let project = json
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .or_else(|| {
+            path.parent()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .unwrap_or_default();
+
+    let Some(targets) = json.get("targets") else {
+        return Ok(Vec::new());
+    };
+    let Some(targets) = targets.as_object() else {
+        return Err(DelaParseError::Syntax(
+            "Failed to parse project.json: 'targets' must be an object".to_string(),
+        ));
+    };
+
+    Ok(targets
+        .iter()
+        .map(|(target, config)| Task {
+            name: target.clone(),
+            file_path: path.to_path_buf(),
+            definition_path: None,
+            definition_type: TaskDefinitionType::NxProjectJson,
+            runner: TaskRunner::Nx,
+            source_name: format!("{}:{}", project, target),
+            description: config
+                .get("executor")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        })
+        .collect())
+}
+
+fn read_json(path: &Path) -> Result<Value, DelaParseError> {
+    let contents = read_definition(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_nx_json_target_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let nx_json = temp_dir.path().join("nx.json");
+        std::fs::write(
+            &nx_json,
+            r#"{
+  "targetDefaults": {
+    "build": { "dependsOn": ["^build"], "cache": true },
+    "test": { "cache": true }
+  },
+  "namedInputs": { "default": ["{projectRoot}/**/*"] }
+}"#,
+        )
+        .unwrap();
+
+        let tasks = parse_nx_json(&nx_json).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "test"]);
+        assert_eq!(tasks[0].definition_type, TaskDefinitionType::NxJson);
+        assert_eq!(
+            TaskRunner::Nx.get_command(&tasks[0]),
+            "nx run-many -t build"
+        );
+    }
+
+    #[test]
+    fn test_parse_nx_json_without_target_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let nx_json = temp_dir.path().join("nx.json");
+        std::fs::write(&nx_json, r#"{"npmScope": "acme"}"#).unwrap();
+        assert!(parse_nx_json(&nx_json).unwrap().is_empty());
+
+        std::fs::write(&nx_json, r#"{"targetDefaults": ["build"]}"#).unwrap();
+        assert!(parse_nx_json(&nx_json).is_err());
+    }
+
+    #[test]
+    fn test_parse_project_json_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_json = temp_dir.path().join("project.json");
+        std::fs::write(
+            &project_json,
+            r#"{
+  "name": "app",
+  "targets": {
+    "build": { "executor": "@nx/vite:build" },
+    "serve": { "command": "vite" }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let tasks = parse_project_json(&project_json).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "serve"]);
+        assert_eq!(tasks[0].source_name, "app:build");
+        assert_eq!(tasks[0].description.as_deref(), Some("@nx/vite:build"));
+        assert_eq!(tasks[1].description, None);
+        assert_eq!(TaskRunner::Nx.get_command(&tasks[0]), "nx run app:build");
+    }
+
+    #[test]
+    fn test_parse_project_json_name_falls_back_to_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path().join("web");
+        std::fs::create_dir(&project_dir).unwrap();
+        let project_json = project_dir.join("project.json");
+        std::fs::write(&project_json, r#"{"targets": {"lint": {}}}"#).unwrap();
+
+        let tasks = parse_project_json(&project_json).unwrap();
+        assert_eq!(tasks[0].source_name, "web:lint");
+    }
+}
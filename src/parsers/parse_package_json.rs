@@ -1,21 +1,26 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::parsers::sudo::starts_with_sudo;
 use crate::types::{Task, TaskDefinitionType};
-use std::path::PathBuf;
+use std::path::Path;
 
 /// Parse a package.json file at the given path and extract tasks
-pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
-    let contents = std::fs::read_to_string(path)?;
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
 
     let json: serde_json::Value = serde_json::from_str(&contents)?;
 
     let parent = path.parent().unwrap_or(path);
-    let runner = match crate::runners::runners_package_json::detect_package_manager(parent) {
-        Some(runner) => runner,
-        None => {
-            // No package managers available, return empty list
-            return Ok(vec![]);
-        }
-    };
+    let package_manager = json.get("packageManager").and_then(|field| field.as_str());
+    let runner =
+        match crate::runners::runners_package_json::detect_package_manager(parent, package_manager)
+        {
+            Some(runner) => runner,
+            None => {
+                // No package managers available, return empty list
+                return Ok(vec![]);
+            }
+        };
 
     let mut tasks = Vec::new();
 
@@ -25,7 +30,7 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
         for (name, cmd) in scripts_obj {
             tasks.push(Task {
                 name: name.clone(),
-                file_path: path.clone(),
+                file_path: path.to_path_buf(),
                 definition_path: None,
                 definition_type: TaskDefinitionType::PackageJson,
                 runner: runner.clone(),
@@ -33,6 +38,17 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
                 description: cmd.as_str().map(|s| s.to_string()),
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: cmd.as_str().is_some_and(starts_with_sudo),
+                line: script_line(&contents, name),
+                body: cmd.as_str().map(str::to_string),
+                kind: None,
             });
         }
     }
@@ -40,6 +56,20 @@ pub fn parse(path: &PathBuf) -> Result<Vec<Task>, DelaParseError> {
     Ok(tasks)
 }
 
+/// The 1-based line of `name`'s entry in the `scripts` object, found by scanning the text
+/// because serde_json does not keep positions.
+fn script_line(contents: &str, name: &str) -> Option<usize> {
+    let key = serde_json::to_string(name).ok()?;
+    let mut lines = contents.lines().enumerate();
+    lines.find(|(_, line)| line.contains("\"scripts\""))?;
+    lines
+        .find(|(_, line)| {
+            line.find(&key)
+                .is_some_and(|start| line[start + key.len()..].trim_start().starts_with(':'))
+        })
+        .map(|(index, _)| index + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +124,8 @@ mod tests {
         let test_task = tasks.iter().find(|t| t.name == "test").unwrap();
         assert_eq!(test_task.runner, TaskRunner::NodeNpm);
         assert_eq!(test_task.description, Some("jest".to_string()));
+        assert_eq!(test_task.line, Some(4));
+        assert_eq!(test_task.body.as_deref(), Some("jest"));
 
         let build_task = tasks.iter().find(|t| t.name == "build").unwrap();
         assert_eq!(build_task.runner, TaskRunner::NodeNpm);
@@ -163,4 +195,23 @@ mod tests {
         reset_mock();
         reset_to_real_environment();
     }
+
+    #[test]
+    #[serial]
+    fn test_parse_package_json_package_manager_field() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        set_test_environment(TestEnvironment::new().with_executable("npm"));
+        std::fs::write(
+            &package_json_path,
+            r#"{"packageManager": "pnpm@9.1.0", "scripts": {"build": "tsc"}}"#,
+        )
+        .unwrap();
+
+        let tasks = parse(&package_json_path).unwrap();
+        assert_eq!(tasks[0].runner, TaskRunner::NodePnpm);
+        assert_eq!(tasks[0].runner.short_name(), "pnpm");
+
+        reset_to_real_environment();
+    }
 }
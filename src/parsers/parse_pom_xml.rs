@@ -1,6 +1,6 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use roxmltree::{Document, Node};
-use std::fs;
 use std::path::Path;
 
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
@@ -8,7 +8,7 @@ use crate::types::{Task, TaskDefinitionType, TaskRunner};
 /// Parse a Maven pom.xml file and return a list of tasks
 pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
     // Read the file
-    let content = fs::read_to_string(file_path)?;
+    let content = read_definition(file_path)?;
 
     // Parse the XML
     let doc = Document::parse(&content)?;
@@ -48,6 +48,17 @@ fn add_default_maven_goals(tasks: &mut Vec<Task>, file_path: &Path) {
             description: Some(format!("Maven {} phase", goal)),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
     }
 }
@@ -79,6 +90,17 @@ fn add_profile_tasks(
                     description: Some(format!("Maven profile {}", profile_id)),
                     shadowed_by: None,
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: true,
+                    dependencies: Vec::new(),
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body: None,
+                    kind: None,
                 });
             }
         }
@@ -141,6 +163,17 @@ fn add_plugin_tasks(
                                     )),
                                     shadowed_by: None,
                                     disambiguated_name: None,
+                                    parameters: Vec::new(),
+                                    runnable: true,
+                                    dependencies: Vec::new(),
+                                    workspace_dir: None,
+                                    tags: Vec::new(),
+                                    hidden: false,
+                                    deprecated: false,
+                                    requires_sudo: false,
+                                    line: None,
+                                    body: None,
+                                    kind: None,
                                 });
                             }
                         }
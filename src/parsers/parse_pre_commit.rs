@@ -0,0 +1,164 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use serde_yaml::Value;
+use std::path::Path;
+
+/// Name of the task that runs every configured hook.
+const ALL_HOOKS_TASK_NAME: &str = "pre-commit";
+
+/// Parse a .pre-commit-config.yaml file and extract its hooks as tasks
+///
+/// A `pre-commit` task that runs all hooks is included whenever at least one hook is found.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
+    parse_pre_commit_string(&contents, path)
+}
+
+fn parse_pre_commit_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    if content.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let config: Value = serde_yaml::from_str(content)?;
+    let Value::Mapping(config_map) = config else {
+        return Err(DelaParseError::Syntax(
+            "pre-commit config is not a mapping".to_string(),
+        ));
+    };
+
+    let repos = config_map
+        .get(Value::String("repos".to_string()))
+        .and_then(Value::as_sequence);
+
+    let mut hooks: Vec<Task> = Vec::new();
+    for repo in repos.into_iter().flatten() {
+        let repo_hooks = repo
+            .get("hooks")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten();
+        for hook in repo_hooks {
+            let Some(id) = hook.get("id").and_then(Value::as_str) else {
+                continue;
+            };
+            // The same hook can be listed twice with different arguments; pre-commit runs both.
+            if hooks.iter().any(|task| task.name == id) {
+                continue;
+            }
+            let description = hook.get("name").and_then(Value::as_str).map(str::to_string);
+            hooks.push(create_task(path, id, description));
+        }
+    }
+
+    if hooks.is_empty() {
+        return Ok(hooks);
+    }
+
+    let mut tasks = vec![create_task(
+        path,
+        ALL_HOOKS_TASK_NAME,
+        Some("Run all pre-commit hooks".to_string()),
+    )];
+    tasks.extend(hooks);
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::PreCommitConfig,
+        runner: TaskRunner::PreCommit,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pre_commit_hooks() {
+        let content = r#"
+repos:
+  - repo: https://github.com/pre-commit/pre-commit-hooks
+    rev: v4.6.0
+    hooks:
+      - id: trailing-whitespace
+        name: Trim trailing whitespace
+      - id: end-of-file-fixer
+  - repo: local
+    hooks:
+      - id: cargo-fmt
+        name: cargo fmt
+        entry: cargo fmt --
+        language: system
+      - name: hook without an id
+        entry: ./check.sh
+        language: script
+"#;
+        let tasks = parse_pre_commit_string(content, Path::new(".pre-commit-config.yaml")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "pre-commit",
+                "trailing-whitespace",
+                "end-of-file-fixer",
+                "cargo-fmt"
+            ]
+        );
+        assert_eq!(
+            tasks[1].description.as_deref(),
+            Some("Trim trailing whitespace")
+        );
+        assert_eq!(tasks[2].description, None);
+        assert_eq!(tasks[3].description.as_deref(), Some("cargo fmt"));
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::PreCommit);
+            assert_eq!(task.definition_type, TaskDefinitionType::PreCommitConfig);
+        }
+    }
+
+    #[test]
+    fn test_parse_pre_commit_without_hooks() {
+        let tasks =
+            parse_pre_commit_string("repos: []\n", Path::new(".pre-commit-config.yaml")).unwrap();
+        assert!(tasks.is_empty());
+        assert!(parse_pre_commit_string("- not a mapping\n", Path::new("x")).is_err());
+    }
+
+    #[test]
+    fn test_pre_commit_get_command() {
+        let tasks = parse_pre_commit_string(
+            "repos:\n  - repo: local\n    hooks:\n      - id: lint\n",
+            Path::new(".pre-commit-config.yaml"),
+        )
+        .unwrap();
+        assert_eq!(
+            TaskRunner::PreCommit.get_command(&tasks[0]),
+            "pre-commit run --all-files"
+        );
+        assert_eq!(
+            TaskRunner::PreCommit.get_command(&tasks[1]),
+            "pre-commit run lint --all-files"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::parsers::sudo::starts_with_sudo;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::path::Path;
+
+/// Parse a Procfile or Procfile.dev and extract each process as a task
+///
+/// Each `name: command` line becomes a task that runs the command directly, without
+/// foreman or honcho. The command is kept as the task's body, and also as its
+/// description unless a sidecar gives another. Lines that are not a `name: command`
+/// process, or whose name foreman would reject, are skipped as foreman and honcho do.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    Ok(parse_procfile_string(&content, path))
+}
+
+fn parse_procfile_string(content: &str, path: &Path) -> Vec<Task> {
+    let mut tasks: Vec<Task> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, command)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        let command = command.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            continue;
+        }
+        // Later entries replace earlier ones, as in foreman
+        tasks.retain(|task| task.name != name);
+        if !command.is_empty() {
+            let mut task = create_task(path, name, command);
+            task.line = Some(index + 1);
+            tasks.push(task);
+        }
+    }
+
+    tasks
+}
+
+fn create_task(path: &Path, name: &str, command: &str) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::Procfile,
+        runner: TaskRunner::Procfile,
+        source_name: name.to_string(),
+        description: Some(command.to_string()),
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: starts_with_sudo(command),
+        line: None,
+        body: Some(command.to_string()),
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_procfile_processes() {
+        let content = r#"
+# Processes started by `heroku local`
+web: bundle exec puma -C config/puma.rb
+worker:   bundle exec sidekiq -q default:2
+release: bin/rails db:migrate
+"#;
+        let tasks = parse_procfile_string(content, Path::new("Procfile"));
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["web", "worker", "release"]);
+        assert_eq!(tasks[0].line, Some(3));
+        assert_eq!(
+            TaskRunner::Procfile.get_command(&tasks[0]),
+            "bundle exec puma -C config/puma.rb"
+        );
+        assert_eq!(
+            TaskRunner::Procfile.get_command(&tasks[1]),
+            "bundle exec sidekiq -q default:2"
+        );
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Procfile);
+            assert_eq!(task.definition_type, TaskDefinitionType::Procfile);
+        }
+    }
+
+    #[test]
+    fn test_parse_procfile_skips_malformed_lines() {
+        let content = "web bin/server\nmy web: bin/server\nweb: old\nweb: new\nidle:\n";
+        let tasks = parse_procfile_string(content, Path::new("Procfile"));
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "web");
+        assert_eq!(tasks[0].body.as_deref(), Some("new"));
+        assert_eq!(tasks[0].line, Some(4));
+    }
+
+    #[test]
+    fn test_procfile_command_ignores_description() {
+        let mut task = parse_procfile_string("web: bin/server\n", Path::new("Procfile"))
+            .pop()
+            .unwrap();
+        task.description = Some("Serve the app".to_string());
+        assert_eq!(TaskRunner::Procfile.get_command(&task), "bin/server");
+    }
+}
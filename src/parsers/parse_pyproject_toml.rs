@@ -1,10 +1,16 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::runner::is_runner_available;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 /// Parse a pyproject.toml file at the given path and extract tasks
+///
+/// A name defined in more than one section is kept only from the section that comes first
+/// in [`runner_precedence`].
 pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
-    let content = std::fs::read_to_string(path)?;
+    let content = read_definition(path)?;
 
     let toml: toml::Value = toml::from_str(&content)?;
 
@@ -28,6 +34,17 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
                 description,
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: true,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: cmd.as_str().map(str::to_string),
+                kind: None,
             });
         }
     }
@@ -57,6 +74,17 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
                     description,
                     shadowed_by: None,
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: true,
+                    dependencies: Vec::new(),
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body: cmd.as_str().map(str::to_string),
+                    kind: None,
                 });
             }
         }
@@ -86,6 +114,15 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
                     }
                     _ => None,
                 };
+                let body = match task_def {
+                    toml::Value::String(cmd) => Some(cmd.clone()),
+                    toml::Value::Table(table) => ["cmd", "script", "shell"]
+                        .into_iter()
+                        .find_map(|key| table.get(key).and_then(toml::Value::as_str))
+                        .map(str::to_string),
+                    _ => None,
+                };
+                let dependencies = poe_dependencies(task_def);
 
                 tasks.push(Task {
                     name: name.clone(),
@@ -97,14 +134,173 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
                     description,
                     shadowed_by: None,
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: true,
+                    dependencies,
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body,
+                    kind: None,
                 });
             }
         }
     }
 
+    // Check for Hatch environment scripts
+    if let Some(envs) = toml
+        .get("tool")
+        .and_then(|tool| tool.get("hatch"))
+        .and_then(|hatch| hatch.get("envs"))
+        .and_then(toml::Value::as_table)
+    {
+        for (env, env_def) in envs {
+            let Some(scripts_table) = env_def.get("scripts").and_then(toml::Value::as_table) else {
+                continue;
+            };
+            for (script, cmd) in scripts_table {
+                // Scripts of the default environment run without an environment prefix
+                let name = if env == "default" {
+                    script.clone()
+                } else {
+                    format!("{}:{}", env, script)
+                };
+                let commands: Vec<&str> = match cmd {
+                    toml::Value::String(cmd) => vec![cmd.as_str()],
+                    toml::Value::Array(cmds) => {
+                        cmds.iter().filter_map(toml::Value::as_str).collect()
+                    }
+                    _ => Vec::new(),
+                };
+                let description = (!commands.is_empty())
+                    .then(|| format!("hatch script: {}", commands.join(" && ")));
+
+                tasks.push(Task {
+                    name: name.clone(),
+                    file_path: path.to_path_buf(),
+                    definition_path: None,
+                    definition_type: TaskDefinitionType::PyprojectToml,
+                    runner: TaskRunner::PythonHatch,
+                    source_name: name,
+                    description,
+                    shadowed_by: None,
+                    disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: true,
+                    dependencies: Vec::new(),
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body: (!commands.is_empty()).then(|| commands.join("\n")),
+                    kind: None,
+                });
+            }
+        }
+    }
+
+    let precedence = runner_precedence(path.parent().unwrap_or(Path::new(".")));
+    let rank = |runner: &TaskRunner| precedence.iter().position(|r| r == runner);
+    let mut best_rank: HashMap<String, Option<usize>> = HashMap::new();
+    for task in &tasks {
+        let task_rank = rank(&task.runner);
+        best_rank
+            .entry(task.name.clone())
+            .and_modify(|best| *best = (*best).min(task_rank))
+            .or_insert(task_rank);
+    }
+    tasks.retain(|task| best_rank[&task.name] == rank(&task.runner));
+
     Ok(tasks)
 }
 
+/// The poe tasks a task refers to: the `deps` of any task, the steps of a sequence,
+/// whether written as an array or under `sequence`, and the target of a `ref` task.
+///
+/// A reference may carry arguments, as in `"test --verbose"`, and only the task name is
+/// kept. Sequence steps that are inline tasks, such as `{ cmd = "..." }`, are not
+/// references, nor are plain strings when `default_item_type` is not `ref`.
+fn poe_dependencies(task_def: &toml::Value) -> Vec<String> {
+    let task_name = |reference: &str| reference.split_whitespace().next().map(str::to_string);
+    let sequence_refs = |steps: &toml::Value, strings_are_refs: bool| -> Vec<String> {
+        steps
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|step| match step {
+                toml::Value::String(step) if strings_are_refs => task_name(step),
+                toml::Value::Table(step) => step
+                    .get("ref")
+                    .and_then(toml::Value::as_str)
+                    .and_then(task_name),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let mut dependencies = match task_def {
+        toml::Value::Array(_) => sequence_refs(task_def, true),
+        toml::Value::Table(table) => {
+            let strings_are_refs = table
+                .get("default_item_type")
+                .and_then(toml::Value::as_str)
+                .is_none_or(|item_type| item_type == "ref");
+            let mut dependencies = table
+                .get("deps")
+                .map(|deps| sequence_refs(deps, true))
+                .unwrap_or_default();
+            if let Some(steps) = table.get("sequence") {
+                dependencies.extend(sequence_refs(steps, strings_are_refs));
+            }
+            if let Some(reference) = table.get("ref").and_then(toml::Value::as_str) {
+                dependencies.extend(task_name(reference));
+            }
+            dependencies
+        }
+        _ => Vec::new(),
+    };
+    let mut seen = HashSet::new();
+    dependencies.retain(|dependency| seen.insert(dependency.clone()));
+    dependencies
+}
+
+/// The order in which the sections of a pyproject.toml claim a task name.
+///
+/// `[tool.poe.tasks]` comes first, as it exists only to define tasks, then Hatch
+/// environment scripts. `[tool.poetry.scripts]` comes before `[project.scripts]` when
+/// poetry.lock is present, or when there is no uv.lock and poetry is installed but uv
+/// is not; otherwise the uv scripts come first.
+fn runner_precedence(dir: &Path) -> [TaskRunner; 4] {
+    let poetry_first = if dir.join("poetry.lock").exists() {
+        true
+    } else if dir.join("uv.lock").exists() {
+        false
+    } else {
+        is_runner_available(&TaskRunner::PythonPoetry)
+            && !is_runner_available(&TaskRunner::PythonUv)
+    };
+    if poetry_first {
+        [
+            TaskRunner::PythonPoe,
+            TaskRunner::PythonHatch,
+            TaskRunner::PythonPoetry,
+            TaskRunner::PythonUv,
+        ]
+    } else {
+        [
+            TaskRunner::PythonPoe,
+            TaskRunner::PythonHatch,
+            TaskRunner::PythonUv,
+            TaskRunner::PythonPoetry,
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,6 +484,7 @@ lint = { shell = "flake8" }
             serve_task.description,
             Some("command: python -m http.server".to_string())
         );
+        assert_eq!(serve_task.body.as_deref(), Some("python -m http.server"));
 
         let test_task = tasks.iter().find(|t| t.name == "test").unwrap();
         assert_eq!(test_task.runner, TaskRunner::PythonPoe);
@@ -302,6 +499,7 @@ lint = { shell = "flake8" }
             lint_task.description,
             Some("shell script: flake8".to_string())
         );
+        assert_eq!(lint_task.body.as_deref(), Some("flake8"));
 
         reset_mock();
     }
@@ -350,4 +548,169 @@ poe-task = "pytest"
         let poe_task = tasks.iter().find(|t| t.name == "poe-task").unwrap();
         assert_eq!(poe_task.runner, TaskRunner::PythonPoe);
     }
+
+    #[test]
+    fn test_parse_hatch_env_scripts() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+
+        let content = r#"
+[project]
+name = "test-project"
+
+[project.scripts]
+serve = "app:main"
+
+[tool.hatch.envs.default.scripts]
+test = "pytest {args}"
+cov = ["coverage run -m pytest", "coverage report"]
+
+[tool.hatch.envs.docs]
+dependencies = ["mkdocs"]
+
+[tool.hatch.envs.docs.scripts]
+build = "mkdocs build"
+
+[tool.hatch.envs.lint]
+detached = true
+"#;
+
+        File::create(&pyproject_path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+
+        let tasks = parse(&pyproject_path).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["serve", "cov", "test", "docs:build"]);
+        assert_eq!(tasks[0].runner, TaskRunner::PythonUv);
+
+        let test_task = tasks.iter().find(|t| t.name == "test").unwrap();
+        assert_eq!(test_task.runner, TaskRunner::PythonHatch);
+        assert_eq!(
+            TaskRunner::PythonHatch.get_command(test_task),
+            "hatch run test"
+        );
+
+        let cov_task = tasks.iter().find(|t| t.name == "cov").unwrap();
+        assert_eq!(
+            cov_task.description.as_deref(),
+            Some("hatch script: coverage run -m pytest && coverage report")
+        );
+        assert_eq!(
+            cov_task.body.as_deref(),
+            Some("coverage run -m pytest\ncoverage report")
+        );
+
+        let build_task = tasks.iter().find(|t| t.name == "docs:build").unwrap();
+        assert_eq!(build_task.runner, TaskRunner::PythonHatch);
+        assert_eq!(
+            TaskRunner::PythonHatch.get_command(build_task),
+            "hatch run docs:build"
+        );
+    }
+
+    #[test]
+    fn test_parse_prefers_poe_then_poetry_then_uv() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        File::create(temp_dir.path().join("poetry.lock")).unwrap();
+
+        let content = r#"
+[project]
+name = "test-project"
+
+[project.scripts]
+test = "pkg.cli:test"
+serve = "pkg.cli:serve"
+docs = "pkg.cli:docs"
+
+[tool.poetry.scripts]
+test = "pkg.cli:test"
+serve = "pkg.cli:serve"
+
+[tool.poe.tasks]
+test = "pytest"
+"#;
+
+        File::create(&pyproject_path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+
+        let tasks = parse(&pyproject_path).unwrap();
+
+        let runner_of = |name: &str| {
+            let matching: Vec<_> = tasks.iter().filter(|t| t.name == name).collect();
+            assert_eq!(matching.len(), 1, "{} should be defined once", name);
+            matching[0].runner.clone()
+        };
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(runner_of("test"), TaskRunner::PythonPoe);
+        assert_eq!(runner_of("serve"), TaskRunner::PythonPoetry);
+        assert_eq!(runner_of("docs"), TaskRunner::PythonUv);
+
+        // With a uv.lock instead, the uv scripts claim their names before poetry's
+        std::fs::remove_file(temp_dir.path().join("poetry.lock")).unwrap();
+        File::create(temp_dir.path().join("uv.lock")).unwrap();
+        let tasks = parse(&pyproject_path).unwrap();
+        let serve: Vec<_> = tasks.iter().filter(|t| t.name == "serve").collect();
+        assert_eq!(serve.len(), 1);
+        assert_eq!(serve[0].runner, TaskRunner::PythonUv);
+        assert_eq!(
+            tasks.iter().find(|t| t.name == "test").unwrap().runner,
+            TaskRunner::PythonPoe
+        );
+    }
+
+    #[test]
+    fn test_parse_poe_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+
+        let content = r#"
+[tool.poe.tasks]
+lint = "ruff check ."
+test = "pytest"
+check = ["lint", "test --verbose"]
+
+[tool.poe.tasks.build]
+cmd = "python -m build"
+deps = ["check"]
+
+[tool.poe.tasks.ci]
+sequence = ["lint", { ref = "test" }, { cmd = "coverage report" }]
+
+[tool.poe.tasks.release]
+sequence = ["git tag", "git push --tags"]
+default_item_type = "cmd"
+deps = ["build"]
+
+[tool.poe.tasks.unit]
+ref = "test tests/unit"
+"#;
+
+        File::create(&pyproject_path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+
+        let tasks = parse(&pyproject_path).unwrap();
+        let dependencies = |name: &str| {
+            tasks
+                .iter()
+                .find(|t| t.name == name)
+                .unwrap()
+                .dependencies
+                .clone()
+        };
+
+        assert_eq!(dependencies("check"), vec!["lint", "test"]);
+        assert_eq!(dependencies("build"), vec!["check"]);
+        assert_eq!(dependencies("ci"), vec!["lint", "test"]);
+        assert_eq!(dependencies("release"), vec!["build"]);
+        assert_eq!(dependencies("unit"), vec!["test"]);
+        assert!(dependencies("lint").is_empty());
+    }
 }
@@ -0,0 +1,138 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use std::path::Path;
+
+/// Built-in sbt commands exposed for every build.sbt.
+const DEFAULT_COMMANDS: &[(&str, &str)] = &[
+    ("compile", "Compile the main sources"),
+    ("test", "Compile and run all tests"),
+    ("run", "Run the main class"),
+    ("clean", "Delete files produced by the build"),
+    ("package", "Produce the main artifact"),
+];
+
+/// Parse a build.sbt file and extract sbt commands and custom task keys as tasks
+///
+/// sbt starts slowly, so keys are found by scanning for `taskKey[...]` and `inputKey[...]`
+/// definitions rather than asking sbt. Keys defined in `project/*.scala` are not found.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_sbt_string(&content, path)
+}
+
+fn parse_sbt_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let key_pattern = Regex::new(
+        r#"^\s*(?:lazy\s+)?val\s+([A-Za-z_][A-Za-z0-9_]*)\s*=\s*(?:taskKey|inputKey)\s*\[.*?\]+\s*(?:\(\s*"([^"]*)")?"#,
+    )?;
+
+    let mut tasks: Vec<Task> = DEFAULT_COMMANDS
+        .iter()
+        .map(|(name, description)| create_task(path, name, Some(description.to_string())))
+        .collect();
+
+    for line in content.lines() {
+        let Some(captures) = key_pattern.captures(line) else {
+            continue;
+        };
+        let name = &captures[1];
+        if tasks.iter().any(|task| task.name == name) {
+            continue;
+        }
+        let description = captures
+            .get(2)
+            .map(|description| description.as_str().trim().to_string())
+            .filter(|description| !description.is_empty());
+        tasks.push(create_task(path, name, description));
+    }
+
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::BuildSbt,
+        runner: TaskRunner::Sbt,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sbt_task_keys() {
+        let content = r#"
+ThisBuild / scalaVersion := "3.4.2"
+
+lazy val generateDocs = taskKey[Unit]("Generate the API docs")
+lazy val bundle = taskKey[Seq[File]]("Bundle the assets")
+val deploy = inputKey[Unit]("Deploy to an environment")
+lazy val quiet = taskKey[Unit]
+// lazy val commented = taskKey[Unit]("Not a key")
+lazy val root = (project in file("."))
+lazy val compile = taskKey[Unit]("Shadows the built-in")
+"#;
+        let tasks = parse_sbt_string(content, Path::new("build.sbt")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "compile",
+                "test",
+                "run",
+                "clean",
+                "package",
+                "generateDocs",
+                "bundle",
+                "deploy",
+                "quiet"
+            ]
+        );
+        assert_eq!(
+            tasks[5].description.as_deref(),
+            Some("Generate the API docs")
+        );
+        assert_eq!(tasks[6].description.as_deref(), Some("Bundle the assets"));
+        assert_eq!(
+            tasks[7].description.as_deref(),
+            Some("Deploy to an environment")
+        );
+        assert_eq!(tasks[8].description, None);
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Sbt);
+            assert_eq!(task.definition_type, TaskDefinitionType::BuildSbt);
+        }
+    }
+
+    #[test]
+    fn test_sbt_get_command() {
+        let tasks = parse_sbt_string(
+            "lazy val docs = taskKey[Unit](\"Docs\")\n",
+            Path::new("build.sbt"),
+        )
+        .unwrap();
+        let docs = tasks.iter().find(|t| t.name == "docs").unwrap();
+        assert_eq!(TaskRunner::Sbt.get_command(docs), "sbt docs");
+    }
+}
@@ -0,0 +1,103 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use regex::Regex;
+use std::path::Path;
+
+/// Name of the task that runs the default SCons build.
+const DEFAULT_BUILD_TASK_NAME: &str = "scons";
+
+/// Parse an SConstruct file and extract its `Alias(...)` names as tasks
+///
+/// A `scons` task for the default build is always included.
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let content = read_definition(path)?;
+    parse_sconstruct_string(&content, path)
+}
+
+fn parse_sconstruct_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    // Matches `Alias(` and method calls such as `env.Alias(`, but not `MyAlias(`.
+    let alias_pattern = Regex::new(r#"(?:^|[^\w])Alias\s*\(\s*(?:"([^"]+)"|'([^']+)')"#)?;
+
+    let mut tasks = vec![create_task(
+        path,
+        DEFAULT_BUILD_TASK_NAME,
+        Some("Run the default SCons build".to_string()),
+    )];
+
+    for line in content.lines() {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        for captures in alias_pattern.captures_iter(line) {
+            let Some(name) = captures.get(1).or_else(|| captures.get(2)) else {
+                continue;
+            };
+            if !tasks.iter().any(|task| task.name == name.as_str()) {
+                tasks.push(create_task(path, name.as_str(), None));
+            }
+        }
+    }
+
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::SConstruct,
+        runner: TaskRunner::Scons,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sconstruct_aliases() {
+        let content = r#"
+env = Environment()
+program = env.Program('hello', ['hello.c'])
+env.Alias('install', env.Install('/usr/local/bin', program))
+Alias("docs", env.Command('docs/index.html', [], 'doxygen'))
+#Alias('old', program)
+    # env.Alias("disabled", program)
+env.MyAlias('custom', program)
+Alias('install', program)
+"#;
+        let tasks = parse_sconstruct_string(content, Path::new("SConstruct")).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["scons", "install", "docs"]);
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Scons);
+            assert_eq!(task.definition_type, TaskDefinitionType::SConstruct);
+        }
+    }
+
+    #[test]
+    fn test_scons_get_command() {
+        let tasks =
+            parse_sconstruct_string("Alias('test', [])\n", Path::new("SConstruct")).unwrap();
+        assert_eq!(TaskRunner::Scons.get_command(&tasks[0]), "scons");
+        assert_eq!(TaskRunner::Scons.get_command(&tasks[1]), "scons test");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::Task;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Annotations for one task in a sidecar file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskAnnotations {
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    hidden: bool,
+    #[serde(default)]
+    deprecated: bool,
+    /// Files whose contents decide whether a cached run is still up to date
+    #[serde(default)]
+    inputs: Vec<String>,
+    /// Files the task produces, which must all exist for a run to be skipped
+    #[serde(default)]
+    outputs: Vec<String>,
+}
+
+/// Task annotations read from a `<file>.dela.toml` sidecar.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Sidecar {
+    #[serde(default)]
+    tasks: HashMap<String, TaskAnnotations>,
+}
+
+impl Sidecar {
+    /// Merge the entry for `task`, matched by the name used in the definition file.
+    /// A description in the sidecar replaces the parsed one.
+    pub fn apply(&self, task: &mut Task) {
+        let Some(annotations) = self.tasks.get(&task.source_name) else {
+            return;
+        };
+        if let Some(description) = &annotations.description {
+            task.description = Some(description.clone());
+        }
+        for tag in &annotations.tags {
+            if !task.tags.contains(tag) {
+                task.tags.push(tag.clone());
+            }
+        }
+        task.hidden |= annotations.hidden;
+        task.deprecated |= annotations.deprecated;
+    }
+
+    /// The `inputs` and `outputs` globs of `task`, if it declares any inputs.
+    pub fn cache_globs(&self, task: &Task) -> Option<(&[String], &[String])> {
+        self.tasks
+            .get(&task.source_name)
+            .filter(|annotations| !annotations.inputs.is_empty())
+            .map(|annotations| {
+                (
+                    annotations.inputs.as_slice(),
+                    annotations.outputs.as_slice(),
+                )
+            })
+    }
+}
+
+/// The sidecar that annotates `definition_path`, e.g. `Makefile.dela.toml` for `Makefile`.
+pub fn sidecar_path(definition_path: &Path) -> PathBuf {
+    let mut file_name = definition_path
+        .file_name()
+        .unwrap_or_default()
+        .to_os_string();
+    file_name.push(".dela.toml");
+    definition_path.with_file_name(file_name)
+}
+
+/// Read the sidecar of `definition_path`, if it has one.
+pub fn load_sidecar(definition_path: &Path) -> Result<Option<Sidecar>, DelaParseError> {
+    let path = sidecar_path(definition_path);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = read_definition(&path)?;
+    Ok(Some(toml::from_str(&content)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_makefile;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sidecar_path() {
+        assert_eq!(
+            sidecar_path(Path::new("/project/Makefile")),
+            PathBuf::from("/project/Makefile.dela.toml")
+        );
+        assert_eq!(
+            sidecar_path(Path::new("docker-compose.yml")),
+            PathBuf::from("docker-compose.yml.dela.toml")
+        );
+    }
+
+    #[test]
+    fn test_apply_sidecar_to_makefile_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let makefile = temp_dir.path().join("Makefile");
+        std::fs::write(
+            &makefile,
+            "build:\n\techo build\n\nlegacy:\n\techo legacy\n\ninternal:\n\techo internal\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("Makefile.dela.toml"),
+            r#"
+[tasks.build]
+description = "Build the release binaries"
+tags = ["ci", "release"]
+
+[tasks.legacy]
+deprecated = true
+
+[tasks.internal]
+hidden = true
+
+[tasks.missing]
+description = "No such target"
+"#,
+        )
+        .unwrap();
+
+        let mut tasks = parse_makefile(&makefile).unwrap();
+        assert_eq!(
+            tasks
+                .iter()
+                .find(|t| t.name == "build")
+                .unwrap()
+                .description,
+            None
+        );
+
+        let sidecar = load_sidecar(&makefile).unwrap().unwrap();
+        for task in &mut tasks {
+            sidecar.apply(task);
+        }
+
+        let build = tasks.iter().find(|t| t.name == "build").unwrap();
+        assert_eq!(
+            build.description.as_deref(),
+            Some("Build the release binaries")
+        );
+        assert_eq!(build.tags, vec!["ci", "release"]);
+        assert!(!build.hidden && !build.deprecated);
+        assert!(
+            tasks
+                .iter()
+                .find(|t| t.name == "legacy")
+                .unwrap()
+                .deprecated
+        );
+        assert!(tasks.iter().find(|t| t.name == "internal").unwrap().hidden);
+    }
+
+    #[test]
+    fn test_apply_sidecar_errors() {
+        assert!(
+            load_sidecar(Path::new("/nonexistent/Makefile"))
+                .unwrap()
+                .is_none()
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let makefile = temp_dir.path().join("Makefile");
+        std::fs::write(
+            temp_dir.path().join("Makefile.dela.toml"),
+            "[tasks.build]\ncolour = \"red\"\n",
+        )
+        .unwrap();
+        assert!(load_sidecar(&makefile).is_err());
+    }
+}
@@ -1,4 +1,6 @@
+use crate::parsers::env_expansion::{EnvScope, load_dotenv};
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -37,6 +39,8 @@ struct TaskfileTask {
     cmds: Option<Vec<TaskCommand>>,
     deps: Option<Vec<TaskDependency>>,
     internal: Option<bool>,
+    #[serde(default)]
+    env: HashMap<String, serde_yaml::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -76,6 +80,10 @@ struct Taskfile {
     includes: HashMap<String, TaskfileIncludeEntry>,
     #[serde(default)]
     tasks: HashMap<String, TaskfileTask>,
+    #[serde(default)]
+    env: HashMap<String, serde_yaml::Value>,
+    #[serde(default)]
+    dotenv: Vec<String>,
 }
 
 pub fn find_taskfile_in_dir(dir: &Path) -> Option<PathBuf> {
@@ -106,6 +114,8 @@ pub fn resolve_taskfile_include_path(candidate: &Path) -> PathBuf {
 /// Parse a Taskfile.yml file at the given path and extract tasks
 pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
     let taskfile = load_taskfile(path)?;
+    let global_env = scalar_env(&taskfile.env);
+    let dotenv = load_taskfile_dotenv(path, &taskfile.dotenv);
     let mut task_entries: Vec<_> = taskfile.tasks.into_iter().collect();
     task_entries.sort_by(|a, b| a.0.cmp(&b.0));
 
@@ -117,11 +127,16 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
             continue;
         }
 
-        let description = task_def.desc.or_else(|| {
+        let env = EnvScope::new()
+            .with_layer(scalar_env(&task_def.env))
+            .with_layer(global_env.clone())
+            .with_layer(dotenv.clone());
+
+        let description = task_def.desc.map(|desc| env.expand(&desc)).or_else(|| {
             task_def.cmds.as_ref().map(|cmds| {
                 if cmds.len() == 1 {
                     match &cmds[0] {
-                        TaskCommand::String(cmd) => format!("command: {}", cmd),
+                        TaskCommand::String(cmd) => format!("command: {}", env.expand(cmd)),
                         TaskCommand::Map(_map) => {
                             // Just indicate it's a complex command without parsing details
                             "complex command".to_string()
@@ -133,6 +148,27 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
             })
         });
 
+        // The commands as YAML, unexpanded, e.g. `- go build ./...` or `- task: lint`
+        let body = task_def
+            .cmds
+            .as_ref()
+            .filter(|cmds| !cmds.is_empty())
+            .and_then(|cmds| serde_yaml::to_string(cmds).ok())
+            .map(|cmds| cmds.trim_end().to_string());
+
+        let dependencies = task_def
+            .deps
+            .iter()
+            .flatten()
+            .filter_map(|dep| match dep {
+                TaskDependency::String(name) => Some(name.clone()),
+                TaskDependency::Map(map) => map
+                    .get("task")
+                    .and_then(serde_yaml::Value::as_str)
+                    .map(str::to_string),
+            })
+            .collect();
+
         tasks.push(Task {
             name: name.clone(),
             file_path: path.to_path_buf(),
@@ -143,6 +179,17 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
             description,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies,
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body,
+            kind: None,
         });
     }
 
@@ -187,12 +234,42 @@ pub fn extract_include_directives(path: &Path) -> Result<Vec<TaskfileInclude>, D
 }
 
 fn load_taskfile(path: &Path) -> Result<Taskfile, DelaParseError> {
-    let contents = std::fs::read_to_string(path)?;
+    let contents = read_definition(path)?;
 
     let taskfile = serde_yaml::from_str(&contents)?;
     Ok(taskfile)
 }
 
+/// Keep only env values Task can resolve statically; `sh:` entries are evaluated at runtime.
+fn scalar_env(env: &HashMap<String, serde_yaml::Value>) -> HashMap<String, String> {
+    env.iter()
+        .filter_map(|(key, value)| {
+            let value = match value {
+                serde_yaml::Value::String(value) => value.clone(),
+                serde_yaml::Value::Number(value) => value.to_string(),
+                serde_yaml::Value::Bool(value) => value.to_string(),
+                _ => return None,
+            };
+            Some((key.clone(), value))
+        })
+        .collect()
+}
+
+/// Task loads dotenv files in order and earlier files win over later ones.
+fn load_taskfile_dotenv(path: &Path, dotenv_files: &[String]) -> HashMap<String, String> {
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut vars = HashMap::new();
+    for file in dotenv_files {
+        if should_skip_non_local_include(Path::new(file)) {
+            continue;
+        }
+        for (key, value) in load_dotenv(&base_dir.join(file)) {
+            vars.entry(key).or_insert(value);
+        }
+    }
+    vars
+}
+
 fn looks_like_taskfile_file(path: &Path) -> bool {
     path.file_name()
         .and_then(|name| name.to_str())
@@ -270,6 +347,10 @@ tasks:
             Some("Clean build artifacts")
         );
         assert_eq!(clean_task.runner, TaskRunner::Task);
+        assert_eq!(clean_task.dependencies, vec!["test"]);
+
+        let fix_task = tasks.iter().find(|t| t.name == "fix").unwrap();
+        assert_eq!(fix_task.dependencies, vec!["build", "two"]);
 
         let format_task = tasks.iter().find(|t| t.name == "format").unwrap();
         assert_eq!(
@@ -470,4 +551,43 @@ includes:
         let explicit_file = temp_dir.path().join("Shared.yml");
         assert_eq!(resolve_taskfile_include_path(&explicit_file), explicit_file);
     }
+
+    #[test]
+    fn test_parse_taskfile_expands_env_in_descriptions() {
+        let temp_dir = TempDir::new().unwrap();
+        let taskfile_path = temp_dir.path().join("Taskfile.yml");
+        std::fs::write(
+            temp_dir.path().join(".env"),
+            "REGISTRY=ghcr.io\nIMAGE=ignored\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &taskfile_path,
+            r#"
+version: '3'
+dotenv: ['.env']
+env:
+  IMAGE: app
+tasks:
+  publish:
+    desc: Push $REGISTRY/${IMAGE}:${TAG}
+    env:
+      TAG: v1
+  build:
+    cmds:
+      - docker build -t ${IMAGE}:{{.VERSION}} .
+"#,
+        )
+        .unwrap();
+
+        let tasks = parse(&taskfile_path).unwrap();
+
+        let publish = tasks.iter().find(|t| t.name == "publish").unwrap();
+        assert_eq!(publish.description.as_deref(), Some("Push ghcr.io/app:v1"));
+        let build = tasks.iter().find(|t| t.name == "build").unwrap();
+        assert_eq!(
+            build.description.as_deref(),
+            Some("command: docker build -t app:{{.VERSION}} .")
+        );
+    }
 }
@@ -0,0 +1,302 @@
+use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
+use crate::types::{Task, TaskDefinitionType, TaskRunner};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Name of the task that runs every environment in `envlist`.
+const ENVLIST_TASK_NAME: &str = "tox";
+
+/// Kind of the task that runs every environment in `envlist`.
+const ENVLIST_KIND: &str = "envlist";
+
+/// Name of the task exposed for the bare `[testenv]` section.
+const DEFAULT_ENV_NAME: &str = "default";
+
+#[derive(Debug, Default)]
+struct Section {
+    name: String,
+    values: HashMap<String, String>,
+}
+
+/// Parse a tox.ini file and extract its test environments as tasks
+pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let contents = read_definition(path)?;
+    parse_tox_string(&contents, path)
+}
+
+fn parse_tox_string(content: &str, path: &Path) -> Result<Vec<Task>, DelaParseError> {
+    let mut tasks = Vec::new();
+
+    for section in parse_sections(content) {
+        if section.name == "tox" {
+            if let Some(envlist) = section.values.get("envlist") {
+                let envs = split_envlist(envlist);
+                if !envs.is_empty() {
+                    let mut task = create_task(
+                        path,
+                        ENVLIST_TASK_NAME,
+                        Some(format!("Run all tox environments: {}", envs.join(", "))),
+                    );
+                    task.kind = Some(ENVLIST_KIND.to_string());
+                    tasks.push(task);
+                }
+            }
+            continue;
+        }
+
+        let env_name = if section.name == "testenv" {
+            DEFAULT_ENV_NAME
+        } else if let Some(name) = section.name.strip_prefix("testenv:") {
+            name.trim()
+        } else {
+            continue;
+        };
+
+        // Generative section names like `py{38,39}` expand inside tox itself.
+        if env_name.is_empty() || env_name.contains('{') {
+            continue;
+        }
+
+        let description = section
+            .values
+            .get("description")
+            .and_then(|value| value.lines().next())
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty());
+
+        let mut task = create_task(path, env_name, description);
+        // An environment named like the envlist task is listed apart from it
+        if env_name == ENVLIST_TASK_NAME {
+            task.name = format!("{}-env", env_name);
+        }
+        tasks.push(task);
+    }
+
+    Ok(tasks)
+}
+
+fn create_task(path: &Path, name: &str, description: Option<String>) -> Task {
+    Task {
+        name: name.to_string(),
+        file_path: path.to_path_buf(),
+        definition_path: None,
+        definition_type: TaskDefinitionType::ToxIni,
+        runner: TaskRunner::Tox,
+        source_name: name.to_string(),
+        description,
+        shadowed_by: None,
+        disambiguated_name: None,
+        parameters: Vec::new(),
+        runnable: true,
+        dependencies: Vec::new(),
+        workspace_dir: None,
+        tags: Vec::new(),
+        hidden: false,
+        deprecated: false,
+        requires_sudo: false,
+        line: None,
+        body: None,
+        kind: None,
+    }
+}
+
+/// Split INI content into sections, joining indented continuation lines onto their key.
+fn parse_sections(content: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut current_key: Option<String> = None;
+
+    for raw_line in content.lines() {
+        let trimmed = raw_line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            sections.push(Section {
+                name: trimmed[1..trimmed.len() - 1].trim().to_string(),
+                values: HashMap::new(),
+            });
+            current_key = None;
+            continue;
+        }
+
+        let Some(section) = sections.last_mut() else {
+            continue;
+        };
+
+        let is_continuation = raw_line.starts_with(char::is_whitespace);
+        if is_continuation && let Some(key) = &current_key {
+            let value = section.values.entry(key.clone()).or_default();
+            if !value.is_empty() {
+                value.push('\n');
+            }
+            value.push_str(trimmed);
+            continue;
+        }
+
+        if let Some((key, value)) = trimmed.split_once('=') {
+            let key = key.trim().to_string();
+            section.values.insert(key.clone(), value.trim().to_string());
+            current_key = Some(key);
+        }
+    }
+
+    sections
+}
+
+fn split_envlist(envlist: &str) -> Vec<String> {
+    let mut envs = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+
+    for c in envlist.chars() {
+        match c {
+            '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            ',' | '\n' if depth == 0 => {
+                if !current.trim().is_empty() {
+                    envs.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        envs.push(current.trim().to_string());
+    }
+
+    envs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn find<'a>(tasks: &'a [Task], name: &str) -> &'a Task {
+        tasks.iter().find(|t| t.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_parse_tox_environments() {
+        let content = r#"
+[tox]
+envlist = py311, lint
+
+[testenv]
+description = run the unit tests
+deps = pytest
+commands = pytest {posargs}
+
+[testenv:lint]
+description =
+    run linters
+    on all files
+commands = ruff check .
+
+[testenv:docs]
+commands = sphinx-build docs docs/_build
+"#;
+        let path = PathBuf::from("tox.ini");
+        let tasks = parse_tox_string(content, &path).unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["tox", "default", "lint", "docs"]);
+
+        for task in &tasks {
+            assert_eq!(task.runner, TaskRunner::Tox);
+            assert_eq!(task.definition_type, TaskDefinitionType::ToxIni);
+            assert_eq!(task.file_path, path);
+        }
+
+        assert_eq!(
+            find(&tasks, "tox").description.as_deref(),
+            Some("Run all tox environments: py311, lint")
+        );
+        assert_eq!(
+            find(&tasks, "default").description.as_deref(),
+            Some("run the unit tests")
+        );
+        assert_eq!(
+            find(&tasks, "lint").description.as_deref(),
+            Some("run linters")
+        );
+        assert_eq!(find(&tasks, "docs").description, None);
+    }
+
+    #[test]
+    fn test_parse_tox_without_envlist() {
+        let content = r#"
+[tox]
+minversion = 4.0
+
+[testenv:type]
+commands = mypy src
+"#;
+        let tasks = parse_tox_string(content, Path::new("tox.ini")).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "type");
+    }
+
+    #[test]
+    fn test_parse_tox_skips_generative_sections() {
+        let content = r#"
+[tox]
+envlist =
+    py{310,311}
+    lint
+
+[testenv:py{310,311}]
+commands = pytest
+
+[testenv:lint]
+commands = ruff check .
+"#;
+        let tasks = parse_tox_string(content, Path::new("tox.ini")).unwrap();
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["tox", "lint"]);
+        assert_eq!(
+            find(&tasks, "tox").description.as_deref(),
+            Some("Run all tox environments: py{310,311}, lint")
+        );
+    }
+
+    #[test]
+    fn test_tox_get_command() {
+        let tasks = parse_tox_string(
+            "[tox]\nenvlist = lint\n\n[testenv:lint]\ncommands = ruff\n",
+            Path::new("tox.ini"),
+        )
+        .unwrap();
+        assert_eq!(
+            TaskRunner::Tox.get_command(find(&tasks, "lint")),
+            "tox -e lint"
+        );
+        assert_eq!(TaskRunner::Tox.get_command(find(&tasks, "tox")), "tox");
+    }
+
+    #[test]
+    fn test_tox_env_named_tox() {
+        let tasks = parse_tox_string(
+            "[tox]\nenvlist = tox, lint\n\n[testenv:tox]\ncommands = pytest\n",
+            Path::new("tox.ini"),
+        )
+        .unwrap();
+
+        let names: Vec<&str> = tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["tox", "tox-env"]);
+        assert_eq!(TaskRunner::Tox.get_command(find(&tasks, "tox")), "tox");
+        assert_eq!(
+            TaskRunner::Tox.get_command(find(&tasks, "tox-env")),
+            "tox -e tox"
+        );
+    }
+}
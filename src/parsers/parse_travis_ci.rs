@@ -1,8 +1,7 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use serde_yaml::Value;
-use std::fs::File;
-use std::io::Read;
 use std::path::Path;
 
 /// Parse Travis CI configuration file and extract jobs as tasks
@@ -10,9 +9,7 @@ use std::path::Path;
 /// This function parses a .travis.yml file and extracts each job as a separate task.
 /// Note: Travis CI tasks are listed for discovery but cannot be executed locally.
 pub fn parse(file_path: &Path) -> Result<Vec<Task>, DelaParseError> {
-    let mut file = File::open(file_path)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+    let contents = read_definition(file_path)?;
 
     parse_travis_string(&contents, file_path)
 }
@@ -53,6 +50,17 @@ fn parse_travis_string(content: &str, file_path: &Path) -> Result<Vec<Task>, Del
                     description,
                     shadowed_by: None,
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: false,
+                    dependencies: Vec::new(),
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body: None,
+                    kind: None,
                 };
 
                 tasks.push(task);
@@ -84,6 +92,17 @@ fn parse_travis_string(content: &str, file_path: &Path) -> Result<Vec<Task>, Del
                                 description,
                                 shadowed_by: None,
                                 disambiguated_name: None,
+                                parameters: Vec::new(),
+                                runnable: false,
+                                dependencies: Vec::new(),
+                                workspace_dir: None,
+                                tags: Vec::new(),
+                                hidden: false,
+                                deprecated: false,
+                                requires_sudo: false,
+                                line: None,
+                                body: None,
+                                kind: None,
                             };
 
                             tasks.push(task);
@@ -102,6 +121,17 @@ fn parse_travis_string(content: &str, file_path: &Path) -> Result<Vec<Task>, Del
                                 description,
                                 shadowed_by: None,
                                 disambiguated_name: None,
+                                parameters: Vec::new(),
+                                runnable: false,
+                                dependencies: Vec::new(),
+                                workspace_dir: None,
+                                tags: Vec::new(),
+                                hidden: false,
+                                deprecated: false,
+                                requires_sudo: false,
+                                line: None,
+                                body: None,
+                                kind: None,
                             };
 
                             tasks.push(task);
@@ -123,6 +153,17 @@ fn parse_travis_string(content: &str, file_path: &Path) -> Result<Vec<Task>, Del
                 description: Some("Travis CI configuration".to_string()),
                 shadowed_by: None,
                 disambiguated_name: None,
+                parameters: Vec::new(),
+                runnable: false,
+                dependencies: Vec::new(),
+                workspace_dir: None,
+                tags: Vec::new(),
+                hidden: false,
+                deprecated: false,
+                requires_sudo: false,
+                line: None,
+                body: None,
+                kind: None,
             };
 
             tasks.push(task);
@@ -219,6 +260,10 @@ jobs:
             build_task.description,
             Some("Travis CI job: Build".to_string())
         );
+        assert!(
+            tasks.iter().all(|t| !t.runnable),
+            "Travis CI jobs have no local executor"
+        );
     }
 
     #[test]
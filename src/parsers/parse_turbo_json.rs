@@ -1,4 +1,5 @@
 use crate::parsers::errors::DelaParseError;
+use crate::parsers::read::read_definition;
 use crate::types::{Task, TaskDefinitionType, TaskRunner};
 use serde_json::Value;
 use std::collections::BTreeMap;
@@ -33,7 +34,7 @@ impl TurboConfig {
 }
 
 pub fn load_config(path: &Path) -> Result<TurboConfig, DelaParseError> {
-    let contents = std::fs::read_to_string(path)?;
+    let contents = read_definition(path)?;
     let json: Value = serde_json::from_str(&contents)?;
 
     let extends = json
@@ -74,6 +75,17 @@ pub fn parse(path: &Path) -> Result<Vec<Task>, DelaParseError> {
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         })
         .collect())
 }
@@ -0,0 +1,189 @@
+use crate::parsers::errors::DelaParseError;
+use regex::Regex;
+
+/// A top-level Python function carrying one of the decorators we look for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecoratedFunction {
+    pub function_name: String,
+    /// Value of a `name="..."` keyword in the decorator call, if any
+    pub explicit_name: Option<String>,
+    /// First line of the function's docstring
+    pub docstring: Option<String>,
+}
+
+/// Find top-level functions decorated with any of `decorators` (e.g. `nox.session`).
+///
+/// This is a line scan rather than a Python parser, so functions nested in classes or
+/// other blocks, and tasks registered dynamically, are not found.
+pub fn find_decorated_functions(
+    content: &str,
+    decorators: &[&str],
+) -> Result<Vec<DecoratedFunction>, DelaParseError> {
+    let name_pattern = Regex::new(r#"\bname\s*=\s*["']([^"']+)["']"#)?;
+    let def_pattern = Regex::new(r"^(?:async\s+)?def\s+([A-Za-z_][A-Za-z0-9_]*)\s*\(")?;
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut functions = Vec::new();
+    let mut pending: Option<Option<String>> = None;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+
+        if is_matching_decorator(line, decorators) {
+            let (decorator, end) = collect_balanced(&lines, index);
+            let explicit_name = name_pattern
+                .captures(&decorator)
+                .and_then(|captures| captures.get(1))
+                .map(|name| name.as_str().to_string());
+            pending = Some(explicit_name);
+            index = end + 1;
+            continue;
+        }
+
+        if let Some(explicit_name) = pending.take() {
+            // Other decorators may sit between the one we matched and the def.
+            if line.starts_with('@') {
+                pending = Some(explicit_name);
+                let (_, end) = collect_balanced(&lines, index);
+                index = end + 1;
+                continue;
+            }
+
+            if let Some(captures) = def_pattern.captures(line) {
+                let (_, signature_end) = collect_balanced(&lines, index);
+                functions.push(DecoratedFunction {
+                    function_name: captures[1].to_string(),
+                    explicit_name,
+                    docstring: docstring_first_line(&lines[signature_end + 1..]),
+                });
+                index = signature_end + 1;
+                continue;
+            }
+        }
+
+        index += 1;
+    }
+
+    Ok(functions)
+}
+
+fn is_matching_decorator(line: &str, decorators: &[&str]) -> bool {
+    let Some(rest) = line.strip_prefix('@') else {
+        return false;
+    };
+    decorators.iter().any(|decorator| {
+        rest.strip_prefix(decorator).is_some_and(|after| {
+            after.is_empty() || after.starts_with('(') || after.starts_with(char::is_whitespace)
+        })
+    })
+}
+
+/// Join lines starting at `start` until brackets balance, returning the text and last line index.
+fn collect_balanced(lines: &[&str], start: usize) -> (String, usize) {
+    let mut depth: i32 = 0;
+    let mut text = String::new();
+
+    for (offset, line) in lines[start..].iter().enumerate() {
+        text.push_str(line);
+        text.push('\n');
+        for c in line.chars() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                '#' => break,
+                _ => {}
+            }
+        }
+        if depth <= 0 {
+            return (text, start + offset);
+        }
+    }
+
+    (text, lines.len() - 1)
+}
+
+fn docstring_first_line(body: &[&str]) -> Option<String> {
+    let mut lines = body
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty());
+    let first = lines.next()?;
+
+    for quote in ["\"\"\"", "'''", "\"", "'"] {
+        if let Some(rest) = first.strip_prefix(quote) {
+            let text = rest.split(quote).next().unwrap_or(rest).trim();
+            let text = if text.is_empty() {
+                // The docstring text starts on the line after the opening quotes.
+                lines
+                    .next()
+                    .map(|line| line.split(quote).next().unwrap_or(line).trim())
+                    .unwrap_or("")
+            } else {
+                text
+            };
+            return (!text.is_empty()).then(|| text.to_string());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_decorated_functions() {
+        let content = r#"
+@task
+def build(c):
+    """Build the project.
+
+    Longer text.
+    """
+
+@task(name="release", help={"version": "x"})
+@other
+def make_release(
+    c,
+    version,
+):
+    '''
+    Cut a release
+    '''
+
+@tasks_helper
+def not_matched(c):
+    pass
+
+class Inner:
+    @task
+    def nested(c):
+        pass
+"#;
+        let functions = find_decorated_functions(content, &["task"]).unwrap();
+
+        assert_eq!(
+            functions,
+            vec![
+                DecoratedFunction {
+                    function_name: "build".to_string(),
+                    explicit_name: None,
+                    docstring: Some("Build the project.".to_string()),
+                },
+                DecoratedFunction {
+                    function_name: "make_release".to_string(),
+                    explicit_name: Some("release".to_string()),
+                    docstring: Some("Cut a release".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_docstring_first_line_without_docstring() {
+        assert_eq!(docstring_first_line(&["    c.run('make')"]), None);
+        assert_eq!(docstring_first_line(&[]), None);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::config::{DiscoveryConfig, load_config};
+use crate::parsers::errors::DelaParseError;
+use once_cell::sync::Lazy;
+use std::path::Path;
+
+/// Largest definition file that is parsed, from `[discovery] max_file_size` in config.toml.
+static MAX_FILE_SIZE: Lazy<u64> = Lazy::new(|| {
+    load_config()
+        .map(|config| config.discovery.max_file_size)
+        .unwrap_or(DiscoveryConfig::default().max_file_size)
+});
+
+/// Read a task definition file, refusing files larger than the configured limit so a
+/// binary or generated file with a definition file's name cannot stall discovery.
+pub fn read_definition(path: &Path) -> Result<String, DelaParseError> {
+    read_with_limit(path, *MAX_FILE_SIZE)
+}
+
+fn read_with_limit(path: &Path, limit: u64) -> Result<String, DelaParseError> {
+    let size = std::fs::metadata(path)?.len();
+    if size > limit {
+        return Err(DelaParseError::TooLarge { size, limit });
+    }
+    Ok(std::fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_with_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("Makefile");
+        std::fs::write(&path, "build:\n\techo build\n").unwrap();
+
+        assert_eq!(
+            read_with_limit(&path, 1024).unwrap(),
+            "build:\n\techo build\n"
+        );
+        let error = read_with_limit(&path, 8).unwrap_err();
+        assert!(matches!(
+            error,
+            DelaParseError::TooLarge { size: 19, limit: 8 }
+        ));
+        assert!(error.to_string().starts_with("file too large to parse"));
+    }
+}
@@ -0,0 +1,35 @@
+/// Whether a shell command line runs `sudo`, judged by its first command token.
+///
+/// Make's `@`, `-` and `+` recipe prefixes and leading `NAME=value` assignments are
+/// skipped, so `@sudo make install` and `FORCE=1 sudo ./setup` both count.
+pub fn starts_with_sudo(command: &str) -> bool {
+    let command = command.trim_start_matches(['@', '-', '+', ' ', '\t']);
+    command
+        .split_whitespace()
+        .find(|word| !is_env_assignment(word))
+        .is_some_and(|word| word == "sudo" || word.ends_with("/sudo"))
+}
+
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with_sudo() {
+        assert!(starts_with_sudo("sudo apt-get install -y jq"));
+        assert!(starts_with_sudo("@sudo make install"));
+        assert!(starts_with_sudo(
+            "DEBIAN_FRONTEND=noninteractive sudo apt-get update"
+        ));
+        assert!(starts_with_sudo("/usr/bin/sudo systemctl restart app"));
+        assert!(!starts_with_sudo("echo sudo"));
+        assert!(!starts_with_sudo("sudoku --solve"));
+        assert!(!starts_with_sudo(""));
+    }
+}
@@ -13,7 +13,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
 };
 use std::io::Stdout;
-use std::io::{self, IsTerminal, Write};
+use std::io::{self, BufRead, IsTerminal, Write};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum AllowDecision {
@@ -80,8 +80,9 @@ fn prompt_for_task_fallback(task: &Task) -> anyhow::Result<AllowDecision> {
     println!("3) Allow file (remember for all tasks in this file)");
     println!("4) Allow directory (remember for all tasks in this directory)");
     println!("5) Deny (don't run this task)");
+    println!("6) Allow directory tree (remember for this directory and all subdirectories)");
 
-    print!("\nEnter your choice (1-5): ");
+    print!("\nEnter your choice (1-6): ");
     io::stdout()
         .flush()
         .map_err(|e| anyhow::anyhow!("Failed to flush stdout: {}", e))?;
@@ -97,12 +98,90 @@ fn prompt_for_task_fallback(task: &Task) -> anyhow::Result<AllowDecision> {
         "3" => Ok(AllowDecision::Allow(AllowScope::File)),
         "4" => Ok(AllowDecision::Allow(AllowScope::Directory)),
         "5" => Ok(AllowDecision::Deny),
+        "6" => Ok(AllowDecision::Allow(AllowScope::DirectoryRecursive)),
         _ => Err(anyhow::anyhow!(
-            "Invalid choice. Please enter a number between 1 and 5."
+            "Invalid choice. Please enter a number between 1 and 6."
         )),
     }
 }
 
+/// Ask a yes/no question on stdin. Anything other than an explicit yes is a no.
+pub fn confirm(question: &str) -> anyhow::Result<bool> {
+    print!("{} [y/N]: ", question);
+    io::stdout()
+        .flush()
+        .map_err(|e| anyhow::anyhow!("Failed to flush stdout: {}", e))?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| anyhow::anyhow!("Failed to read input: {}", e))?;
+
+    Ok(matches!(
+        input.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
+
+/// Ask which of `tasks` to run with a numbered menu on stdin and stdout.
+pub fn select_task<'a>(tasks: &[&'a Task]) -> anyhow::Result<&'a Task> {
+    select_task_from(tasks, &mut io::stdin().lock(), &mut io::stdout())
+}
+
+/// The numbered menu behind [`select_task`]. A task can be chosen by its number or its name.
+fn select_task_from<'a>(
+    tasks: &[&'a Task],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> anyhow::Result<&'a Task> {
+    if tasks.is_empty() {
+        return Err(anyhow::anyhow!("No tasks found in the current directory"));
+    }
+
+    writeln!(output, "Select a task to run:")?;
+    for (index, task) in tasks.iter().enumerate() {
+        let name = task.disambiguated_name.as_ref().unwrap_or(&task.name);
+        write!(
+            output,
+            "{:>3}) {} ({})",
+            index + 1,
+            name,
+            task.runner.short_name()
+        )?;
+        match &task.description {
+            Some(description) => writeln!(output, " - {}", description)?,
+            None => writeln!(output)?,
+        }
+    }
+    write!(output, "\nEnter your choice (1-{}): ", tasks.len())?;
+    output
+        .flush()
+        .map_err(|e| anyhow::anyhow!("Failed to flush stdout: {}", e))?;
+
+    let mut choice = String::new();
+    input
+        .read_line(&mut choice)
+        .map_err(|e| anyhow::anyhow!("Failed to read input: {}", e))?;
+    let choice = choice.trim();
+
+    let by_number = choice
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| tasks.get(number.checked_sub(1)?));
+    let by_name = || {
+        tasks.iter().find(|task| {
+            task.disambiguated_name.as_deref() == Some(choice)
+                || (task.disambiguated_name.is_none() && task.name == choice)
+        })
+    };
+    by_number.or_else(by_name).copied().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid choice. Please enter a number between 1 and {}.",
+            tasks.len()
+        )
+    })
+}
+
 fn run_tui(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
     task: &Task,
@@ -125,6 +204,10 @@ fn run_tui(
             AllowDecision::Allow(AllowScope::Directory),
         ),
         ("Deny (don't run this task)", AllowDecision::Deny),
+        (
+            "Allow directory tree (remember for this directory and all subdirectories)",
+            AllowDecision::Allow(AllowScope::DirectoryRecursive),
+        ),
     ];
 
     let mut selected = 0;
@@ -174,7 +257,7 @@ fn ui(f: &mut Frame, task: &Task, options: &[(&str, AllowDecision)], selected: u
             [
                 Constraint::Min(3),    // Header (allow for wrapping)
                 Constraint::Length(1), // Spacer
-                Constraint::Length(7), // Options list (exactly 5 lines)
+                Constraint::Length(8), // Options list (exactly 6 lines)
                 Constraint::Length(1), // Spacer
                 Constraint::Length(3), // Instructions
             ]
@@ -268,6 +351,10 @@ mod tests {
                 AllowDecision::Allow(AllowScope::Directory),
             ),
             ("Deny (don't run this task)", AllowDecision::Deny),
+            (
+                "Allow directory tree (remember for this directory and all subdirectories)",
+                AllowDecision::Allow(AllowScope::DirectoryRecursive),
+            ),
         ];
 
         if selected_index < options.len() {
@@ -312,6 +399,16 @@ mod tests {
         assert_eq!(result.unwrap(), AllowDecision::Deny);
     }
 
+    #[test]
+    fn test_prompt_allow_directory_recursive() {
+        let result = test_tui_logic(5);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            AllowDecision::Allow(AllowScope::DirectoryRecursive)
+        );
+    }
+
     #[test]
     fn test_prompt_invalid_selection() {
         let result = test_tui_logic(10);
@@ -339,6 +436,17 @@ mod tests {
             description: Some("Run unit tests".to_string()),
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let options = vec![
@@ -365,4 +473,54 @@ mod tests {
         assert!(contents.contains("Allow once"));
         assert!(contents.contains("Deny"));
     }
+
+    #[test]
+    fn test_select_task_by_number_or_name() {
+        use crate::types::{TaskDefinitionType, TaskRunner};
+        use std::io::Cursor;
+        use std::path::PathBuf;
+
+        let task = |name: &str, description: Option<&str>| Task {
+            name: name.to_string(),
+            file_path: PathBuf::from("Makefile"),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: name.to_string(),
+            description: description.map(str::to_string),
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let build = task("build", Some("Build the project"));
+        let test = task("test", None);
+        let tasks = vec![&build, &test];
+
+        let mut output = Vec::new();
+        let picked = select_task_from(&tasks, &mut Cursor::new("2\n"), &mut output).unwrap();
+        assert_eq!(picked.name, "test");
+        let menu = String::from_utf8(output).unwrap();
+        assert!(menu.contains("  1) build (make) - Build the project\n"));
+        assert!(menu.contains("  2) test (make)\n"));
+        assert!(menu.contains("Enter your choice (1-2): "));
+
+        let picked =
+            select_task_from(&tasks, &mut Cursor::new("build\n"), &mut Vec::new()).unwrap();
+        assert_eq!(picked.name, "build");
+
+        for choice in ["0\n", "3\n", "\n", "deploy\n"] {
+            assert!(select_task_from(&tasks, &mut Cursor::new(choice), &mut Vec::new()).is_err());
+        }
+        assert!(select_task_from(&[], &mut Cursor::new("1\n"), &mut Vec::new()).is_err());
+    }
 }
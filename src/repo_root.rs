@@ -1,3 +1,4 @@
+use crate::types::{Task, TaskRunner};
 use std::path::{Path, PathBuf};
 
 pub fn find_ancestor<F>(start: &Path, predicate: F) -> Option<PathBuf>
@@ -25,6 +26,37 @@ pub fn find_git_repo_root(start: &Path) -> Option<PathBuf> {
     find_ancestor(start, |dir| dir.join(".git").exists())
 }
 
+/// Marker files that identify the root of a multi-project build for runners that must
+/// run from there rather than from the directory of a sub-project's build file.
+fn runner_root_markers(runner: &TaskRunner) -> &'static [&'static str] {
+    match runner {
+        TaskRunner::Gradle => &["settings.gradle", "settings.gradle.kts", "gradlew"],
+        TaskRunner::Maven => &[".mvn", "mvnw"],
+        _ => &[],
+    }
+}
+
+/// The directory a task's runner treats as the project root.
+///
+/// This is the nearest ancestor of the task's definition file holding one of the runner's
+/// root markers, falling back to the directory of the definition file itself.
+pub fn find_runner_project_root(task: &Task) -> PathBuf {
+    let definition_dir = task
+        .definition_path()
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let markers = runner_root_markers(&task.runner);
+    if markers.is_empty() {
+        return definition_dir;
+    }
+
+    find_ancestor(&definition_dir, |dir| {
+        markers.iter().any(|marker| dir.join(marker).exists())
+    })
+    .unwrap_or(definition_dir)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,4 +96,57 @@ mod tests {
 
         assert_eq!(find_git_repo_root(&nested), None);
     }
+
+    fn gradle_task(build_file: PathBuf) -> Task {
+        Task {
+            name: "build".to_string(),
+            file_path: build_file,
+            definition_path: None,
+            definition_type: crate::types::TaskDefinitionType::Gradle,
+            runner: TaskRunner::Gradle,
+            source_name: "build".to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        }
+    }
+
+    #[test]
+    fn test_find_runner_project_root_uses_gradle_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let app = root.join("app");
+        std::fs::create_dir_all(&app).unwrap();
+        std::fs::write(root.join("settings.gradle"), "include 'app'").unwrap();
+        std::fs::write(app.join("build.gradle"), "").unwrap();
+
+        let task = gradle_task(app.join("build.gradle"));
+        assert_eq!(find_runner_project_root(&task), root.to_path_buf());
+    }
+
+    #[test]
+    fn test_find_runner_project_root_falls_back_to_build_file_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let app = temp_dir.path().join("app");
+        std::fs::create_dir_all(&app).unwrap();
+        std::fs::write(app.join("build.gradle"), "").unwrap();
+
+        let task = gradle_task(app.join("build.gradle"));
+        assert_eq!(find_runner_project_root(&task), app);
+
+        let mut make_task = gradle_task(app.join("Makefile"));
+        make_task.runner = TaskRunner::Make;
+        assert_eq!(find_runner_project_root(&make_task), app);
+    }
 }
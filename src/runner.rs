@@ -1,9 +1,12 @@
+use crate::environment::{Environment, current_environment};
 #[cfg(test)]
 use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
-use crate::task_shadowing::check_path_executable;
 use crate::types::TaskRunner;
+use once_cell::sync::Lazy;
 #[cfg(test)]
 use serial_test::serial;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 /// Parse a shell-style command string into executable + args preserving quoting.
 /// Returns an error when the command cannot be parsed or is empty.
@@ -18,29 +21,80 @@ pub fn split_command_words(command: &str) -> anyhow::Result<Vec<String>> {
     Ok(parts)
 }
 
+/// The program and arguments that run the command `parts` on this platform.
+/// On Windows, runners such as `npm` and `yarn` are `.cmd` scripts that only `cmd` can
+/// start, so every command is run through `cmd /C` there.
+pub fn platform_command(parts: Vec<String>) -> Vec<String> {
+    if cfg!(windows) {
+        ["cmd".to_string(), "/C".to_string()]
+            .into_iter()
+            .chain(parts)
+            .collect()
+    } else {
+        parts
+    }
+}
+
+/// Availability of each runner probed so far in this process. Only lookups against the
+/// real environment are remembered, so a test environment is always probed afresh.
+static RUNNER_AVAILABILITY: Lazy<Mutex<HashMap<TaskRunner, bool>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 pub fn is_runner_available(runner: &TaskRunner) -> bool {
+    let env = current_environment();
+    if !env.is_real() {
+        return probe_runner(runner, env.as_ref());
+    }
+    if let Some(available) = RUNNER_AVAILABILITY.lock().unwrap().get(runner) {
+        return *available;
+    }
+    let available = probe_runner(runner, env.as_ref());
+    RUNNER_AVAILABILITY
+        .lock()
+        .unwrap()
+        .insert(runner.clone(), available);
+    available
+}
+
+fn probe_runner(runner: &TaskRunner, env: &dyn Environment) -> bool {
+    let found = |name: &str| env.check_executable(name).is_some();
     match runner {
-        TaskRunner::Make => check_path_executable("make").is_some(),
-        TaskRunner::NodeNpm => check_path_executable("npm").is_some(),
-        TaskRunner::NodeYarn => check_path_executable("yarn").is_some(),
-        TaskRunner::NodePnpm => check_path_executable("pnpm").is_some(),
-        TaskRunner::NodeBun => check_path_executable("bun").is_some(),
-        TaskRunner::PythonUv => check_path_executable("uv").is_some(),
-        TaskRunner::PythonPoetry => check_path_executable("poetry").is_some(),
-        TaskRunner::PythonPoe => check_path_executable("poe").is_some(),
+        TaskRunner::Make => found("make"),
+        TaskRunner::NodeNpm => found("npm"),
+        TaskRunner::NodeYarn | TaskRunner::NodeYarnBerry => found("yarn"),
+        TaskRunner::NodePnpm => found("pnpm"),
+        TaskRunner::NodeBun => found("bun"),
+        TaskRunner::PythonUv => found("uv"),
+        TaskRunner::PythonPoetry => found("poetry"),
+        TaskRunner::PythonPoe => found("poe"),
+        TaskRunner::PythonHatch => found("hatch"),
         TaskRunner::ShellScript => true, // Shell scripts don't need a runner
-        TaskRunner::Task => check_path_executable("task").is_some(),
-        TaskRunner::Turbo => check_path_executable("turbo").is_some(),
-        TaskRunner::Maven => check_path_executable("mvn").is_some(),
-        TaskRunner::Gradle => {
-            check_path_executable("gradle").is_some()
-                || check_path_executable("./gradlew").is_some()
-        }
-        TaskRunner::Act => check_path_executable("act").is_some(),
-        TaskRunner::DockerCompose => check_path_executable("docker").is_some(),
+        TaskRunner::Task => found("task"),
+        TaskRunner::Turbo => found("turbo"),
+        TaskRunner::Maven => found("mvn"),
+        TaskRunner::Gradle => found("gradle") || found("./gradlew"),
+        TaskRunner::Act => found("act"),
+        TaskRunner::DockerCompose => found("docker"),
         TaskRunner::TravisCi => false, // Travis CI tasks are not executable locally
-        TaskRunner::CMake => check_path_executable("cmake").is_some(),
-        TaskRunner::Just => check_path_executable("just").is_some(),
+        TaskRunner::CMake => found("cmake"),
+        TaskRunner::Just => found("just"),
+        TaskRunner::Tox => found("tox"),
+        TaskRunner::Nox => found("nox"),
+        TaskRunner::Invoke => found("invoke"),
+        TaskRunner::GitlabCi => found("gitlab-ci-local"),
+        TaskRunner::Nx => found("nx"),
+        TaskRunner::Mise => found("mise"),
+        TaskRunner::Bazel => found("bazel"),
+        TaskRunner::Meson => found("meson"),
+        TaskRunner::Scons => found("scons"),
+        TaskRunner::PreCommit => found("pre-commit"),
+        TaskRunner::Ant => found("ant"),
+        TaskRunner::Sbt => found("sbt"),
+        TaskRunner::Leiningen => found("lein"),
+        TaskRunner::Procfile => true, // Processes run in the user's shell
+        TaskRunner::Mage => found("mage"),
+        TaskRunner::DockerBake | TaskRunner::Docker => found("docker"),
+        TaskRunner::Helm => found("helm"),
     }
 }
 
@@ -98,6 +152,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_command() {
+        let parts = vec!["npm".to_string(), "run".to_string(), "build".to_string()];
+        let command = platform_command(parts.clone());
+        if cfg!(windows) {
+            assert_eq!(command[..2], ["cmd", "/C"]);
+            assert_eq!(command[2..], parts[..]);
+        } else {
+            assert_eq!(command, parts);
+        }
+    }
+
     #[test]
     #[serial]
     fn test_shell_script_always_available() {
@@ -300,4 +366,34 @@ mod tests {
         reset_mock();
         reset_to_real_environment();
     }
+
+    #[test]
+    #[serial]
+    fn test_runner_availability_cached_for_real_environment_only() {
+        reset_to_real_environment();
+        let real = is_runner_available(&TaskRunner::Act);
+        assert!(
+            RUNNER_AVAILABILITY
+                .lock()
+                .unwrap()
+                .contains_key(&TaskRunner::Act)
+        );
+
+        // A remembered answer is reused rather than probed again
+        RUNNER_AVAILABILITY
+            .lock()
+            .unwrap()
+            .insert(TaskRunner::Act, !real);
+        assert_eq!(is_runner_available(&TaskRunner::Act), !real);
+
+        // A test environment is never answered from the cache
+        set_test_environment(TestEnvironment::new().with_executable("act"));
+        assert!(is_runner_available(&TaskRunner::Act));
+        set_test_environment(TestEnvironment::new());
+        assert!(!is_runner_available(&TaskRunner::Act));
+
+        reset_to_real_environment();
+        RUNNER_AVAILABILITY.lock().unwrap().remove(&TaskRunner::Act);
+        assert_eq!(is_runner_available(&TaskRunner::Act), real);
+    }
 }
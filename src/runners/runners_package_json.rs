@@ -1,75 +1,72 @@
-use crate::task_shadowing::check_path_executable;
+use crate::runner::is_runner_available;
 use crate::types::TaskRunner;
 use std::path::Path;
 
-/// Detect which package manager to use for a Node.js project
-pub fn detect_package_manager(dir: &Path) -> Option<TaskRunner> {
-    // Check for lock files first (highest priority)
-
-    // Check for package-lock.json (npm)
-    if dir.join("package-lock.json").exists() {
-        return Some(TaskRunner::NodeNpm);
-    }
+/// Lock files in the order they are checked, with the package manager that writes each.
+const LOCK_FILES: [(&str, TaskRunner); 5] = [
+    ("bun.lockb", TaskRunner::NodeBun),
+    ("bun.lock", TaskRunner::NodeBun),
+    ("pnpm-lock.yaml", TaskRunner::NodePnpm),
+    ("yarn.lock", TaskRunner::NodeYarn),
+    ("package-lock.json", TaskRunner::NodeNpm),
+];
 
-    // Check for yarn.lock (yarn)
-    if dir.join("yarn.lock").exists() {
-        return Some(TaskRunner::NodeYarn);
+/// Detect which package manager to use for a Node.js project
+///
+/// A lock file decides, even when its package manager is not installed. Without one, the
+/// `packageManager` field of package.json (e.g. `pnpm@9.1.0`) is used, and failing that the
+/// first installed of bun, pnpm, yarn and npm. Yarn is Yarn Berry when the project has a
+/// `.yarnrc.yml` or asks for yarn 2 or later in `packageManager`.
+pub fn detect_package_manager(dir: &Path, package_manager: Option<&str>) -> Option<TaskRunner> {
+    let runner = detect_node_runner(dir, package_manager)?;
+    if runner == TaskRunner::NodeYarn && is_yarn_berry(dir, package_manager) {
+        return Some(TaskRunner::NodeYarnBerry);
     }
+    Some(runner)
+}
 
-    // Check for pnpm-lock.yaml (pnpm)
-    if dir.join("pnpm-lock.yaml").exists() {
-        return Some(TaskRunner::NodePnpm);
+fn detect_node_runner(dir: &Path, package_manager: Option<&str>) -> Option<TaskRunner> {
+    if let Some((_, runner)) = LOCK_FILES
+        .iter()
+        .find(|(lock_file, _)| dir.join(lock_file).exists())
+    {
+        return Some(runner.clone());
     }
 
-    // Check for bun.lockb (bun)
-    if dir.join("bun.lockb").exists() {
-        return Some(TaskRunner::NodeBun);
+    if let Some(runner) = package_manager.and_then(package_manager_runner) {
+        return Some(runner);
     }
 
-    // If no lock files, check which package managers are available
-    #[cfg(not(test))]
-    {
-        let has_bun = check_path_executable("bun").is_some();
-        let has_npm = check_path_executable("npm").is_some();
-        let has_yarn = check_path_executable("yarn").is_some();
-        let has_pnpm = check_path_executable("pnpm").is_some();
-
-        // Prefer Bun > PNPM > Yarn > NPM
-        if has_bun {
-            return Some(TaskRunner::NodeBun);
-        } else if has_pnpm {
-            return Some(TaskRunner::NodePnpm);
-        } else if has_yarn {
-            return Some(TaskRunner::NodeYarn);
-        } else if has_npm {
-            return Some(TaskRunner::NodeNpm);
-        }
-    }
+    [
+        TaskRunner::NodeBun,
+        TaskRunner::NodePnpm,
+        TaskRunner::NodeYarn,
+        TaskRunner::NodeNpm,
+    ]
+    .into_iter()
+    .find(is_runner_available)
+}
 
-    // In test mode, no need to do anything special as the test environment
-    // will handle mocking the available executables
+/// Whether the project uses Yarn 2 or later, which reads `.yarnrc.yml` instead of `.yarnrc`.
+fn is_yarn_berry(dir: &Path, package_manager: Option<&str>) -> bool {
+    dir.join(".yarnrc.yml").exists()
+        || package_manager
+            .and_then(|package_manager| package_manager.strip_prefix("yarn@"))
+            .and_then(|version| version.split('.').next())
+            .and_then(|major| major.parse::<u32>().ok())
+            .is_some_and(|major| major >= 2)
+}
 
-    #[cfg(test)]
-    {
-        let has_bun = check_path_executable("bun").is_some();
-        let has_pnpm = check_path_executable("pnpm").is_some();
-        let has_yarn = check_path_executable("yarn").is_some();
-        let has_npm = check_path_executable("npm").is_some();
-
-        // Prefer Bun > PNPM > Yarn > NPM
-        if has_bun {
-            return Some(TaskRunner::NodeBun);
-        } else if has_pnpm {
-            return Some(TaskRunner::NodePnpm);
-        } else if has_yarn {
-            return Some(TaskRunner::NodeYarn);
-        } else if has_npm {
-            return Some(TaskRunner::NodeNpm);
-        }
+/// The runner named by a `packageManager` field, which has the form `name@version`.
+fn package_manager_runner(package_manager: &str) -> Option<TaskRunner> {
+    let name = package_manager.split('@').next().unwrap_or_default().trim();
+    match name {
+        "bun" => Some(TaskRunner::NodeBun),
+        "pnpm" => Some(TaskRunner::NodePnpm),
+        "yarn" => Some(TaskRunner::NodeYarn),
+        "npm" => Some(TaskRunner::NodeNpm),
+        _ => None,
     }
-
-    // No package managers available
-    None
 }
 
 #[cfg(test)]
@@ -96,6 +93,7 @@ mod tests {
             let _ = std::fs::remove_file(dir.join("yarn.lock"));
             let _ = std::fs::remove_file(dir.join("pnpm-lock.yaml"));
             let _ = std::fs::remove_file(dir.join("bun.lockb"));
+            let _ = std::fs::remove_file(dir.join("bun.lock"));
         }
 
         // Enable mocking
@@ -107,7 +105,7 @@ mod tests {
         create_lock_file(temp_dir.path(), "package-lock.json");
         mock_executable("npm");
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeNpm)
         );
 
@@ -116,7 +114,7 @@ mod tests {
         create_lock_file(temp_dir.path(), "yarn.lock");
         mock_executable("yarn");
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeYarn)
         );
 
@@ -125,7 +123,7 @@ mod tests {
         create_lock_file(temp_dir.path(), "pnpm-lock.yaml");
         mock_executable("pnpm");
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodePnpm)
         );
 
@@ -134,7 +132,7 @@ mod tests {
         create_lock_file(temp_dir.path(), "bun.lockb");
         mock_executable("bun");
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeBun)
         );
 
@@ -150,7 +148,7 @@ mod tests {
         let env = TestEnvironment::new().with_executable("bun");
         set_test_environment(env);
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeBun)
         );
         reset_to_real_environment();
@@ -159,7 +157,7 @@ mod tests {
         let env = TestEnvironment::new().with_executable("npm");
         set_test_environment(env);
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeNpm)
         );
         reset_to_real_environment();
@@ -170,7 +168,7 @@ mod tests {
             .with_executable("npm");
         set_test_environment(env);
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeBun)
         );
         reset_to_real_environment();
@@ -178,7 +176,7 @@ mod tests {
         // Test with no package managers
         let env = TestEnvironment::new();
         set_test_environment(env);
-        assert_eq!(detect_package_manager(temp_dir.path()), None);
+        assert_eq!(detect_package_manager(temp_dir.path(), None), None);
         reset_to_real_environment();
     }
 
@@ -201,18 +199,114 @@ mod tests {
 
         // Test preference order with no lock files
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeBun)
         );
 
         // Test that lock files take precedence
         create_lock_file(temp_dir.path(), "package-lock.json");
         assert_eq!(
-            detect_package_manager(temp_dir.path()),
+            detect_package_manager(temp_dir.path(), None),
             Some(TaskRunner::NodeNpm)
         );
 
         reset_mock();
         reset_to_real_environment();
     }
+
+    #[test]
+    #[serial]
+    fn test_detect_package_manager_lock_file_precedence() {
+        let temp_dir = TempDir::new().unwrap();
+        set_test_environment(TestEnvironment::new().with_executable("npm"));
+
+        // Lock files decide even when their package manager is not installed
+        create_lock_file(temp_dir.path(), "package-lock.json");
+        create_lock_file(temp_dir.path(), "yarn.lock");
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), Some("npm@10.0.0")),
+            Some(TaskRunner::NodeYarn)
+        );
+        create_lock_file(temp_dir.path(), "pnpm-lock.yaml");
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), None),
+            Some(TaskRunner::NodePnpm)
+        );
+        create_lock_file(temp_dir.path(), "bun.lock");
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), None),
+            Some(TaskRunner::NodeBun)
+        );
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_detect_package_manager_from_package_manager_field() {
+        let temp_dir = TempDir::new().unwrap();
+        set_test_environment(TestEnvironment::new().with_executable("npm"));
+
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), Some("pnpm@9.1.0")),
+            Some(TaskRunner::NodePnpm)
+        );
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), Some("yarn@4.2.2+sha512.abc")),
+            Some(TaskRunner::NodeYarnBerry)
+        );
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), Some("bun")),
+            Some(TaskRunner::NodeBun)
+        );
+        // An unknown package manager falls back to what is installed
+        assert_eq!(
+            detect_package_manager(temp_dir.path(), Some("deno@2.0.0")),
+            Some(TaskRunner::NodeNpm)
+        );
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_detect_yarn_berry() {
+        set_test_environment(TestEnvironment::new().with_executable("yarn"));
+
+        let classic = TempDir::new().unwrap();
+        create_lock_file(classic.path(), "yarn.lock");
+        assert_eq!(
+            detect_package_manager(classic.path(), None),
+            Some(TaskRunner::NodeYarn)
+        );
+        assert_eq!(
+            detect_package_manager(classic.path(), Some("yarn@1.22.19")),
+            Some(TaskRunner::NodeYarn)
+        );
+        assert_eq!(
+            detect_package_manager(classic.path(), Some("yarn@3.6.4")),
+            Some(TaskRunner::NodeYarnBerry)
+        );
+
+        let berry = TempDir::new().unwrap();
+        create_lock_file(berry.path(), "yarn.lock");
+        std::fs::write(
+            berry.path().join(".yarnrc.yml"),
+            "nodeLinker: node-modules\n",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_package_manager(berry.path(), None),
+            Some(TaskRunner::NodeYarnBerry)
+        );
+
+        // .yarnrc.yml only matters for yarn projects
+        create_lock_file(berry.path(), "pnpm-lock.yaml");
+        assert_eq!(
+            detect_package_manager(berry.path(), None),
+            Some(TaskRunner::NodePnpm)
+        );
+
+        reset_to_real_environment();
+    }
 }
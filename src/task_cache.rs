@@ -0,0 +1,214 @@
+use crate::config::active_dela_config_dir;
+use crate::glob::glob_match;
+use crate::parsers::parse_sidecar::load_sidecar;
+use crate::types::Task;
+use std::path::{Path, PathBuf};
+
+/// Directories never searched for input files.
+const SKIPPED_DIRS: &[&str] = &[".git", "node_modules", "target"];
+
+/// The inputs of a task that declares `inputs` in its sidecar, hashed for one invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedRun {
+    /// Where the hash of the last successful run of this task is stored
+    entry_path: PathBuf,
+    /// Hash of the command line and the contents of every input file
+    hash: String,
+    /// Whether every declared output glob matches an existing file
+    outputs_present: bool,
+}
+
+impl CachedRun {
+    /// Hash the inputs of `task` for running `command`, or `None` if it declares no inputs.
+    /// Globs are relative to the directory of the task's definition file.
+    pub fn for_task(task: &Task, command: &str) -> anyhow::Result<Option<Self>> {
+        let Some(sidecar) = load_sidecar(task.definition_path())? else {
+            return Ok(None);
+        };
+        let Some((inputs, outputs)) = sidecar.cache_globs(task) else {
+            return Ok(None);
+        };
+        let base_dir = task.definition_path().parent().unwrap_or(Path::new("."));
+        let cache_dir = active_dela_config_dir()?.join("taskcache");
+        Self::new(&cache_dir, task, base_dir, command, inputs, outputs).map(Some)
+    }
+
+    fn new(
+        cache_dir: &Path,
+        task: &Task,
+        base_dir: &Path,
+        command: &str,
+        inputs: &[String],
+        outputs: &[String],
+    ) -> anyhow::Result<Self> {
+        let mut files = Vec::new();
+        collect_files(base_dir, base_dir, &mut files);
+
+        let mut hasher = Fnv64::default();
+        hasher.write(command.as_bytes());
+        let mut matched: Vec<&String> = files
+            .iter()
+            .filter(|relative| inputs.iter().any(|glob| glob_match(glob, relative)))
+            .collect();
+        matched.sort();
+        for relative in matched {
+            let contents = std::fs::read(base_dir.join(relative))
+                .map_err(|e| anyhow::anyhow!("Failed to read input {}: {}", relative, e))?;
+            hasher.write(relative.as_bytes());
+            hasher.write(&(contents.len() as u64).to_le_bytes());
+            hasher.write(&contents);
+        }
+
+        let outputs_present = outputs
+            .iter()
+            .all(|glob| files.iter().any(|relative| glob_match(glob, relative)));
+
+        let mut key = Fnv64::default();
+        key.write(task.definition_path().to_string_lossy().as_bytes());
+        key.write(task.source_name.as_bytes());
+        Ok(CachedRun {
+            entry_path: cache_dir.join(format!("{:016x}", key.finish())),
+            hash: format!("{:016x}", hasher.finish()),
+            outputs_present,
+        })
+    }
+
+    /// Whether the last successful run had the same inputs and its outputs still exist.
+    pub fn is_up_to_date(&self) -> bool {
+        self.outputs_present
+            && std::fs::read_to_string(&self.entry_path)
+                .is_ok_and(|stored| stored.trim() == self.hash)
+    }
+
+    /// Remember these inputs as the last successful run.
+    pub fn record_success(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.entry_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| anyhow::anyhow!("Failed to create task cache directory: {}", e))?;
+        }
+        std::fs::write(&self.entry_path, &self.hash)
+            .map_err(|e| anyhow::anyhow!("Failed to write task cache: {}", e))
+    }
+}
+
+/// Every file under `dir`, as a `/`-separated path relative to `base_dir`.
+fn collect_files(base_dir: &Path, dir: &Path, files: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            if !SKIPPED_DIRS.contains(&name.to_string_lossy().as_ref()) {
+                collect_files(base_dir, &path, files);
+            }
+        } else if let Ok(relative) = path.strip_prefix(base_dir) {
+            let relative: Vec<String> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            files.push(relative.join("/"));
+        }
+    }
+}
+
+/// 64-bit FNV-1a, chosen because its output is stable across Rust releases.
+pub(crate) struct Fnv64(u64);
+
+impl Default for Fnv64 {
+    fn default() -> Self {
+        Fnv64(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv64 {
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_makefile;
+    use tempfile::TempDir;
+
+    fn cached_run(project: &Path, cache_dir: &Path) -> CachedRun {
+        let makefile = project.join("Makefile");
+        let tasks = parse_makefile(&makefile).unwrap();
+        let task = tasks.iter().find(|t| t.name == "build").unwrap();
+        let sidecar = load_sidecar(&makefile).unwrap().unwrap();
+        let (inputs, outputs) = sidecar.cache_globs(task).unwrap();
+        CachedRun::new(cache_dir, task, project, "make build", inputs, outputs).unwrap()
+    }
+
+    #[test]
+    fn test_unchanged_inputs_are_up_to_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let cache_dir = temp_dir.path().join("taskcache");
+        std::fs::create_dir_all(project.join("src/nested")).unwrap();
+        std::fs::write(project.join("Makefile"), "build:\n\tcc -o app src/*.c\n").unwrap();
+        std::fs::write(
+            project.join("Makefile.dela.toml"),
+            "[tasks.build]\ninputs = [\"src/**/*.c\"]\noutputs = [\"app\"]\n",
+        )
+        .unwrap();
+        std::fs::write(project.join("src/main.c"), "int main() {}\n").unwrap();
+        std::fs::write(project.join("src/nested/util.c"), "void util() {}\n").unwrap();
+
+        let first = cached_run(&project, &cache_dir);
+        assert!(!first.is_up_to_date(), "Never run before");
+        std::fs::write(project.join("app"), "binary").unwrap();
+        first.record_success().unwrap();
+
+        let second = cached_run(&project, &cache_dir);
+        assert!(second.is_up_to_date());
+
+        // Files that are not inputs do not matter
+        std::fs::write(project.join("README.md"), "docs").unwrap();
+        assert!(cached_run(&project, &cache_dir).is_up_to_date());
+
+        // A missing output makes the task run again
+        std::fs::remove_file(project.join("app")).unwrap();
+        assert!(!cached_run(&project, &cache_dir).is_up_to_date());
+    }
+
+    #[test]
+    fn test_changed_inputs_run_again() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let cache_dir = temp_dir.path().join("taskcache");
+        std::fs::create_dir_all(project.join("src")).unwrap();
+        std::fs::write(project.join("Makefile"), "build:\n\tcc -o app src/*.c\n").unwrap();
+        std::fs::write(
+            project.join("Makefile.dela.toml"),
+            "[tasks.build]\ninputs = [\"src/*.c\"]\n",
+        )
+        .unwrap();
+        std::fs::write(project.join("src/main.c"), "int main() {}\n").unwrap();
+
+        cached_run(&project, &cache_dir).record_success().unwrap();
+        assert!(cached_run(&project, &cache_dir).is_up_to_date());
+
+        std::fs::write(project.join("src/main.c"), "int main() { return 1; }\n").unwrap();
+        assert!(!cached_run(&project, &cache_dir).is_up_to_date());
+
+        std::fs::write(project.join("src/extra.c"), "void extra() {}\n").unwrap();
+        let changed = cached_run(&project, &cache_dir);
+        assert!(!changed.is_up_to_date());
+        changed.record_success().unwrap();
+        assert!(cached_run(&project, &cache_dir).is_up_to_date());
+    }
+}
@@ -1,27 +1,56 @@
+mod ant;
+mod bazel;
+mod cache;
 mod cmake;
+mod dependency_graph;
 mod disambiguation;
+mod docker_bake;
 mod docker_compose;
+mod dockerfile;
 mod github_actions;
+mod gitlab_ci;
 mod gradle;
+mod helm;
+mod invoke;
 mod justfile;
+mod leiningen;
+mod mage;
 mod make;
 mod maven;
+mod meson;
+mod mise;
+mod nox;
 mod npm;
+mod nx;
+mod pre_commit;
+mod procfile;
 mod python;
+mod recursive;
 mod registry;
+mod sbt;
+mod scons;
 mod shell_scripts;
 mod support;
 mod taskfile;
+mod tox;
 mod travis_ci;
 mod turbo;
 
-use crate::types::{DiscoveredTaskDefinitions, Task, TaskDefinitionFile};
+use crate::config;
+use crate::environment::get_current_home;
+use crate::types::{DiscoveredTaskDefinitions, Task, TaskDefinitionFile, TaskRunner};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+pub use cache::{clear_discovery_cache, discover_tasks_cached, unreadable_discovery_cache_files};
+pub use dependency_graph::{dependency_roots, dependency_tree, find_dependency_cycles};
 pub use disambiguation::{
-    format_ambiguous_task_error, get_matching_tasks, is_task_ambiguous, process_task_disambiguation,
+    apply_runner_priority, format_ambiguous_task_error, format_task_not_found_error,
+    get_matching_tasks, get_matching_tasks_qualified, ignore_shadowing, is_task_ambiguous,
+    process_task_disambiguation,
 };
+pub use recursive::discover_tasks_recursive;
+pub(crate) use recursive::{IgnoreRule, SKIPPED_DIRS, read_gitignore};
 
 #[derive(Debug, Clone, Default)]
 pub struct DiscoveredTasks {
@@ -45,18 +74,100 @@ impl DiscoveredTasks {
 }
 
 pub(crate) trait TaskDiscovery {
+    /// The runners of the tasks this discoverer finds
+    fn runners(&self) -> &'static [TaskRunner];
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks);
 }
 
 pub fn discover_tasks(dir: &Path) -> DiscoveredTasks {
     let mut discovered = DiscoveredTasks::default();
+    discover_tasks_in(dir, &mut discovered);
+    process_task_disambiguation(&mut discovered);
+    discovered
+}
+
+/// Discover tasks in `dir`, or in the nearest parent directory that defines any when `dir`
+/// does not, returning the directory the tasks were found in.
+///
+/// The search stops at a VCS root (a directory containing `.git`), below `$HOME`, and at
+/// the filesystem root. When nothing is found, the tasks of `dir` itself are returned.
+pub fn discover_tasks_walking_up(dir: &Path) -> (PathBuf, DiscoveredTasks) {
+    // Discoverers also report repository-root files such as workflows, which do not
+    // make a subdirectory a place that defines tasks.
+    let defines_tasks = |discovered: &DiscoveredTasks, search_dir: &Path| {
+        discovered
+            .tasks
+            .iter()
+            .any(|task| task.definition_path().starts_with(search_dir))
+    };
+
+    let in_dir = discover_tasks(dir);
+    if defines_tasks(&in_dir, dir) {
+        return (dir.to_path_buf(), in_dir);
+    }
+
+    let home = get_current_home().map(PathBuf::from);
+    let mut search_dir = dir;
+    while !search_dir.join(".git").exists() {
+        match search_dir.parent() {
+            Some(parent) if home.as_deref() != Some(parent) => search_dir = parent,
+            _ => break,
+        }
+        let discovered = discover_tasks(search_dir);
+        if defines_tasks(&discovered, search_dir) {
+            return (search_dir.to_path_buf(), discovered);
+        }
+    }
+    (dir.to_path_buf(), in_dir)
+}
 
+/// Run every registered discoverer against `dir` without disambiguating the results.
+fn discover_tasks_in(dir: &Path, discovered: &mut DiscoveredTasks) {
+    let disabled = DisabledRunners::for_dir(dir, discovered);
     for discoverer in registry::registered_discoveries() {
-        discoverer.discover(dir, &mut discovered);
+        if !disabled.skips(discoverer) {
+            discoverer.discover(dir, discovered);
+        }
     }
+    disabled.remove_tasks(&mut discovered.tasks);
+}
 
-    process_task_disambiguation(&mut discovered);
-    discovered
+/// The runners turned off by `disabled_runners` in config.toml or `.dela.toml`.
+pub(crate) struct DisabledRunners(Vec<String>);
+
+impl DisabledRunners {
+    /// The runners disabled in `dir`. A `.dela.toml` that cannot be read disables nothing
+    /// and is reported as a discovery error.
+    pub(crate) fn for_dir(dir: &Path, discovered: &mut DiscoveredTasks) -> Self {
+        match config::disabled_runners(dir) {
+            Ok(names) => DisabledRunners(names),
+            Err(error) => {
+                discovered.errors.push(error.to_string());
+                DisabledRunners(Vec::new())
+            }
+        }
+    }
+
+    fn contains(&self, runner: &TaskRunner) -> bool {
+        self.0.iter().any(|name| runner.matches_filter(name))
+    }
+
+    /// Whether every runner `discoverer` finds tasks for is disabled, so it need not run.
+    pub(crate) fn skips(&self, discoverer: &dyn TaskDiscovery) -> bool {
+        !self.0.is_empty()
+            && discoverer
+                .runners()
+                .iter()
+                .all(|runner| self.contains(runner))
+    }
+
+    /// Drop the tasks of disabled runners that a partly enabled discoverer found.
+    pub(crate) fn remove_tasks(&self, tasks: &mut Vec<Task>) {
+        if !self.0.is_empty() {
+            tasks.retain(|task| !self.contains(&task.runner));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -924,6 +1035,36 @@ include mk/valid.mk"#,
         ));
     }
 
+    #[test]
+    fn test_discover_tasks_with_oversized_makefile() {
+        let temp_dir = TempDir::new().unwrap();
+        let makefile = File::create(temp_dir.path().join("Makefile")).unwrap();
+        // A sparse file, so the test does not write the bytes out
+        makefile
+            .set_len(crate::config::DiscoveryConfig::default().max_file_size + 1)
+            .unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+
+        assert!(discovered.tasks.is_empty());
+        assert!(
+            discovered
+                .errors
+                .iter()
+                .any(|error| error.contains("file too large to parse")),
+            "Expected a size limit error, found: {:?}",
+            discovered.errors
+        );
+        assert!(matches!(
+            &discovered
+                .definitions
+                .get_first(&TaskDefinitionType::Makefile)
+                .unwrap()
+                .status,
+            TaskFileStatus::ParseError(message) if message.starts_with("file too large to parse")
+        ));
+    }
+
     #[test]
     #[serial]
     fn test_discover_tasks_with_unimplemented_parsers() {
@@ -2006,6 +2147,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::ShellBuiltin("bash".to_string())),
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Mock a task with name "ls" that is shadowed by PATH executable
@@ -2019,6 +2171,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/ls".to_string())),
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Mock a task that is not shadowed (should not get a disambiguated name)
@@ -2032,6 +2195,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Process the tasks
@@ -2071,6 +2245,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         discovered.tasks.push(Task {
@@ -2083,6 +2268,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: Some("test-npm".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Shadowed task - "ls" shadowed by PATH executable
@@ -2096,6 +2292,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/ls".to_string())),
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Shadowed task with name collision - "cd" shadowed by shell builtin
@@ -2109,6 +2316,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::ShellBuiltin("bash".to_string())),
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         discovered.tasks.push(Task {
@@ -2121,6 +2339,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::ShellBuiltin("bash".to_string())),
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Normal task - no collision, not shadowed
@@ -2134,6 +2363,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Process the tasks
@@ -2205,6 +2445,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/usr/bin/install".to_string())),
             disambiguated_name: Some("install-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         // Look up the task by original name
@@ -2239,6 +2490,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/test".to_string())),
             disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
         discovered.tasks.push(Task {
             name: "test-m".to_string(),
@@ -2250,6 +2512,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         let matching_tasks = get_matching_tasks(&discovered, "test-m");
@@ -2273,6 +2546,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/test".to_string())),
             disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         discovered_tasks.add_task(task);
@@ -2310,6 +2594,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/grep".to_string())),
             disambiguated_name: Some("grep-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         discovered_tasks.add_task(task);
@@ -2349,6 +2644,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/test".to_string())),
             disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let task2 = Task {
@@ -2361,6 +2667,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: Some("test-npm".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         // Manually set task name counts to mark "test" as ambiguous
@@ -2420,6 +2737,17 @@ jobs:
             description: None,
             shadowed_by: Some(ShadowType::PathExecutable("/bin/test".to_string())),
             disambiguated_name: Some("test-m".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
         discovered_tasks.add_task(Task {
             name: "test-m".to_string(),
@@ -2431,6 +2759,17 @@ jobs:
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         });
 
         let mut executor = CommandExecutor::new(MockTaskExecutor::new());
@@ -3238,4 +3577,708 @@ build: # Build the project
         assert_eq!(justfile_def.path, dir4.join("Justfile")); // Should use default path
         assert!(matches!(justfile_def.status, TaskFileStatus::NotFound));
     }
+
+    #[test]
+    fn test_discover_single_file_runners() {
+        // (file name, contents, definition type, runner, tasks found in the file)
+        let cases: &[(&str, &str, TaskDefinitionType, TaskRunner, usize)] = &[
+            (
+                "tox.ini",
+                "[tox]\nenvlist = py312, lint\n\n[testenv:lint]\ndescription = run linters\ncommands = ruff check .\n",
+                TaskDefinitionType::ToxIni,
+                TaskRunner::Tox,
+                2,
+            ),
+            (
+                "noxfile.py",
+                "import nox\n\n@nox.session\ndef lint(session):\n    pass\n\n@nox.session\ndef tests(session):\n    pass\n",
+                TaskDefinitionType::Noxfile,
+                TaskRunner::Nox,
+                2,
+            ),
+            (
+                "tasks.py",
+                "from invoke import task\n\n@task\ndef build(c):\n    c.run(\"make\")\n",
+                TaskDefinitionType::InvokeTasks,
+                TaskRunner::Invoke,
+                1,
+            ),
+            (
+                ".gitlab-ci.yml",
+                "build:\n  stage: build\n  script:\n    - make\n",
+                TaskDefinitionType::GitlabCi,
+                TaskRunner::GitlabCi,
+                1,
+            ),
+            (
+                "meson.build",
+                "project('demo', 'c')\nexe = executable('demo', 'main.c')\ntest('basic', exe)\n",
+                TaskDefinitionType::MesonBuild,
+                TaskRunner::Meson,
+                2,
+            ),
+            (
+                "SConstruct",
+                "env = Environment()\nenv.Alias('install', [])\n",
+                TaskDefinitionType::SConstruct,
+                TaskRunner::Scons,
+                2,
+            ),
+            (
+                ".pre-commit-config.yaml",
+                "repos:\n  - repo: local\n    hooks:\n      - id: lint\n      - id: format\n",
+                TaskDefinitionType::PreCommitConfig,
+                TaskRunner::PreCommit,
+                3,
+            ),
+            (
+                "build.xml",
+                "<project><target name=\"compile\"/><target name=\"test\" depends=\"compile\"/></project>\n",
+                TaskDefinitionType::AntBuildXml,
+                TaskRunner::Ant,
+                2,
+            ),
+            (
+                "build.sbt",
+                "lazy val docs = taskKey[Unit](\"Generate docs\")\n",
+                TaskDefinitionType::BuildSbt,
+                TaskRunner::Sbt,
+                6,
+            ),
+            (
+                "project.clj",
+                "(defproject demo \"0.1.0\"\n  :aliases {\"lint\" [\"do\" [\"clean\"] \"eastwood\"]})\n",
+                TaskDefinitionType::ProjectClj,
+                TaskRunner::Leiningen,
+                4,
+            ),
+        ];
+
+        for (file_name, contents, definition_type, runner, task_count) in cases {
+            let empty_dir = TempDir::new().unwrap();
+            let discovered = discover_tasks(empty_dir.path());
+            let definition = discovered.definitions.get_first(definition_type).unwrap();
+            assert_eq!(definition.path, empty_dir.path().join(file_name));
+            assert!(
+                matches!(definition.status, TaskFileStatus::NotFound),
+                "{} should not be found in an empty directory",
+                file_name
+            );
+
+            let temp_dir = TempDir::new().unwrap();
+            let dir = temp_dir.path();
+            std::fs::write(dir.join(file_name), contents).unwrap();
+
+            let discovered = discover_tasks(dir);
+
+            let tasks: Vec<_> = discovered
+                .tasks
+                .iter()
+                .filter(|t| t.definition_type == *definition_type)
+                .collect();
+            assert_eq!(tasks.len(), *task_count, "tasks from {}", file_name);
+            assert!(tasks.iter().all(|t| t.runner == *runner), "{}", file_name);
+
+            let definition = discovered.definitions.get_first(definition_type).unwrap();
+            assert_eq!(definition.path, dir.join(file_name));
+            assert!(
+                matches!(definition.status, TaskFileStatus::Parsed),
+                "{} should be parsed",
+                file_name
+            );
+        }
+    }
+
+    #[test]
+    fn test_discover_nx_project_targets_and_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("nx.json"),
+            r#"{"targetDefaults": {"build": {"cache": true}, "lint": {}}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("project.json"),
+            r#"{"name": "app", "targets": {"build": {"executor": "@nx/vite:build"}}}"#,
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let build = discovered
+            .tasks
+            .iter()
+            .find(|t| t.runner == TaskRunner::Nx && t.name == "build")
+            .unwrap();
+        assert_eq!(build.definition_type, TaskDefinitionType::NxProjectJson);
+        assert_eq!(build.runner.get_command(build), "nx run app:build");
+
+        let lint = discovered
+            .tasks
+            .iter()
+            .find(|t| t.runner == TaskRunner::Nx && t.name == "lint")
+            .unwrap();
+        assert_eq!(lint.definition_type, TaskDefinitionType::NxJson);
+        assert_eq!(lint.runner.get_command(lint), "nx run-many -t lint");
+
+        let nx_tasks = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Nx)
+            .count();
+        assert_eq!(nx_tasks, 2);
+
+        for definition_type in [
+            TaskDefinitionType::NxJson,
+            TaskDefinitionType::NxProjectJson,
+        ] {
+            let definition = discovered.definitions.get_first(&definition_type).unwrap();
+            assert!(matches!(definition.status, TaskFileStatus::Parsed));
+        }
+    }
+
+    #[test]
+    fn test_discover_nx_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+
+        let project_def = discovered
+            .definitions
+            .get_first(&TaskDefinitionType::NxProjectJson)
+            .unwrap();
+        assert!(matches!(project_def.status, TaskFileStatus::NotFound));
+        assert!(!discovered.tasks.iter().any(|t| t.runner == TaskRunner::Nx));
+    }
+
+    #[test]
+    fn test_discover_mise_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join(".mise.toml"),
+            "[tasks.build]\ndescription = \"Build it\"\nrun = \"cargo build\"\n",
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let mise_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Mise)
+            .collect();
+        assert_eq!(mise_tasks.len(), 1);
+        assert_eq!(mise_tasks[0].name, "build");
+        assert_eq!(mise_tasks[0].definition_type, TaskDefinitionType::MiseToml);
+
+        let mise_def = discovered
+            .definitions
+            .get_first(&TaskDefinitionType::MiseToml)
+            .unwrap();
+        assert_eq!(mise_def.path, dir.join(".mise.toml"));
+        assert!(matches!(mise_def.status, TaskFileStatus::Parsed));
+
+        let task_dir_def = discovered
+            .definitions
+            .get_first(&TaskDefinitionType::MiseTaskDir)
+            .unwrap();
+        assert!(matches!(task_dir_def.status, TaskFileStatus::NotFound));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_mise_file_tasks_defer_to_config_tasks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(dir.join("mise.toml"), "[tasks]\nbuild = \"cargo build\"\n").unwrap();
+        let task_dir = dir.join(".mise").join("tasks");
+        std::fs::create_dir_all(&task_dir).unwrap();
+        for name in ["build", "release"] {
+            let script = task_dir.join(name);
+            std::fs::write(&script, "#!/bin/sh\necho hi\n").unwrap();
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let discovered = discover_tasks(dir);
+
+        let mut mise_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Mise)
+            .map(|t| (t.name.as_str(), t.definition_type.clone()))
+            .collect();
+        mise_tasks.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(
+            mise_tasks,
+            vec![
+                ("build", TaskDefinitionType::MiseToml),
+                ("release", TaskDefinitionType::MiseTaskDir),
+            ]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_discover_bazel_skipped_without_bazel() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("MODULE.bazel"),
+            "module(name = \"app\")\n",
+        )
+        .unwrap();
+        set_test_environment(TestEnvironment::new());
+
+        let discovered = discover_tasks(temp_dir.path());
+        reset_to_real_environment();
+
+        assert!(
+            !discovered
+                .tasks
+                .iter()
+                .any(|t| t.runner == TaskRunner::Bazel)
+        );
+        assert!(
+            discovered
+                .definitions
+                .get_first(&TaskDefinitionType::BazelWorkspace)
+                .is_none()
+        );
+        assert!(discovered.errors.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_ignore_shadowing_keeps_collision_suffixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let env = TestEnvironment::new().with_shell("/bin/zsh");
+        set_test_environment(env);
+
+        File::create(temp_dir.path().join("Makefile"))
+            .unwrap()
+            .write_all(b"cd:\n\t@echo cd\nbuild:\n\t@echo build\n")
+            .unwrap();
+        File::create(temp_dir.path().join("package.json"))
+            .unwrap()
+            .write_all(br#"{"scripts": {"build": "tsc"}}"#)
+            .unwrap();
+        File::create(temp_dir.path().join("package-lock.json"))
+            .unwrap()
+            .write_all(b"{}")
+            .unwrap();
+
+        let mut discovered = discover_tasks(temp_dir.path());
+        ignore_shadowing(&mut discovered);
+
+        let cd_task = discovered.tasks.iter().find(|t| t.name == "cd").unwrap();
+        assert_eq!(cd_task.shadowed_by, None);
+        assert_eq!(cd_task.disambiguated_name, None);
+
+        let build_names: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.name == "build")
+            .map(|t| t.disambiguated_name.clone())
+            .collect();
+        assert_eq!(build_names.len(), 2);
+        assert!(build_names.iter().all(|name| name.is_some()));
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_discover_turbo_tasks_disambiguated_from_package_scripts() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("npm"));
+
+        create_test_turbo_json(
+            temp_dir.path(),
+            r#"{"pipeline": {"build": {"outputs": ["dist/**"]}, "lint": {}}}"#,
+        );
+        std::fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"scripts": {"build": "turbo run build", "dev": "next dev"}}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("package-lock.json"), "{}").unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+
+        let build_names: Vec<(TaskRunner, Option<&str>)> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.name == "build")
+            .map(|t| (t.runner.clone(), t.disambiguated_name.as_deref()))
+            .collect();
+        assert_eq!(build_names.len(), 2);
+        assert!(build_names.contains(&(TaskRunner::Turbo, Some("build-t"))));
+        assert!(build_names.contains(&(TaskRunner::NodeNpm, Some("build-n"))));
+
+        let lint_task = discovered.tasks.iter().find(|t| t.name == "lint").unwrap();
+        assert_eq!(lint_task.disambiguated_name, None);
+        assert_eq!(TaskRunner::Turbo.get_command(lint_task), "turbo run lint");
+
+        reset_mock();
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_discover_nx_workspace_projects() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(dir.join("nx.json"), r#"{"targetDefaults": {}}"#).unwrap();
+
+        let write_project = |relative: &str, content: &str| {
+            let project_dir = dir.join(relative);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            std::fs::write(project_dir.join("project.json"), content).unwrap();
+        };
+        write_project(
+            "apps/web",
+            r#"{"name": "web", "targets": {"build": {"executor": "@nx/vite:build"}, "serve": {}}}"#,
+        );
+        write_project("libs/shared/ui", r#"{"targets": {"test": {}}}"#);
+        write_project("a/b/c/too-deep", r#"{"targets": {"build": {}}}"#);
+        write_project("node_modules/pkg", r#"{"targets": {"build": {}}}"#);
+
+        let discovered = discover_tasks(dir);
+
+        let mut names: Vec<&str> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Nx)
+            .map(|t| t.name.as_str())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["ui:test", "web:build", "web:serve"]);
+
+        let build = discovered
+            .tasks
+            .iter()
+            .find(|t| t.name == "web:build")
+            .unwrap();
+        assert_eq!(build.runner.get_command(build), "nx run web:build");
+        assert_eq!(build.description.as_deref(), Some("@nx/vite:build"));
+        assert_eq!(build.file_path, dir.join("apps/web/project.json"));
+    }
+
+    #[test]
+    fn test_discover_tasks_applies_sidecar() {
+        let temp_dir = TempDir::new().unwrap();
+        File::create(temp_dir.path().join("Makefile"))
+            .unwrap()
+            .write_all(b"build:\n\t@echo build\nclean:\n\t@rm -rf out\n")
+            .unwrap();
+        File::create(temp_dir.path().join("Makefile.dela.toml"))
+            .unwrap()
+            .write_all(b"[tasks.build]\ndescription = \"Build everything\"\n")
+            .unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+
+        let build = discovered.tasks.iter().find(|t| t.name == "build").unwrap();
+        assert_eq!(build.description.as_deref(), Some("Build everything"));
+        let clean = discovered.tasks.iter().find(|t| t.name == "clean").unwrap();
+        assert_eq!(clean.description, None);
+        assert!(discovered.errors.is_empty());
+
+        File::create(temp_dir.path().join("Makefile.dela.toml"))
+            .unwrap()
+            .write_all(b"not toml")
+            .unwrap();
+        let discovered = discover_tasks(temp_dir.path());
+        assert_eq!(discovered.tasks.len(), 2);
+        assert!(discovered.errors[0].contains("Makefile.dela.toml"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_discover_tasks_walking_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        set_test_environment(TestEnvironment::new());
+
+        let project = root.join("project");
+        let nested = project.join("src").join("app");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(project.join("Makefile"), "build:\n\t@echo build\n").unwrap();
+
+        let (found_dir, discovered) = discover_tasks_walking_up(&nested);
+        assert_eq!(found_dir, project);
+        assert!(discovered.tasks.iter().any(|t| t.name == "build"));
+
+        // A directory that defines tasks is used as is
+        let (found_dir, _) = discover_tasks_walking_up(&project);
+        assert_eq!(found_dir, project);
+
+        // The search does not leave the repository
+        std::fs::create_dir_all(project.join("src").join(".git")).unwrap();
+        let (found_dir, discovered) = discover_tasks_walking_up(&nested);
+        assert_eq!(found_dir, nested);
+        assert!(discovered.tasks.is_empty());
+        std::fs::remove_dir_all(project.join("src").join(".git")).unwrap();
+
+        // Nor does it look in $HOME
+        set_test_environment(TestEnvironment::new().with_home(project.to_string_lossy()));
+        let (found_dir, discovered) = discover_tasks_walking_up(&nested);
+        assert_eq!(found_dir, nested);
+        assert!(discovered.tasks.is_empty());
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    fn test_discover_procfile_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("Procfile"),
+            "web: bin/server --port $PORT\nworker: bin/jobs\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("Procfile.dev"), "css: bin/tailwind --watch\n").unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let procfile_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Procfile)
+            .collect();
+        let names: Vec<&str> = procfile_tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["web", "worker", "css"]);
+        assert_eq!(procfile_tasks[2].file_path, dir.join("Procfile.dev"));
+
+        let procfile_defs = discovered
+            .definitions
+            .get_all(&TaskDefinitionType::Procfile)
+            .unwrap();
+        assert_eq!(procfile_defs.len(), 2);
+        assert!(
+            procfile_defs
+                .iter()
+                .all(|def| matches!(def.status, TaskFileStatus::Parsed))
+        );
+    }
+
+    #[test]
+    fn test_discover_mage_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("magefile.go"),
+            "//go:build mage\n\npackage main\n\n// Build the binary\nfunc Build() error { return nil }\n\n// Test runs the tests\nfunc Test() error { return nil }\n",
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let mage_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.definition_type == TaskDefinitionType::Magefile)
+            .collect();
+        assert_eq!(mage_tasks.len(), 2);
+        assert!(mage_tasks.iter().all(|t| t.runner == TaskRunner::Mage));
+
+        let mage_def = discovered
+            .definitions
+            .get_first(&TaskDefinitionType::Magefile)
+            .unwrap();
+        assert_eq!(mage_def.path, dir.join("magefile.go"));
+        assert!(matches!(mage_def.status, TaskFileStatus::Parsed));
+    }
+
+    #[test]
+    fn test_discover_docker_bake_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("docker-bake.hcl"),
+            "group \"default\" {\n  targets = [\"api\", \"web\"]\n}\n\ntarget \"api\" {\n  context = \"./api\"\n}\n\ntarget \"web\" {\n  context = \"./web\"\n}\n",
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let mut bake_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.definition_type == TaskDefinitionType::DockerBake)
+            .map(|t| (t.name.as_str(), t.dependencies.clone()))
+            .collect();
+        bake_tasks.sort();
+        assert_eq!(
+            bake_tasks,
+            vec![
+                ("api", vec![]),
+                ("default", vec!["api".to_string(), "web".to_string()]),
+                ("web", vec![]),
+            ]
+        );
+
+        let bake_defs = discovered
+            .definitions
+            .get_all(&TaskDefinitionType::DockerBake)
+            .unwrap();
+        let parsed: Vec<_> = bake_defs
+            .iter()
+            .filter(|def| matches!(def.status, TaskFileStatus::Parsed))
+            .map(|def| def.path.clone())
+            .collect();
+        assert_eq!(parsed, vec![dir.join("docker-bake.hcl")]);
+    }
+
+    #[test]
+    fn test_discover_tasks_skips_disabled_runners() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_makefile(temp_dir.path(), "build:\n\techo build\n");
+        std::fs::write(
+            temp_dir.path().join("docker-compose.yml"),
+            "services:\n  web:\n    image: nginx\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join(".travis.yml"),
+            "language: rust\nscript:\n  - cargo test\n",
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+        let runners: std::collections::HashSet<TaskRunner> =
+            discovered.tasks.iter().map(|t| t.runner.clone()).collect();
+        assert!(runners.contains(&TaskRunner::DockerCompose));
+        assert!(runners.contains(&TaskRunner::TravisCi));
+
+        // The project config turns both off, one by its short name and one by an alias
+        std::fs::write(
+            temp_dir.path().join(config::PROJECT_CONFIG_FILE),
+            "[discovery]\ndisabled_runners = [\"travis\", \"docker-compose\"]\n",
+        )
+        .unwrap();
+        let discovered = discover_tasks(temp_dir.path());
+        let names: Vec<&str> = discovered.tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["build"]);
+        assert!(
+            discovered
+                .definitions
+                .get_first(&TaskDefinitionType::TravisCi)
+                .is_none()
+        );
+        assert!(discovered.errors.is_empty());
+    }
+
+    #[test]
+    fn test_included_makefile_fragments_skip_special_and_pattern_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_makefile(
+            temp_dir.path(),
+            "include build.mk\n-include missing.mk\n\n.PHONY: all\nall: app\n",
+        );
+        std::fs::write(
+            temp_dir.path().join("build.mk"),
+            ".PHONY: app clean\n.SUFFIXES:\n\ninclude clean.mk\n\n%.o: %.c\n\tcc -c $<\n\napp: main.o\n\tcc -o app main.o\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("clean.mk"),
+            "include build.mk\n\nclean:\n\trm -f app *.o\n",
+        )
+        .unwrap();
+
+        let discovered = discover_tasks(temp_dir.path());
+
+        assert!(discovered.errors.is_empty(), "{:?}", discovered.errors);
+        let mut names: Vec<&str> = discovered.tasks.iter().map(|t| t.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["all", "app", "clean"]);
+        let clean = discovered.tasks.iter().find(|t| t.name == "clean").unwrap();
+        assert_eq!(clean.definition_path(), temp_dir.path().join("clean.mk"));
+        assert_eq!(clean.file_path, temp_dir.path().join("Makefile"));
+    }
+
+    #[test]
+    fn test_discover_dockerfile_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("Dockerfile"),
+            "FROM rust:1.80 AS builder\nRUN cargo build --release\n\nFROM debian:bookworm-slim\nCOPY --from=builder /app /app\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("worker.Dockerfile"), "FROM python:3.12 AS deps\n").unwrap();
+        std::fs::write(dir.join(".Dockerfile"), "FROM scratch AS hidden\n").unwrap();
+
+        let discovered = discover_tasks(dir);
+
+        let mut docker_tasks: Vec<_> = discovered
+            .tasks
+            .iter()
+            .filter(|t| t.runner == TaskRunner::Docker)
+            .map(|t| t.name.as_str())
+            .collect();
+        docker_tasks.sort();
+        assert_eq!(
+            docker_tasks,
+            vec!["builder", "docker", "docker-worker", "worker-deps"]
+        );
+
+        let parsed: Vec<_> = discovered
+            .definitions
+            .get_all(&TaskDefinitionType::Dockerfile)
+            .unwrap()
+            .iter()
+            .filter(|def| matches!(def.status, TaskFileStatus::Parsed))
+            .map(|def| def.path.clone())
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![dir.join("Dockerfile"), dir.join("worker.Dockerfile")]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_discover_helm_tasks_needs_helm() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(
+            dir.join("Chart.yaml"),
+            "apiVersion: v2\nname: web\nversion: 0.1.0\n",
+        )
+        .unwrap();
+        let helm_tasks = |discovered: &DiscoveredTasks| -> Vec<String> {
+            discovered
+                .tasks
+                .iter()
+                .filter(|t| t.runner == TaskRunner::Helm)
+                .map(|t| TaskRunner::Helm.get_command(t))
+                .collect()
+        };
+
+        set_test_environment(TestEnvironment::new());
+        let without_helm = discover_tasks(dir);
+        set_test_environment(TestEnvironment::new().with_executable("helm"));
+        let with_helm = discover_tasks(dir);
+        reset_to_real_environment();
+
+        assert!(helm_tasks(&without_helm).is_empty());
+        assert_eq!(
+            helm_tasks(&with_helm),
+            vec![
+                "helm install web .",
+                "helm upgrade web .",
+                "helm template web .",
+                "helm lint .",
+                "helm package .",
+            ]
+        );
+    }
 }
@@ -0,0 +1,45 @@
+use crate::parsers::parse_ant;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct AntDiscovery;
+
+impl TaskDiscovery for AntDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Ant]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_ant_tasks(dir, discovered);
+    }
+}
+
+fn discover_ant_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let ant_path = dir.join("build.xml");
+    if !ant_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: ant_path,
+                definition_type: TaskDefinitionType::AntBuildXml,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_ant::parse(&ant_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, ant_path, TaskDefinitionType::AntBuildXml, discovered);
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(error, ant_path, TaskDefinitionType::AntBuildXml, discovered);
+            Err(anyhow::anyhow!("Error parsing build.xml"))
+        }
+    }
+}
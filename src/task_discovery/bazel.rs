@@ -0,0 +1,58 @@
+use crate::parsers::parse_bazel;
+use crate::runner::is_runner_available;
+use crate::task_discovery::support::{handle_discovery_error, handle_discovery_success};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionType, TaskRunner};
+use std::path::Path;
+
+/// Files that mark a Bazel workspace root, in lookup order.
+const BAZEL_MARKERS: [&str; 5] = [
+    "MODULE.bazel",
+    "WORKSPACE.bazel",
+    "WORKSPACE",
+    "BUILD.bazel",
+    "BUILD",
+];
+
+pub(crate) struct BazelDiscovery;
+
+impl TaskDiscovery for BazelDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Bazel]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        discover_bazel_tasks(dir, discovered);
+    }
+}
+
+fn discover_bazel_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
+    let Some(marker_path) = BAZEL_MARKERS
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+    else {
+        return;
+    };
+
+    // Targets come from `bazel query`, which is slow and needs bazel, so without it
+    // there is nothing to list.
+    if !is_runner_available(&TaskRunner::Bazel) {
+        return;
+    }
+
+    match parse_bazel::parse(&marker_path) {
+        Ok(tasks) => handle_discovery_success(
+            tasks,
+            marker_path,
+            TaskDefinitionType::BazelWorkspace,
+            discovered,
+        ),
+        Err(error) => handle_discovery_error(
+            error,
+            marker_path,
+            TaskDefinitionType::BazelWorkspace,
+            discovered,
+        ),
+    }
+}
@@ -0,0 +1,325 @@
+use crate::config::active_dela_config_dir;
+use crate::parsers::parse_sidecar::sidecar_path;
+use crate::task_cache::Fnv64;
+use crate::task_discovery::support::apply_shadowing;
+use crate::task_discovery::{
+    DisabledRunners, DiscoveredTasks, TaskDiscovery, process_task_disambiguation, registry,
+};
+use crate::types::{Task, TaskDefinitionFile};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What one discoverer found, with the files its results were read from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedDiscovery {
+    /// Each file the results depend on, with its modification time, or `None` if it was missing
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    definitions: Vec<TaskDefinitionFile>,
+    /// Tasks as parsed, before shadowing and disambiguation
+    tasks: Vec<Task>,
+    errors: Vec<String>,
+}
+
+/// The cached discovery of one directory, one entry per registered discoverer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DiscoveryCache {
+    /// The dela version that wrote the cache, since parsers change between releases
+    version: String,
+    dir: PathBuf,
+    /// Modification time of `dir`, which changes whenever an entry is added or removed
+    dir_modified: Option<SystemTime>,
+    discoveries: Vec<CachedDiscovery>,
+}
+
+impl CachedDiscovery {
+    fn run(discoverer: &dyn TaskDiscovery, dir: &Path) -> Self {
+        let mut found = DiscoveredTasks::default();
+        discoverer.discover(dir, &mut found);
+        for task in &mut found.tasks {
+            task.shadowed_by = None;
+        }
+
+        let definitions: Vec<TaskDefinitionFile> = found
+            .definitions
+            .iter()
+            .flat_map(|(_, files)| files.iter().cloned())
+            .collect();
+        let mut paths: Vec<PathBuf> =
+            definitions
+                .iter()
+                .map(|definition| definition.path.clone())
+                .chain(found.tasks.iter().flat_map(|task| {
+                    [task.file_path.clone(), task.definition_path().to_path_buf()]
+                }))
+                .collect();
+        // A sidecar that appears later changes the tasks of the file it annotates
+        let sidecars: Vec<PathBuf> = paths.iter().map(|path| sidecar_path(path)).collect();
+        paths.extend(sidecars);
+        paths.sort();
+        paths.dedup();
+
+        CachedDiscovery {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let modified = modified(&path);
+                    (path, modified)
+                })
+                .collect(),
+            definitions,
+            tasks: found.tasks,
+            errors: found.errors,
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        self.files
+            .iter()
+            .all(|(path, recorded)| modified(path) == *recorded)
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn cache_dir() -> anyhow::Result<PathBuf> {
+    Ok(active_dela_config_dir()?.join("cache"))
+}
+
+/// The file the discovery of `dir` is cached in, named by a hash of the directory.
+fn cache_path(dir: &Path) -> anyhow::Result<PathBuf> {
+    let mut hasher = Fnv64::default();
+    hasher.write(dir.to_string_lossy().as_bytes());
+    Ok(cache_dir()?.join(format!("{:016x}.json", hasher.finish())))
+}
+
+/// Discover tasks in `dir` like [`discover_tasks`](super::discover_tasks), reusing what an
+/// earlier call cached for the same directory.
+///
+/// A discoverer runs again only when a file it read has a different modification time, and
+/// adding or removing an entry of `dir` discards the whole cache. Shadowing depends on PATH
+/// rather than on files, so it is always checked again. A cache that cannot be read or
+/// written is ignored.
+pub fn discover_tasks_cached(dir: &Path) -> DiscoveredTasks {
+    match cache_path(dir) {
+        Ok(path) => discover_tasks_cached_at(dir, &path),
+        Err(_) => super::discover_tasks(dir),
+    }
+}
+
+fn discover_tasks_cached_at(dir: &Path, cache_file: &Path) -> DiscoveredTasks {
+    let dir_modified = modified(dir);
+    let discoverers = registry::registered_discoveries();
+    let mut previous: Vec<Option<CachedDiscovery>> = std::fs::read_to_string(cache_file)
+        .ok()
+        .and_then(|content| serde_json::from_str::<DiscoveryCache>(&content).ok())
+        .filter(|cache| {
+            cache.version == env!("CARGO_PKG_VERSION")
+                && cache.dir == dir
+                && cache.dir_modified == dir_modified
+                && cache.discoveries.len() == discoverers.len()
+        })
+        .map(|cache| cache.discoveries.into_iter().map(Some).collect())
+        .unwrap_or_default();
+
+    let mut changed = previous.is_empty();
+    let mut discovered = DiscoveredTasks::default();
+    let disabled = DisabledRunners::for_dir(dir, &mut discovered);
+    let mut discoveries = Vec::with_capacity(discoverers.len());
+    for (index, discoverer) in discoverers.into_iter().enumerate() {
+        let discovery = match previous
+            .get_mut(index)
+            .and_then(Option::take)
+            .filter(CachedDiscovery::is_fresh)
+        {
+            Some(discovery) => discovery,
+            None => {
+                changed = true;
+                CachedDiscovery::run(discoverer, dir)
+            }
+        };
+
+        // Disabled runners stay cached, so turning them back on needs no re-parse
+        if disabled.skips(discoverer) {
+            discoveries.push(discovery);
+            continue;
+        }
+        let mut tasks = discovery.tasks.clone();
+        disabled.remove_tasks(&mut tasks);
+        apply_shadowing(&mut tasks);
+        discovered.tasks.extend(tasks);
+        for definition in &discovery.definitions {
+            discovered.definitions.insert(definition.clone());
+        }
+        discovered.errors.extend(discovery.errors.iter().cloned());
+        discoveries.push(discovery);
+    }
+
+    if changed {
+        let cache = DiscoveryCache {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            dir: dir.to_path_buf(),
+            dir_modified,
+            discoveries,
+        };
+        let _ = save(cache_file, &cache);
+    }
+
+    process_task_disambiguation(&mut discovered);
+    discovered
+}
+
+fn save(cache_file: &Path, cache: &DiscoveryCache) -> anyhow::Result<()> {
+    if let Some(parent) = cache_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(cache_file, serde_json::to_string(cache)?)?;
+    Ok(())
+}
+
+/// Cached discoveries that can no longer be read, such as a file cut short by a crash.
+pub fn unreadable_discovery_cache_files() -> anyhow::Result<Vec<PathBuf>> {
+    Ok(unreadable_cache_files(&cache_dir()?))
+}
+
+fn unreadable_cache_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut unreadable: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .filter(|path| {
+            std::fs::read_to_string(path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<DiscoveryCache>(&content).ok())
+                .is_none()
+        })
+        .collect();
+    unreadable.sort();
+    unreadable
+}
+
+/// Delete every cached discovery, returning how many directories had one.
+pub fn clear_discovery_cache() -> anyhow::Result<usize> {
+    clear_cache_dir(&cache_dir()?)
+}
+
+fn clear_cache_dir(dir: &Path) -> anyhow::Result<usize> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(0);
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            std::fs::remove_file(&path)
+                .map_err(|e| anyhow::anyhow!("Failed to remove {}: {}", path.display(), e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn task_names(discovered: &DiscoveredTasks) -> Vec<&str> {
+        let mut names: Vec<&str> = discovered.tasks.iter().map(|t| t.name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_cached_discovery_reparses_changed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let cache_file = temp_dir.path().join("cache").join("project.json");
+        std::fs::create_dir(&project).unwrap();
+        let makefile = project.join("Makefile");
+        std::fs::write(&makefile, "build:\n\techo build\n").unwrap();
+
+        let first = discover_tasks_cached_at(&project, &cache_file);
+        assert_eq!(task_names(&first), vec!["build"]);
+        assert!(cache_file.exists());
+
+        // Rename the cached task to tell a cache hit from a fresh parse
+        let mut cache: DiscoveryCache =
+            serde_json::from_str(&std::fs::read_to_string(&cache_file).unwrap()).unwrap();
+        for discovery in &mut cache.discoveries {
+            for task in &mut discovery.tasks {
+                task.name = "cached".to_string();
+            }
+        }
+        save(&cache_file, &cache).unwrap();
+        let cached = discover_tasks_cached_at(&project, &cache_file);
+        assert_eq!(task_names(&cached), vec!["cached"]);
+
+        // Editing the Makefile re-parses it
+        std::fs::write(&makefile, "build:\n\techo build\n\ntest:\n\techo test\n").unwrap();
+        let file = std::fs::File::options()
+            .write(true)
+            .open(&makefile)
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        let edited = discover_tasks_cached_at(&project, &cache_file);
+        assert_eq!(task_names(&edited), vec!["build", "test"]);
+
+        // A new definition file is found even though nothing cached changed
+        std::fs::write(
+            project.join("package.json"),
+            r#"{"scripts": {"lint": "eslint ."}}"#,
+        )
+        .unwrap();
+        let added = discover_tasks_cached_at(&project, &cache_file);
+        assert_eq!(task_names(&added), vec!["build", "lint", "test"]);
+    }
+
+    #[test]
+    fn test_clear_cache_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(
+            clear_cache_dir(&temp_dir.path().join("missing")).unwrap(),
+            0
+        );
+
+        std::fs::write(temp_dir.path().join("a.json"), "{}").unwrap();
+        std::fs::write(temp_dir.path().join("b.json"), "{}").unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "keep").unwrap();
+        assert_eq!(clear_cache_dir(temp_dir.path()).unwrap(), 2);
+        assert!(temp_dir.path().join("notes.txt").exists());
+    }
+
+    #[test]
+    fn test_unreadable_cache_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        let cache = temp_dir.path().join("cache");
+        std::fs::create_dir(&project).unwrap();
+        std::fs::write(project.join("Makefile"), "build:\n\techo build\n").unwrap();
+        discover_tasks_cached_at(&project, &cache.join("project.json"));
+        assert!(unreadable_cache_files(&cache).is_empty());
+
+        std::fs::write(cache.join("truncated.json"), "{\"version\": \"0.0").unwrap();
+        std::fs::write(cache.join("notes.txt"), "not a cache").unwrap();
+        assert_eq!(
+            unreadable_cache_files(&cache),
+            vec![cache.join("truncated.json")]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::types::Task;
+use std::collections::HashSet;
+
+/// A dependency cycle, as the tasks along it with the first task repeated at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyCycle {
+    pub path: Vec<String>,
+}
+
+impl std::fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path.join(" → "))
+    }
+}
+
+fn node_label(task: &Task) -> String {
+    format!("{} ({})", task.name, task.runner.short_name())
+}
+
+/// Indices of the tasks `task` depends on.
+fn resolve_dependencies(tasks: &[Task], task: &Task) -> Vec<usize> {
+    task.dependencies
+        .iter()
+        .flat_map(|dependency| resolve_dependency(tasks, task, dependency))
+        .collect()
+}
+
+/// Indices of the tasks named `dependency` that `task` runs first.
+///
+/// A dependency resolves to the task of that name in the same definition file when there
+/// is one, since that is what the runner itself runs, and otherwise to every task of that
+/// name from another runner. Dependencies that are not tasks, such as files a Makefile
+/// target is built from, resolve to nothing.
+fn resolve_dependency(tasks: &[Task], task: &Task, dependency: &str) -> Vec<usize> {
+    let same_file = tasks.iter().position(|candidate| {
+        candidate.name == dependency && candidate.definition_path() == task.definition_path()
+    });
+    match same_file {
+        Some(index) => vec![index],
+        None => tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.name == dependency)
+            .map(|(index, _)| index)
+            .collect(),
+    }
+}
+
+/// Find every cycle in the graph formed by the tasks' `dependencies`.
+pub fn find_dependency_cycles(tasks: &[Task]) -> Vec<DependencyCycle> {
+    let edges: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| resolve_dependencies(tasks, task))
+        .collect();
+
+    let mut cycles = Vec::new();
+    let mut seen_cycles: HashSet<Vec<usize>> = HashSet::new();
+    let mut finished = vec![false; tasks.len()];
+    let mut stack = Vec::new();
+
+    for start in 0..tasks.len() {
+        if !finished[start] {
+            visit(
+                start,
+                &edges,
+                &mut stack,
+                &mut finished,
+                &mut |cycle: &[usize]| {
+                    // The same cycle is reached once per entry point, so compare by rotation
+                    let mut key = cycle.to_vec();
+                    let min = key.iter().enumerate().min_by_key(|(_, n)| **n).unwrap().0;
+                    key.rotate_left(min);
+                    if seen_cycles.insert(key) {
+                        let mut path: Vec<String> =
+                            cycle.iter().map(|&n| node_label(&tasks[n])).collect();
+                        path.push(node_label(&tasks[cycle[0]]));
+                        cycles.push(DependencyCycle { path });
+                    }
+                },
+            );
+        }
+    }
+
+    cycles
+}
+
+fn visit(
+    node: usize,
+    edges: &[Vec<usize>],
+    stack: &mut Vec<usize>,
+    finished: &mut [bool],
+    report: &mut dyn FnMut(&[usize]),
+) {
+    stack.push(node);
+    for &next in &edges[node] {
+        if let Some(position) = stack.iter().position(|&n| n == next) {
+            report(&stack[position..]);
+        } else if !finished[next] {
+            visit(next, edges, stack, finished, report);
+        }
+    }
+    stack.pop();
+    finished[node] = true;
+}
+
+/// The tasks no other task depends on, followed by any task only reachable through a cycle,
+/// so that trees grown from them cover the whole graph.
+pub fn dependency_roots(tasks: &[Task]) -> Vec<usize> {
+    let edges: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| resolve_dependencies(tasks, task))
+        .collect();
+    let mut is_dependency = vec![false; tasks.len()];
+    for &next in edges.iter().flatten() {
+        is_dependency[next] = true;
+    }
+
+    let mut roots: Vec<usize> = (0..tasks.len()).filter(|&n| !is_dependency[n]).collect();
+    let mut reached = vec![false; tasks.len()];
+    let mut pending = roots.clone();
+    while let Some(node) = pending.pop() {
+        if !std::mem::replace(&mut reached[node], true) {
+            pending.extend(&edges[node]);
+        }
+    }
+    for node in 0..tasks.len() {
+        if !reached[node] {
+            roots.push(node);
+            let mut pending = vec![node];
+            while let Some(node) = pending.pop() {
+                if !std::mem::replace(&mut reached[node], true) {
+                    pending.extend(&edges[node]);
+                }
+            }
+        }
+    }
+    roots
+}
+
+/// An indented tree of what `root` depends on, one line per task.
+///
+/// A dependency that leads back to a task already on the way down is marked with `↺`
+/// instead of being followed. Dependencies that are not tasks are listed by name.
+pub fn dependency_tree(tasks: &[Task], root: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut path = vec![root];
+    lines.push(node_label(&tasks[root]));
+    tree_lines(tasks, &mut path, &mut lines);
+    lines
+}
+
+fn tree_lines(tasks: &[Task], path: &mut Vec<usize>, lines: &mut Vec<String>) {
+    let task = &tasks[*path.last().expect("path starts at the root")];
+    let indent = "  ".repeat(path.len());
+    for dependency in &task.dependencies {
+        let resolved = resolve_dependency(tasks, task, dependency);
+        if resolved.is_empty() {
+            lines.push(format!("{}{}", indent, dependency));
+        }
+        for next in resolved {
+            if path.contains(&next) {
+                lines.push(format!("{}{} ↺", indent, node_label(&tasks[next])));
+                continue;
+            }
+            lines.push(format!("{}{}", indent, node_label(&tasks[next])));
+            path.push(next);
+            tree_lines(tasks, path, lines);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TaskDefinitionType, TaskRunner};
+    use std::path::PathBuf;
+
+    fn task(name: &str, runner: TaskRunner, file: &str, dependencies: &[&str]) -> Task {
+        Task {
+            name: name.to_string(),
+            file_path: PathBuf::from(file),
+            definition_path: None,
+            definition_type: match runner {
+                TaskRunner::Make => TaskDefinitionType::Makefile,
+                _ => TaskDefinitionType::Taskfile,
+            },
+            runner,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        }
+    }
+
+    #[test]
+    fn test_detects_cross_runner_cycle() {
+        let tasks = vec![
+            task("a", TaskRunner::Make, "Makefile", &["b"]),
+            task("b", TaskRunner::Task, "Taskfile.yml", &["a"]),
+        ];
+
+        let cycles = find_dependency_cycles(&tasks);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].to_string(), "a (make) → b (task) → a (make)");
+    }
+
+    #[test]
+    fn test_acyclic_and_self_dependencies() {
+        let tasks = vec![
+            task(
+                "all",
+                TaskRunner::Make,
+                "Makefile",
+                &["build", "test", "main.o"],
+            ),
+            task("build", TaskRunner::Make, "Makefile", &[]),
+            task("test", TaskRunner::Make, "Makefile", &["build"]),
+        ];
+        assert!(find_dependency_cycles(&tasks).is_empty());
+
+        let tasks = vec![task("loop", TaskRunner::Make, "Makefile", &["loop"])];
+        let cycles = find_dependency_cycles(&tasks);
+        assert_eq!(cycles[0].to_string(), "loop (make) → loop (make)");
+    }
+
+    #[test]
+    fn test_same_file_dependency_wins_over_other_runners() {
+        let tasks = vec![
+            task("a", TaskRunner::Make, "Makefile", &["b"]),
+            task("b", TaskRunner::Make, "Makefile", &[]),
+            task("b", TaskRunner::Task, "Taskfile.yml", &["a"]),
+        ];
+        assert!(find_dependency_cycles(&tasks).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_tree() {
+        let tasks = vec![
+            task("all", TaskRunner::Make, "Makefile", &["build", "test"]),
+            task("build", TaskRunner::Make, "Makefile", &["main.o"]),
+            task("test", TaskRunner::Make, "Makefile", &["build"]),
+            task("lint", TaskRunner::Task, "Taskfile.yml", &[]),
+        ];
+
+        assert_eq!(
+            dependency_tree(&tasks, 0),
+            vec![
+                "all (make)",
+                "  build (make)",
+                "    main.o",
+                "  test (make)",
+                "    build (make)",
+                "      main.o",
+            ]
+        );
+        assert_eq!(dependency_roots(&tasks), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_dependency_tree_stops_at_cycles() {
+        let tasks = vec![
+            task("a", TaskRunner::Make, "Makefile", &["b"]),
+            task("b", TaskRunner::Make, "Makefile", &["a"]),
+        ];
+
+        assert_eq!(
+            dependency_tree(&tasks, 0),
+            vec!["a (make)", "  b (make)", "    a (make) ↺"]
+        );
+        // Neither task is a root, so the cycle is entered at its first task
+        assert_eq!(dependency_roots(&tasks), vec![0]);
+    }
+}
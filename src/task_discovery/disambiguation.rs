@@ -1,10 +1,14 @@
+use crate::config::load_config;
 use crate::task_discovery::DiscoveredTasks;
 use crate::types::{Task, TaskRunner};
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 const MIN_PREFIX_LEN: usize = 3;
 
 pub fn process_task_disambiguation(discovered: &mut DiscoveredTasks) {
+    merge_identical_tasks(&mut discovered.tasks);
+
     let mut task_name_counts: HashMap<String, usize> = HashMap::new();
     let mut tasks_by_name: HashMap<String, Vec<usize>> = HashMap::new();
 
@@ -26,13 +30,15 @@ pub fn process_task_disambiguation(discovered: &mut DiscoveredTasks) {
         let task_indices = tasks_by_name
             .get(name)
             .expect("task collision indexes should exist");
-        let mut used_prefixes = HashSet::new();
+        // Tasks from different directories already differ by their directory suffix.
+        let mut used_prefixes: HashMap<Option<PathBuf>, HashSet<String>> = HashMap::new();
 
         for &index in task_indices {
             let task = &mut discovered.tasks[index];
-            let runner_prefix = generate_runner_prefix(&task.runner, &used_prefixes);
-            used_prefixes.insert(runner_prefix.clone());
-            task.disambiguated_name = Some(format!("{}-{}", task.name, runner_prefix));
+            let used = used_prefixes.entry(task.workspace_dir.clone()).or_default();
+            let runner_prefix = generate_runner_prefix(&task.runner, used);
+            used.insert(runner_prefix.clone());
+            task.disambiguated_name = Some(suffixed_name(task, &runner_prefix));
         }
     }
 
@@ -43,11 +49,51 @@ pub fn process_task_disambiguation(discovered: &mut DiscoveredTasks) {
 
         if task.shadowed_by.is_some() {
             let runner_prefix = generate_runner_prefix(&task.runner, &HashSet::new());
-            task.disambiguated_name = Some(format!("{}-{}", task.name, runner_prefix));
+            task.disambiguated_name = Some(suffixed_name(task, &runner_prefix));
         }
     }
 }
 
+/// Keep only the first of tasks that are the same task found more than once, as when
+/// one definition file is reached from several discovery roots, so they are not
+/// reported as ambiguous with each other.
+fn merge_identical_tasks(tasks: &mut Vec<Task>) {
+    let mut seen: HashSet<(PathBuf, String, TaskRunner)> = HashSet::new();
+    tasks.retain(|task| {
+        seen.insert((
+            task.file_path.clone(),
+            task.name.clone(),
+            task.runner.clone(),
+        ))
+    });
+}
+
+/// `name-prefix`, or `name-dir-prefix` for a task found in a subdirectory by recursive
+/// discovery, with the directory's path separators replaced by dashes.
+fn suffixed_name(task: &Task, runner_prefix: &str) -> String {
+    match &task.workspace_dir {
+        Some(dir) => {
+            let dir = dir
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("-");
+            format!("{}-{}-{}", task.name, dir, runner_prefix)
+        }
+        None => format!("{}-{}", task.name, runner_prefix),
+    }
+}
+
+/// Forget builtin and PATH shadowing, keeping only the suffixes needed to tell apart
+/// tasks that share a name across runners.
+pub fn ignore_shadowing(discovered: &mut DiscoveredTasks) {
+    for task in &mut discovered.tasks {
+        task.shadowed_by = None;
+        task.disambiguated_name = None;
+    }
+    process_task_disambiguation(discovered);
+}
+
 fn generate_runner_prefix(runner: &TaskRunner, used_prefixes: &HashSet<String>) -> String {
     let short_name = runner.short_name().to_lowercase();
     generate_prefix_from_short_name(&short_name, used_prefixes)
@@ -118,6 +164,54 @@ pub fn get_matching_tasks<'a>(discovered: &'a DiscoveredTasks, task_name: &str)
         .collect()
 }
 
+/// Narrow an ambiguous match down to the task of the runner ranked highest by
+/// `[preferences] runner_priority` in config.toml, leaving it unchanged when no listed
+/// runner decides between the tasks.
+pub fn apply_runner_priority(matching_tasks: &mut Vec<&Task>) {
+    if matching_tasks.len() < 2 {
+        return;
+    }
+    let priority = load_config()
+        .map(|config| config.preferences.runner_priority)
+        .unwrap_or_default();
+    if let Some(task) = preferred_task(matching_tasks, &priority) {
+        *matching_tasks = vec![task];
+    }
+}
+
+/// The one task run by the first runner in `priority` that any of `matching_tasks` uses.
+/// Two tasks from that runner are still ambiguous.
+fn preferred_task<'a>(matching_tasks: &[&'a Task], priority: &[String]) -> Option<&'a Task> {
+    let runner = priority.iter().find(|runner| {
+        matching_tasks
+            .iter()
+            .any(|task| task.runner.matches_filter(runner))
+    })?;
+    let mut candidates = matching_tasks
+        .iter()
+        .filter(|task| task.runner.matches_filter(runner));
+    match (candidates.next(), candidates.next()) {
+        (Some(task), None) => Some(*task),
+        _ => None,
+    }
+}
+
+/// Like [`get_matching_tasks`], but a name that matches no task may also put a runner
+/// in front, as in `uv:serve` for the `serve` task of uv. Aliases name tasks this way.
+pub fn get_matching_tasks_qualified<'a>(
+    discovered: &'a DiscoveredTasks,
+    task_name: &str,
+) -> Vec<&'a Task> {
+    let matching_tasks = get_matching_tasks(discovered, task_name);
+    match task_name.split_once(':') {
+        Some((runner, name)) if matching_tasks.is_empty() => get_matching_tasks(discovered, name)
+            .into_iter()
+            .filter(|task| task.runner.matches_filter(runner))
+            .collect(),
+        _ => matching_tasks,
+    }
+}
+
 pub fn format_ambiguous_task_error(task_name: &str, matching_tasks: &[&Task]) -> String {
     let mut message = format!("Multiple tasks named '{}' found. Use one of:\n", task_name);
 
@@ -135,9 +229,63 @@ pub fn format_ambiguous_task_error(task_name: &str, matching_tasks: &[&Task]) ->
     message
 }
 
+/// The error for a task name that matches nothing, followed by up to three task names
+/// within two edits of it.
+pub fn format_task_not_found_error(discovered: &DiscoveredTasks, task_name: &str) -> String {
+    let message = format!("dela: command or task not found: {}", task_name);
+    let suggestions = suggest_task_names(discovered, task_name);
+    if suggestions.is_empty() {
+        message
+    } else {
+        format!("{}\ndid you mean: {}?", message, suggestions.join(", "))
+    }
+}
+
+fn suggest_task_names(discovered: &DiscoveredTasks, task_name: &str) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+    const MAX_DISTANCE: usize = 2;
+
+    // A distance as long as the name itself would match any short task
+    let max_distance = MAX_DISTANCE.min(task_name.chars().count().saturating_sub(1));
+    let mut candidates: Vec<(usize, &str)> = discovered
+        .tasks
+        .iter()
+        .filter(|task| !task.hidden)
+        .flat_map(|task| std::iter::once(&task.name).chain(task.disambiguated_name.as_ref()))
+        .map(|name| (edit_distance(task_name, name), name.as_str()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    candidates.sort();
+    candidates.dedup_by(|a, b| a.1 == b.1);
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{format_ambiguous_task_error, generate_prefix_from_short_name};
+    use super::{
+        format_ambiguous_task_error, format_task_not_found_error, generate_prefix_from_short_name,
+        preferred_task, process_task_disambiguation,
+    };
+    use crate::task_discovery::DiscoveredTasks;
     use crate::types::{Task, TaskDefinitionType, TaskRunner};
     use std::collections::HashSet;
     use std::path::PathBuf;
@@ -164,6 +312,17 @@ mod tests {
             description: None,
             shadowed_by: None,
             disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
         let npm_task = Task {
             name: "test".to_string(),
@@ -175,6 +334,17 @@ mod tests {
             description: None,
             shadowed_by: None,
             disambiguated_name: Some("test-npm".to_string()),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
         };
 
         let error = format_ambiguous_task_error("test", &[&make_task, &npm_task]);
@@ -190,4 +360,148 @@ mod tests {
                 .contains("  • test-npm (npm from /tmp/package.json)")
         );
     }
+
+    #[test]
+    fn identical_tasks_from_several_roots_are_merged() {
+        let make_task = |name: &str, file: &str| Task {
+            name: name.to_string(),
+            file_path: PathBuf::from(file),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let mut discovered = DiscoveredTasks {
+            tasks: vec![
+                make_task("build", "/project/Makefile"),
+                make_task("build", "/project/Makefile"),
+                make_task("test", "/project/Makefile"),
+                make_task("test", "/project/api/Makefile"),
+            ],
+            ..Default::default()
+        };
+
+        process_task_disambiguation(&mut discovered);
+
+        assert_eq!(discovered.tasks.len(), 3);
+        assert_eq!(discovered.task_name_counts["build"], 1);
+        assert!(discovered.tasks[0].disambiguated_name.is_none());
+        // Same name from different files is still ambiguous
+        assert_eq!(discovered.task_name_counts["test"], 2);
+        assert!(discovered.tasks[1].disambiguated_name.is_some());
+    }
+
+    #[test]
+    fn task_not_found_error_suggests_close_names() {
+        let task = |name: &str, disambiguated_name: Option<&str>, hidden: bool| Task {
+            name: name.to_string(),
+            file_path: PathBuf::from("/tmp/Makefile"),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner: TaskRunner::Make,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: disambiguated_name.map(str::to_string),
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let mut discovered = DiscoveredTasks::new();
+        discovered.tasks = vec![
+            task("build", None, false),
+            task("guild", None, false),
+            task("rebuild", None, false),
+            task("built", None, true),
+            task("test", Some("test-m"), false),
+            task("deploy", None, false),
+        ];
+
+        assert_eq!(
+            format_task_not_found_error(&discovered, "buld"),
+            "dela: command or task not found: buld\ndid you mean: build, guild?"
+        );
+        // Disambiguated names are suggested too
+        assert_eq!(
+            format_task_not_found_error(&discovered, "test-n"),
+            "dela: command or task not found: test-n\ndid you mean: test-m, test?"
+        );
+        assert_eq!(
+            format_task_not_found_error(&discovered, "xyz"),
+            "dela: command or task not found: xyz"
+        );
+        // A one-letter name is not close to every short task
+        assert_eq!(
+            format_task_not_found_error(&discovered, "b"),
+            "dela: command or task not found: b"
+        );
+    }
+
+    #[test]
+    fn preferred_task_follows_runner_priority() {
+        let task = |runner: TaskRunner, file: &str| Task {
+            name: "test".to_string(),
+            file_path: PathBuf::from(file),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner,
+            source_name: "test".to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let make = task(TaskRunner::Make, "/tmp/Makefile");
+        let npm = task(TaskRunner::NodeNpm, "/tmp/package.json");
+        let matching = [&npm, &make];
+        let priority =
+            |names: &[&str]| -> Vec<String> { names.iter().map(|name| name.to_string()).collect() };
+
+        let chosen = preferred_task(&matching, &priority(&["make", "task", "npm"])).unwrap();
+        assert_eq!(chosen.runner, TaskRunner::Make);
+        // Runners missing from the match are skipped, and aliases are accepted
+        let chosen = preferred_task(&matching, &priority(&["task", "node", "make"])).unwrap();
+        assert_eq!(chosen.runner, TaskRunner::NodeNpm);
+
+        // No listed runner, or an empty list, keeps the name ambiguous
+        assert!(preferred_task(&matching, &priority(&["task", "just"])).is_none());
+        assert!(preferred_task(&matching, &[]).is_none());
+
+        // Two tasks of the preferred runner cannot be told apart either
+        let other_make = task(TaskRunner::Make, "/tmp/sub/Makefile");
+        let matching = [&make, &other_make, &npm];
+        assert!(preferred_task(&matching, &priority(&["make", "npm"])).is_none());
+    }
 }
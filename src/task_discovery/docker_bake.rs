@@ -0,0 +1,62 @@
+use crate::parsers::parse_docker_bake;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+/// Bake files that `docker buildx bake` reads by default, merging them when both exist.
+const BAKE_FILE_NAMES: [&str; 2] = ["docker-bake.json", "docker-bake.hcl"];
+
+pub(crate) struct DockerBakeDiscovery;
+
+impl TaskDiscovery for DockerBakeDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::DockerBake]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        for file_name in BAKE_FILE_NAMES {
+            let _ = discover_docker_bake_tasks(&dir.join(file_name), discovered);
+        }
+    }
+}
+
+fn discover_docker_bake_tasks(
+    bake_path: &Path,
+    discovered: &mut DiscoveredTasks,
+) -> anyhow::Result<()> {
+    if !bake_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: bake_path.to_path_buf(),
+                definition_type: TaskDefinitionType::DockerBake,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_docker_bake::parse(bake_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                bake_path.to_path_buf(),
+                TaskDefinitionType::DockerBake,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                bake_path.to_path_buf(),
+                TaskDefinitionType::DockerBake,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing {}", bake_path.display()))
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::parsers::parse_dockerfile;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::{Path, PathBuf};
+
+pub(crate) struct DockerfileDiscovery;
+
+impl TaskDiscovery for DockerfileDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Docker]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let dockerfiles = dockerfiles(dir);
+        if dockerfiles.is_empty() {
+            set_definition(
+                discovered,
+                TaskDefinitionFile {
+                    path: dir.join("Dockerfile"),
+                    definition_type: TaskDefinitionType::Dockerfile,
+                    status: TaskFileStatus::NotFound,
+                },
+            );
+        }
+        for dockerfile in dockerfiles {
+            discover_dockerfile_tasks(dockerfile, discovered);
+        }
+    }
+}
+
+/// `Dockerfile` followed by each `*.Dockerfile` in `dir`, sorted by name.
+fn dockerfiles(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut named: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| {
+                        name.len() > ".Dockerfile".len() && name.ends_with(".Dockerfile")
+                    })
+        })
+        .collect();
+    named.sort();
+
+    let dockerfile = dir.join("Dockerfile");
+    if dockerfile.is_file() {
+        named.insert(0, dockerfile);
+    }
+    named
+}
+
+fn discover_dockerfile_tasks(dockerfile: PathBuf, discovered: &mut DiscoveredTasks) {
+    match parse_dockerfile::parse(&dockerfile) {
+        Ok(tasks) => handle_discovery_success(
+            tasks,
+            dockerfile,
+            TaskDefinitionType::Dockerfile,
+            discovered,
+        ),
+        Err(error) => handle_discovery_error(
+            error,
+            dockerfile,
+            TaskDefinitionType::Dockerfile,
+            discovered,
+        ),
+    }
+}
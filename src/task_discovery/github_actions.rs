@@ -2,13 +2,17 @@ use crate::composed_paths::ComposedDefinitionSource;
 use crate::parsers::parse_github_actions;
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
 use crate::task_shadowing::check_shadowing;
-use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 pub(crate) struct GithubActionsDiscovery;
 
 impl TaskDiscovery for GithubActionsDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Act]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_github_actions_tasks(dir, discovered);
     }
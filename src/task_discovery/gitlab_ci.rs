@@ -0,0 +1,55 @@
+use crate::parsers::parse_gitlab_ci;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct GitlabCiDiscovery;
+
+impl TaskDiscovery for GitlabCiDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::GitlabCi]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_gitlab_ci_tasks(dir, discovered);
+    }
+}
+
+fn discover_gitlab_ci_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let gitlab_ci_path = dir.join(".gitlab-ci.yml");
+    if !gitlab_ci_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: gitlab_ci_path,
+                definition_type: TaskDefinitionType::GitlabCi,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_gitlab_ci::parse(&gitlab_ci_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                gitlab_ci_path,
+                TaskDefinitionType::GitlabCi,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                gitlab_ci_path,
+                TaskDefinitionType::GitlabCi,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing .gitlab-ci.yml"))
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::parsers::parse_helm_chart;
+use crate::runner::is_runner_available;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct HelmDiscovery;
+
+impl TaskDiscovery for HelmDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Helm]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        discover_helm_tasks(dir, discovered);
+    }
+}
+
+fn discover_helm_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
+    let chart_path = dir.join("Chart.yaml");
+    if !chart_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: chart_path,
+                definition_type: TaskDefinitionType::HelmChart,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return;
+    }
+
+    // The tasks are only shortcuts for helm commands, so without helm there is nothing
+    // to offer.
+    if !is_runner_available(&TaskRunner::Helm) {
+        return;
+    }
+
+    match parse_helm_chart::parse(&chart_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, chart_path, TaskDefinitionType::HelmChart, discovered)
+        }
+        Err(error) => {
+            handle_discovery_error(error, chart_path, TaskDefinitionType::HelmChart, discovered)
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::parsers::parse_invoke;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct InvokeDiscovery;
+
+impl TaskDiscovery for InvokeDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Invoke]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_invoke_tasks(dir, discovered);
+    }
+}
+
+fn discover_invoke_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let invoke_path = dir.join("tasks.py");
+    if !invoke_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: invoke_path,
+                definition_type: TaskDefinitionType::InvokeTasks,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_invoke::parse(&invoke_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                invoke_path,
+                TaskDefinitionType::InvokeTasks,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                invoke_path,
+                TaskDefinitionType::InvokeTasks,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing tasks.py"))
+        }
+    }
+}
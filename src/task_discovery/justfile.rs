@@ -1,12 +1,16 @@
 use crate::parsers::parse_justfile;
 use crate::task_discovery::support::{handle_discovery_error, handle_discovery_success};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::path::Path;
 
 pub(crate) struct JustfileDiscovery;
 
 impl TaskDiscovery for JustfileDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Just]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_justfile_tasks(dir, discovered);
     }
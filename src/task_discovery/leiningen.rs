@@ -0,0 +1,55 @@
+use crate::parsers::parse_leiningen;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct LeiningenDiscovery;
+
+impl TaskDiscovery for LeiningenDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Leiningen]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_leiningen_tasks(dir, discovered);
+    }
+}
+
+fn discover_leiningen_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let leiningen_path = dir.join("project.clj");
+    if !leiningen_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: leiningen_path,
+                definition_type: TaskDefinitionType::ProjectClj,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_leiningen::parse(&leiningen_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                leiningen_path,
+                TaskDefinitionType::ProjectClj,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                leiningen_path,
+                TaskDefinitionType::ProjectClj,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing project.clj"))
+        }
+    }
+}
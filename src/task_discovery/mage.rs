@@ -0,0 +1,45 @@
+use crate::parsers::parse_mage;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct MageDiscovery;
+
+impl TaskDiscovery for MageDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Mage]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_mage_tasks(dir, discovered);
+    }
+}
+
+fn discover_mage_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let mage_path = dir.join("magefile.go");
+    if !mage_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: mage_path,
+                definition_type: TaskDefinitionType::Magefile,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_mage::parse(&mage_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, mage_path, TaskDefinitionType::Magefile, discovered);
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(error, mage_path, TaskDefinitionType::Magefile, discovered);
+            Err(anyhow::anyhow!("Error parsing magefile.go"))
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::composed_paths::{ComposedDefinitionSource, RecursiveDiscoveryState, VisitState};
 use crate::parsers::parse_makefile;
-use crate::task_discovery::support::{apply_shadowing, set_definition};
+use crate::task_discovery::support::{apply_shadowing, apply_sidecars, set_definition};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
@@ -12,6 +12,10 @@ pub(crate) struct MakefileDiscovery;
 const MAKEFILE_NAMES: [&str; 3] = ["GNUmakefile", "makefile", "Makefile"];
 
 impl TaskDiscovery for MakefileDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Make]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         discover_makefile_tasks(dir, discovered);
     }
@@ -46,6 +50,7 @@ fn discover_makefile_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
     );
 
     apply_shadowing(&mut tasks);
+    apply_sidecars(&mut tasks, discovered);
     discovered.tasks.extend(tasks);
     discovered.errors.extend(include_errors);
 
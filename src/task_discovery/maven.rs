@@ -1,12 +1,16 @@
 use crate::parsers::parse_pom_xml;
 use crate::task_discovery::support::{handle_discovery_error, handle_discovery_success};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::TaskDefinitionType;
+use crate::types::{TaskDefinitionType, TaskRunner};
 use std::path::Path;
 
 pub(crate) struct MavenDiscovery;
 
 impl TaskDiscovery for MavenDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Maven]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_maven_tasks(dir, discovered);
     }
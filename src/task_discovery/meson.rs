@@ -0,0 +1,55 @@
+use crate::parsers::parse_meson;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct MesonDiscovery;
+
+impl TaskDiscovery for MesonDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Meson]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_meson_tasks(dir, discovered);
+    }
+}
+
+fn discover_meson_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let meson_path = dir.join("meson.build");
+    if !meson_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: meson_path,
+                definition_type: TaskDefinitionType::MesonBuild,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_meson::parse(&meson_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                meson_path,
+                TaskDefinitionType::MesonBuild,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                meson_path,
+                TaskDefinitionType::MesonBuild,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing meson.build"))
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::parsers::parse_mise;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+/// Config file names mise reads from a project directory, in lookup order.
+const MISE_CONFIG_FILES: [&str; 2] = ["mise.toml", ".mise.toml"];
+
+pub(crate) struct MiseDiscovery;
+
+impl TaskDiscovery for MiseDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Mise]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        discover_mise_tasks(dir, discovered);
+    }
+}
+
+fn discover_mise_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
+    let mut config_task_names = Vec::new();
+    match MISE_CONFIG_FILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+    {
+        Some(config_path) => match parse_mise::parse(&config_path) {
+            Ok(tasks) => {
+                config_task_names = tasks.iter().map(|task| task.name.clone()).collect();
+                handle_discovery_success(
+                    tasks,
+                    config_path,
+                    TaskDefinitionType::MiseToml,
+                    discovered,
+                );
+            }
+            Err(error) => {
+                handle_discovery_error(error, config_path, TaskDefinitionType::MiseToml, discovered)
+            }
+        },
+        None => set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: dir.join(MISE_CONFIG_FILES[0]),
+                definition_type: TaskDefinitionType::MiseToml,
+                status: TaskFileStatus::NotFound,
+            },
+        ),
+    }
+
+    let task_dir = dir.join(".mise").join("tasks");
+    if !task_dir.is_dir() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: task_dir,
+                definition_type: TaskDefinitionType::MiseTaskDir,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return;
+    }
+
+    match parse_mise::parse_task_dir(&task_dir) {
+        Ok(mut tasks) => {
+            // A task defined in the config file takes precedence over a file task of the same name
+            tasks.retain(|task| !config_task_names.contains(&task.name));
+            handle_discovery_success(tasks, task_dir, TaskDefinitionType::MiseTaskDir, discovered);
+        }
+        Err(error) => {
+            handle_discovery_error(error, task_dir, TaskDefinitionType::MiseTaskDir, discovered)
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::parsers::parse_noxfile;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct NoxDiscovery;
+
+impl TaskDiscovery for NoxDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Nox]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_nox_tasks(dir, discovered);
+    }
+}
+
+fn discover_nox_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let nox_path = dir.join("noxfile.py");
+    if !nox_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: nox_path,
+                definition_type: TaskDefinitionType::Noxfile,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_noxfile::parse(&nox_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, nox_path, TaskDefinitionType::Noxfile, discovered);
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(error, nox_path, TaskDefinitionType::Noxfile, discovered);
+            Err(anyhow::anyhow!("Error parsing noxfile.py"))
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use crate::parsers::parse_package_json;
 use crate::task_discovery::support::{handle_discovery_error, handle_discovery_success};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{
+    NODE_RUNNERS, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner,
+};
 use std::path::Path;
 
 pub(crate) struct NpmDiscovery;
 
 impl TaskDiscovery for NpmDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        NODE_RUNNERS
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_npm_tasks(dir, discovered);
     }
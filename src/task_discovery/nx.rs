@@ -0,0 +1,143 @@
+use crate::parsers::parse_nx::{parse_nx_json, parse_project_json};
+use crate::repo_root::find_git_repo_root;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::{Path, PathBuf};
+
+/// How many directory levels below the workspace root are searched for project.json,
+/// enough for layouts such as `apps/web` or `libs/shared/ui`.
+const PROJECT_SEARCH_DEPTH: usize = 3;
+
+/// Directories that never hold workspace projects and can be very large.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "dist", "build", "target", "tmp"];
+
+pub(crate) struct NxDiscovery;
+
+impl TaskDiscovery for NxDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Nx]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        discover_nx_tasks(dir, discovered);
+    }
+}
+
+fn discover_nx_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
+    // Targets of the project in this directory are more specific than the workspace
+    // defaults, so a default is only listed when the project does not define it.
+    let project_json = dir.join("project.json");
+    let mut project_targets = Vec::new();
+    if !project_json.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: project_json,
+                definition_type: TaskDefinitionType::NxProjectJson,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+    } else {
+        match parse_project_json(&project_json) {
+            Ok(tasks) => {
+                project_targets = tasks.iter().map(|task| task.name.clone()).collect();
+                handle_discovery_success(
+                    tasks,
+                    project_json,
+                    TaskDefinitionType::NxProjectJson,
+                    discovered,
+                );
+            }
+            Err(error) => handle_discovery_error(
+                error,
+                project_json,
+                TaskDefinitionType::NxProjectJson,
+                discovered,
+            ),
+        }
+    }
+
+    let repo_root = find_git_repo_root(dir).unwrap_or_else(|| dir.to_path_buf());
+    let nx_json = repo_root.join("nx.json");
+    if !nx_json.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: nx_json,
+                definition_type: TaskDefinitionType::NxJson,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return;
+    }
+
+    match parse_nx_json(&nx_json) {
+        Ok(mut tasks) => {
+            tasks.retain(|task| !project_targets.contains(&task.name));
+            handle_discovery_success(tasks, nx_json, TaskDefinitionType::NxJson, discovered);
+        }
+        Err(error) => {
+            handle_discovery_error(error, nx_json, TaskDefinitionType::NxJson, discovered);
+            return;
+        }
+    }
+
+    if repo_root == dir {
+        discover_workspace_projects(dir, discovered);
+    }
+}
+
+/// From the workspace root, list the targets of every project as `<project>:<target>`.
+fn discover_workspace_projects(root: &Path, discovered: &mut DiscoveredTasks) {
+    let mut project_files = Vec::new();
+    collect_project_files(root, PROJECT_SEARCH_DEPTH, &mut project_files);
+    project_files.sort();
+
+    for project_json in project_files {
+        match parse_project_json(&project_json) {
+            Ok(mut tasks) => {
+                for task in &mut tasks {
+                    task.name = task.source_name.clone();
+                }
+                handle_discovery_success(
+                    tasks,
+                    project_json,
+                    TaskDefinitionType::NxProjectJson,
+                    discovered,
+                );
+            }
+            Err(error) => handle_discovery_error(
+                error,
+                project_json,
+                TaskDefinitionType::NxProjectJson,
+                discovered,
+            ),
+        }
+    }
+}
+
+fn collect_project_files(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    if depth == 0 {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !path.is_dir() || name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref()) {
+            continue;
+        }
+        let project_json = path.join("project.json");
+        if project_json.is_file() {
+            found.push(project_json);
+        }
+        collect_project_files(&path, depth - 1, found);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::parsers::parse_pre_commit;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct PreCommitDiscovery;
+
+impl TaskDiscovery for PreCommitDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::PreCommit]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_pre_commit_tasks(dir, discovered);
+    }
+}
+
+fn discover_pre_commit_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let pre_commit_path = dir.join(".pre-commit-config.yaml");
+    if !pre_commit_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: pre_commit_path,
+                definition_type: TaskDefinitionType::PreCommitConfig,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_pre_commit::parse(&pre_commit_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                pre_commit_path,
+                TaskDefinitionType::PreCommitConfig,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                pre_commit_path,
+                TaskDefinitionType::PreCommitConfig,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing .pre-commit-config.yaml"))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::parsers::parse_procfile;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+/// `Procfile` for production processes and `Procfile.dev` for local development.
+const PROCFILE_NAMES: [&str; 2] = ["Procfile", "Procfile.dev"];
+
+pub(crate) struct ProcfileDiscovery;
+
+impl TaskDiscovery for ProcfileDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Procfile]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        for file_name in PROCFILE_NAMES {
+            let _ = discover_procfile_tasks(&dir.join(file_name), discovered);
+        }
+    }
+}
+
+fn discover_procfile_tasks(
+    procfile_path: &Path,
+    discovered: &mut DiscoveredTasks,
+) -> anyhow::Result<()> {
+    if !procfile_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: procfile_path.to_path_buf(),
+                definition_type: TaskDefinitionType::Procfile,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_procfile::parse(procfile_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                procfile_path.to_path_buf(),
+                TaskDefinitionType::Procfile,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                procfile_path.to_path_buf(),
+                TaskDefinitionType::Procfile,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing {}", procfile_path.display()))
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use crate::parsers::parse_pyproject_toml;
 use crate::task_discovery::support::{handle_discovery_error, handle_discovery_success};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{
+    PYTHON_RUNNERS, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner,
+};
 use std::path::Path;
 
 pub(crate) struct PythonDiscovery;
 
 impl TaskDiscovery for PythonDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        PYTHON_RUNNERS
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_python_tasks(dir, discovered);
     }
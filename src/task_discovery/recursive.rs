@@ -0,0 +1,227 @@
+use crate::glob::glob_match;
+use crate::task_discovery::{DiscoveredTasks, discover_tasks_in, process_task_disambiguation};
+use crate::types::TaskFileStatus;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Directories that are never searched, whether or not they are gitignored.
+pub(crate) const SKIPPED_DIRS: &[&str] = &["node_modules", "target", ".git", "vendor"];
+
+/// Discover tasks in `dir` and in its subdirectories up to `max_depth` levels below it.
+///
+/// Tasks found below `dir` record the subdirectory they came from in `workspace_dir`.
+/// Directories matched by a `.gitignore` are not searched.
+pub fn discover_tasks_recursive(dir: &Path, max_depth: usize) -> DiscoveredTasks {
+    let mut discovered = DiscoveredTasks::default();
+    discover_tasks_in(dir, &mut discovered);
+
+    let mut subdirs = Vec::new();
+    collect_subdirs(dir, 1, max_depth, &[], &mut subdirs);
+
+    for subdir in subdirs {
+        let mut nested = DiscoveredTasks::default();
+        discover_tasks_in(&subdir, &mut nested);
+        merge_nested(&mut discovered, nested, dir, &subdir);
+    }
+
+    process_task_disambiguation(&mut discovered);
+    discovered
+}
+
+/// Add the tasks and definitions found in `subdir` that are defined inside it, skipping
+/// files that an earlier directory already listed.
+fn merge_nested(
+    discovered: &mut DiscoveredTasks,
+    nested: DiscoveredTasks,
+    root: &Path,
+    subdir: &Path,
+) {
+    let workspace_dir = subdir.strip_prefix(root).unwrap_or(subdir).to_path_buf();
+    let known_files: HashSet<PathBuf> = discovered
+        .tasks
+        .iter()
+        .map(|task| task.definition_path().to_path_buf())
+        .collect();
+
+    for mut task in nested.tasks {
+        let definition_path = task.definition_path();
+        // Discoverers also look at the repository root, whose files are not this directory's.
+        if !definition_path.starts_with(subdir) || known_files.contains(definition_path) {
+            continue;
+        }
+        task.workspace_dir = Some(workspace_dir.clone());
+        discovered.tasks.push(task);
+    }
+
+    for (_, files) in nested.definitions.iter() {
+        for file in files {
+            if file.status != TaskFileStatus::NotFound
+                && file.path.starts_with(subdir)
+                && !known_files.contains(&file.path)
+            {
+                discovered.definitions.insert(file.clone());
+            }
+        }
+    }
+    discovered.errors.extend(nested.errors);
+}
+
+/// A `.gitignore` pattern together with the directory of the file it came from.
+#[derive(Debug, Clone)]
+pub(crate) struct IgnoreRule {
+    base: PathBuf,
+    pattern: String,
+    /// Patterns containing a slash match against the path relative to `base`
+    anchored: bool,
+}
+
+impl IgnoreRule {
+    pub(crate) fn matches(&self, path: &Path) -> bool {
+        if self.anchored {
+            path.strip_prefix(&self.base)
+                .is_ok_and(|relative| glob_match(&self.pattern, &relative.to_string_lossy()))
+        } else {
+            path.file_name()
+                .is_some_and(|name| glob_match(&self.pattern, &name.to_string_lossy()))
+        }
+    }
+}
+
+/// Read the directory patterns of `dir/.gitignore`. Negated patterns are not supported
+/// and are skipped, which can only cause more directories to be searched.
+pub(crate) fn read_gitignore(dir: &Path) -> Vec<IgnoreRule> {
+    let Ok(contents) = std::fs::read_to_string(dir.join(".gitignore")) else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .filter_map(|line| {
+            let pattern = line.trim_end_matches('/');
+            let anchored = pattern.contains('/');
+            let pattern = pattern.trim_start_matches('/');
+            (!pattern.is_empty()).then(|| IgnoreRule {
+                base: dir.to_path_buf(),
+                pattern: pattern.to_string(),
+                anchored,
+            })
+        })
+        .collect()
+}
+
+fn collect_subdirs(
+    dir: &Path,
+    depth: usize,
+    max_depth: usize,
+    inherited_rules: &[IgnoreRule],
+    subdirs: &mut Vec<PathBuf>,
+) {
+    if depth > max_depth {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut rules = inherited_rules.to_vec();
+    rules.extend(read_gitignore(dir));
+
+    let mut children: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            !SKIPPED_DIRS.contains(&name.as_ref()) && !rules.iter().any(|rule| rule.matches(path))
+        })
+        .collect();
+    children.sort();
+
+    for child in children {
+        subdirs.push(child.clone());
+        collect_subdirs(&child, depth + 1, max_depth, &rules, subdirs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{TestEnvironment, reset_to_real_environment, set_test_environment};
+    use crate::task_shadowing::{enable_mock, reset_mock};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn test_discover_tasks_recursive() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("Makefile"), "build:\n\techo root\n").unwrap();
+        std::fs::write(root.join(".gitignore"), "# generated\n/generated/\n").unwrap();
+        for (dir, target) in [
+            ("api", "build"),
+            ("api/worker", "deploy"),
+            ("web", "serve"),
+            ("generated", "ignored"),
+            ("node_modules/pkg", "ignored"),
+            ("a/b/c/d", "too-deep"),
+        ] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+            std::fs::write(
+                root.join(dir).join("Makefile"),
+                format!("{}:\n\techo {}\n", target, dir),
+            )
+            .unwrap();
+        }
+
+        reset_mock();
+        enable_mock();
+        set_test_environment(TestEnvironment::new().with_executable("make"));
+
+        let discovered = discover_tasks_recursive(root, 3);
+        let mut tasks: Vec<(String, Option<String>, Option<PathBuf>)> = discovered
+            .tasks
+            .iter()
+            .map(|task| {
+                (
+                    task.name.clone(),
+                    task.disambiguated_name.clone(),
+                    task.workspace_dir.clone(),
+                )
+            })
+            .collect();
+        tasks.sort();
+        assert_eq!(
+            tasks,
+            vec![
+                (
+                    "build".to_string(),
+                    Some("build-api-m".to_string()),
+                    Some(PathBuf::from("api"))
+                ),
+                ("build".to_string(), Some("build-m".to_string()), None),
+                (
+                    "deploy".to_string(),
+                    None,
+                    Some(PathBuf::from("api/worker"))
+                ),
+                ("serve".to_string(), None, Some(PathBuf::from("web"))),
+            ]
+        );
+        assert!(
+            discovered
+                .definitions
+                .iter()
+                .flat_map(|(_, files)| files)
+                .any(|file| file.path == root.join("web").join("Makefile"))
+        );
+
+        let shallow = discover_tasks_recursive(root, 1);
+        assert!(!shallow.tasks.iter().any(|task| task.name == "deploy"));
+
+        reset_mock();
+        reset_to_real_environment();
+    }
+}
@@ -1,8 +1,14 @@
 use crate::task_discovery::{
-    TaskDiscovery, cmake::CmakeDiscovery, docker_compose::DockerComposeDiscovery,
-    github_actions::GithubActionsDiscovery, gradle::GradleDiscovery, justfile::JustfileDiscovery,
-    make::MakefileDiscovery, maven::MavenDiscovery, npm::NpmDiscovery, python::PythonDiscovery,
-    shell_scripts::ShellScriptDiscovery, taskfile::TaskfileDiscovery, travis_ci::TravisCiDiscovery,
+    TaskDiscovery, ant::AntDiscovery, bazel::BazelDiscovery, cmake::CmakeDiscovery,
+    docker_bake::DockerBakeDiscovery, docker_compose::DockerComposeDiscovery,
+    dockerfile::DockerfileDiscovery, github_actions::GithubActionsDiscovery,
+    gitlab_ci::GitlabCiDiscovery, gradle::GradleDiscovery, helm::HelmDiscovery,
+    invoke::InvokeDiscovery, justfile::JustfileDiscovery, leiningen::LeiningenDiscovery,
+    mage::MageDiscovery, make::MakefileDiscovery, maven::MavenDiscovery, meson::MesonDiscovery,
+    mise::MiseDiscovery, nox::NoxDiscovery, npm::NpmDiscovery, nx::NxDiscovery,
+    pre_commit::PreCommitDiscovery, procfile::ProcfileDiscovery, python::PythonDiscovery,
+    sbt::SbtDiscovery, scons::SconsDiscovery, shell_scripts::ShellScriptDiscovery,
+    taskfile::TaskfileDiscovery, tox::ToxDiscovery, travis_ci::TravisCiDiscovery,
     turbo::TurboDiscovery,
 };
 
@@ -18,6 +24,24 @@ static DOCKER_COMPOSE_DISCOVERY: DockerComposeDiscovery = DockerComposeDiscovery
 static TRAVIS_CI_DISCOVERY: TravisCiDiscovery = TravisCiDiscovery;
 static CMAKE_DISCOVERY: CmakeDiscovery = CmakeDiscovery;
 static JUSTFILE_DISCOVERY: JustfileDiscovery = JustfileDiscovery;
+static TOX_DISCOVERY: ToxDiscovery = ToxDiscovery;
+static NOX_DISCOVERY: NoxDiscovery = NoxDiscovery;
+static INVOKE_DISCOVERY: InvokeDiscovery = InvokeDiscovery;
+static GITLAB_CI_DISCOVERY: GitlabCiDiscovery = GitlabCiDiscovery;
+static NX_DISCOVERY: NxDiscovery = NxDiscovery;
+static MISE_DISCOVERY: MiseDiscovery = MiseDiscovery;
+static BAZEL_DISCOVERY: BazelDiscovery = BazelDiscovery;
+static MESON_DISCOVERY: MesonDiscovery = MesonDiscovery;
+static SCONS_DISCOVERY: SconsDiscovery = SconsDiscovery;
+static PRE_COMMIT_DISCOVERY: PreCommitDiscovery = PreCommitDiscovery;
+static ANT_DISCOVERY: AntDiscovery = AntDiscovery;
+static SBT_DISCOVERY: SbtDiscovery = SbtDiscovery;
+static LEININGEN_DISCOVERY: LeiningenDiscovery = LeiningenDiscovery;
+static PROCFILE_DISCOVERY: ProcfileDiscovery = ProcfileDiscovery;
+static MAGE_DISCOVERY: MageDiscovery = MageDiscovery;
+static DOCKER_BAKE_DISCOVERY: DockerBakeDiscovery = DockerBakeDiscovery;
+static DOCKERFILE_DISCOVERY: DockerfileDiscovery = DockerfileDiscovery;
+static HELM_DISCOVERY: HelmDiscovery = HelmDiscovery;
 static SHELL_SCRIPT_DISCOVERY: ShellScriptDiscovery = ShellScriptDiscovery;
 
 pub(crate) fn registered_discoveries() -> Vec<&'static dyn TaskDiscovery> {
@@ -34,6 +58,24 @@ pub(crate) fn registered_discoveries() -> Vec<&'static dyn TaskDiscovery> {
         &TRAVIS_CI_DISCOVERY,
         &CMAKE_DISCOVERY,
         &JUSTFILE_DISCOVERY,
+        &TOX_DISCOVERY,
+        &NOX_DISCOVERY,
+        &INVOKE_DISCOVERY,
+        &GITLAB_CI_DISCOVERY,
+        &NX_DISCOVERY,
+        &MISE_DISCOVERY,
+        &BAZEL_DISCOVERY,
+        &MESON_DISCOVERY,
+        &SCONS_DISCOVERY,
+        &PRE_COMMIT_DISCOVERY,
+        &ANT_DISCOVERY,
+        &SBT_DISCOVERY,
+        &LEININGEN_DISCOVERY,
+        &PROCFILE_DISCOVERY,
+        &MAGE_DISCOVERY,
+        &DOCKER_BAKE_DISCOVERY,
+        &DOCKERFILE_DISCOVERY,
+        &HELM_DISCOVERY,
         &SHELL_SCRIPT_DISCOVERY,
     ]
 }
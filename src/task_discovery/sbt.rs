@@ -0,0 +1,45 @@
+use crate::parsers::parse_sbt;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct SbtDiscovery;
+
+impl TaskDiscovery for SbtDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Sbt]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_sbt_tasks(dir, discovered);
+    }
+}
+
+fn discover_sbt_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let sbt_path = dir.join("build.sbt");
+    if !sbt_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: sbt_path,
+                definition_type: TaskDefinitionType::BuildSbt,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_sbt::parse(&sbt_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, sbt_path, TaskDefinitionType::BuildSbt, discovered);
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(error, sbt_path, TaskDefinitionType::BuildSbt, discovered);
+            Err(anyhow::anyhow!("Error parsing build.sbt"))
+        }
+    }
+}
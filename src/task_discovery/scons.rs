@@ -0,0 +1,55 @@
+use crate::parsers::parse_sconstruct;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct SconsDiscovery;
+
+impl TaskDiscovery for SconsDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Scons]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_scons_tasks(dir, discovered);
+    }
+}
+
+fn discover_scons_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let scons_path = dir.join("SConstruct");
+    if !scons_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: scons_path,
+                definition_type: TaskDefinitionType::SConstruct,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_sconstruct::parse(&scons_path) {
+        Ok(tasks) => {
+            handle_discovery_success(
+                tasks,
+                scons_path,
+                TaskDefinitionType::SConstruct,
+                discovered,
+            );
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(
+                error,
+                scons_path,
+                TaskDefinitionType::SConstruct,
+                discovered,
+            );
+            Err(anyhow::anyhow!("Error parsing SConstruct"))
+        }
+    }
+}
@@ -7,6 +7,10 @@ use std::path::Path;
 pub(crate) struct ShellScriptDiscovery;
 
 impl TaskDiscovery for ShellScriptDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::ShellScript]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         discover_shell_script_tasks(dir, discovered);
     }
@@ -41,6 +45,17 @@ fn discover_shell_script_tasks(dir: &Path, discovered: &mut DiscoveredTasks) {
                     description: None,
                     shadowed_by: check_shadowing(&name),
                     disambiguated_name: None,
+                    parameters: Vec::new(),
+                    runnable: true,
+                    dependencies: Vec::new(),
+                    workspace_dir: None,
+                    tags: Vec::new(),
+                    hidden: false,
+                    deprecated: false,
+                    requires_sudo: false,
+                    line: None,
+                    body: None,
+                    kind: None,
                 });
             }
         }
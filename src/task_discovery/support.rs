@@ -1,3 +1,4 @@
+use crate::parsers::parse_sidecar::{load_sidecar, sidecar_path};
 use crate::task_discovery::{DiscoveredTasks, TaskDefinitionFile};
 use crate::task_shadowing::check_shadowing;
 use crate::types::{Task, TaskDefinitionType, TaskFileStatus};
@@ -9,6 +10,31 @@ pub(crate) fn apply_shadowing(tasks: &mut [Task]) {
     }
 }
 
+/// Merge the `<file>.dela.toml` sidecar of each task's definition file onto the task.
+pub(crate) fn apply_sidecars(tasks: &mut [Task], discovered: &mut DiscoveredTasks) {
+    let mut definition_paths: Vec<PathBuf> = tasks
+        .iter()
+        .map(|task| task.definition_path().to_path_buf())
+        .collect();
+    definition_paths.sort();
+    definition_paths.dedup();
+
+    for path in definition_paths {
+        match load_sidecar(&path) {
+            Ok(Some(sidecar)) => tasks
+                .iter_mut()
+                .filter(|task| task.definition_path() == path)
+                .for_each(|task| sidecar.apply(task)),
+            Ok(None) => {}
+            Err(error) => discovered.errors.push(format!(
+                "Failed to parse {}: {}",
+                sidecar_path(&path).display(),
+                error
+            )),
+        }
+    }
+}
+
 pub(crate) fn set_definition(discovered: &mut DiscoveredTasks, definition: TaskDefinitionFile) {
     discovered.definitions.insert(definition);
 }
@@ -41,6 +67,7 @@ pub(crate) fn handle_discovery_success(
     discovered: &mut DiscoveredTasks,
 ) {
     apply_shadowing(&mut tasks);
+    apply_sidecars(&mut tasks, discovered);
     set_definition(
         discovered,
         TaskDefinitionFile {
@@ -1,14 +1,18 @@
 use crate::composed_paths::{ComposedDefinitionSource, RecursiveDiscoveryState, VisitState};
 use crate::parsers::parse_taskfile;
-use crate::task_discovery::support::{apply_shadowing, set_definition};
+use crate::task_discovery::support::{apply_shadowing, apply_sidecars, set_definition};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::collections::HashSet;
 use std::path::Path;
 
 pub(crate) struct TaskfileDiscovery;
 
 impl TaskDiscovery for TaskfileDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Task]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_taskfile_tasks(dir, discovered);
     }
@@ -52,6 +56,7 @@ fn discover_taskfile_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyh
     );
 
     apply_shadowing(&mut tasks);
+    apply_sidecars(&mut tasks, discovered);
     discovered.tasks.extend(tasks);
     discovered.errors.extend(include_errors);
 
@@ -0,0 +1,45 @@
+use crate::parsers::parse_tox_ini;
+use crate::task_discovery::support::{
+    handle_discovery_error, handle_discovery_success, set_definition,
+};
+use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
+use std::path::Path;
+
+pub(crate) struct ToxDiscovery;
+
+impl TaskDiscovery for ToxDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Tox]
+    }
+
+    fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
+        let _ = discover_tox_tasks(dir, discovered);
+    }
+}
+
+fn discover_tox_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow::Result<()> {
+    let tox_path = dir.join("tox.ini");
+    if !tox_path.exists() {
+        set_definition(
+            discovered,
+            TaskDefinitionFile {
+                path: tox_path,
+                definition_type: TaskDefinitionType::ToxIni,
+                status: TaskFileStatus::NotFound,
+            },
+        );
+        return Ok(());
+    }
+
+    match parse_tox_ini::parse(&tox_path) {
+        Ok(tasks) => {
+            handle_discovery_success(tasks, tox_path, TaskDefinitionType::ToxIni, discovered);
+            Ok(())
+        }
+        Err(error) => {
+            handle_discovery_error(error, tox_path, TaskDefinitionType::ToxIni, discovered);
+            Err(anyhow::anyhow!("Error parsing tox.ini"))
+        }
+    }
+}
@@ -3,12 +3,16 @@ use crate::task_discovery::support::{
     handle_discovery_error, handle_discovery_success, set_definition,
 };
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::path::Path;
 
 pub(crate) struct TravisCiDiscovery;
 
 impl TaskDiscovery for TravisCiDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::TravisCi]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_travis_ci_tasks(dir, discovered);
     }
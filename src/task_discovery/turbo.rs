@@ -1,9 +1,9 @@
 use crate::composed_paths::{ComposedDefinitionSource, RecursiveDiscoveryState, VisitState};
 use crate::parsers::parse_turbo_json;
 use crate::repo_root::find_git_repo_root;
-use crate::task_discovery::support::{apply_shadowing, set_definition};
+use crate::task_discovery::support::{apply_shadowing, apply_sidecars, set_definition};
 use crate::task_discovery::{DiscoveredTasks, TaskDiscovery};
-use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus};
+use crate::types::{Task, TaskDefinitionFile, TaskDefinitionType, TaskFileStatus, TaskRunner};
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -25,6 +25,10 @@ pub(crate) enum TurboDiscoverError {
 pub(crate) struct TurboDiscovery;
 
 impl TaskDiscovery for TurboDiscovery {
+    fn runners(&self) -> &'static [TaskRunner] {
+        &[TaskRunner::Turbo]
+    }
+
     fn discover(&self, dir: &Path, discovered: &mut DiscoveredTasks) {
         let _ = discover_turbo_tasks(dir, discovered);
     }
@@ -59,6 +63,7 @@ fn discover_turbo_tasks(dir: &Path, discovered: &mut DiscoveredTasks) -> anyhow:
 
     let mut tasks: Vec<_> = tasks_by_name.into_values().collect();
     apply_shadowing(&mut tasks);
+    apply_sidecars(&mut tasks, discovered);
     discovered.tasks.extend(tasks);
     discovered.errors.extend(config_errors);
 
@@ -1,9 +1,23 @@
-use crate::builtins::check_shell_builtin;
+use crate::builtins::{check_shell_alias, check_shell_builtin};
 use crate::environment::ENVIRONMENT;
 use crate::types::ShadowType;
 use once_cell::sync::Lazy;
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set for the whole process when shadowing is not worth probing for, as in completion.
+static SHADOWING_DISABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Set on this thread while a [`ShadowingPause`] is held.
+    static SHADOWING_PAUSED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether `DELA_SKIP_SHADOW` turned shadow checks off when the process started.
+static SKIP_SHADOW_REQUESTED: Lazy<bool> =
+    Lazy::new(|| skip_shadow_requested(std::env::var("DELA_SKIP_SHADOW").ok().as_deref()));
 
 // Global mock state for tests
 static MOCK_EXECUTABLES: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
@@ -35,9 +49,52 @@ pub fn reset_mock() {
     *USE_MOCK.lock().unwrap() = false;
 }
 
+/// Stop probing shell builtins and PATH for the rest of this process, so no task is
+/// reported as shadowed.
+pub fn disable_shadowing_checks() {
+    SHADOWING_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Skips shadow checks on this thread until it is dropped.
+pub struct ShadowingPause {
+    was_paused: bool,
+}
+
+impl Drop for ShadowingPause {
+    fn drop(&mut self) {
+        SHADOWING_PAUSED.with(|paused| paused.set(self.was_paused));
+    }
+}
+
+/// Stop probing shell builtins and PATH on this thread while the returned guard is held,
+/// for callers that only need how many tasks there are, not their shadowing.
+pub fn pause_shadowing_checks() -> ShadowingPause {
+    ShadowingPause {
+        was_paused: SHADOWING_PAUSED.with(|paused| paused.replace(true)),
+    }
+}
+
+/// Whether a `DELA_SKIP_SHADOW` value asks for shadow checks to be skipped: any value
+/// but an empty one, `0` or `false`.
+fn skip_shadow_requested(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
 /// Check if a task name is shadowed by a shell builtin or PATH executable
 pub fn check_shadowing(task_name: &str) -> Option<ShadowType> {
-    // First check shell builtins
+    if SHADOWING_DISABLED.load(Ordering::Relaxed)
+        || SHADOWING_PAUSED.with(Cell::get)
+        || *SKIP_SHADOW_REQUESTED
+    {
+        return None;
+    }
+
+    // Aliases are expanded before anything else is looked up
+    if let Some(shadow) = check_shell_alias(task_name) {
+        return Some(shadow);
+    }
+
+    // Then check shell builtins
     if let Some(shadow) = check_shell_builtin(task_name) {
         return Some(shadow);
     }
@@ -48,11 +105,30 @@ pub fn check_shadowing(task_name: &str) -> Option<ShadowType> {
 
 /// Check if a command exists in PATH
 pub fn check_path_executable(name: &str) -> Option<ShadowType> {
-    ENVIRONMENT
-        .lock()
-        .unwrap()
-        .check_executable(name)
-        .map(ShadowType::PathExecutable)
+    let env = ENVIRONMENT.lock().unwrap();
+    // Looking in PATH directly saves starting `which` once per task
+    #[cfg(unix)]
+    if env.is_real() && !name.contains('/') {
+        return std::env::var_os("PATH")
+            .and_then(|path| find_in_path(name, &path))
+            .map(ShadowType::PathExecutable);
+    }
+    env.check_executable(name).map(ShadowType::PathExecutable)
+}
+
+/// The first executable file called `name` in the directories of `path`, as `which`
+/// reports it.
+#[cfg(unix)]
+fn find_in_path(name: &str, path: &std::ffi::OsStr) -> Option<String> {
+    use std::os::unix::fs::PermissionsExt;
+    std::env::split_paths(path)
+        .map(|dir| dir.join(name))
+        .find(|candidate| {
+            std::fs::metadata(candidate).is_ok_and(|metadata| {
+                metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+            })
+        })
+        .map(|candidate| candidate.to_string_lossy().into_owned())
 }
 
 #[cfg(test)]
@@ -135,4 +211,85 @@ mod tests {
 
         reset_to_real_environment();
     }
+
+    #[test]
+    fn test_skip_shadow_requested() {
+        assert!(!skip_shadow_requested(None));
+        assert!(!skip_shadow_requested(Some("")));
+        assert!(!skip_shadow_requested(Some("0")));
+        assert!(!skip_shadow_requested(Some("false")));
+        assert!(skip_shadow_requested(Some("1")));
+        assert!(skip_shadow_requested(Some("yes")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_in_path() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let first = temp_dir.path().join("first");
+        let second = temp_dir.path().join("second");
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(second.join("nested")).unwrap();
+        for (dir, name, mode) in [
+            (&first, "notes", 0o644),
+            (&second, "notes", 0o755),
+            (&second, "deploy", 0o755),
+        ] {
+            let file = dir.join(name);
+            std::fs::write(&file, "").unwrap();
+            std::fs::set_permissions(&file, std::fs::Permissions::from_mode(mode)).unwrap();
+        }
+        let path = std::env::join_paths([&first, &second]).unwrap();
+
+        assert_eq!(
+            find_in_path("deploy", &path),
+            Some(second.join("deploy").display().to_string())
+        );
+        // A file that is not executable is passed over, as `which` does
+        assert_eq!(
+            find_in_path("notes", &path),
+            Some(second.join("notes").display().to_string())
+        );
+        // So is a directory
+        assert_eq!(find_in_path("nested", &path), None);
+        assert_eq!(find_in_path("missing", &path), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_shadowing_prefers_aliases() {
+        let env = TestEnvironment::new()
+            .with_shell("/bin/zsh")
+            .with_alias("cd")
+            .with_executable("cd");
+        set_test_environment(env);
+
+        // The shell expands an alias before it looks for a builtin
+        assert_eq!(
+            check_shadowing("cd"),
+            Some(ShadowType::ShellAlias("zsh".to_string()))
+        );
+
+        reset_to_real_environment();
+    }
+
+    #[test]
+    #[serial]
+    fn test_pause_shadowing_checks_lasts_while_held() {
+        set_test_environment(TestEnvironment::new().with_shell("/bin/zsh"));
+
+        {
+            let _pause = pause_shadowing_checks();
+            assert_eq!(check_shadowing("cd"), None);
+            drop(pause_shadowing_checks());
+            assert_eq!(check_shadowing("cd"), None);
+        }
+        assert!(matches!(
+            check_shadowing("cd"),
+            Some(ShadowType::ShellBuiltin(shell)) if shell == "zsh"
+        ));
+
+        reset_to_real_environment();
+    }
 }
@@ -2,16 +2,20 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 /// Information about what shadows a task name
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ShadowType {
     /// Task is shadowed by a shell builtin
     ShellBuiltin(String), // shell name
     /// Task is shadowed by an executable in PATH
     PathExecutable(String), // full path
+    /// Task is shadowed by an alias the user's shell defines
+    ShellAlias(String), // shell name
 }
 
 /// Different types of task definition files supported by dela
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TaskDefinitionType {
     /// Makefile
     Makefile,
@@ -30,21 +34,65 @@ pub enum TaskDefinitionType {
     /// Gradle build files (build.gradle, build.gradle.kts)
     Gradle,
     /// GitHub Actions workflow files
+    #[serde(rename = "github_actions")]
     GitHubActions,
     /// Docker Compose files
     DockerCompose,
     /// Travis CI configuration files
     TravisCi,
     /// CMake CMakeLists.txt files
+    #[serde(rename = "cmake")]
     CMake,
     /// Justfile
     Justfile,
+    /// tox.ini environments
+    ToxIni,
+    /// noxfile.py sessions
+    Noxfile,
+    /// Invoke tasks.py
+    InvokeTasks,
+    /// GitLab CI .gitlab-ci.yml
+    GitlabCi,
+    /// Nx nx.json targetDefaults
+    NxJson,
+    /// Nx project.json targets
+    NxProjectJson,
+    /// mise.toml or .mise.toml
+    MiseToml,
+    /// Executable file tasks in .mise/tasks
+    MiseTaskDir,
+    /// Bazel WORKSPACE, MODULE.bazel or root BUILD file
+    BazelWorkspace,
+    /// Meson meson.build
+    MesonBuild,
+    /// SCons SConstruct
+    #[serde(rename = "sconstruct")]
+    SConstruct,
+    /// pre-commit .pre-commit-config.yaml
+    PreCommitConfig,
+    /// Apache Ant build.xml
+    AntBuildXml,
+    /// sbt build.sbt
+    BuildSbt,
+    /// Leiningen project.clj
+    ProjectClj,
+    /// Procfile or Procfile.dev
+    Procfile,
+    /// Mage magefile.go
+    Magefile,
+    /// Docker Buildx Bake docker-bake.hcl or docker-bake.json
+    DockerBake,
+    /// Dockerfile or *.Dockerfile
+    Dockerfile,
+    /// Helm Chart.yaml
+    HelmChart,
 }
 
 /// Different types of task runners supported by dela.
 /// Each variant represents a specific task runner that can execute tasks.
 /// The runner is selected based on the task definition file type and available commands.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TaskRunner {
     /// Make tasks from Makefile
     /// Used when a Makefile is present in the project root
@@ -55,6 +103,9 @@ pub enum TaskRunner {
     /// Node.js tasks using yarn
     /// Selected when yarn.lock is present, or yarn is the preferred available runner
     NodeYarn,
+    /// Node.js tasks using Yarn 2 or later (Berry)
+    /// Selected instead of NodeYarn when .yarnrc.yml is present or packageManager names yarn@2+
+    NodeYarnBerry,
     /// Node.js tasks using pnpm
     /// Selected when pnpm-lock.yaml is present, or pnpm is the preferred available runner
     NodePnpm,
@@ -70,6 +121,9 @@ pub enum TaskRunner {
     /// Python tasks using poethepoet
     /// Selected when poe is available and no other Python runner is preferred
     PythonPoe,
+    /// Python tasks using Hatch environment scripts
+    /// Selected for scripts under [tool.hatch.envs.*.scripts]
+    PythonHatch,
     /// Shell script tasks
     /// Used for direct execution of shell scripts
     ShellScript,
@@ -96,15 +150,71 @@ pub enum TaskRunner {
     TravisCi,
     /// CMake task runner
     /// Used when CMakeLists.txt is present
+    #[serde(rename = "cmake")]
     CMake,
     /// Just task runner
     /// Used when Justfile is present
     Just,
+    /// Tox task runner
+    /// Used when tox.ini is present
+    Tox,
+    /// Nox task runner
+    /// Used when noxfile.py is present
+    Nox,
+    /// Invoke task runner
+    /// Used when tasks.py is present
+    Invoke,
+    /// GitLab CI job runner
+    /// Used when .gitlab-ci.yml is present (jobs run locally through gitlab-ci-local)
+    GitlabCi,
+    /// Nx monorepo task runner
+    /// Used when nx.json or project.json is present
+    Nx,
+    /// mise task runner (formerly rtx)
+    /// Used when mise.toml, .mise.toml or .mise/tasks is present
+    Mise,
+    /// Bazel build system
+    /// Used when a Bazel workspace is present and bazel is installed
+    Bazel,
+    /// Meson build system
+    /// Used when meson.build is present
+    Meson,
+    /// SCons build tool
+    /// Used when SConstruct is present
+    Scons,
+    /// pre-commit hook manager
+    /// Used when .pre-commit-config.yaml is present
+    PreCommit,
+    /// Apache Ant build tool
+    /// Used when build.xml is present
+    Ant,
+    /// sbt build tool for Scala
+    /// Used when build.sbt is present
+    Sbt,
+    /// Leiningen build tool for Clojure
+    /// Used when project.clj is present
+    Leiningen,
+    /// Heroku-style Procfile processes, run directly in the shell
+    /// Used when a Procfile or Procfile.dev is present
+    Procfile,
+    /// Mage build tool for Go
+    /// Used when magefile.go is present
+    Mage,
+    /// docker buildx bake for multi-image builds
+    /// Used when docker-bake.hcl or docker-bake.json is present
+    DockerBake,
+    /// docker build for the stages of a multi-stage Dockerfile
+    /// Used when a Dockerfile or *.Dockerfile is present
+    Docker,
+    /// Helm package manager for Kubernetes
+    /// Used when Chart.yaml is present and helm is installed
+    Helm,
 }
 
 /// Status of a task definition file
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TaskFileStatus {
     /// File exists and was successfully parsed
     Parsed,
@@ -119,7 +229,7 @@ pub enum TaskFileStatus {
 }
 
 /// Information about a task definition file
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskDefinitionFile {
     /// Path to the task definition file
     pub path: PathBuf,
@@ -162,7 +272,7 @@ impl DiscoveredTaskDefinitions {
 }
 
 /// Represents a discovered task that can be executed
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     /// Name of the task (e.g., "build", "test", "start")
     pub name: String,
@@ -186,6 +296,29 @@ pub struct Task {
     pub shadowed_by: Option<ShadowType>,
     /// Disambiguated task name if the task name is ambiguous
     pub disambiguated_name: Option<String>,
+    /// Inputs the task accepts, written as `name` or `name="default"`
+    pub parameters: Vec<String>,
+    /// False when running the task locally would do nothing, e.g. an empty Makefile recipe
+    pub runnable: bool,
+    /// Tasks this task runs first, named as in its definition file
+    pub dependencies: Vec<String>,
+    /// Subdirectory the task was found in, relative to where recursive discovery started
+    pub workspace_dir: Option<PathBuf>,
+    /// Labels attached through a `<file>.dela.toml` sidecar
+    pub tags: Vec<String>,
+    /// Left out of `dela list` while still being runnable
+    pub hidden: bool,
+    /// Still listed and runnable, but marked as deprecated
+    pub deprecated: bool,
+    /// The task runs a command through `sudo`, which can stop on a password prompt
+    pub requires_sudo: bool,
+    /// 1-based line of the definition file where the task is defined, if known
+    pub line: Option<usize>,
+    /// The command as written in the definition file, e.g. a recipe or script, if kept
+    pub body: Option<String>,
+    /// The kind of target, for runners that run kinds differently, e.g. a Bazel `cc_test`
+    /// rule, a Meson `test` or the tox task for the whole envlist
+    pub kind: Option<String>,
 }
 
 impl Task {
@@ -207,11 +340,20 @@ impl TaskRunner {
             TaskRunner::Make => format!("make {}", task.source_name),
             TaskRunner::NodeNpm => format!("npm run {}", task.source_name),
             TaskRunner::NodeYarn => format!("yarn run {}", task.source_name),
+            TaskRunner::NodeYarnBerry => {
+                // Berry runs scripts directly unless the name is one of its own commands
+                if YARN_BERRY_COMMANDS.contains(&task.source_name.as_str()) {
+                    format!("yarn run {}", task.source_name)
+                } else {
+                    format!("yarn {}", task.source_name)
+                }
+            }
             TaskRunner::NodePnpm => format!("pnpm run {}", task.source_name),
             TaskRunner::NodeBun => format!("bun run {}", task.source_name),
             TaskRunner::PythonUv => format!("uv run {}", task.source_name),
             TaskRunner::PythonPoetry => format!("poetry run {}", task.source_name),
             TaskRunner::PythonPoe => format!("poe {}", task.source_name),
+            TaskRunner::PythonHatch => format!("hatch run {}", task.source_name),
             TaskRunner::ShellScript => format!("./{}", task.source_name),
             TaskRunner::Task => format!("task {} --", task.source_name),
             TaskRunner::Turbo => format!("turbo run {}", task.source_name),
@@ -241,6 +383,108 @@ impl TaskRunner {
                 )
             }
             TaskRunner::Just => format!("just {}", task.source_name),
+            TaskRunner::Tox => {
+                // The task for the whole envlist carries the `envlist` kind
+                if task.kind.as_deref() == Some("envlist") {
+                    "tox".to_string()
+                } else {
+                    format!("tox -e {}", task.source_name)
+                }
+            }
+            TaskRunner::Nox => format!("nox -s {}", task.source_name),
+            TaskRunner::Invoke => format!("invoke {}", task.source_name),
+            TaskRunner::GitlabCi => format!("gitlab-ci-local {}", task.source_name),
+            TaskRunner::Nx => {
+                // project.json targets are qualified with their project, targetDefaults are not
+                if task.source_name.contains(':') {
+                    format!("nx run {}", task.source_name)
+                } else {
+                    format!("nx run-many -t {}", task.source_name)
+                }
+            }
+            TaskRunner::Mise => format!("mise run {}", task.source_name),
+            TaskRunner::Bazel => {
+                // Bazel tasks carry their rule kind, e.g. `cc_test`
+                let is_test = task
+                    .kind
+                    .as_deref()
+                    .is_some_and(|kind| kind.ends_with("_test"));
+                let verb = if is_test { "test" } else { "run" };
+                format!("bazel {} {}", verb, task.source_name)
+            }
+            TaskRunner::Meson => {
+                // Meson tasks carry their target kind, e.g. `test`
+                if task.kind.as_deref() == Some("test") {
+                    format!("meson test {}", task.source_name)
+                } else {
+                    format!("meson compile {}", task.source_name)
+                }
+            }
+            TaskRunner::Scons => {
+                if task.source_name == "scons" {
+                    "scons".to_string()
+                } else {
+                    format!("scons {}", task.source_name)
+                }
+            }
+            TaskRunner::PreCommit => {
+                if task.source_name == "pre-commit" {
+                    "pre-commit run --all-files".to_string()
+                } else {
+                    format!("pre-commit run {} --all-files", task.source_name)
+                }
+            }
+            TaskRunner::Ant => format!("ant {}", task.source_name),
+            TaskRunner::Sbt => format!("sbt {}", task.source_name),
+            TaskRunner::Leiningen => format!("lein {}", task.source_name),
+            TaskRunner::Procfile => {
+                // Procfile tasks carry their command as the body
+                task.body.clone().unwrap_or_default()
+            }
+            TaskRunner::Mage => format!("mage {}", task.source_name),
+            TaskRunner::DockerBake => format!("docker buildx bake {}", task.source_name),
+            TaskRunner::Docker => {
+                let file = match task.file_path.file_name().and_then(|name| name.to_str()) {
+                    Some("Dockerfile") | None => String::new(),
+                    Some(name) => format!(" -f {}", shell_words::quote(name)),
+                };
+                if task.source_name == "docker" {
+                    format!("docker build{} .", file)
+                } else {
+                    format!("docker build{} --target {} .", file, task.source_name)
+                }
+            }
+            // Helm tasks carry every argument after `helm`, including the release and chart
+            TaskRunner::Helm => format!("helm {}", task.source_name),
+        }
+    }
+
+    /// The arguments to append to `get_command` so they reach the task rather than the
+    /// runner. npm needs a `--` before them; `task` already ends its command with one.
+    /// A leading `--` from the user is dropped in favor of the runner's convention.
+    pub fn task_arguments(&self, args: &[String]) -> Vec<String> {
+        let args = match args.split_first() {
+            Some((first, rest)) if first == "--" => rest,
+            _ => args,
+        };
+        if args.is_empty() {
+            return Vec::new();
+        }
+        let separator = match self {
+            TaskRunner::NodeNpm => Some("--".to_string()),
+            _ => None,
+        };
+        separator.into_iter().chain(args.iter().cloned()).collect()
+    }
+
+    /// `get_command` followed by `args`, each quoted for the shell as needed.
+    pub fn get_command_with_args(&self, task: &Task, args: &[String]) -> String {
+        let command = self.get_command(task);
+        let args = self.task_arguments(args);
+        if args.is_empty() {
+            command
+        } else {
+            format!("{} {}", command, shell_words::join(args))
         }
     }
 
@@ -250,11 +494,13 @@ impl TaskRunner {
             TaskRunner::Make => "make",
             TaskRunner::NodeNpm => "npm",
             TaskRunner::NodeYarn => "yarn",
+            TaskRunner::NodeYarnBerry => "yarn-berry",
             TaskRunner::NodePnpm => "pnpm",
             TaskRunner::NodeBun => "bun",
             TaskRunner::PythonUv => "uv",
             TaskRunner::PythonPoetry => "poetry",
             TaskRunner::PythonPoe => "poe",
+            TaskRunner::PythonHatch => "hatch",
             TaskRunner::ShellScript => "sh",
             TaskRunner::Task => "task",
             TaskRunner::Turbo => "turbo",
@@ -265,10 +511,222 @@ impl TaskRunner {
             TaskRunner::TravisCi => "travis",
             TaskRunner::CMake => "cmake",
             TaskRunner::Just => "just",
+            TaskRunner::Tox => "tox",
+            TaskRunner::Nox => "nox",
+            TaskRunner::Invoke => "invoke",
+            TaskRunner::GitlabCi => "gitlab",
+            TaskRunner::Nx => "nx",
+            TaskRunner::Mise => "mise",
+            TaskRunner::Bazel => "bazel",
+            TaskRunner::Meson => "meson",
+            TaskRunner::Scons => "scons",
+            TaskRunner::PreCommit => "pre-commit",
+            TaskRunner::Ant => "ant",
+            TaskRunner::Sbt => "sbt",
+            TaskRunner::Leiningen => "lein",
+            TaskRunner::Procfile => "procfile",
+            TaskRunner::Mage => "mage",
+            TaskRunner::DockerBake => "bake",
+            TaskRunner::Docker => "dockerfile",
+            TaskRunner::Helm => "helm",
+        }
+    }
+
+    /// The files, directories or patterns dela reads this runner's tasks from.
+    pub fn definition_files(&self) -> &'static [&'static str] {
+        match self {
+            TaskRunner::Make => &["GNUmakefile", "makefile", "Makefile"],
+            TaskRunner::NodeNpm
+            | TaskRunner::NodeYarn
+            | TaskRunner::NodeYarnBerry
+            | TaskRunner::NodePnpm
+            | TaskRunner::NodeBun => &["package.json"],
+            TaskRunner::PythonUv
+            | TaskRunner::PythonPoetry
+            | TaskRunner::PythonPoe
+            | TaskRunner::PythonHatch => &["pyproject.toml"],
+            TaskRunner::ShellScript => &["*.sh"],
+            TaskRunner::Task => &crate::parsers::parse_taskfile::SUPPORTED_TASKFILE_NAMES,
+            TaskRunner::Turbo => &["turbo.json"],
+            TaskRunner::Maven => &["pom.xml"],
+            TaskRunner::Gradle => &["build.gradle", "build.gradle.kts"],
+            TaskRunner::Act => &[".github/workflows/*.yml", ".github/workflows/*.yaml"],
+            TaskRunner::DockerCompose => &[
+                "docker-compose.yml",
+                "docker-compose.yaml",
+                "compose.yml",
+                "compose.yaml",
+            ],
+            TaskRunner::TravisCi => &[".travis.yml"],
+            TaskRunner::CMake => &["CMakeLists.txt"],
+            TaskRunner::Just => &["Justfile", "justfile", ".justfile"],
+            TaskRunner::Tox => &["tox.ini"],
+            TaskRunner::Nox => &["noxfile.py"],
+            TaskRunner::Invoke => &["tasks.py"],
+            TaskRunner::GitlabCi => &[".gitlab-ci.yml"],
+            TaskRunner::Nx => &["nx.json", "project.json"],
+            TaskRunner::Mise => &["mise.toml", ".mise.toml", ".mise/tasks"],
+            TaskRunner::Bazel => &[
+                "MODULE.bazel",
+                "WORKSPACE.bazel",
+                "WORKSPACE",
+                "BUILD.bazel",
+                "BUILD",
+            ],
+            TaskRunner::Meson => &["meson.build"],
+            TaskRunner::Scons => &["SConstruct"],
+            TaskRunner::PreCommit => &[".pre-commit-config.yaml"],
+            TaskRunner::Ant => &["build.xml"],
+            TaskRunner::Sbt => &["build.sbt"],
+            TaskRunner::Leiningen => &["project.clj"],
+            TaskRunner::Procfile => &["Procfile"],
+            TaskRunner::Mage => &["magefile.go"],
+            TaskRunner::DockerBake => &["docker-bake.hcl", "docker-bake.json"],
+            TaskRunner::Docker => &["Dockerfile", "*.Dockerfile"],
+            TaskRunner::Helm => &["Chart.yaml"],
+        }
+    }
+
+    /// Every runner dela knows about.
+    pub const ALL: &'static [TaskRunner] = &[
+        TaskRunner::Make,
+        TaskRunner::NodeNpm,
+        TaskRunner::NodeYarn,
+        TaskRunner::NodeYarnBerry,
+        TaskRunner::NodePnpm,
+        TaskRunner::NodeBun,
+        TaskRunner::PythonUv,
+        TaskRunner::PythonPoetry,
+        TaskRunner::PythonPoe,
+        TaskRunner::PythonHatch,
+        TaskRunner::ShellScript,
+        TaskRunner::Task,
+        TaskRunner::Turbo,
+        TaskRunner::Maven,
+        TaskRunner::Gradle,
+        TaskRunner::Act,
+        TaskRunner::DockerCompose,
+        TaskRunner::TravisCi,
+        TaskRunner::CMake,
+        TaskRunner::Just,
+        TaskRunner::Tox,
+        TaskRunner::Nox,
+        TaskRunner::Invoke,
+        TaskRunner::GitlabCi,
+        TaskRunner::Nx,
+        TaskRunner::Mise,
+        TaskRunner::Bazel,
+        TaskRunner::Meson,
+        TaskRunner::Scons,
+        TaskRunner::PreCommit,
+        TaskRunner::Ant,
+        TaskRunner::Sbt,
+        TaskRunner::Leiningen,
+        TaskRunner::Procfile,
+        TaskRunner::Mage,
+        TaskRunner::DockerBake,
+        TaskRunner::Docker,
+        TaskRunner::Helm,
+    ];
+
+    /// Whether a `--runner` or MCP `runner` filter selects this runner. Matching ignores
+    /// case and also accepts aliases, some of which cover a family of runners.
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_lowercase();
+        filter == self.short_name()
+            || RUNNER_FILTER_ALIASES
+                .iter()
+                .any(|(alias, runners)| *alias == filter && runners.contains(self))
+    }
+
+    /// Every name a runner filter accepts: the short names followed by the aliases.
+    pub fn filter_names() -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = Vec::new();
+        for name in TaskRunner::ALL
+            .iter()
+            .map(TaskRunner::short_name)
+            .chain(RUNNER_FILTER_ALIASES.iter().map(|(alias, _)| *alias))
+        {
+            // `yarn` is both a short name and an alias
+            if !names.contains(&name) {
+                names.push(name);
+            }
         }
+        names
     }
 }
 
+/// Filter names accepted in addition to each runner's short name.
+const RUNNER_FILTER_ALIASES: &[(&str, &[TaskRunner])] = &[
+    ("node", NODE_RUNNERS),
+    ("nodejs", NODE_RUNNERS),
+    ("js", NODE_RUNNERS),
+    ("py", PYTHON_RUNNERS),
+    ("python", PYTHON_RUNNERS),
+    ("yarn", &[TaskRunner::NodeYarnBerry]),
+    ("gh", &[TaskRunner::Act]),
+    ("github", &[TaskRunner::Act]),
+    ("docker", &[TaskRunner::DockerCompose]),
+    ("compose", &[TaskRunner::DockerCompose]),
+    ("docker-compose", &[TaskRunner::DockerCompose]),
+    ("maven", &[TaskRunner::Maven]),
+    ("shell", &[TaskRunner::ShellScript]),
+    ("gitlab-ci", &[TaskRunner::GitlabCi]),
+    ("leiningen", &[TaskRunner::Leiningen]),
+];
+
+pub(crate) const NODE_RUNNERS: &[TaskRunner] = &[
+    TaskRunner::NodeNpm,
+    TaskRunner::NodeYarn,
+    TaskRunner::NodeYarnBerry,
+    TaskRunner::NodePnpm,
+    TaskRunner::NodeBun,
+];
+
+/// Yarn Berry commands that take precedence over a script of the same name in `yarn <name>`.
+const YARN_BERRY_COMMANDS: &[&str] = &[
+    "add",
+    "bin",
+    "cache",
+    "config",
+    "constraints",
+    "dedupe",
+    "dlx",
+    "exec",
+    "explain",
+    "info",
+    "init",
+    "install",
+    "link",
+    "node",
+    "npm",
+    "pack",
+    "patch",
+    "patch-commit",
+    "plugin",
+    "rebuild",
+    "remove",
+    "run",
+    "search",
+    "set",
+    "stage",
+    "unlink",
+    "unplug",
+    "up",
+    "upgrade-interactive",
+    "version",
+    "why",
+    "workspace",
+    "workspaces",
+];
+
+pub(crate) const PYTHON_RUNNERS: &[TaskRunner] = &[
+    TaskRunner::PythonUv,
+    TaskRunner::PythonPoetry,
+    TaskRunner::PythonPoe,
+    TaskRunner::PythonHatch,
+];
+
 /// Represents the scope of user approval
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AllowScope {
@@ -280,6 +738,9 @@ pub enum AllowScope {
     File,
     /// Allow all tasks from a directory (recursively)
     Directory,
+    /// Allow all tasks from the directory of a task file and all of its subdirectories,
+    /// matched after resolving symlinks and `..`
+    DirectoryRecursive,
     /// Deny execution
     Deny,
 }
@@ -287,8 +748,9 @@ pub enum AllowScope {
 /// A single allowlist entry
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AllowlistEntry {
-    /// The file or directory path
+    /// The file or directory path. Pattern entries may leave it empty to match anywhere.
     #[serde(
+        default,
         serialize_with = "serialize_path",
         deserialize_with = "deserialize_path"
     )]
@@ -297,6 +759,10 @@ pub struct AllowlistEntry {
     pub scope: AllowScope,
     /// If scope is Task, hold the list of allowed tasks
     pub tasks: Option<Vec<String>>,
+    /// A glob matched against task names, or against definition file paths when it
+    /// contains a `/`, such as `build*` or `**/package.json`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
 }
 
 fn serialize_path<S>(path: &std::path::Path, serializer: S) -> Result<S::Ok, S::Error>
@@ -370,4 +836,225 @@ mod tests {
         // 5. Assert get_all returns None for query on non-inserted key
         assert!(defs.get_all(&TaskDefinitionType::PyprojectToml).is_none());
     }
+
+    #[test]
+    fn test_runner_matches_filter() {
+        assert!(TaskRunner::Make.matches_filter("make"));
+        assert!(TaskRunner::Make.matches_filter("Make"));
+        assert!(TaskRunner::Make.matches_filter(" MAKE "));
+        assert!(!TaskRunner::Make.matches_filter("mak"));
+
+        for runner in [
+            TaskRunner::NodeNpm,
+            TaskRunner::NodeYarn,
+            TaskRunner::NodePnpm,
+            TaskRunner::NodeBun,
+        ] {
+            assert!(runner.matches_filter("node"));
+            assert!(runner.matches_filter("NodeJS"));
+        }
+        assert!(!TaskRunner::Turbo.matches_filter("node"));
+
+        for runner in [
+            TaskRunner::PythonUv,
+            TaskRunner::PythonPoetry,
+            TaskRunner::PythonPoe,
+            TaskRunner::PythonHatch,
+        ] {
+            assert!(runner.matches_filter("py"));
+            assert!(runner.matches_filter("python"));
+        }
+        assert!(TaskRunner::PythonPoetry.matches_filter("poetry"));
+        assert!(!TaskRunner::PythonPoetry.matches_filter("uv"));
+
+        assert!(TaskRunner::Act.matches_filter("gh"));
+        assert!(TaskRunner::DockerCompose.matches_filter("docker compose"));
+        assert!(TaskRunner::DockerCompose.matches_filter("docker"));
+
+        // `docker` is only an alias for Compose, not also the Dockerfile runner
+        let docker: Vec<&TaskRunner> = TaskRunner::ALL
+            .iter()
+            .filter(|runner| runner.matches_filter("docker"))
+            .collect();
+        assert_eq!(docker, vec![&TaskRunner::DockerCompose]);
+        assert!(TaskRunner::Docker.matches_filter("dockerfile"));
+    }
+
+    #[test]
+    fn test_all_runners_have_distinct_short_names() {
+        let mut names: Vec<&str> = TaskRunner::ALL.iter().map(TaskRunner::short_name).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), TaskRunner::ALL.len());
+    }
+
+    #[test]
+    fn test_get_command_with_args_follows_runner_conventions() {
+        let task = |runner: TaskRunner| Task {
+            name: "test".to_string(),
+            file_path: PathBuf::from("/project/file"),
+            definition_path: None,
+            definition_type: TaskDefinitionType::Makefile,
+            runner,
+            source_name: "test".to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+        let args = vec!["--grep".to_string(), "two words".to_string()];
+
+        assert_eq!(
+            TaskRunner::Make.get_command_with_args(&task(TaskRunner::Make), &args),
+            "make test --grep 'two words'"
+        );
+        assert_eq!(
+            TaskRunner::NodeNpm.get_command_with_args(&task(TaskRunner::NodeNpm), &args),
+            "npm run test -- --grep 'two words'"
+        );
+        assert_eq!(
+            TaskRunner::Task.get_command_with_args(&task(TaskRunner::Task), &args),
+            "task test -- --grep 'two words'"
+        );
+
+        // The user's own `--` is replaced by the runner's convention
+        let separated = vec!["--".to_string(), "--nocapture".to_string()];
+        assert_eq!(
+            TaskRunner::Make.get_command_with_args(&task(TaskRunner::Make), &separated),
+            "make test --nocapture"
+        );
+        assert_eq!(
+            TaskRunner::NodeNpm.get_command_with_args(&task(TaskRunner::NodeNpm), &separated),
+            "npm run test -- --nocapture"
+        );
+        assert_eq!(
+            TaskRunner::Make.get_command_with_args(&task(TaskRunner::Make), &[]),
+            "make test"
+        );
+    }
+
+    #[test]
+    fn test_task_serde_round_trip() {
+        let task = Task {
+            name: "test".to_string(),
+            file_path: PathBuf::from("/project"),
+            definition_path: Some(PathBuf::from("/project/.github/workflows/ci.yml")),
+            definition_type: TaskDefinitionType::GitHubActions,
+            runner: TaskRunner::Act,
+            source_name: "test".to_string(),
+            description: Some("Run the tests".to_string()),
+            shadowed_by: Some(ShadowType::ShellBuiltin("zsh".to_string())),
+            disambiguated_name: Some("test-a".to_string()),
+            parameters: vec!["target=\"all\"".to_string()],
+            runnable: false,
+            dependencies: vec!["build".to_string()],
+            workspace_dir: Some(PathBuf::from("packages/api")),
+            tags: vec!["ci".to_string()],
+            hidden: true,
+            deprecated: true,
+            requires_sudo: true,
+            line: Some(12),
+            body: None,
+            kind: None,
+        };
+
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["definition_type"], "github_actions");
+        assert_eq!(json["runner"], "act");
+        assert_eq!(
+            json["shadowed_by"],
+            serde_json::json!({"shell_builtin": "zsh"})
+        );
+        let round_trip: Task = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip, task);
+    }
+
+    #[test]
+    fn test_serde_variant_names() {
+        let name = |value: serde_json::Value| value.as_str().unwrap().to_string();
+        assert_eq!(
+            name(serde_json::to_value(TaskRunner::NodeNpm).unwrap()),
+            "node_npm"
+        );
+        assert_eq!(
+            name(serde_json::to_value(TaskRunner::CMake).unwrap()),
+            "cmake"
+        );
+        assert_eq!(
+            name(serde_json::to_value(TaskDefinitionType::SConstruct).unwrap()),
+            "sconstruct"
+        );
+        assert_eq!(
+            name(serde_json::to_value(TaskFileStatus::NotFound).unwrap()),
+            "not_found"
+        );
+
+        for runner in TaskRunner::ALL {
+            let json = serde_json::to_string(runner).unwrap();
+            assert_eq!(&serde_json::from_str::<TaskRunner>(&json).unwrap(), runner);
+        }
+        let status = TaskFileStatus::ParseError("bad".to_string());
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TaskFileStatus>(&json).unwrap(),
+            status
+        );
+    }
+
+    #[test]
+    fn test_yarn_berry_command() {
+        let task = |name: &str| Task {
+            name: name.to_string(),
+            file_path: PathBuf::from("/project/package.json"),
+            definition_path: None,
+            definition_type: TaskDefinitionType::PackageJson,
+            runner: TaskRunner::NodeYarnBerry,
+            source_name: name.to_string(),
+            description: None,
+            shadowed_by: None,
+            disambiguated_name: None,
+            parameters: Vec::new(),
+            runnable: true,
+            dependencies: Vec::new(),
+            workspace_dir: None,
+            tags: Vec::new(),
+            hidden: false,
+            deprecated: false,
+            requires_sudo: false,
+            line: None,
+            body: None,
+            kind: None,
+        };
+
+        assert_eq!(
+            TaskRunner::NodeYarnBerry.get_command(&task("build")),
+            "yarn build"
+        );
+        // A script named after a yarn command still needs `run`
+        assert_eq!(
+            TaskRunner::NodeYarnBerry.get_command(&task("version")),
+            "yarn run version"
+        );
+        // Berry forwards a `--` to the script, so none is added
+        let args = vec!["--".to_string(), "--watch".to_string()];
+        assert_eq!(
+            TaskRunner::NodeYarnBerry.get_command_with_args(&task("test"), &args),
+            "yarn test --watch"
+        );
+
+        assert!(TaskRunner::NodeYarnBerry.matches_filter("yarn"));
+        assert!(TaskRunner::NodeYarnBerry.matches_filter("node"));
+        let names = TaskRunner::filter_names();
+        assert_eq!(names.iter().filter(|name| **name == "yarn").count(), 1);
+    }
 }
@@ -994,6 +994,25 @@ else
     exit 1
 fi
 
+# Test 34: A failing task's exit code becomes dela's exit code
+echo "\nTest 34: Testing exit code propagation for failing tasks"
+
+mkdir -p /home/testuser/exit_code_project
+printf 'fail:\n\t@exit 3\n' > /home/testuser/exit_code_project/Makefile
+cd /home/testuser/exit_code_project
+
+make_exit_code=0
+make fail > /dev/null 2>&1 || make_exit_code=$?
+exit_code=0
+dela run fail > /dev/null 2>&1 || exit_code=$?
+if [ $make_exit_code -ne 0 ] && [ $exit_code -eq $make_exit_code ]; then
+    echo "${GREEN}✓ dela run exits with the failing task's exit code${NC}"
+else
+    echo "${RED}✗ dela run exited with $exit_code instead of make's exit code $make_exit_code${NC}"
+    exit 1
+fi
+cd /home/testuser/test_project
+
 # Clean up test files
 rm -f duplicate_test.json duplicate_test.mk list_output.txt list_output_long.txt
 